@@ -17,8 +17,9 @@ pub(crate) fn expand_borrow(
         }
     };
 
+    // All the view's lifetimes are tied to the AllStorages borrow
     let mut gat_generics = generics.clone();
-    if let Some(lifetime) = gat_generics.lifetimes_mut().next() {
+    for lifetime in gat_generics.lifetimes_mut() {
         lifetime.lifetime = parse_quote!('__view);
     }
 
@@ -50,6 +50,17 @@ pub(crate) fn expand_borrow_info(
                     }
                 },
             );
+            let field_required = field_type.clone().zip(field_is_default.clone()).map(
+                |(field_type, field_is_default)| {
+                    if field_is_default {
+                        quote!(();)
+                    } else {
+                        quote!(
+                            <#field_type>::required_storages(required);
+                        )
+                    }
+                },
+            );
             let field_tracking =
                 field_type
                     .zip(field_is_default)
@@ -73,12 +84,16 @@ pub(crate) fn expand_borrow_info(
                     ) {
                         #(#field_tracking)*
                     }
+                    fn required_storages(required: &mut Vec<::shipyard::StorageId>) {
+                        #(#field_required)*
+                    }
                 }
             ))
         }
         syn::Fields::Unnamed(fields) => {
             let field_type = fields.unnamed.iter().map(|field| &field.ty);
             let field_type_clone = field_type.clone();
+            let field_type_required = field_type.clone();
 
             Ok(quote!(
                 unsafe impl #impl_generics ::shipyard::BorrowInfo for #name #ty_generics #where_clause {
@@ -90,6 +105,9 @@ pub(crate) fn expand_borrow_info(
                     ) {
                         #(<#field_type>::enable_tracking(enable_tracking_fn);)*
                     }
+                    fn required_storages(required: &mut Vec<::shipyard::StorageId>) {
+                        #(<#field_type_required>::required_storages(required);)*
+                    }
                 }
             ))
         }
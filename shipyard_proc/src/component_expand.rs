@@ -5,7 +5,9 @@ use syn::{Error, Result};
 pub(crate) fn expand_component(
     name: syn::Ident,
     generics: syn::Generics,
+    data: syn::Data,
     attribute_input: Option<&syn::Attribute>,
+    component_attribute: Option<&syn::Attribute>,
 ) -> Result<TokenStream> {
     let tracking = if let Some(tracking_attr) = attribute_input {
         let mut track_insertion = false;
@@ -76,12 +78,69 @@ pub(crate) fn expand_component(
         syn::Ident::new("Untracked", Span::call_site())
     };
 
+    let mut deref = false;
+
+    if let Some(component_attr) = component_attribute {
+        component_attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("deref") {
+                deref = true;
+
+                Ok(())
+            } else {
+                Err(meta.error("Component attribute should be: deref."))
+            }
+        })?;
+    }
+
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    let deref_impl = if deref {
+        let field = match data {
+            syn::Data::Struct(syn::DataStruct { fields, .. }) if fields.len() == 1 => {
+                fields.into_iter().next().unwrap()
+            }
+            _ => {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "Component deref can only be used on structs with a single field.",
+                ))
+            }
+        };
+
+        let field_type = field.ty;
+        let field_access = if let Some(field_name) = field.ident {
+            quote!(#field_name)
+        } else {
+            quote!(0)
+        };
+
+        quote!(
+            impl #impl_generics ::core::ops::Deref for #name #ty_generics #where_clause {
+                type Target = #field_type;
+
+                #[inline]
+                fn deref(&self) -> &Self::Target {
+                    &self.#field_access
+                }
+            }
+
+            impl #impl_generics ::core::ops::DerefMut for #name #ty_generics #where_clause {
+                #[inline]
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.#field_access
+                }
+            }
+        )
+    } else {
+        quote!()
+    };
+
     Ok(quote!(
         impl #impl_generics ::shipyard::Component for #name #ty_generics #where_clause {
             type Tracking = ::shipyard::track::#tracking;
         }
+
+        #deref_impl
     ))
 }
 
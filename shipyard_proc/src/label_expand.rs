@@ -1,7 +1,23 @@
 use proc_macro2::TokenStream;
 use quote::quote;
+use syn::parse_quote;
+
+pub(crate) fn expand_label(name: syn::Ident, mut generics: syn::Generics) -> TokenStream {
+    if !generics.params.is_empty() {
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let predicate: syn::WherePredicate = parse_quote!(
+            #name #ty_generics: ::core::hash::Hash
+                + ::core::fmt::Debug
+                + ::core::cmp::PartialEq
+                + ::core::clone::Clone
+                + ::core::marker::Send
+                + ::core::marker::Sync
+                + 'static
+        );
+
+        generics.make_where_clause().predicates.push(predicate);
+    }
 
-pub(crate) fn expand_label(name: syn::Ident, generics: syn::Generics) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     quote!(
@@ -20,7 +36,7 @@ pub(crate) fn expand_label(name: syn::Ident, generics: syn::Generics) -> TokenSt
                 ::core::hash::Hash::hash(self, &mut state);
             }
             fn dyn_clone(&self) -> Box<dyn ::shipyard::Label> {
-                Box::new(Clone::clone(self))
+                Box::new(::core::clone::Clone::clone(self))
             }
             fn dyn_debug(&self, f: &mut ::core::fmt::Formatter<'_>) -> core::result::Result<(), core::fmt::Error> {
                 ::core::fmt::Debug::fmt(self, f)
@@ -14,12 +14,13 @@ use into_iter_expand::expand_into_iter;
 use label_expand::expand_label;
 use world_borrow_expand::expand_world_borrow;
 
-#[proc_macro_derive(Component, attributes(track))]
+#[proc_macro_derive(Component, attributes(track, component))]
 pub fn component(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(item as syn::DeriveInput);
 
     let name = input.ident;
     let generics = input.generics;
+    let data = input.data;
 
     let attribute_input: Option<&syn::Attribute> = input
         .attrs
@@ -35,7 +36,21 @@ pub fn component(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
                 .unwrap_or(false)
         });
 
-    expand_component(name, generics, attribute_input)
+    let component_attribute: Option<&syn::Attribute> = input
+        .attrs
+        .iter()
+        .filter(|attr| match attr.style {
+            syn::AttrStyle::Outer => true,
+            syn::AttrStyle::Inner(_) => false,
+        })
+        .find(|attr| {
+            attr.path()
+                .get_ident()
+                .map(|ident| ident == "component")
+                .unwrap_or(false)
+        });
+
+    expand_component(name, generics, data, attribute_input, component_attribute)
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
@@ -90,6 +105,9 @@ pub fn borrow_info(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 }
 
 /// Requires `Hash`, `Debug`, `PartialEq`, `Clone`
+///
+/// Works on structs and enums, each enum variant is a distinct label.\
+/// Generic types are supported, the bounds are added to the impl.
 #[proc_macro_derive(Label)]
 pub fn label(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(item as syn::DeriveInput);
@@ -17,12 +17,10 @@ pub(crate) fn expand_world_borrow(
         }
     };
 
+    // All the view's lifetimes are tied to the World borrow
     let mut gat_generics = generics.clone();
-    for generic in gat_generics.params.iter_mut() {
-        if let syn::GenericParam::Lifetime(lifetime) = generic {
-            lifetime.lifetime = parse_quote!('__view);
-            break;
-        }
+    for lifetime in gat_generics.lifetimes_mut() {
+        lifetime.lifetime = parse_quote!('__view);
     }
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -69,7 +67,7 @@ pub(crate) fn expand_world_borrow(
                 impl #impl_generics ::shipyard::WorldBorrow for #name #ty_generics #where_clause {
                     type WorldView<'__view> = #name #gat_ty_generics;
 
-                    fn world_borrow<'__w>(world: & '__w ::shipyard::World, last_run: Option<TrackingTimestamp>, current: TrackingTimestamp) -> core::result::Result<Self::WorldView<'__w>, ::shipyard::error::GetStorage> {
+                    fn world_borrow<'__w>(world: & '__w ::shipyard::World, last_run: Option<::shipyard::TrackingTimestamp>, current: ::shipyard::TrackingTimestamp) -> core::result::Result<Self::WorldView<'__w>, ::shipyard::error::GetStorage> {
                         Ok(#name {
                             #(#field),*
                         })
@@ -87,7 +85,7 @@ pub(crate) fn expand_world_borrow(
                 impl #impl_generics ::shipyard::WorldBorrow for #name #ty_generics #where_clause {
                     type WorldView<'__view> = #name #gat_ty_generics;
 
-                    fn world_borrow<'__w>(world: & '__w ::shipyard::World, last_run: Option<TrackingTimestamp>, current: TrackingTimestamp) -> core::result::Result<Self::WorldView<'__w>, ::shipyard::error::GetStorage> {
+                    fn world_borrow<'__w>(world: & '__w ::shipyard::World, last_run: Option<::shipyard::TrackingTimestamp>, current: ::shipyard::TrackingTimestamp) -> core::result::Result<Self::WorldView<'__w>, ::shipyard::error::GetStorage> {
                         Ok(#name(#(#world_borrow),*))
                     }
                 }
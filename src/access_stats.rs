@@ -0,0 +1,292 @@
+use crate::borrow::Mutability;
+use crate::scheduler::info::TypeInfo;
+use crate::scheduler::Label;
+use crate::storage::StorageId;
+use crate::type_id::TypeId;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::sync::Mutex;
+
+/// Storage accesses of workload systems recorded since [`World::start_access_stats`](crate::World::start_access_stats).
+///
+/// Each run of a system counts once for every storage it borrows, by the borrow's mutability.\
+/// Storages borrowed exclusively are counted even if the system doesn't modify them.\
+/// There is no sampling window, every run since the recording started is counted.\
+/// Only systems run as part of a workload are recorded.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, View, ViewMut, World};
+///
+/// #[derive(Component)]
+/// struct Position(f32);
+///
+/// #[derive(Component)]
+/// struct Velocity(f32);
+///
+/// #[derive(Component)]
+/// struct Log(String);
+///
+/// fn movement(mut positions: ViewMut<Position>, velocities: View<Velocity>) {}
+/// fn render(positions: View<Position>) {}
+/// fn log(mut logs: ViewMut<Log>) {}
+///
+/// let mut world = World::new();
+///
+/// world.add_workload(|| (movement, render, log));
+///
+/// world.start_access_stats();
+/// world.run_default_workload().unwrap();
+/// world.run_default_workload().unwrap();
+///
+/// let stats = world.access_stats();
+///
+/// // Position is also read without Velocity by render
+/// let accessed_together = stats.accessed_together();
+/// assert_eq!(accessed_together.len(), 1);
+/// assert_eq!(accessed_together[0].runs, 2);
+/// assert!(!accessed_together[0].always);
+///
+/// // Log is written but nothing reads it
+/// let never_read = stats.never_read();
+/// assert_eq!(never_read.len(), 1);
+/// assert!(never_read[0].name.contains("Log"));
+/// assert_eq!(never_read[0].exclusive_runs, 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccessStats {
+    systems: Vec<SystemAccessStats>,
+}
+
+/// Accesses of a single system.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SystemAccessStats {
+    /// Name of the system.
+    pub name: String,
+    /// Number of times the system ran.
+    pub runs: u64,
+    /// Storages borrowed by the system.
+    pub storages: Vec<StorageAccessStats>,
+}
+
+/// Number of system runs borrowing a storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageAccessStats {
+    /// Name of the storage's type.
+    pub name: Cow<'static, str>,
+    #[allow(missing_docs)]
+    pub storage_id: StorageId,
+    /// Number of system runs borrowing the storage shared.
+    pub shared_runs: u64,
+    /// Number of system runs borrowing the storage exclusively.
+    pub exclusive_runs: u64,
+}
+
+/// Two storages accessed by the same system runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessedTogether {
+    /// Names of the storages' type.
+    pub storages: [Cow<'static, str>; 2],
+    /// Number of system runs accessing both storages.
+    pub runs: u64,
+    /// `true` if the storages were never accessed one without the other.
+    pub always: bool,
+}
+
+impl AccessStats {
+    /// Returns the accesses of each system, in the order they first ran.
+    pub fn systems(&self) -> &[SystemAccessStats] {
+        &self.systems
+    }
+    /// Returns the accesses of each storage, summed over all systems.
+    pub fn storages(&self) -> Vec<StorageAccessStats> {
+        let mut storages: Vec<StorageAccessStats> = Vec::new();
+
+        for storage in self.systems.iter().flat_map(|system| &system.storages) {
+            match storages
+                .iter_mut()
+                .find(|other| other.storage_id == storage.storage_id)
+            {
+                Some(other) => {
+                    other.shared_runs += storage.shared_runs;
+                    other.exclusive_runs += storage.exclusive_runs;
+                }
+                None => storages.push(storage.clone()),
+            }
+        }
+
+        storages
+    }
+    /// Returns the pairs of storages accessed by the same systems, most accessed first.
+    ///
+    /// Storages [`always`](AccessedTogether::always) accessed together are candidates to be merged in a single component.
+    pub fn accessed_together(&self) -> Vec<AccessedTogether> {
+        let storages = self.storages();
+        let mut pairs: Vec<(StorageId, StorageId, AccessedTogether)> = Vec::new();
+
+        for system in &self.systems {
+            for (i, first) in system.storages.iter().enumerate() {
+                for second in &system.storages[i + 1..] {
+                    let (first, second) = if first.storage_id <= second.storage_id {
+                        (first, second)
+                    } else {
+                        (second, first)
+                    };
+
+                    match pairs.iter_mut().find(|(first_id, second_id, _)| {
+                        *first_id == first.storage_id && *second_id == second.storage_id
+                    }) {
+                        Some((_, _, pair)) => pair.runs += system.runs,
+                        None => pairs.push((
+                            first.storage_id,
+                            second.storage_id,
+                            AccessedTogether {
+                                storages: [first.name.clone(), second.name.clone()],
+                                runs: system.runs,
+                                always: false,
+                            },
+                        )),
+                    }
+                }
+            }
+        }
+
+        let accesses = |storage_id: StorageId| {
+            storages
+                .iter()
+                .find(|storage| storage.storage_id == storage_id)
+                .map(|storage| storage.shared_runs + storage.exclusive_runs)
+                .unwrap_or(0)
+        };
+
+        let mut pairs = pairs
+            .into_iter()
+            .map(|(first, second, mut pair)| {
+                pair.always = accesses(first) == pair.runs && accesses(second) == pair.runs;
+
+                pair
+            })
+            .collect::<Vec<_>>();
+
+        pairs.sort_by_key(|pair| core::cmp::Reverse(pair.runs));
+
+        pairs
+    }
+    /// Returns the storages only ever borrowed exclusively, candidates for deletion.
+    pub fn never_read(&self) -> Vec<StorageAccessStats> {
+        let mut storages = self.storages();
+
+        storages.retain(|storage| storage.shared_runs == 0);
+
+        storages
+    }
+}
+
+/// Counts system runs while access statistics are active.
+pub(crate) struct AccessRecorder {
+    systems: Mutex<AccessRecorderState>,
+}
+
+#[derive(Default)]
+struct AccessRecorderState {
+    /// System index to an index into `systems`
+    lookup: Vec<Option<usize>>,
+    systems: Vec<RecordedSystem>,
+}
+
+struct RecordedSystem {
+    label: Box<dyn Label>,
+    runs: u64,
+    borrows: Vec<TypeInfo>,
+}
+
+impl AccessRecorder {
+    pub(crate) fn new() -> AccessRecorder {
+        AccessRecorder {
+            systems: Mutex::new(AccessRecorderState::default()),
+        }
+    }
+    /// Records a run of the system at `index`, its borrows are only generated the first time it runs.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn record(
+        &self,
+        index: usize,
+        label: &dyn Label,
+        generator: &(dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync),
+    ) {
+        let mut state = self.systems.lock().unwrap_or_else(|err| err.into_inner());
+        let state = &mut *state;
+
+        if state.lookup.len() <= index {
+            state.lookup.resize(index + 1, None);
+        }
+
+        // A `ScheduledWorkload` can run a different system at the same index
+        let recorded = match state.lookup[index] {
+            Some(recorded) if state.systems[recorded].label.dyn_eq(label) => recorded,
+            _ => {
+                let recorded = match state
+                    .systems
+                    .iter()
+                    .position(|system| system.label.dyn_eq(label))
+                {
+                    Some(recorded) => recorded,
+                    None => {
+                        let mut borrows = Vec::new();
+                        generator(&mut borrows);
+
+                        state.systems.push(RecordedSystem {
+                            label: label.dyn_clone(),
+                            runs: 0,
+                            borrows,
+                        });
+
+                        state.systems.len() - 1
+                    }
+                };
+
+                state.lookup[index] = Some(recorded);
+
+                recorded
+            }
+        };
+
+        state.systems[recorded].runs += 1;
+    }
+    /// Returns the accesses recorded so far.
+    pub(crate) fn stats(&self) -> AccessStats {
+        let state = self.systems.lock().unwrap_or_else(|err| err.into_inner());
+
+        AccessStats {
+            systems: state
+                .systems
+                .iter()
+                .map(|system| SystemAccessStats {
+                    name: format!("{:?}", system.label),
+                    runs: system.runs,
+                    storages: system
+                        .borrows
+                        .iter()
+                        .map(|borrow| StorageAccessStats {
+                            name: borrow.name.clone(),
+                            storage_id: borrow.storage_id,
+                            shared_runs: if borrow.mutability == Mutability::Shared {
+                                system.runs
+                            } else {
+                                0
+                            },
+                            exclusive_runs: if borrow.mutability == Mutability::Exclusive {
+                                system.runs
+                            } else {
+                                0
+                            },
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
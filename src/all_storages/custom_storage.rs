@@ -4,6 +4,7 @@ use crate::error;
 use crate::storage::{SBox, Storage, StorageId};
 use alloc::vec::Vec;
 use core::any::type_name;
+use core::panic::Location;
 
 /// Low level access to storage.
 ///
@@ -209,7 +210,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&StorageId::of::<S>());
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow();
+            let storage = unsafe { &*storage.ptr }.borrow();
             drop(storages);
             match storage {
                 Ok(storage) => Ok(ARef::map(storage, |storage| {
@@ -235,7 +236,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&storage_id);
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow();
+            let storage = unsafe { &*storage.ptr }.borrow();
             drop(storages);
             storage.map_err(|err| error::GetStorage::StorageBorrow {
                 name: None,
@@ -254,7 +255,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&StorageId::of::<S>());
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow_mut();
+            let storage = unsafe { &*storage.ptr }.borrow_mut();
             drop(storages);
             match storage {
                 Ok(storage) => Ok(ARefMut::map(storage, |storage| {
@@ -280,7 +281,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&storage_id);
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow_mut();
+            let storage = unsafe { &*storage.ptr }.borrow_mut();
             drop(storages);
             storage.map_err(|err| error::GetStorage::StorageBorrow {
                 name: None,
@@ -295,6 +296,7 @@ impl CustomStorageAccess for AllStorages {
         }
     }
     #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn custom_storage_or_insert<S, F>(&self, f: F) -> Result<ARef<'_, &'_ S>, error::GetStorage>
     where
         S: 'static + Storage + Send + Sync,
@@ -302,11 +304,236 @@ impl CustomStorageAccess for AllStorages {
     {
         self.custom_storage_or_insert_by_id(StorageId::of::<S>(), f)
     }
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn custom_storage_or_insert_by_id<S, F>(
         &self,
         storage_id: StorageId,
         f: F,
     ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
+    where
+        S: 'static + Storage + Send + Sync,
+        F: FnOnce() -> S,
+    {
+        self.storage_or_insert(storage_id, f, self.strict)
+    }
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_send<S, F>(
+        &self,
+        f: F,
+    ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
+    where
+        S: 'static + Storage + Sync,
+        F: FnOnce() -> S,
+    {
+        self.custom_storage_or_insert_non_send_by_id(StorageId::of::<S>(), f)
+    }
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_send_by_id<S, F>(
+        &self,
+        storage_id: StorageId,
+        f: F,
+    ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
+    where
+        S: 'static + Storage + Sync,
+        F: FnOnce() -> S,
+    {
+        self.storage_or_insert_non_send(storage_id, f, self.strict)
+    }
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_sync<S, F>(
+        &self,
+        f: F,
+    ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
+    where
+        S: 'static + Storage + Send,
+        F: FnOnce() -> S,
+    {
+        self.custom_storage_or_insert_non_sync_by_id(StorageId::of::<S>(), f)
+    }
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_sync_by_id<S, F>(
+        &self,
+        storage_id: StorageId,
+        f: F,
+    ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
+    where
+        S: 'static + Storage + Send,
+        F: FnOnce() -> S,
+    {
+        self.storage_or_insert_non_sync(storage_id, f, self.strict)
+    }
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_send_sync<S, F>(
+        &self,
+        f: F,
+    ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
+    where
+        S: 'static + Storage,
+        F: FnOnce() -> S,
+    {
+        self.custom_storage_or_insert_non_send_sync_by_id(StorageId::of::<S>(), f)
+    }
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_send_sync_by_id<S, F>(
+        &self,
+        storage_id: StorageId,
+        f: F,
+    ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
+    where
+        S: 'static + Storage,
+        F: FnOnce() -> S,
+    {
+        self.storage_or_insert_non_send_sync(storage_id, f, self.strict)
+    }
+    #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_mut<S, F>(
+        &self,
+        f: F,
+    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
+    where
+        S: 'static + Storage + Send + Sync,
+        F: FnOnce() -> S,
+    {
+        self.custom_storage_or_insert_mut_by_id(StorageId::of::<S>(), f)
+    }
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_mut_by_id<S, F>(
+        &self,
+        storage_id: StorageId,
+        f: F,
+    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
+    where
+        S: 'static + Storage + Send + Sync,
+        F: FnOnce() -> S,
+    {
+        self.storage_or_insert_mut(storage_id, f, self.strict)
+    }
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_send_mut<S, F>(
+        &self,
+        f: F,
+    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
+    where
+        S: 'static + Storage + Sync,
+        F: FnOnce() -> S,
+    {
+        self.custom_storage_or_insert_non_send_mut_by_id(StorageId::of::<S>(), f)
+    }
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_send_mut_by_id<S, F>(
+        &self,
+        storage_id: StorageId,
+        f: F,
+    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
+    where
+        S: 'static + Storage + Sync,
+        F: FnOnce() -> S,
+    {
+        self.storage_or_insert_non_send_mut(storage_id, f, self.strict)
+    }
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_sync_mut<S, F>(
+        &self,
+        f: F,
+    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
+    where
+        S: 'static + Storage + Send,
+        F: FnOnce() -> S,
+    {
+        self.custom_storage_or_insert_non_sync_mut_by_id(StorageId::of::<S>(), f)
+    }
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_sync_mut_by_id<S, F>(
+        &self,
+        storage_id: StorageId,
+        f: F,
+    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
+    where
+        S: 'static + Storage + Send,
+        F: FnOnce() -> S,
+    {
+        self.storage_or_insert_non_sync_mut(storage_id, f, self.strict)
+    }
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_send_sync_mut<S, F>(
+        &self,
+        f: F,
+    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
+    where
+        S: 'static + Storage,
+        F: FnOnce() -> S,
+    {
+        self.custom_storage_or_insert_non_send_sync_mut_by_id(StorageId::of::<S>(), f)
+    }
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    fn custom_storage_or_insert_non_send_sync_mut_by_id<S, F>(
+        &self,
+        storage_id: StorageId,
+        f: F,
+    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
+    where
+        S: 'static + Storage,
+        F: FnOnce() -> S,
+    {
+        self.storage_or_insert_non_send_sync_mut(storage_id, f, self.strict)
+    }
+    fn iter_storages(&self) -> Vec<ARef<'_, &dyn Storage>> {
+        self.storages
+            .read()
+            .iter()
+            .flat_map(|(storage_id, storage)| unsafe {
+                (*storage.ptr)
+                    .borrow()
+                    .map_err(|err| error::GetStorage::StorageBorrow {
+                        name: None,
+                        id: *storage_id,
+                        borrow: err,
+                    })
+            })
+            .collect()
+    }
+}
+
+impl AllStorages {
+    /// Returns a `MissingStorage` error if `strict` is `true`.\
+    /// Called before creating a missing storage.
+    fn check_strict<S>(storage_id: StorageId, strict: bool) -> Result<(), error::GetStorage> {
+        if strict {
+            Err(error::GetStorage::MissingStorage {
+                name: Some(type_name::<S>()),
+                id: storage_id,
+            })
+        } else {
+            Ok(())
+        }
+    }
+    /// [`CustomStorageAccess::custom_storage_or_insert_by_id`] with `strict` instead of the `AllStorages`' strict mode.
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    pub(crate) fn storage_or_insert<S, F>(
+        &self,
+        storage_id: StorageId,
+        f: F,
+        strict: bool,
+    ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
     where
         S: 'static + Storage + Send + Sync,
         F: FnOnce() -> S,
@@ -314,7 +541,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&storage_id);
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow();
+            let storage = unsafe { &*storage.ptr }.borrow();
             drop(storages);
             match storage {
                 Ok(storage) => Ok(ARef::map(storage, |storage| {
@@ -327,14 +554,17 @@ impl CustomStorageAccess for AllStorages {
                 }),
             }
         } else {
+            Self::check_strict::<S>(storage_id, strict)?;
+
             drop(storages);
+            let location = Location::caller();
             let mut storages = self.storages.write();
 
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new(f()))
-                    .0
+                    .or_insert_with(|| SBox::new(f()).with_location(location))
+                    .ptr
             }
             .borrow()
             .map_err(|err| error::GetStorage::StorageBorrow {
@@ -348,23 +578,14 @@ impl CustomStorageAccess for AllStorages {
             }))
         }
     }
+    /// [`CustomStorageAccess::custom_storage_or_insert_non_send_by_id`] with `strict` instead of the `AllStorages`' strict mode.
     #[cfg(feature = "thread_local")]
-    #[inline]
-    fn custom_storage_or_insert_non_send<S, F>(
-        &self,
-        f: F,
-    ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
-    where
-        S: 'static + Storage + Sync,
-        F: FnOnce() -> S,
-    {
-        self.custom_storage_or_insert_non_send_by_id(StorageId::of::<S>(), f)
-    }
-    #[cfg(feature = "thread_local")]
-    fn custom_storage_or_insert_non_send_by_id<S, F>(
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    pub(crate) fn storage_or_insert_non_send<S, F>(
         &self,
         storage_id: StorageId,
         f: F,
+        strict: bool,
     ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
     where
         S: 'static + Storage + Sync,
@@ -373,7 +594,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&storage_id);
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow();
+            let storage = unsafe { &*storage.ptr }.borrow();
 
             match storage {
                 Ok(storage) => Ok(ARef::map(storage, |storage| {
@@ -386,22 +607,32 @@ impl CustomStorageAccess for AllStorages {
                 }),
             }
         } else {
-            if (self.thread_id_generator)() != self.main_thread_id {
+            Self::check_strict::<S>(storage_id, strict)?;
+
+            let thread_id = (self.thread_id_generator)();
+            if thread_id != self.main_thread_id {
                 return Err(error::GetStorage::StorageBorrow {
                     name: Some(type_name::<S>()),
                     id: StorageId::of::<S>(),
-                    borrow: error::Borrow::WrongThread,
+                    borrow: error::Borrow::WrongThread {
+                        expected: self.main_thread_id,
+                        actual: thread_id,
+                    },
                 });
             }
 
             drop(storages);
+            let location = Location::caller();
             let mut storages = self.storages.write();
 
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_send(f(), self.thread_id_generator.clone()))
-                    .0
+                    .or_insert_with(|| {
+                        SBox::new_non_send(f(), self.thread_id_generator.clone())
+                            .with_location(location)
+                    })
+                    .ptr
             }
             .borrow()
             .map_err(|err| error::GetStorage::StorageBorrow {
@@ -415,23 +646,14 @@ impl CustomStorageAccess for AllStorages {
             }))
         }
     }
+    /// [`CustomStorageAccess::custom_storage_or_insert_non_sync_by_id`] with `strict` instead of the `AllStorages`' strict mode.
     #[cfg(feature = "thread_local")]
-    #[inline]
-    fn custom_storage_or_insert_non_sync<S, F>(
-        &self,
-        f: F,
-    ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
-    where
-        S: 'static + Storage + Send,
-        F: FnOnce() -> S,
-    {
-        self.custom_storage_or_insert_non_sync_by_id(StorageId::of::<S>(), f)
-    }
-    #[cfg(feature = "thread_local")]
-    fn custom_storage_or_insert_non_sync_by_id<S, F>(
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    pub(crate) fn storage_or_insert_non_sync<S, F>(
         &self,
         storage_id: StorageId,
         f: F,
+        strict: bool,
     ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
     where
         S: 'static + Storage + Send,
@@ -440,7 +662,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&storage_id);
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow();
+            let storage = unsafe { &*storage.ptr }.borrow();
 
             match storage {
                 Ok(storage) => Ok(ARef::map(storage, |storage| {
@@ -453,14 +675,17 @@ impl CustomStorageAccess for AllStorages {
                 }),
             }
         } else {
+            Self::check_strict::<S>(storage_id, strict)?;
+
             drop(storages);
+            let location = Location::caller();
             let mut storages = self.storages.write();
 
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_sync(f()))
-                    .0
+                    .or_insert_with(|| SBox::new_non_sync(f()).with_location(location))
+                    .ptr
             }
             .borrow()
             .map_err(|err| error::GetStorage::StorageBorrow {
@@ -474,23 +699,14 @@ impl CustomStorageAccess for AllStorages {
             }))
         }
     }
+    /// [`CustomStorageAccess::custom_storage_or_insert_non_send_sync_by_id`] with `strict` instead of the `AllStorages`' strict mode.
     #[cfg(feature = "thread_local")]
-    #[inline]
-    fn custom_storage_or_insert_non_send_sync<S, F>(
-        &self,
-        f: F,
-    ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
-    where
-        S: 'static + Storage,
-        F: FnOnce() -> S,
-    {
-        self.custom_storage_or_insert_non_send_sync_by_id(StorageId::of::<S>(), f)
-    }
-    #[cfg(feature = "thread_local")]
-    fn custom_storage_or_insert_non_send_sync_by_id<S, F>(
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    pub(crate) fn storage_or_insert_non_send_sync<S, F>(
         &self,
         storage_id: StorageId,
         f: F,
+        strict: bool,
     ) -> Result<ARef<'_, &'_ S>, error::GetStorage>
     where
         S: 'static + Storage,
@@ -499,7 +715,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&storage_id);
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow();
+            let storage = unsafe { &*storage.ptr }.borrow();
 
             match storage {
                 Ok(storage) => Ok(ARef::map(storage, |storage| {
@@ -512,15 +728,22 @@ impl CustomStorageAccess for AllStorages {
                 }),
             }
         } else {
-            if (self.thread_id_generator)() != self.main_thread_id {
+            Self::check_strict::<S>(storage_id, strict)?;
+
+            let thread_id = (self.thread_id_generator)();
+            if thread_id != self.main_thread_id {
                 return Err(error::GetStorage::StorageBorrow {
                     name: Some(type_name::<S>()),
                     id: StorageId::of::<S>(),
-                    borrow: error::Borrow::WrongThread,
+                    borrow: error::Borrow::WrongThread {
+                        expected: self.main_thread_id,
+                        actual: thread_id,
+                    },
                 });
             }
 
             drop(storages);
+            let location = Location::caller();
             let mut storages = self.storages.write();
 
             let storage = unsafe {
@@ -528,8 +751,9 @@ impl CustomStorageAccess for AllStorages {
                     .entry(storage_id)
                     .or_insert_with(|| {
                         SBox::new_non_send_sync(f(), self.thread_id_generator.clone())
+                            .with_location(location)
                     })
-                    .0
+                    .ptr
             }
             .borrow()
             .map_err(|err| error::GetStorage::StorageBorrow {
@@ -543,21 +767,15 @@ impl CustomStorageAccess for AllStorages {
             }))
         }
     }
-    #[inline]
-    fn custom_storage_or_insert_mut<S, F>(
-        &self,
-        f: F,
-    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
-    where
-        S: 'static + Storage + Send + Sync,
-        F: FnOnce() -> S,
-    {
-        self.custom_storage_or_insert_mut_by_id(StorageId::of::<S>(), f)
-    }
-    fn custom_storage_or_insert_mut_by_id<S, F>(
+    /// [`CustomStorageAccess::custom_storage_or_insert_mut_by_id`] with `strict` instead of the `AllStorages`' strict mode.
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    // the returned ARefMut holds the storage's exclusive borrow
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn storage_or_insert_mut<S, F>(
         &self,
         storage_id: StorageId,
         f: F,
+        strict: bool,
     ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
     where
         S: 'static + Storage + Send + Sync,
@@ -566,7 +784,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&storage_id);
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow_mut();
+            let storage = unsafe { &*storage.ptr }.borrow_mut();
             drop(storages);
             match storage {
                 Ok(storage) => Ok(ARefMut::map(storage, |storage| {
@@ -579,14 +797,17 @@ impl CustomStorageAccess for AllStorages {
                 }),
             }
         } else {
+            Self::check_strict::<S>(storage_id, strict)?;
+
             drop(storages);
+            let location = Location::caller();
             let mut storages = self.storages.write();
 
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new(f()))
-                    .0
+                    .or_insert_with(|| SBox::new(f()).with_location(location))
+                    .ptr
             }
             .borrow_mut()
             .map_err(|err| error::GetStorage::StorageBorrow {
@@ -600,23 +821,16 @@ impl CustomStorageAccess for AllStorages {
             }))
         }
     }
+    /// [`CustomStorageAccess::custom_storage_or_insert_non_send_mut_by_id`] with `strict` instead of the `AllStorages`' strict mode.
     #[cfg(feature = "thread_local")]
-    #[inline]
-    fn custom_storage_or_insert_non_send_mut<S, F>(
-        &self,
-        f: F,
-    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
-    where
-        S: 'static + Storage + Sync,
-        F: FnOnce() -> S,
-    {
-        self.custom_storage_or_insert_non_send_mut_by_id(StorageId::of::<S>(), f)
-    }
-    #[cfg(feature = "thread_local")]
-    fn custom_storage_or_insert_non_send_mut_by_id<S, F>(
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    // the returned ARefMut holds the storage's exclusive borrow
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn storage_or_insert_non_send_mut<S, F>(
         &self,
         storage_id: StorageId,
         f: F,
+        strict: bool,
     ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
     where
         S: 'static + Storage + Sync,
@@ -625,7 +839,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&storage_id);
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow_mut();
+            let storage = unsafe { &*storage.ptr }.borrow_mut();
 
             match storage {
                 Ok(storage) => Ok(ARefMut::map(storage, |storage| {
@@ -638,22 +852,32 @@ impl CustomStorageAccess for AllStorages {
                 }),
             }
         } else {
-            if (self.thread_id_generator)() != self.main_thread_id {
+            Self::check_strict::<S>(storage_id, strict)?;
+
+            let thread_id = (self.thread_id_generator)();
+            if thread_id != self.main_thread_id {
                 return Err(error::GetStorage::StorageBorrow {
                     name: Some(type_name::<S>()),
                     id: StorageId::of::<S>(),
-                    borrow: error::Borrow::WrongThread,
+                    borrow: error::Borrow::WrongThread {
+                        expected: self.main_thread_id,
+                        actual: thread_id,
+                    },
                 });
             }
 
             drop(storages);
+            let location = Location::caller();
             let mut storages = self.storages.write();
 
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_send(f(), self.thread_id_generator.clone()))
-                    .0
+                    .or_insert_with(|| {
+                        SBox::new_non_send(f(), self.thread_id_generator.clone())
+                            .with_location(location)
+                    })
+                    .ptr
             }
             .borrow_mut()
             .map_err(|err| error::GetStorage::StorageBorrow {
@@ -667,23 +891,16 @@ impl CustomStorageAccess for AllStorages {
             }))
         }
     }
+    /// [`CustomStorageAccess::custom_storage_or_insert_non_sync_mut_by_id`] with `strict` instead of the `AllStorages`' strict mode.
     #[cfg(feature = "thread_local")]
-    #[inline]
-    fn custom_storage_or_insert_non_sync_mut<S, F>(
-        &self,
-        f: F,
-    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
-    where
-        S: 'static + Storage + Send,
-        F: FnOnce() -> S,
-    {
-        self.custom_storage_or_insert_non_sync_mut_by_id(StorageId::of::<S>(), f)
-    }
-    #[cfg(feature = "thread_local")]
-    fn custom_storage_or_insert_non_sync_mut_by_id<S, F>(
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    // the returned ARefMut holds the storage's exclusive borrow
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn storage_or_insert_non_sync_mut<S, F>(
         &self,
         storage_id: StorageId,
         f: F,
+        strict: bool,
     ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
     where
         S: 'static + Storage + Send,
@@ -692,7 +909,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&storage_id);
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow_mut();
+            let storage = unsafe { &*storage.ptr }.borrow_mut();
 
             match storage {
                 Ok(storage) => Ok(ARefMut::map(storage, |storage| {
@@ -705,14 +922,17 @@ impl CustomStorageAccess for AllStorages {
                 }),
             }
         } else {
+            Self::check_strict::<S>(storage_id, strict)?;
+
             drop(storages);
+            let location = Location::caller();
             let mut storages = self.storages.write();
 
             let storage = unsafe {
                 &*storages
                     .entry(storage_id)
-                    .or_insert_with(|| SBox::new_non_sync(f()))
-                    .0
+                    .or_insert_with(|| SBox::new_non_sync(f()).with_location(location))
+                    .ptr
             }
             .borrow_mut()
             .map_err(|err| error::GetStorage::StorageBorrow {
@@ -726,23 +946,16 @@ impl CustomStorageAccess for AllStorages {
             }))
         }
     }
+    /// [`CustomStorageAccess::custom_storage_or_insert_non_send_sync_mut_by_id`] with `strict` instead of the `AllStorages`' strict mode.
     #[cfg(feature = "thread_local")]
-    #[inline]
-    fn custom_storage_or_insert_non_send_sync_mut<S, F>(
-        &self,
-        f: F,
-    ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
-    where
-        S: 'static + Storage,
-        F: FnOnce() -> S,
-    {
-        self.custom_storage_or_insert_non_send_sync_mut_by_id(StorageId::of::<S>(), f)
-    }
-    #[cfg(feature = "thread_local")]
-    fn custom_storage_or_insert_non_send_sync_mut_by_id<S, F>(
+    #[cfg_attr(feature = "storage_location", track_caller)]
+    // the returned ARefMut holds the storage's exclusive borrow
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn storage_or_insert_non_send_sync_mut<S, F>(
         &self,
         storage_id: StorageId,
         f: F,
+        strict: bool,
     ) -> Result<ARefMut<'_, &'_ mut S>, error::GetStorage>
     where
         S: 'static + Storage,
@@ -751,7 +964,7 @@ impl CustomStorageAccess for AllStorages {
         let storages = self.storages.read();
         let storage = storages.get(&storage_id);
         if let Some(storage) = storage {
-            let storage = unsafe { &*storage.0 }.borrow_mut();
+            let storage = unsafe { &*storage.ptr }.borrow_mut();
 
             match storage {
                 Ok(storage) => Ok(ARefMut::map(storage, |storage| {
@@ -764,15 +977,22 @@ impl CustomStorageAccess for AllStorages {
                 }),
             }
         } else {
-            if (self.thread_id_generator)() != self.main_thread_id {
+            Self::check_strict::<S>(storage_id, strict)?;
+
+            let thread_id = (self.thread_id_generator)();
+            if thread_id != self.main_thread_id {
                 return Err(error::GetStorage::StorageBorrow {
                     name: Some(type_name::<S>()),
                     id: StorageId::of::<S>(),
-                    borrow: error::Borrow::WrongThread,
+                    borrow: error::Borrow::WrongThread {
+                        expected: self.main_thread_id,
+                        actual: thread_id,
+                    },
                 });
             }
 
             drop(storages);
+            let location = Location::caller();
             let mut storages = self.storages.write();
 
             let storage = unsafe {
@@ -780,8 +1000,9 @@ impl CustomStorageAccess for AllStorages {
                     .entry(storage_id)
                     .or_insert_with(|| {
                         SBox::new_non_send_sync(f(), self.thread_id_generator.clone())
+                            .with_location(location)
                     })
-                    .0
+                    .ptr
             }
             .borrow_mut()
             .map_err(|err| error::GetStorage::StorageBorrow {
@@ -795,19 +1016,4 @@ impl CustomStorageAccess for AllStorages {
             }))
         }
     }
-    fn iter_storages(&self) -> Vec<ARef<'_, &dyn Storage>> {
-        self.storages
-            .read()
-            .iter()
-            .flat_map(|(storage_id, storage)| unsafe {
-                (*storage.0)
-                    .borrow()
-                    .map_err(|err| error::GetStorage::StorageBorrow {
-                        name: None,
-                        id: *storage_id,
-                        borrow: err,
-                    })
-            })
-            .collect()
-    }
 }
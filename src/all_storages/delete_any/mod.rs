@@ -1,7 +1,7 @@
 mod custom_delete_any;
 
 use crate::all_storages::AllStorages;
-use crate::storage::{Storage, StorageId};
+use crate::storage::{SBox, Storage, StorageId};
 #[cfg(doc)]
 use crate::world::World;
 use crate::ShipHashSet;
@@ -20,12 +20,18 @@ impl<T: 'static + Storage + CustomDeleteAny> TupleDeleteAny for T {
     fn delete_any(all_storages: &mut AllStorages) {
         let mut ids = ShipHashSet::with_hasher(BuildHasherDefault::default());
 
+        all_storages.assert_mutable(&[], |storage| {
+            storage.as_any().is::<T>() && !storage.is_empty()
+        });
+
         let current = all_storages.get_current();
         let storages = all_storages.storages.get_mut();
 
-        if let Some(storage) = storages.get_mut(&StorageId::of::<T>()) {
-            unsafe { &mut *storage.0 }
-                .get_mut()
+        if let Some(storage) = storages
+            .get_mut(&StorageId::of::<T>())
+            .and_then(SBox::get_mut)
+        {
+            storage
                 .as_any_mut()
                 .downcast_mut::<T>()
                 .unwrap()
@@ -44,12 +50,16 @@ macro_rules! impl_delete_any {
             fn delete_any(all_storages: &mut AllStorages) {
                 let mut ids = ShipHashSet::with_hasher(BuildHasherDefault::default());
 
+                all_storages.assert_mutable(&[], |storage| {
+                    ($(storage.as_any().is::<$storage>())||+) && !storage.is_empty()
+                });
+
                 let current = all_storages.get_current();
                 let storages = all_storages.storages.get_mut();
 
                 $(
-                    if let Some(storage) = storages.get_mut(&StorageId::of::<$storage>()) {
-                        unsafe { &mut *storage.0 }.get_mut().as_any_mut().downcast_mut::<$storage>().unwrap().delete_any(&mut ids, current);
+                    if let Some(storage) = storages.get_mut(&StorageId::of::<$storage>()).and_then(SBox::get_mut) {
+                        storage.as_any_mut().downcast_mut::<$storage>().unwrap().delete_any(&mut ids, current);
                     }
                 )+
 
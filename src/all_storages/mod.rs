@@ -1,16 +1,28 @@
 mod custom_storage;
 mod delete_any;
 mod retain;
+#[cfg(feature = "serde1")]
+mod serialize_query;
+mod snapshot;
 
 pub use custom_storage::CustomStorageAccess;
 pub use delete_any::{CustomDeleteAny, TupleDeleteAny};
 pub use retain::TupleRetainStorage;
+#[cfg(feature = "serde1")]
+pub use serialize_query::{TupleFilter, TupleSerialize};
+pub use snapshot::{PartialSnapshot, TupleSnapshot};
 
+use crate::add_component::AddComponent;
+use crate::archive::ArchivedEntity;
 use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
 use crate::borrow::Borrow;
 use crate::component::{Component, Unique};
-use crate::entities::Entities;
+use crate::drop_queue::DropQueue;
+#[cfg(feature = "thread_local")]
+use crate::drop_queue::LocalDropQueue;
+use crate::entities::{AddComponentPolicy, Entities, ReservationTicket};
 use crate::entity_id::EntityId;
+use crate::entity_mut::EntityMut;
 use crate::get_component::GetComponent;
 use crate::get_unique::GetUnique;
 use crate::iter_component::{IntoIterRef, IterComponent};
@@ -18,21 +30,25 @@ use crate::memory_usage::AllStoragesMemoryUsage;
 use crate::public_transport::RwLock;
 use crate::public_transport::ShipyardRwLock;
 use crate::r#mut::Mut;
+use crate::remove::Remove;
 use crate::reserve::BulkEntityIter;
+use crate::seal::Private;
 use crate::sparse_set::{BulkAddEntity, SparseSet, TupleAddComponent, TupleDelete, TupleRemove};
 #[cfg(feature = "std")]
 use crate::std_thread_id_generator;
-use crate::storage::{SBox, Storage, StorageId};
+use crate::storage::{ExclusiveStorage, SBox, Storage, StorageId, StorageInfo};
 use crate::system::AllSystem;
 use crate::tracking::{TrackingTimestamp, TupleTrack};
-use crate::unique::UniqueStorage;
-use crate::views::EntitiesViewMut;
-use crate::{error, ShipHashMap};
+use crate::unique::{UniqueInfo, UniqueStorage};
+use crate::views::{EntitiesViewMut, View, ViewMut};
+use crate::{error, ShipHashMap, ShipHashSet};
 use alloc::boxed::Box;
 use alloc::sync::Arc;
-use core::any::type_name;
-use core::hash::BuildHasherDefault;
+use alloc::vec::Vec;
+use core::any::{type_name, Any};
+use core::hash::{BuildHasherDefault, Hash};
 use core::marker::PhantomData;
+use core::panic::Location;
 use core::sync::atomic::AtomicU32;
 use hashbrown::hash_map::Entry;
 
@@ -46,11 +62,21 @@ pub struct MissingThreadId;
 pub struct ThreadIdPresent;
 
 pub(crate) struct AllStoragesBuilder<Lock, ThreadId> {
-    custom_lock: Option<Box<dyn ShipyardRwLock + Send + Sync>>,
+    custom_lock: Option<fn() -> Box<dyn ShipyardRwLock + Send + Sync>>,
     custom_thread_id: Option<Arc<dyn Fn() -> u64 + Send + Sync>>,
     _phantom: PhantomData<(Lock, ThreadId)>,
 }
 
+impl<Lock, ThreadId> Clone for AllStoragesBuilder<Lock, ThreadId> {
+    fn clone(&self) -> Self {
+        AllStoragesBuilder {
+            custom_lock: self.custom_lock,
+            custom_thread_id: self.custom_thread_id.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
 impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
     #[cfg(feature = "std")]
     pub(crate) fn new() -> AllStoragesBuilder<LockPresent, ThreadIdPresent> {
@@ -83,7 +109,7 @@ impl<Lock, ThreadId> AllStoragesBuilder<Lock, ThreadId> {
         self,
     ) -> AllStoragesBuilder<LockPresent, ThreadId> {
         AllStoragesBuilder {
-            custom_lock: Some(L::new()),
+            custom_lock: Some(L::new),
             custom_thread_id: self.custom_thread_id,
             _phantom: PhantomData,
         }
@@ -109,7 +135,7 @@ impl AllStoragesBuilder<LockPresent, ThreadIdPresent> {
         storages.insert(StorageId::of::<Entities>(), SBox::new(Entities::new()));
 
         let storages = if let Some(custom_lock) = self.custom_lock {
-            RwLock::new_custom(custom_lock, storages)
+            RwLock::new_custom((custom_lock)(), storages)
         } else {
             #[cfg(feature = "std")]
             {
@@ -134,13 +160,20 @@ impl AllStoragesBuilder<LockPresent, ThreadIdPresent> {
                     main_thread_id,
                     thread_id_generator: thread_id_generator.clone(),
                     counter,
+                    strict: false,
+                    entity_names_in_errors: false,
                 },
                 thread_id_generator,
             )
         }
         #[cfg(not(feature = "thread_local"))]
         {
-            AtomicRefCell::new(AllStorages { storages, counter })
+            AtomicRefCell::new(AllStorages {
+                storages,
+                counter,
+                strict: false,
+                entity_names_in_errors: false,
+            })
         }
     }
 }
@@ -160,6 +193,10 @@ pub struct AllStorages {
     #[cfg(feature = "thread_local")]
     thread_id_generator: Arc<dyn Fn() -> u64 + Send + Sync>,
     counter: Arc<AtomicU32>,
+    /// Borrowing a missing storage returns an error instead of creating it
+    pub(crate) strict: bool,
+    /// Missing component errors include the entity's `Name`
+    pub(crate) entity_names_in_errors: bool,
 }
 
 #[cfg(not(feature = "thread_local"))]
@@ -181,6 +218,8 @@ impl AllStorages {
             #[cfg(feature = "thread_local")]
             thread_id_generator: Arc::new(std_thread_id_generator),
             counter,
+            strict: false,
+            entity_names_in_errors: false,
         }
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
@@ -202,70 +241,262 @@ impl AllStorages {
     ///
     /// [`UniqueView`]: crate::UniqueView
     /// [`UniqueViewMut`]: crate::UniqueViewMut
+    #[cfg_attr(
+        feature = "thread_local",
+        doc = "\n### Panics\n\n- A `T` unique was added with a different thread safety, with `add_unique_non_send` for example."
+    )]
+    #[track_caller]
     pub fn add_unique<T: Send + Sync + Unique>(&self, component: T) {
-        let storage_id = StorageId::of::<UniqueStorage<T>>();
+        #[cfg(feature = "thread_local")]
+        if let Err(err) = self.try_add_unique(component) {
+            panic!("{:?}", err);
+        }
 
+        #[cfg(not(feature = "thread_local"))]
         self.storages
             .write()
-            .entry(storage_id)
-            .insert(SBox::new(UniqueStorage::new(
-                component,
-                self.get_tracking_timestamp(),
-            )));
+            .entry(StorageId::of::<UniqueStorage<T>>())
+            .insert(
+                SBox::new(UniqueStorage::new(component, self.get_tracking_timestamp()))
+                    .with_location(Location::caller()),
+            );
+    }
+    /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
+    /// Returns an error if a `T` unique was added with a different thread safety, with `add_unique_non_send` for example.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, AllStoragesViewMut, Unique, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct USIZE(usize);
+    ///
+    /// let world = World::new();
+    /// let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_unique_non_send(USIZE(0));
+    ///
+    /// assert_eq!(
+    ///     all_storages.try_add_unique(USIZE(1)).unwrap_err().existing,
+    ///     error::ThreadSafety::NonSend
+    /// );
+    /// ```
+    #[cfg(feature = "thread_local")]
+    #[track_caller]
+    pub fn try_add_unique<T: Send + Sync + Unique>(
+        &self,
+        component: T,
+    ) -> Result<(), error::DuplicateUnique> {
+        let location = Location::caller();
+
+        self.insert_unique::<T>(error::ThreadSafety::SendSync, true, || {
+            SBox::new(UniqueStorage::new(component, self.get_tracking_timestamp()))
+                .with_location(location)
+        })
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
     /// To access a unique storage value, use [NonSend] and [UniqueViewMut] or [UniqueViewMut].  
     /// Does nothing if the storage already exists.
     ///
+    /// ### Panics
+    ///
+    /// - A `T` unique was added with a different thread safety, with `add_unique` for example.
+    ///
     /// [NonSend]: crate::NonSend
     /// [UniqueView]: crate::UniqueView
     /// [UniqueViewMut]: crate::UniqueViewMut
     #[cfg(feature = "thread_local")]
+    #[track_caller]
     pub fn add_unique_non_send<T: Sync + Unique>(&self, component: T) {
-        if (self.thread_id_generator)() == self.main_thread_id {
-            let storage_id = StorageId::of::<UniqueStorage<T>>();
-
-            self.storages.write().entry(storage_id).or_insert_with(|| {
-                SBox::new_non_send(
-                    UniqueStorage::new(component, self.get_tracking_timestamp()),
-                    self.thread_id_generator.clone(),
-                )
-            });
+        if let Err(err) = self.try_add_unique_non_send(component) {
+            panic!("{:?}", err);
         }
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
+    /// Does nothing if the storage already exists.  
+    /// Returns an error if a `T` unique was added with a different thread safety, with `add_unique` for example.
+    #[cfg(feature = "thread_local")]
+    #[track_caller]
+    pub fn try_add_unique_non_send<T: Sync + Unique>(
+        &self,
+        component: T,
+    ) -> Result<(), error::DuplicateUnique> {
+        if (self.thread_id_generator)() != self.main_thread_id {
+            return Ok(());
+        }
+
+        let location = Location::caller();
+
+        self.insert_unique::<T>(error::ThreadSafety::NonSend, false, || {
+            SBox::new_non_send(
+                UniqueStorage::new(component, self.get_tracking_timestamp()),
+                self.thread_id_generator.clone(),
+            )
+            .with_location(location)
+        })
+    }
+    /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
     /// To access a unique storage value, use [NonSync] and [UniqueViewMut] or [UniqueViewMut].  
     /// Does nothing if the storage already exists.
     ///
+    /// ### Panics
+    ///
+    /// - A `T` unique was added with a different thread safety, with `add_unique` for example.
+    ///
     /// [NonSync]: crate::NonSync
     /// [UniqueView]: crate::UniqueView
     /// [UniqueViewMut]: crate::UniqueViewMut
     #[cfg(feature = "thread_local")]
+    #[track_caller]
     pub fn add_unique_non_sync<T: Send + Unique>(&self, component: T) {
-        let storage_id = StorageId::of::<UniqueStorage<T>>();
+        if let Err(err) = self.try_add_unique_non_sync(component) {
+            panic!("{:?}", err);
+        }
+    }
+    /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
+    /// Does nothing if the storage already exists.  
+    /// Returns an error if a `T` unique was added with a different thread safety, with `add_unique` for example.
+    #[cfg(feature = "thread_local")]
+    #[track_caller]
+    pub fn try_add_unique_non_sync<T: Send + Unique>(
+        &self,
+        component: T,
+    ) -> Result<(), error::DuplicateUnique> {
+        let location = Location::caller();
 
-        self.storages.write().entry(storage_id).or_insert_with(|| {
+        self.insert_unique::<T>(error::ThreadSafety::NonSync, false, || {
             SBox::new_non_sync(UniqueStorage::new(component, self.get_tracking_timestamp()))
-        });
+                .with_location(location)
+        })
     }
     /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
     /// To access a unique storage value, use [NonSync] and [UniqueViewMut] or [UniqueViewMut].  
     /// Does nothing if the storage already exists.  
     ///
+    /// ### Panics
+    ///
+    /// - A `T` unique was added with a different thread safety, with `add_unique` for example.
+    ///
     /// [NonSync]: crate::NonSync
     /// [UniqueView]: crate::UniqueView
     /// [UniqueViewMut]: crate::UniqueViewMut
     #[cfg(feature = "thread_local")]
+    #[track_caller]
     pub fn add_unique_non_send_sync<T: Unique>(&self, component: T) {
-        if (self.thread_id_generator)() == self.main_thread_id {
-            let storage_id = StorageId::of::<UniqueStorage<T>>();
-
-            self.storages.write().entry(storage_id).or_insert_with(|| {
-                SBox::new_non_send_sync(
-                    UniqueStorage::new(component, self.get_tracking_timestamp()),
-                    self.thread_id_generator.clone(),
-                )
-            });
+        if let Err(err) = self.try_add_unique_non_send_sync(component) {
+            panic!("{:?}", err);
+        }
+    }
+    /// Adds a new unique storage, unique storages store exactly one `T` at any time.  
+    /// Does nothing if the storage already exists.  
+    /// Returns an error if a `T` unique was added with a different thread safety, with `add_unique` for example.
+    #[cfg(feature = "thread_local")]
+    #[track_caller]
+    pub fn try_add_unique_non_send_sync<T: Unique>(
+        &self,
+        component: T,
+    ) -> Result<(), error::DuplicateUnique> {
+        if (self.thread_id_generator)() != self.main_thread_id {
+            return Ok(());
+        }
+
+        let location = Location::caller();
+
+        self.insert_unique::<T>(error::ThreadSafety::NonSendSync, false, || {
+            SBox::new_non_send_sync(
+                UniqueStorage::new(component, self.get_tracking_timestamp()),
+                self.thread_id_generator.clone(),
+            )
+            .with_location(location)
+        })
+    }
+    /// Inserts the `T` unique storage created by `sbox`, unless a `T` unique is already present.\
+    /// Returns an error if the present unique has a thread safety other than `added`, otherwise it is only replaced if `replace` is `true`.
+    ///
+    /// The check and the insertion are made under the same lock so two threads can't add a `T` unique with different thread safety.
+    #[cfg(feature = "thread_local")]
+    fn insert_unique<T: Unique>(
+        &self,
+        added: error::ThreadSafety,
+        replace: bool,
+        sbox: impl FnOnce() -> SBox,
+    ) -> Result<(), error::DuplicateUnique> {
+        match self
+            .storages
+            .write()
+            .entry(StorageId::of::<UniqueStorage<T>>())
+        {
+            Entry::Occupied(mut entry) => {
+                let existing = entry.get().thread_safety();
+
+                if existing != added {
+                    return Err(error::DuplicateUnique {
+                        name: type_name::<T>(),
+                        existing,
+                        added,
+                        location: entry.get().location(),
+                    });
+                }
+
+                if replace {
+                    entry.insert(sbox());
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(sbox());
+            }
+        }
+
+        Ok(())
+    }
+    /// Returns the id of the thread `!Send` storages are bound to when created.
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    pub fn main_thread_id(&self) -> u64 {
+        self.main_thread_id
+    }
+    /// Returns the `!Send` storages and the id of the thread they are bound to.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, StorageId, Unique, UniqueStorage, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// // I'm using `u32` here but imagine it's a `!Send` type
+    /// all_storages.add_unique_non_send(U32(0));
+    ///
+    /// assert_eq!(
+    ///     all_storages.non_send_storages(),
+    ///     [(
+    ///         StorageId::of::<UniqueStorage<U32>>(),
+    ///         all_storages.main_thread_id()
+    ///     )]
+    /// );
+    /// ```
+    #[cfg(feature = "thread_local")]
+    pub fn non_send_storages(&self) -> Vec<(StorageId, u64)> {
+        self.storages
+            .read()
+            .iter()
+            .filter_map(|(&storage_id, sbox)| {
+                sbox.bound_thread().map(|thread_id| (storage_id, thread_id))
+            })
+            .collect()
+    }
+    /// Binds the main thread and all `!Send` storages to the current thread.\
+    /// Storages shared with another `World` stay bound to their thread.
+    #[cfg(feature = "thread_local")]
+    pub(crate) fn rebind_main_thread(&mut self) {
+        self.main_thread_id = (self.thread_id_generator)();
+
+        for sbox in self.storages.get_mut().values_mut() {
+            sbox.rebind_thread();
         }
     }
     /// Removes a unique storage.
@@ -301,7 +532,7 @@ impl AllStorages {
 
             let storage = if let Entry::Occupied(entry) = storages.entry(storage_id) {
                 // `.err()` to avoid borrowing `entry` in the `Ok` case
-                if let Some(err) = unsafe { &*entry.get().0 }.borrow_mut().err() {
+                if let Some(err) = unsafe { &*entry.get().ptr }.borrow_mut().err() {
                     return Err(error::UniqueRemove::StorageBorrow((type_name::<T>(), err)));
                 } else {
                     // We were able to lock the storage, we've still got exclusive access even though
@@ -313,7 +544,7 @@ impl AllStorages {
             };
 
             let unique: Box<AtomicRefCell<UniqueStorage<T>>> =
-                unsafe { Box::from_raw(storage.0 as *mut AtomicRefCell<UniqueStorage<T>>) };
+                unsafe { Box::from_raw(storage.ptr as *mut AtomicRefCell<UniqueStorage<T>>) };
 
             core::mem::forget(storage);
 
@@ -323,6 +554,11 @@ impl AllStorages {
     /// Delete an entity and all its components.
     /// Returns `true` if `entity` was alive.
     ///
+    /// ### Panics
+    ///
+    /// - `entity` has a component in an immutable storage, see [`World::make_immutable`](crate::World::make_immutable).\
+    ///   Nothing is modified in this case.
+    ///
     /// ### Example
     ///
     /// ```
@@ -349,14 +585,17 @@ impl AllStorages {
     ///     assert_eq!(u32s.get(entity2), Ok(&U32(3)));
     /// });
     /// ```
+    #[track_caller]
     pub fn delete_entity(&mut self, entity: EntityId) -> bool {
+        self.assert_mutable(&[], |storage| storage_contains(storage, entity));
+
         // no need to lock here since we have a unique access
         let mut entities = self.entities_mut().unwrap();
 
         if entities.delete_unchecked(entity) {
             drop(entities);
 
-            self.strip(entity);
+            self.strip_unchecked(entity);
 
             true
         } else {
@@ -365,6 +604,11 @@ impl AllStorages {
     }
     /// Deletes all components from an entity without deleting it.
     ///
+    /// ### Panics
+    ///
+    /// - `entity` has a component in an immutable storage, see [`World::make_immutable`](crate::World::make_immutable).\
+    ///   Nothing is modified in this case.
+    ///
     /// ### Example
     ///
     /// ```
@@ -385,11 +629,182 @@ impl AllStorages {
     /// ```
     #[track_caller]
     pub fn strip(&mut self, entity: EntityId) {
+        self.assert_mutable(&[], |storage| storage_contains(storage, entity));
+
+        self.strip_unchecked(entity);
+    }
+    /// Deletes all components from an entity, skipping immutable storages.
+    fn strip_unchecked(&mut self, entity: EntityId) {
         let current = self.get_current();
 
         for storage in self.storages.get_mut().values_mut() {
-            unsafe { &mut *storage.0 }.get_mut().delete(entity, current);
+            if let Some(storage) = storage.get_mut() {
+                storage.delete(entity, current);
+            }
+        }
+    }
+    /// Hides all components of `entity` from views and iteration without deleting them.\
+    /// The components can still be accessed with [`SparseSet::get_disabled`].\
+    /// Returns `true` if `entity` is alive.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` has a component in an immutable storage, see [`World::make_immutable`](crate::World::make_immutable).\
+    ///   Nothing is modified in this case.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Get, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(U32(0));
+    ///
+    /// all_storages.disable_entity(entity);
+    ///
+    /// all_storages.run(|u32s: View<U32>| {
+    ///     assert!(u32s.get(entity).is_err());
+    ///     assert_eq!(u32s.get_disabled(entity), Some(&U32(0)));
+    /// });
+    /// ```
+    ///
+    /// [`SparseSet::get_disabled`]: crate::sparse_set::SparseSet::get_disabled
+    #[track_caller]
+    pub fn disable_entity(&mut self, entity: EntityId) -> bool {
+        self.assert_mutable(&[], |storage| storage_contains(storage, entity));
+
+        if !self
+            .exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .is_alive(entity)
+        {
+            return false;
+        }
+
+        for storage in self.storages.get_mut().values_mut() {
+            if let Some(storage) = storage.get_mut() {
+                storage.disable(entity, Private);
+            }
+        }
+
+        true
+    }
+    /// Makes all components of `entity` hidden by [`AllStorages::disable_entity`] visible again.\
+    /// Components added to `entity` while it was disabled replace the disabled ones.\
+    /// Returns `true` if `entity` is alive.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Get, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(U32(0));
+    ///
+    /// all_storages.disable_entity(entity);
+    /// all_storages.enable_entity(entity);
+    ///
+    /// all_storages.run(|u32s: View<U32>| {
+    ///     assert_eq!(u32s.get(entity), Ok(&U32(0)));
+    /// });
+    /// ```
+    pub fn enable_entity(&mut self, entity: EntityId) -> bool {
+        if !self
+            .exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .is_alive(entity)
+        {
+            return false;
+        }
+
+        for storage in self.storages.get_mut().values_mut() {
+            if let Some(storage) = storage.get_mut() {
+                storage.enable(entity, Private);
+            }
+        }
+
+        true
+    }
+    /// Increments the generation of `entity` and moves its components to the new id.\
+    /// All copies of the previous id become dead, while the entity keeps its components.\
+    /// Returns the new id.
+    ///
+    /// Components storing the previous id, a hierarchy for example, are not updated.\
+    /// Components of custom storages keep the previous id.\
+    /// Storages shared with another `World` are skipped, their components keep the previous id.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive.
+    /// - `entity`'s generation can't be increased.
+    /// - `entity` has a component in an immutable storage, see [`World::make_immutable`](crate::World::make_immutable).
+    ///
+    /// Nothing is modified in these cases.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, Get, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(U32(0));
+    ///
+    /// let new_entity = all_storages.bump_generation(entity).unwrap();
+    ///
+    /// assert!(!all_storages.is_entity_alive(entity));
+    /// all_storages.run(|u32s: View<U32>| {
+    ///     assert!(u32s.get(entity).is_err());
+    ///     assert_eq!(u32s.get(new_entity), Ok(&U32(0)));
+    /// });
+    /// ```
+    pub fn bump_generation(&mut self, entity: EntityId) -> Result<EntityId, error::BumpGeneration> {
+        if !self
+            .exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .is_alive(entity)
+        {
+            return Err(error::BumpGeneration::EntityIsNotAlive);
+        }
+
+        // immutable storages are checked before modifying anything
+        // to not leave components under the previous id
+        for (&storage_id, sbox) in self.storages.get_mut().iter() {
+            if let Some(storage) = sbox.immutable() {
+                if storage_contains(storage, entity) {
+                    return Err(error::BumpGeneration::ImmutableStorage(storage_id));
+                }
+            }
+        }
+
+        let new_entity = self
+            .exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .bump_generation(entity)
+            .ok_or(error::BumpGeneration::MaxGeneration)?;
+
+        // shared and immutable storages are skipped
+        for storage in self.storages.get_mut().values_mut() {
+            if let Some(storage) = storage.get_mut() {
+                storage.bump_generation(entity, new_entity, Private);
+            }
         }
+
+        Ok(new_entity)
     }
     /// Deletes all components of an entity except the ones passed in `S`.  
     /// The storage's type has to be used and not the component.  
@@ -419,18 +834,34 @@ impl AllStorages {
     /// Deletes all components of an entity except the ones passed in `S`.  
     /// This is identical to `retain_storage` but uses `StorageId` and not generics.  
     /// You should only use this method if you use a custom storage with a runtime id.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` has a component in an immutable storage not in `excluded_storage`, see [`World::make_immutable`](crate::World::make_immutable).\
+    ///   Nothing is modified in this case.
     #[track_caller]
     pub fn retain_storage_by_id(&mut self, entity: EntityId, excluded_storage: &[StorageId]) {
+        self.assert_mutable(excluded_storage, |storage| {
+            storage_contains(storage, entity)
+        });
+
         let current = self.get_current();
 
         for (storage_id, storage) in self.storages.get_mut().iter_mut() {
             if !excluded_storage.contains(storage_id) {
-                unsafe { &mut *storage.0 }.get_mut().delete(entity, current);
+                if let Some(storage) = storage.get_mut() {
+                    storage.delete(entity, current);
+                }
             }
         }
     }
     /// Deletes all entities and components in the `World`.
     ///
+    /// ### Panics
+    ///
+    /// - An immutable storage isn't empty, see [`World::make_immutable`](crate::World::make_immutable).\
+    ///   Nothing is modified in this case.
+    ///
     /// ### Example
     ///
     /// ```
@@ -443,31 +874,102 @@ impl AllStorages {
     /// ```
     #[track_caller]
     pub fn clear(&mut self) {
+        self.assert_mutable(&[], |storage| !storage.is_empty());
+
         let current = self.get_current();
 
         for storage in self.storages.get_mut().values_mut() {
-            unsafe { &mut *storage.0 }.get_mut().clear(current);
+            if let Some(storage) = storage.get_mut() {
+                storage.clear(current);
+            }
         }
     }
-    /// Clear all deletion and removal tracking data.
+    /// In strict mode, borrowing a storage that doesn't exist returns a [`MissingStorage`] error instead of creating it.\
+    /// Storages can still be created with [`AllStorages::add_storage`], by adding components to an entity or by enabling tracking.
+    ///
+    /// [`MissingStorage`]: error::GetStorage::MissingStorage
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+    /// Returns `true` if borrowing a storage that doesn't exist returns an error instead of creating it.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+    /// When enabled, panics from indexing a view with an entity missing the component include the entity's [`Name`](crate::Name).
+    pub fn set_entity_names_in_errors(&mut self, enabled: bool) {
+        self.entity_names_in_errors = enabled;
+    }
+    /// Returns `true` if panics about a missing component include the entity's [`Name`](crate::Name).
+    pub fn entity_names_in_errors(&self) -> bool {
+        self.entity_names_in_errors
+    }
+    /// Returns `self` when panics about a missing component include the entity's [`Name`](crate::Name).
+    pub(crate) fn entity_names(&self) -> Option<&AllStorages> {
+        self.entity_names_in_errors.then_some(self)
+    }
+    /// Creates `T`'s storage if it doesn't exist.
     #[track_caller]
-    pub fn clear_all_removed_and_deleted(&mut self) {
+    pub fn add_storage<T: Send + Sync + Component>(&self) {
+        let location = Location::caller();
+
+        self.storages
+            .write()
+            .entry(StorageId::of::<SparseSet<T>>())
+            .or_insert_with(|| SBox::new(SparseSet::<T>::new()).with_location(location));
+    }
+    /// Makes `T`'s storage keep the components it deletes instead of dropping them.\
+    /// They can then be collected with [`AllStorages::take_deferred_drops`].
+    pub fn defer_drops<T: Send + Sync + Component>(&mut self) {
+        self.exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .unwrap()
+            .defer_drops();
+    }
+    /// Collects the components kept by storages deferring their drops.\
+    /// With the `thread_local` feature, `!Send` components stay in their storage, `take_local_deferred_drops` collects them.
+    pub fn take_deferred_drops(&mut self) -> DropQueue {
+        let mut drop_queue = DropQueue::new();
+
         for storage in self.storages.get_mut().values_mut() {
-            unsafe { &mut *storage.0 }
-                .get_mut()
-                .clear_all_removed_and_deleted();
+            if let Some(storage) = storage.get_mut() {
+                storage.take_deferred_drops(&mut drop_queue, Private);
+            }
         }
+
+        drop_queue
     }
-    /// Clear all deletion and removal tracking data older than some timestamp.
-    #[track_caller]
+    /// Collects the `!Send` components kept by storages deferring their drops.
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
+    pub fn take_local_deferred_drops(&mut self) -> LocalDropQueue {
+        let mut drop_queue = LocalDropQueue::new();
+
+        for storage in self.storages.get_mut().values_mut() {
+            if let Some(storage) = storage.get_mut() {
+                storage.take_local_deferred_drops(&mut drop_queue, Private);
+            }
+        }
+
+        drop_queue
+    }
+    /// Clear all deletion and removal tracking data.
+    #[track_caller]
+    pub fn clear_all_removed_and_deleted(&mut self) {
+        for storage in self.storages.get_mut().values_mut() {
+            if let Some(storage) = storage.get_mut() {
+                storage.clear_all_removed_and_deleted();
+            }
+        }
+    }
+    /// Clear all deletion and removal tracking data older than some timestamp.
+    #[track_caller]
     pub fn clear_all_removed_and_deleted_older_than_timestamp(
         &mut self,
         timestamp: TrackingTimestamp,
     ) {
         for storage in self.storages.get_mut().values_mut() {
-            unsafe { &mut *storage.0 }
-                .get_mut()
-                .clear_all_removed_and_deleted_older_than_timestamp(timestamp);
+            if let Some(storage) = storage.get_mut() {
+                storage.clear_all_removed_and_deleted_older_than_timestamp(timestamp);
+            }
         }
     }
 
@@ -522,6 +1024,7 @@ impl AllStorages {
     /// let entity0 = all_storages.add_entity((U32(0),));
     /// let entity1 = all_storages.add_entity((U32(1), USIZE(11)));
     /// ```
+    #[track_caller]
     #[inline]
     pub fn add_entity<T: TupleAddComponent>(&mut self, component: T) -> EntityId {
         let current = self.get_current();
@@ -531,6 +1034,14 @@ impl AllStorages {
 
         entity
     }
+    /// Creates a new entity with the components passed as argument and returns an [`EntityMut`] to keep working on it.\
+    /// `component` must always be a tuple, even for a single component.
+    #[track_caller]
+    pub fn add_entity_mut<T: TupleAddComponent>(&mut self, component: T) -> EntityMut<'_> {
+        let entity = self.add_entity(component);
+
+        EntityMut::new(self, entity)
+    }
     /// Creates multiple new entities and returns an iterator yielding the new `EntityId`s.  
     /// `source` must always yield a tuple, even for a single component.
     ///
@@ -560,7 +1071,7 @@ impl AllStorages {
     ///
     /// ### Panics
     ///
-    /// - `entity` is not alive.
+    /// - `entity` is not alive and the [`AddComponentPolicy`] is `Panic`.
     ///
     /// ### Example
     ///
@@ -586,6 +1097,92 @@ impl AllStorages {
     #[track_caller]
     #[inline]
     pub fn add_component<T: TupleAddComponent>(&mut self, entity: EntityId, component: T) {
+        if let Err(err) = self.try_add_component(entity, component) {
+            if self.add_component_policy() == AddComponentPolicy::Panic {
+                panic!("{:?}: {:?}", entity, err);
+            }
+        }
+    }
+    /// Adds components to multiple existing entities, in iteration order.\
+    /// If an entity already owned a component it will be replaced.\
+    /// Each item must always hold a tuple, even for a single component.
+    ///
+    /// All components share the same tracking timestamp and new components are added to their storage in iteration order,
+    /// the same input always results in the same tracking data.
+    ///
+    /// ### Panics
+    ///
+    /// - An entity is not alive and the [`AddComponentPolicy`] is `Panic`, the components of the previous entities are already added.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entities = [all_storages.add_entity(()), all_storages.add_entity(())];
+    ///
+    /// all_storages.bulk_add_component(entities.iter().map(|&entity| (entity, (U32(0),))));
+    /// ```
+    #[track_caller]
+    pub fn bulk_add_component<T: TupleAddComponent>(
+        &mut self,
+        components: impl IntoIterator<Item = (EntityId, T)>,
+    ) {
+        let current = self.get_current();
+        let policy = self.add_component_policy();
+
+        for (entity, component) in components {
+            if self
+                .exclusive_storage_mut::<Entities>()
+                .unwrap()
+                .is_alive(entity)
+            {
+                component.add_component(self, entity, current);
+            } else if policy == AddComponentPolicy::Panic {
+                panic!("{:?}: {:?}", entity, error::AddComponent::EntityIsNotAlive);
+            }
+        }
+    }
+    /// Adds components to an existing entity.  
+    /// If the entity already owned a component it will be replaced.  
+    /// `component` must always be a tuple, even for a single component.  
+    ///
+    /// Returns an error if `entity` is not alive, regardless of the [`AddComponentPolicy`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(());
+    ///
+    /// assert_eq!(all_storages.try_add_component(entity, (U32(0),)), Ok(()));
+    ///
+    /// all_storages.delete_entity(entity);
+    ///
+    /// assert_eq!(
+    ///     all_storages.try_add_component(entity, (U32(1),)),
+    ///     Err(error::AddComponent::EntityIsNotAlive)
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_add_component<T: TupleAddComponent>(
+        &mut self,
+        entity: EntityId,
+        component: T,
+    ) -> Result<(), error::AddComponent> {
         let current = self.get_current();
 
         if self
@@ -594,10 +1191,85 @@ impl AllStorages {
             .is_alive(entity)
         {
             component.add_component(self, entity, current);
+
+            Ok(())
         } else {
-            panic!("{:?}", error::AddComponent::EntityIsNotAlive);
+            Err(error::AddComponent::EntityIsNotAlive)
         }
     }
+    /// Makes the entity reserved with `ticket` alive and adds `component` to it.\
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// Returns an error if the entity was deleted since its reservation.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    ///
+    /// let ticket = world.reserve_entity().unwrap();
+    ///
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.redeem(ticket, (U32(0),)).unwrap();
+    /// assert!(all_storages.is_entity_alive(entity));
+    /// ```
+    pub fn redeem<T: TupleAddComponent>(
+        &mut self,
+        ticket: ReservationTicket,
+        component: T,
+    ) -> Result<EntityId, error::AddComponent> {
+        self.exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .flush_reserved();
+
+        self.try_add_component(ticket.id(), component)?;
+
+        Ok(ticket.id())
+    }
+    /// Sets what happens when adding components to an entity that is not alive.\
+    /// This affects `add_component` and `bulk_add_component` of `World` and `AllStorages`
+    /// and `add_component` and `add_distinct_component` of `Entities`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AddComponentPolicy, AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(());
+    /// all_storages.delete_entity(entity);
+    ///
+    /// all_storages.set_add_component_policy(AddComponentPolicy::Ignore);
+    ///
+    /// // doesn't panic
+    /// all_storages.add_component(entity, (U32(0),));
+    /// ```
+    pub fn set_add_component_policy(&mut self, policy: AddComponentPolicy) {
+        self.exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .set_add_component_policy(policy);
+    }
+    /// Returns what happens when adding components to an entity that is not alive.\
+    /// Only borrows [`Entities`], shared.
+    ///
+    /// ### Panics
+    ///
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn add_component_policy(&self) -> AddComponentPolicy {
+        self.entities().unwrap().add_component_policy()
+    }
     /// Deletes components from an entity. As opposed to `remove`, `delete` doesn't return anything.  
     /// `C` must always be a tuple, even for a single component.
     ///
@@ -649,6 +1321,114 @@ impl AllStorages {
     pub fn remove<C: TupleRemove>(&mut self, entity: EntityId) -> C::Out {
         C::remove(self, entity)
     }
+    /// Replaces `entity`'s `T` component and returns the old one.\
+    /// The component is flagged as modified, insertion tracking and the insertion callback are not triggered.
+    ///
+    /// Returns `None` and drops `component` if `entity` doesn't have a `T` component.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((U32(0),));
+    ///
+    /// assert_eq!(all_storages.replace_component(entity, U32(1)), Some(U32(0)));
+    /// assert_eq!(all_storages.get::<&U32>(entity).as_deref(), Ok(&&U32(1)));
+    /// ```
+    #[inline]
+    pub fn replace_component<T: Send + Sync + Component>(
+        &mut self,
+        entity: EntityId,
+        component: T,
+    ) -> Option<T> {
+        let current = self.get_current();
+
+        self.exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
+            .unwrap()
+            .private_replace(entity, component, current)
+    }
+    /// Moves all `A` components to the `B` storage, converting them with `f`.\
+    /// Each entity keeps its component, under its new type. If it already had a `B` component, it is replaced.
+    ///
+    /// `A` components are tracked as removed and `B` components as inserted, all with the same timestamp.\
+    /// Disabled `A` components are left untouched.\
+    /// Nothing happens and the `B` storage isn't created if the `A` storage doesn't exist.
+    ///
+    /// Returns the number of components converted.
+    ///
+    /// ### Panics
+    ///
+    /// - `A` and `B` are the same type.
+    /// - `A` or `B` storage is already borrowed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// struct Vitality(f32);
+    ///
+    /// let mut world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(Health(10));
+    ///
+    /// let converted =
+    ///     all_storages.convert_component(|health: Health| Vitality(health.0 as f32 / 100.0));
+    ///
+    /// assert_eq!(converted, 1);
+    /// assert!(all_storages.get::<&Health>(entity).is_err());
+    /// assert_eq!(all_storages.get::<&Vitality>(entity).as_deref(), Ok(&&Vitality(0.1)));
+    /// ```
+    #[track_caller]
+    pub fn convert_component<A, B, F>(&mut self, mut f: F) -> usize
+    where
+        A: Send + Sync + Component,
+        B: Send + Sync + Component,
+        F: FnMut(A) -> B,
+    {
+        assert!(
+            core::any::TypeId::of::<A>() != core::any::TypeId::of::<B>(),
+            "Can't convert {} to itself.",
+            type_name::<A>()
+        );
+
+        if !self
+            .storages
+            .read()
+            .contains_key(&StorageId::of::<SparseSet<A>>())
+        {
+            return 0;
+        }
+
+        let (mut sources, mut targets) = match self.borrow::<(ViewMut<'_, A>, ViewMut<'_, B>)>() {
+            Ok(views) => views,
+            Err(err) => panic!("{:?}", err),
+        };
+
+        targets.reserve(sources.len());
+
+        let mut converted = 0;
+        for (entity, component) in sources.drain().with_id() {
+            // replaced components are tracked as removed so the new one is tracked as inserted
+            targets.remove(entity);
+            targets.add_component_unchecked(entity, f(component));
+            converted += 1;
+        }
+
+        converted
+    }
     #[doc = "Borrows the requested storage(s), if it doesn't exist it'll get created.  
 You can use a tuple to get multiple storages at once.
 
@@ -750,6 +1530,7 @@ let (entities, mut usizes) = all_storages
     #[cfg_attr(feature = "thread_local", doc = "[NonSend]: crate::NonSend")]
     #[cfg_attr(feature = "thread_local", doc = "[NonSync]: crate::NonSync")]
     #[cfg_attr(feature = "thread_local", doc = "[NonSendSync]: crate::NonSendSync")]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     pub fn borrow<V: Borrow>(&self) -> Result<V::View<'_>, error::GetStorage> {
         let current = self.get_current();
 
@@ -1005,15 +1786,87 @@ let i = all_storages.run(sys1);
     /// // deletes `entity0` and `entity1`
     /// all_storages.delete_any::<(SparseSet<U32>, SparseSet<USIZE>)>();
     /// ```
+    ///
+    /// ### Panics
+    ///
+    /// - One of the entities has a component in an immutable storage, see [`World::make_immutable`](crate::World::make_immutable).\
+    ///   Nothing is modified if it's one of `T`'s storages, other entities may already be deleted otherwise.
+    #[track_caller]
     pub fn delete_any<T: TupleDeleteAny>(&mut self) {
         T::delete_any(self);
     }
+    /// Deletes any entity with at least one component in the storages identified by `storage_ids`.\
+    /// This is identical to `delete_any` but uses `StorageId` and not generics.\
+    /// Missing storages and storages not listing their entities are ignored.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// #[derive(Component)]
+    /// struct STR(&'static str);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity0 = all_storages.add_entity((U32(0),));
+    /// let entity1 = all_storages.add_entity((USIZE(1),));
+    /// let entity2 = all_storages.add_entity((STR("2"),));
+    ///
+    /// // deletes `entity0` and `entity1`
+    /// all_storages.delete_any_by_id(&[
+    ///     StorageId::of::<SparseSet<U32>>(),
+    ///     StorageId::of::<SparseSet<USIZE>>(),
+    /// ]);
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// - One of the entities has a component in an immutable storage, see [`World::make_immutable`](crate::World::make_immutable).\
+    ///   Nothing is modified in this case.
+    #[track_caller]
+    pub fn delete_any_by_id(&mut self, storage_ids: &[StorageId]) {
+        let mut ids = ShipHashSet::with_hasher(BuildHasherDefault::default());
+
+        let storages = self.storages.get_mut();
+        for storage_id in storage_ids {
+            // immutable storages are listed too, their entities can't be deleted
+            if let Some(entity_ids) = storages
+                .get_mut(storage_id)
+                .and_then(|sbox| {
+                    if sbox.immutable().is_some() {
+                        sbox.immutable()
+                    } else {
+                        sbox.get_mut().map(|storage| &*storage)
+                    }
+                })
+                .and_then(|storage| storage.entity_ids(Private))
+            {
+                ids.extend(entity_ids);
+            }
+        }
+
+        self.assert_mutable(&[], |storage| {
+            ids.iter().any(|&id| storage_contains(storage, id))
+        });
+
+        for id in ids {
+            self.delete_entity(id);
+        }
+    }
     pub(crate) fn entities(&self) -> Result<ARef<'_, &'_ Entities>, error::GetStorage> {
         let storage_id = StorageId::of::<Entities>();
 
         let storages = self.storages.read();
         let storage = storages.get(&storage_id).unwrap();
-        let storage = unsafe { &*storage.0 }.borrow();
+        let storage = unsafe { &*storage.ptr }.borrow();
         drop(storages);
         match storage {
             Ok(storage) => Ok(ARef::map(storage, |storage| {
@@ -1027,7 +1880,7 @@ let i = all_storages.run(sys1);
 
         let storages = self.storages.read();
         let storage = storages.get(&storage_id).unwrap();
-        let storage = unsafe { &*storage.0 }.borrow_mut();
+        let storage = unsafe { &*storage.ptr }.borrow_mut();
         drop(storages);
         match storage {
             Ok(storage) => Ok(ARefMut::map(storage, |storage| {
@@ -1041,18 +1894,44 @@ let i = all_storages.run(sys1);
     ) -> Result<&mut T, error::GetStorage> {
         self.exclusive_storage_mut_by_id(StorageId::of::<T>())
     }
+    /// Panics if an immutable storage not in `excluded_storages` would be modified.\
+    /// `modifies` is only called on immutable storages.
+    #[track_caller]
+    pub(crate) fn assert_mutable(
+        &mut self,
+        excluded_storages: &[StorageId],
+        mut modifies: impl FnMut(&dyn Storage) -> bool,
+    ) {
+        for (storage_id, sbox) in self.storages.get_mut().iter() {
+            if let Some(storage) = sbox.immutable() {
+                if !excluded_storages.contains(storage_id) && modifies(storage) {
+                    panic!(
+                        "{:?}",
+                        error::GetStorage::StorageBorrow {
+                            name: None,
+                            id: *storage_id,
+                            borrow: error::Borrow::Shared,
+                        }
+                    );
+                }
+            }
+        }
+    }
     #[track_caller]
     pub(crate) fn exclusive_storage_mut_by_id<T: 'static>(
         &mut self,
         storage_id: StorageId,
     ) -> Result<&mut T, error::GetStorage> {
         if let Some(storage) = self.storages.get_mut().get_mut(&storage_id) {
-            let storage = unsafe { &mut *storage.0 }
-                .get_mut()
-                .as_any_mut()
-                .downcast_mut()
-                .unwrap();
-            Ok(storage)
+            if let Some(storage) = storage.get_mut() {
+                Ok(storage.as_any_mut().downcast_mut().unwrap())
+            } else {
+                Err(error::GetStorage::StorageBorrow {
+                    name: Some(type_name::<T>()),
+                    id: storage_id,
+                    borrow: error::Borrow::Unique,
+                })
+            }
         } else {
             Err(error::GetStorage::MissingStorage {
                 name: Some(type_name::<T>()),
@@ -1060,27 +1939,44 @@ let i = all_storages.run(sys1);
             })
         }
     }
+    /// Returns an `SBox` sharing the storage, inserting it first if it doesn't exist.
+    #[track_caller]
+    pub(crate) fn share_storage_or_insert<T, F>(&self, storage_id: StorageId, f: F) -> SBox
+    where
+        T: 'static + Storage + Send + Sync,
+        F: FnOnce() -> T,
+    {
+        let location = Location::caller();
+
+        self.storages
+            .write()
+            .entry(storage_id)
+            .or_insert_with(|| SBox::new(f()).with_location(location))
+            .share()
+    }
+    #[track_caller]
     pub(crate) fn exclusive_storage_or_insert_mut<T, F>(
         &mut self,
         storage_id: StorageId,
         f: F,
-    ) -> &mut T
+    ) -> Result<ExclusiveStorage<'_, T>, error::GetStorage>
     where
         T: 'static + Storage + Send + Sync,
         F: FnOnce() -> T,
     {
+        let location = Location::caller();
         let storages = self.storages.get_mut();
 
-        unsafe {
-            &mut *storages
-                .entry(storage_id)
-                .or_insert_with(|| SBox::new(f()))
-                .0
-        }
-        .get_mut()
-        .as_any_mut()
-        .downcast_mut()
-        .unwrap()
+        storages
+            .entry(storage_id)
+            .or_insert_with(|| SBox::new(f()).with_location(location))
+            .get_or_borrow_mut()
+            .map(|storage| storage.map(|storage| storage.as_any_mut().downcast_mut().unwrap()))
+            .map_err(|borrow| error::GetStorage::StorageBorrow {
+                name: Some(type_name::<T>()),
+                id: storage_id,
+                borrow,
+            })
     }
     #[cfg(feature = "thread_local")]
     #[track_caller]
@@ -1088,46 +1984,51 @@ let i = all_storages.run(sys1);
         &mut self,
         storage_id: StorageId,
         f: F,
-    ) -> &mut T
+    ) -> Result<ExclusiveStorage<'_, T>, error::GetStorage>
     where
         T: 'static + Storage + Sync,
         F: FnOnce() -> T,
     {
+        let location = Location::caller();
         let storages = self.storages.get_mut();
 
-        unsafe {
-            &mut *storages
-                .entry(storage_id)
-                .or_insert_with(|| SBox::new_non_send(f(), self.thread_id_generator.clone()))
-                .0
-        }
-        .get_mut()
-        .as_any_mut()
-        .downcast_mut()
-        .unwrap()
+        storages
+            .entry(storage_id)
+            .or_insert_with(|| {
+                SBox::new_non_send(f(), self.thread_id_generator.clone()).with_location(location)
+            })
+            .get_or_borrow_mut()
+            .map(|storage| storage.map(|storage| storage.as_any_mut().downcast_mut().unwrap()))
+            .map_err(|borrow| error::GetStorage::StorageBorrow {
+                name: Some(type_name::<T>()),
+                id: storage_id,
+                borrow,
+            })
     }
     #[cfg(feature = "thread_local")]
+    #[track_caller]
     pub(crate) fn exclusive_storage_or_insert_non_sync_mut<T, F>(
         &mut self,
         storage_id: StorageId,
         f: F,
-    ) -> &mut T
+    ) -> Result<ExclusiveStorage<'_, T>, error::GetStorage>
     where
         T: 'static + Storage + Send,
         F: FnOnce() -> T,
     {
+        let location = Location::caller();
         let storages = self.storages.get_mut();
 
-        unsafe {
-            &mut *storages
-                .entry(storage_id)
-                .or_insert_with(|| SBox::new_non_sync(f()))
-                .0
-        }
-        .get_mut()
-        .as_any_mut()
-        .downcast_mut()
-        .unwrap()
+        storages
+            .entry(storage_id)
+            .or_insert_with(|| SBox::new_non_sync(f()).with_location(location))
+            .get_or_borrow_mut()
+            .map(|storage| storage.map(|storage| storage.as_any_mut().downcast_mut().unwrap()))
+            .map_err(|borrow| error::GetStorage::StorageBorrow {
+                name: Some(type_name::<T>()),
+                id: storage_id,
+                borrow,
+            })
     }
     #[cfg(feature = "thread_local")]
     #[track_caller]
@@ -1135,23 +2036,27 @@ let i = all_storages.run(sys1);
         &mut self,
         storage_id: StorageId,
         f: F,
-    ) -> &mut T
+    ) -> Result<ExclusiveStorage<'_, T>, error::GetStorage>
     where
         T: 'static + Storage,
         F: FnOnce() -> T,
     {
+        let location = Location::caller();
         let storages = self.storages.get_mut();
 
-        unsafe {
-            &mut *storages
-                .entry(storage_id)
-                .or_insert_with(|| SBox::new_non_send_sync(f(), self.thread_id_generator.clone()))
-                .0
-        }
-        .get_mut()
-        .as_any_mut()
-        .downcast_mut()
-        .unwrap()
+        storages
+            .entry(storage_id)
+            .or_insert_with(|| {
+                SBox::new_non_send_sync(f(), self.thread_id_generator.clone())
+                    .with_location(location)
+            })
+            .get_or_borrow_mut()
+            .map(|storage| storage.map(|storage| storage.as_any_mut().downcast_mut().unwrap()))
+            .map_err(|borrow| error::GetStorage::StorageBorrow {
+                name: Some(type_name::<T>()),
+                id: storage_id,
+                borrow,
+            })
     }
     /// Make the given entity alive.  
     /// Does nothing if an entity with a greater generation is already at this index.  
@@ -1166,6 +2071,344 @@ let i = all_storages.run(sys1);
     pub fn memory_usage(&self) -> AllStoragesMemoryUsage<'_> {
         AllStoragesMemoryUsage(self)
     }
+    /// Returns the name, size, thread safety and last modification of every unique, sorted by name.\
+    /// Uniques that can't be borrowed, because they are borrowed exclusively or `!Send` and bound to another thread, are skipped.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Unique, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Camera([f32; 16]);
+    ///
+    /// let world = World::new();
+    /// let all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_unique(Camera([0.0; 16]));
+    ///
+    /// let info = &all_storages.uniques_info()[0];
+    /// assert!(info.name.ends_with("Camera"));
+    /// assert_eq!(info.size_bytes, 64);
+    /// ```
+    pub fn uniques_info(&self) -> Vec<UniqueInfo> {
+        let current = self.get_tracking_timestamp();
+
+        let mut uniques_info: Vec<UniqueInfo> = self
+            .storages
+            .read()
+            .values()
+            .filter_map(|sbox| {
+                let refcell = unsafe { &*sbox.ptr };
+                #[allow(unused_mut)]
+                let mut info = refcell.borrow().ok()?.unique_info(current, Private)?;
+
+                #[cfg(feature = "thread_local")]
+                {
+                    info.is_send = refcell.bound_thread().is_none();
+                    info.is_sync = refcell.is_sync();
+                }
+
+                Some(info)
+            })
+            .collect();
+
+        uniques_info.sort_unstable_by(|info, other| info.name.cmp(&other.name));
+
+        uniques_info
+    }
+    /// Returns the id, name and, with the `storage_location` feature, creation location of every storage.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, StorageId, SparseSet, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_entity((U32(0),));
+    ///
+    /// assert!(all_storages
+    ///     .storages_info()
+    ///     .iter()
+    ///     .any(|info| info.id == StorageId::of::<SparseSet<U32>>()));
+    /// ```
+    pub fn storages_info(&self) -> Vec<StorageInfo> {
+        self.storages
+            .read()
+            .iter()
+            .map(|(&id, sbox)| StorageInfo {
+                id,
+                name: unsafe { &*sbox.ptr }
+                    .borrow()
+                    .ok()
+                    .map(|storage| storage.name()),
+                #[cfg(feature = "storage_location")]
+                created_at: sbox.location(),
+            })
+            .collect()
+    }
+    /// Checks that every storage agrees with the entities of this `AllStorages`.\
+    /// An empty `Vec` means no inconsistency was found.
+    ///
+    /// Component storages are checked for components owned by dead entities, sparse and dense arrays disagreeing
+    /// in both directions and tracking timestamps more recent than the current one.\
+    /// Storages shared with other `World`s are not checked.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared)
+    /// - All storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Entities borrow failed.
+    /// - Storage borrow failed.
+    pub fn validate_integrity(&self) -> Result<Vec<error::IntegrityError>, error::GetStorage> {
+        let entities = self.entities()?;
+        let current = self.get_tracking_timestamp();
+        let mut errors = Vec::new();
+
+        let storages = self.storages.read();
+        for (&id, sbox) in storages.iter() {
+            if sbox.is_shared() {
+                continue;
+            }
+
+            let storage = unsafe { &*sbox.ptr }.borrow().map_err(|borrow| {
+                error::GetStorage::StorageBorrow {
+                    name: None,
+                    id,
+                    borrow,
+                }
+            })?;
+            storage.validate_integrity(&entities, current, &mut errors, Private);
+        }
+
+        Ok(errors)
+    }
+    /// Returns the entities deleted after `timestamp`, each entity is only returned once.\
+    /// Only storages tracking deletion are taken into account.\
+    /// Storages that can't be borrowed, for example because they are borrowed exclusively, are skipped.
+    ///
+    /// ### Borrows
+    ///
+    /// - `Entities` (shared)
+    /// - All storages that aren't borrowed exclusively (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - `Entities` borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Deletion)]
+    /// struct Pos(f32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(Pos(0.0));
+    ///
+    /// let last_frame = all_storages.tick();
+    /// all_storages.delete_entity(entity);
+    ///
+    /// let deleted: Vec<_> = all_storages.entities_deleted_since(last_frame).unwrap().collect();
+    /// assert_eq!(deleted, [entity]);
+    /// ```
+    pub fn entities_deleted_since(
+        &self,
+        timestamp: TrackingTimestamp,
+    ) -> Result<impl Iterator<Item = EntityId>, error::GetStorage> {
+        let entities = self.entities()?;
+        let current = self.get_tracking_timestamp();
+        let mut deleted = Vec::new();
+
+        let storages = self.storages.read();
+        for sbox in storages.values() {
+            if sbox.is_shared() {
+                continue;
+            }
+
+            // busy storages are skipped, they could be borrowed by the caller
+            if let Ok(storage) = unsafe { &*sbox.ptr }.borrow() {
+                storage.deleted_since(timestamp, current, &mut deleted, Private);
+            }
+        }
+
+        deleted.sort_unstable();
+        deleted.dedup();
+        // components can be deleted without deleting their entity
+        deleted.retain(|&entity| !entities.is_alive(entity));
+
+        Ok(deleted.into_iter())
+    }
+
+    /// Returns the entities owning a component in all `include` storages and in none of the `exclude` storages.\
+    /// With an empty `include`, all alive entities are considered.
+    ///
+    /// Unlike typed iteration the storages are chosen at runtime, for example by a scripting layer.\
+    /// Missing storages and storages without entities, like uniques, are considered empty.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared)
+    /// - `include` and `exclude` storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Entities borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Pos(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Frozen;
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity0 = all_storages.add_entity((Pos(0.0),));
+    /// all_storages.add_entity((Pos(1.0), Frozen));
+    ///
+    /// let entities = all_storages
+    ///     .dyn_query(
+    ///         &[StorageId::of::<SparseSet<Pos>>()],
+    ///         &[StorageId::of::<SparseSet<Frozen>>()],
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(entities, [entity0]);
+    /// ```
+    pub fn dyn_query(
+        &self,
+        include: &[StorageId],
+        exclude: &[StorageId],
+    ) -> Result<Vec<EntityId>, error::GetStorage> {
+        let entities = self.entities()?;
+
+        let storages = self.storages.read();
+        let mut included = Vec::with_capacity(include.len());
+        let mut excluded = Vec::with_capacity(exclude.len());
+
+        let storage_ids = include
+            .iter()
+            .map(|storage_id| (storage_id, true))
+            .chain(exclude.iter().map(|storage_id| (storage_id, false)));
+
+        for (&storage_id, is_included) in storage_ids {
+            let sbox = match storages.get(&storage_id) {
+                Some(sbox) => sbox,
+                // no entity can own a component in a missing storage
+                None if is_included => return Ok(Vec::new()),
+                None => continue,
+            };
+
+            let storage = unsafe { &*sbox.ptr }.borrow().map_err(|borrow| {
+                error::GetStorage::StorageBorrow {
+                    name: None,
+                    id: storage_id,
+                    borrow,
+                }
+            })?;
+
+            if is_included {
+                included.push(storage);
+            } else {
+                excluded.push(storage);
+            }
+        }
+
+        drop(storages);
+
+        // starts from the smallest storage to check as few entities as possible
+        let mut query: Vec<EntityId> = match included
+            .iter()
+            .min_by_key(|storage| storage.entity_ids(Private).map_or(0, <[EntityId]>::len))
+        {
+            Some(storage) => storage.entity_ids(Private).unwrap_or_default().to_vec(),
+            None => entities.iter().collect(),
+        };
+
+        query.retain(|&entity| {
+            included.iter().all(|storage| {
+                storage
+                    .sparse_array()
+                    .is_some_and(|sparse_array| sparse_array.contains(entity))
+            }) && !excluded.iter().any(|storage| {
+                storage
+                    .sparse_array()
+                    .is_some_and(|sparse_array| sparse_array.contains(entity))
+            })
+        });
+
+        Ok(query)
+    }
+
+    /// Serializes the `C` components of the entities present in all `F` storages.\
+    /// Entities without any `C` component are skipped.
+    ///
+    /// The output is a sequence of `(EntityId, (Option<C0>, Option<C1>, ...))`.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared)
+    /// - `F` storages (shared)
+    /// - `C` storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed, reported as a custom serialization error.
+    /// - Serialization error.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, serde::Serialize)]
+    /// struct Pos(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Persistent;
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_entity((Pos(0), Persistent));
+    /// // particle, not saved
+    /// all_storages.add_entity((Pos(1),));
+    ///
+    /// let mut save = Vec::new();
+    /// all_storages
+    ///     .serialize_query::<(Pos,), (Persistent,), _>(&mut serde_json::Serializer::new(&mut save))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(save, br#"[[{"index":0,"gen":0},[0]]]"#);
+    /// ```
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn serialize_query<C: TupleSerialize, F: TupleFilter, S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+
+        let entities = self
+            .dyn_query(&F::storage_ids(), &[])
+            .map_err(S::Error::custom)?;
+
+        C::serialize(self, &entities, serializer)
+    }
 
     #[inline]
     pub(crate) fn get_current(&self) -> TrackingTimestamp {
@@ -1179,6 +2422,13 @@ let i = all_storages.run(sys1);
     pub fn get_tracking_timestamp(&self) -> TrackingTimestamp {
         TrackingTimestamp::new(self.counter.load(core::sync::atomic::Ordering::Acquire))
     }
+    /// Advances the tracking cycle and returns the timestamp of this tick.
+    ///
+    /// Everything tracked between two ticks is within `(previous_tick, next_tick]`
+    /// according to [`TrackingTimestamp::is_within`].
+    pub fn tick(&self) -> TrackingTimestamp {
+        self.get_current()
+    }
 
     /// Enable insertion tracking for the given components.
     pub fn track_insertion<T: TupleTrack>(&mut self) -> &mut AllStorages {
@@ -1342,6 +2592,299 @@ assert!(*i == U32(0));
     pub fn get_unique<T: GetUnique>(&self) -> Result<T::Out<'_>, error::GetStorage> {
         T::get_unique(self, None)
     }
+    /// Returns the [`StorageId`] of all unique storages.\
+    /// Uniques currently borrowed exclusively can't be inspected and are not listed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Unique, UniqueStorage, StorageId, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_unique(U32(0));
+    ///
+    /// assert_eq!(
+    ///     all_storages.unique_ids(),
+    ///     vec![StorageId::of::<UniqueStorage<U32>>()]
+    /// );
+    /// ```
+    pub fn unique_ids(&self) -> Vec<StorageId> {
+        let storages = self.storages.read();
+
+        storages
+            .iter()
+            .filter(|(_, storage)| {
+                unsafe { &*storage.ptr }
+                    .borrow()
+                    .map(|storage| storage.unique_value(Private).is_some())
+                    .unwrap_or(false)
+            })
+            .map(|(storage_id, _)| *storage_id)
+            .collect()
+    }
+    /// Returns the value of the unique storage identified by `storage_id` without knowing its type.\
+    /// Intended for tooling, the value can then be downcast.
+    ///
+    /// ### Borrows
+    ///
+    /// - Unique storage (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage is missing or isn't a unique storage.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Unique, UniqueStorage, StorageId, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_unique(U32(0));
+    ///
+    /// let value = all_storages
+    ///     .get_unique_erased(StorageId::of::<UniqueStorage<U32>>())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.downcast_ref::<U32>().unwrap().0, 0);
+    /// ```
+    pub fn get_unique_erased(
+        &self,
+        storage_id: StorageId,
+    ) -> Result<ARef<'_, &'_ dyn Any>, error::GetStorage> {
+        let storage = self.custom_storage_by_id(storage_id)?;
+
+        if storage.unique_value(Private).is_none() {
+            return Err(error::GetStorage::MissingStorage {
+                name: None,
+                id: storage_id,
+            });
+        }
+
+        Ok(ARef::map(storage, |storage| {
+            storage.unique_value(Private).unwrap()
+        }))
+    }
+    /// Returns the value of the unique storage identified by `storage_id` without knowing its type.\
+    /// Intended for tooling, the value can then be downcast. The unique is flagged modified.
+    ///
+    /// ### Borrows
+    ///
+    /// - Unique storage (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage is missing or isn't a unique storage.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Unique, UniqueStorage, StorageId, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// all_storages.add_unique(U32(0));
+    ///
+    /// all_storages
+    ///     .get_unique_erased_mut(StorageId::of::<UniqueStorage<U32>>())
+    ///     .unwrap()
+    ///     .downcast_mut::<U32>()
+    ///     .unwrap()
+    ///     .0 += 1;
+    ///
+    /// assert_eq!(all_storages.get_unique::<&U32>().unwrap().0, 1);
+    /// ```
+    pub fn get_unique_erased_mut(
+        &self,
+        storage_id: StorageId,
+    ) -> Result<ARefMut<'_, &'_ mut (dyn Any + 'static)>, error::GetStorage> {
+        let storage = self.custom_storage_mut_by_id(storage_id)?;
+
+        if storage.unique_value(Private).is_none() {
+            return Err(error::GetStorage::MissingStorage {
+                name: None,
+                id: storage_id,
+            });
+        }
+
+        let current = self.get_current();
+
+        Ok(ARefMut::map(storage, |storage| {
+            storage.unique_value_mut(current, Private).unwrap()
+        }))
+    }
+    /// Borrows the `T` storage identified by `label`, creating it if it doesn't exist.\
+    /// Labeled storages are independent from `T`'s regular storage and from each other.
+    /// Systems can borrow labeled storages with [`ViewLabeled`](crate::ViewLabeled).
+    ///
+    /// ### Borrows
+    ///
+    /// - `T` storage labeled `label` (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AddComponent, AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Input(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(());
+    /// all_storages
+    ///     .view_labeled_mut::<Input>("player 1")
+    ///     .unwrap()
+    ///     .add_component_unchecked(entity, Input(1));
+    ///
+    /// assert!(all_storages.view_labeled::<Input>("player 1").unwrap().contains(entity));
+    /// assert!(!all_storages.view_labeled::<Input>("player 2").unwrap().contains(entity));
+    /// ```
+    pub fn view_labeled<T: Send + Sync + Component>(
+        &self,
+        label: impl Hash,
+    ) -> Result<View<'_, T>, error::GetStorage> {
+        // labeled storages can't be added beforehand, strict mode doesn't apply to them
+        let view =
+            self.storage_or_insert(StorageId::of_labeled::<T>(label), SparseSet::new, false)?;
+
+        let (sparse_set, borrow) = unsafe { ARef::destructure(view) };
+
+        Ok(View::new(
+            sparse_set,
+            borrow,
+            None,
+            None,
+            self.get_current(),
+            self.entity_names(),
+        ))
+    }
+    /// Exclusively borrows the `T` storage identified by `label`, creating it if it doesn't exist.\
+    /// Labeled storages are independent from `T`'s regular storage and from each other.
+    /// Systems can borrow labeled storages with [`ViewLabeledMut`](crate::ViewLabeledMut).
+    ///
+    /// ### Borrows
+    ///
+    /// - `T` storage labeled `label` (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    pub fn view_labeled_mut<T: Send + Sync + Component>(
+        &self,
+        label: impl Hash,
+    ) -> Result<ViewMut<'_, T>, error::GetStorage> {
+        // labeled storages can't be added beforehand, strict mode doesn't apply to them
+        let view =
+            self.storage_or_insert_mut(StorageId::of_labeled::<T>(label), SparseSet::new, false)?;
+
+        let (sparse_set, borrow) = unsafe { ARefMut::destructure(view) };
+        let current = self.get_current();
+        sparse_set.last_change = current;
+
+        Ok(ViewMut {
+            last_insertion: sparse_set.last_insert,
+            last_modification: sparse_set.last_modified,
+            last_removal_or_deletion: current,
+            current,
+            sparse_set,
+            borrow,
+            all_borrow: None,
+            entity_names: self.entity_names(),
+            phantom: PhantomData,
+        })
+    }
+    /// Copies the storages of `T` and which entities are alive.\
+    /// `T` has to be a single component or a tuple of components implementing `Clone`.
+    ///
+    /// ### Borrows
+    ///
+    /// - `Entities` (shared)
+    /// - `T` storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct Health(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity(Health(10));
+    ///
+    /// let snapshot = all_storages.snapshot::<Health>().unwrap();
+    ///
+    /// all_storages.get::<&mut Health>(entity).unwrap().0 = 0;
+    /// let other = all_storages.add_entity(Health(5));
+    ///
+    /// all_storages.restore(&snapshot);
+    ///
+    /// assert_eq!(all_storages.get::<&Health>(entity).unwrap().0, 10);
+    /// assert!(!all_storages.is_entity_alive(other));
+    /// ```
+    pub fn snapshot<T: TupleSnapshot>(&self) -> Result<PartialSnapshot, error::GetStorage> {
+        let (entities, free_list) = self.entities()?.liveness();
+
+        let mut snapshot = PartialSnapshot {
+            entities,
+            free_list,
+            storages: Vec::new(),
+        };
+
+        T::snapshot(self, &mut snapshot)?;
+
+        Ok(snapshot)
+    }
+    /// Brings back the storages and entities' liveness copied in `snapshot`.
+    ///
+    /// Entities created after the snapshot was taken are deleted, their `EntityId` can be handed out again.\
+    /// Entities deleted after the snapshot was taken are alive again but only have the components present in `snapshot`.\
+    /// The other storages are otherwise left untouched.
+    pub fn restore(&mut self, snapshot: &PartialSnapshot) {
+        let current = self.get_current();
+
+        // no need to lock here since we have a unique access
+        let mut entities = self.entities_mut().unwrap();
+        entities.flush_reserved();
+
+        let deleted: Vec<EntityId> = entities
+            .iter()
+            .filter(|entity| snapshot.entities.get(entity.uindex()) != Some(entity))
+            .collect();
+
+        entities.restore_liveness(&snapshot.entities, snapshot.free_list);
+        drop(entities);
+
+        for entity in deleted {
+            self.strip(entity);
+        }
+
+        for storage in &snapshot.storages {
+            storage.restore(self, current);
+        }
+    }
 
     #[doc = "Iterate components.
 
@@ -1440,6 +2983,77 @@ for (i, j) in &mut iter {
             .unwrap()
             .is_alive(entity)
     }
+    /// Returns true if entity matches a living entity.\
+    /// Only borrows [`Entities`], shared.
+    ///
+    /// ### Panics
+    ///
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn entity_exists(&self, entity: EntityId) -> bool {
+        self.entities().unwrap().is_alive(entity)
+    }
+    /// Returns the alive entities that don't have any component.\
+    /// Storages that can't be borrowed, for example because they are borrowed exclusively, are skipped,
+    /// entities only having components in these storages are returned.
+    ///
+    /// ### Borrows
+    ///
+    /// - Entities (shared)
+    /// - All storages that aren't borrowed exclusively (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - Entities borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Pos(f32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let orphan = all_storages.add_entity(());
+    /// all_storages.add_entity((Pos(0.0),));
+    ///
+    /// assert_eq!(all_storages.orphans().unwrap(), [orphan]);
+    /// ```
+    pub fn orphans(&self) -> Result<Vec<EntityId>, error::GetStorage> {
+        let entities = self.entities()?;
+        let entities_id = StorageId::of::<Entities>();
+
+        let storages = self.storages.read();
+        let mut components = Vec::new();
+
+        for (&storage_id, sbox) in storages.iter() {
+            if storage_id == entities_id {
+                continue;
+            }
+
+            // busy storages are skipped, they could be borrowed by the caller
+            if let Ok(storage) = unsafe { &*sbox.ptr }.borrow() {
+                if storage.sparse_array().is_some() {
+                    components.push(storage);
+                }
+            }
+        }
+
+        drop(storages);
+
+        Ok(entities
+            .iter()
+            .filter(|&entity| {
+                !components.iter().any(|storage| {
+                    storage
+                        .sparse_array()
+                        .is_some_and(|sparse_array| sparse_array.contains(entity))
+                })
+            })
+            .collect())
+    }
 
     /// Moves an entity from a `World` to another.
     ///
@@ -1466,8 +3080,11 @@ for (i, j) in &mut iter {
     /// # Panics
     ///
     /// - `entity` is not alive
+    /// - `entity` has a component in an immutable storage, see [`World::make_immutable`](crate::World::make_immutable).
     #[track_caller]
     pub fn move_entity(&mut self, other: &mut AllStorages, entity: EntityId) {
+        self.assert_mutable(&[], |storage| storage_contains(storage, entity));
+
         let current = self.get_current();
         let other_current = other.get_current();
 
@@ -1492,13 +3109,9 @@ for (i, j) in &mut iter {
         );
 
         for storage in self.storages.get_mut().values_mut() {
-            unsafe { &mut *storage.0 }.get_mut().move_component_from(
-                other,
-                entity,
-                entity,
-                current,
-                other_current,
-            );
+            if let Some(storage) = storage.get_mut() {
+                storage.move_component_from(other, entity, entity, current, other_current);
+            }
         }
     }
 
@@ -1528,8 +3141,11 @@ for (i, j) in &mut iter {
     ///
     /// - `from` is not alive
     /// - `to` is not alive
+    /// - `from` has a component in an immutable storage, see [`World::make_immutable`](crate::World::make_immutable).
     #[track_caller]
     pub fn move_components(&mut self, other: &mut AllStorages, from: EntityId, to: EntityId) {
+        self.assert_mutable(&[], |storage| storage_contains(storage, from));
+
         let current = self.get_current();
         let other_current = other.get_current();
 
@@ -1556,14 +3172,143 @@ for (i, j) in &mut iter {
         };
 
         for storage in self.storages.get_mut().values_mut() {
-            unsafe { &mut *storage.0 }.get_mut().move_component_from(
-                other,
-                from,
-                to,
-                current,
-                other_current,
-            );
+            if let Some(storage) = storage.get_mut() {
+                storage.move_component_from(other, from, to, current, other_current);
+            }
+        }
+    }
+    /// Exchanges `T`'s storage with `other`'s, without moving any component.\
+    /// Storages that don't exist are created empty first.
+    ///
+    /// Entities are not checked, both `AllStorages` are expected to have the same entities alive.\
+    /// Tracking information keeps the timestamps of the `World` it was recorded in.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// struct Position(f32);
+    ///
+    /// let world1 = World::new();
+    /// let world2 = World::new();
+    ///
+    /// let mut all_storages1 = world1.borrow::<AllStoragesViewMut>().unwrap();
+    /// let mut all_storages2 = world2.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages1.add_entity(Position(0.0));
+    /// all_storages2.spawn(entity);
+    /// all_storages2.add_component(entity, Position(1.0));
+    ///
+    /// all_storages1.swap_storage::<Position>(&mut all_storages2);
+    ///
+    /// assert_eq!(all_storages1.get::<&Position>(entity).as_deref(), Ok(&&Position(1.0)));
+    /// assert_eq!(all_storages2.get::<&Position>(entity).as_deref(), Ok(&&Position(0.0)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// - One of the storages is immutable or borrowed by another `World` it is shared with.
+    #[track_caller]
+    pub fn swap_storage<T: Send + Sync + Component>(&mut self, other: &mut AllStorages) {
+        let mut sparse_set = self
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .unwrap();
+        let mut other_sparse_set = other
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .unwrap();
+
+        core::mem::swap(&mut *sparse_set, &mut *other_sparse_set);
+    }
+    /// Deletes `entity` and returns its components.
+    ///
+    /// Components in storages shared with another `World` are not archived, they keep `entity`'s id like with [`AllStorages::delete_entity`].\
+    /// Custom storages can't archive their components. If they list them in their [`SparseArray`](crate::sparse_set::SparseArray),
+    /// archiving `entity` fails, otherwise they are deleted.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive.
+    /// - `entity` has components that can't be archived, like `!Send` or `!Sync` components.
+    ///
+    /// Nothing is modified in these cases.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` has a component in an immutable storage, see [`World::make_immutable`](crate::World::make_immutable).\
+    ///   Nothing is modified in this case.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let world = World::new();
+    /// let mut all_storages = world.borrow::<AllStoragesViewMut>().unwrap();
+    ///
+    /// let entity = all_storages.add_entity((U32(0),));
+    /// let archived = all_storages.archive(entity).unwrap();
+    ///
+    /// let entity = all_storages.unarchive(archived);
+    /// assert_eq!(all_storages.get::<&U32>(entity).as_deref(), Ok(&&U32(0)));
+    /// ```
+    #[track_caller]
+    pub fn archive(&mut self, entity: EntityId) -> Result<ArchivedEntity, error::Archive> {
+        self.assert_mutable(&[], |storage| storage_contains(storage, entity));
+
+        if !self
+            .exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .is_alive(entity)
+        {
+            return Err(error::Archive::EntityIsNotAlive);
+        }
+
+        // all storages are checked before modifying any of them to not lose components
+        let unarchivable = self
+            .storages
+            .get_mut()
+            .values_mut()
+            .filter_map(SBox::get_mut)
+            .filter(|storage| !storage.can_archive(entity, Private))
+            .map(|storage| storage.name())
+            .collect::<Vec<_>>();
+
+        if !unarchivable.is_empty() {
+            return Err(error::Archive::Unarchivable(unarchivable));
+        }
+
+        let current = self.get_current();
+
+        self.exclusive_storage_mut::<Entities>()
+            .unwrap()
+            .delete_unchecked(entity);
+
+        let mut archived = ArchivedEntity::default();
+
+        for storage in self.storages.get_mut().values_mut() {
+            if let Some(storage) = storage.get_mut() {
+                storage.archive(entity, current, &mut archived, Private);
+                storage.delete(entity, current);
+            }
         }
+
+        Ok(archived)
+    }
+    /// Creates a new entity with the components of `archived` and returns its `EntityId`.
+    pub fn unarchive(&mut self, archived: ArchivedEntity) -> EntityId {
+        let current = self.get_current();
+
+        let entity = self.exclusive_storage_mut::<Entities>().unwrap().generate();
+
+        for component in archived.components {
+            component.restore(self, entity, current);
+        }
+
+        entity
     }
 }
 
@@ -1589,7 +3334,7 @@ impl core::fmt::Debug for AllStoragesMemoryUsage<'_> {
         let storages = self.0.storages.read();
 
         debug_struct.entries(storages.values().filter_map(|storage| {
-            match unsafe { &*(storage.0) }.borrow() {
+            match unsafe { &*(storage.ptr) }.borrow() {
                 Ok(storage) => storage.memory_usage(),
                 Err(_) => {
                     borrowed_storages += 1;
@@ -1608,3 +3353,10 @@ impl core::fmt::Debug for AllStoragesMemoryUsage<'_> {
         debug_struct.finish()
     }
 }
+
+/// Returns `true` if `storage` has a component for `entity`.
+fn storage_contains(storage: &dyn Storage, entity: EntityId) -> bool {
+    storage
+        .sparse_array()
+        .is_some_and(|sparse_array| sparse_array.contains(entity))
+}
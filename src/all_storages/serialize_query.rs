@@ -0,0 +1,85 @@
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::get::Get;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::views::View;
+#[cfg(doc)]
+use crate::world::World;
+use alloc::vec::Vec;
+use serde::ser::Error;
+use serde::{Serialize, Serializer};
+
+/// Trait used as bound for the components serialized by [`World::serialize_query`] and [`AllStorages::serialize_query`].
+pub trait TupleSerialize {
+    /// Serializes the components of `entities`, skipping the entities without any of them.
+    fn serialize<S: Serializer>(
+        all_storages: &AllStorages,
+        entities: &[EntityId],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+}
+
+/// Trait used as bound for the filter of [`World::serialize_query`] and [`AllStorages::serialize_query`].
+pub trait TupleFilter {
+    /// Returns the storages an entity has to be present in to match the filter.
+    fn storage_ids() -> Vec<StorageId>;
+}
+
+impl TupleFilter for () {
+    #[inline]
+    fn storage_ids() -> Vec<StorageId> {
+        Vec::new()
+    }
+}
+
+macro_rules! impl_serialize_query {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: Send + Sync + Component + Serialize,)+> TupleSerialize for ($($type,)+) {
+            #[allow(non_snake_case)]
+            fn serialize<S: Serializer>(
+                all_storages: &AllStorages,
+                entities: &[EntityId],
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $(
+                    let $type = all_storages
+                        .borrow::<View<'_, $type>>()
+                        .map_err(S::Error::custom)?;
+                )+
+
+                let mut components = Vec::with_capacity(entities.len());
+
+                for &entity in entities {
+                    let entity_components = ($((&$type).get(entity).ok(),)+);
+
+                    if $(entity_components.$index.is_some())||+ {
+                        components.push((entity, entity_components));
+                    }
+                }
+
+                serializer.collect_seq(components)
+            }
+        }
+
+        impl<$($type: Send + Sync + Component,)+> TupleFilter for ($($type,)+) {
+            #[inline]
+            fn storage_ids() -> Vec<StorageId> {
+                alloc::vec![$(StorageId::of::<SparseSet<$type>>()),+]
+            }
+        }
+    }
+}
+
+macro_rules! serialize_query {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_serialize_query![$(($type, $index))*];
+        serialize_query![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_serialize_query![$(($type, $index))*];
+    }
+}
+
+serialize_query![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
@@ -0,0 +1,194 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::ARef;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
+#[cfg(doc)]
+use crate::world::World;
+use crate::ShipHashMap;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::Any;
+
+/// Copy of a few storages and the entities' liveness.
+///
+/// Created with [`World::snapshot`] or [`AllStorages::snapshot`], restored with [`World::restore`] or [`AllStorages::restore`].
+pub struct PartialSnapshot {
+    pub(crate) entities: Vec<EntityId>,
+    pub(crate) free_list: Option<(usize, usize)>,
+    pub(crate) storages: Vec<StorageSnapshot>,
+}
+
+impl PartialSnapshot {
+    /// Returns the ids of the storages in the snapshot.
+    pub fn storage_ids(&self) -> impl Iterator<Item = StorageId> + '_ {
+        self.storages.iter().map(|storage| storage.storage_id)
+    }
+}
+
+impl core::fmt::Debug for PartialSnapshot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PartialSnapshot")
+            .field("entities", &self.entities.len())
+            .field(
+                "storages",
+                &self
+                    .storages
+                    .iter()
+                    .map(|storage| storage.storage_id)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+pub(crate) struct StorageSnapshot {
+    storage_id: StorageId,
+    snapshot: Box<dyn Any + Send + Sync>,
+    restore: fn(&mut AllStorages, &(dyn Any + Send + Sync), TrackingTimestamp),
+}
+
+impl StorageSnapshot {
+    pub(crate) fn restore(&self, all_storages: &mut AllStorages, current: TrackingTimestamp) {
+        (self.restore)(all_storages, &*self.snapshot, current);
+    }
+}
+
+/// Components of a [`SparseSet`] with their tracking information.
+struct SparseSetSnapshot<T> {
+    dense: Vec<EntityId>,
+    data: Vec<T>,
+    insertion_data: Vec<TrackingTimestamp>,
+    modification_data: Vec<TrackingTimestamp>,
+    disabled: ShipHashMap<EntityId, (T, TrackingTimestamp, TrackingTimestamp)>,
+}
+
+impl<T: Component + Clone> SparseSet<T> {
+    fn snapshot(&self) -> SparseSetSnapshot<T> {
+        SparseSetSnapshot {
+            dense: self.dense.clone(),
+            data: self.data.clone(),
+            insertion_data: self.insertion_data.clone(),
+            modification_data: self.modification_data.clone(),
+            disabled: self.disabled.clone(),
+        }
+    }
+    fn restore(&mut self, snapshot: &SparseSetSnapshot<T>, current: TrackingTimestamp) {
+        for &id in &self.dense {
+            unsafe {
+                *self.sparse.get_mut_unchecked(id) = EntityId::dead();
+            }
+        }
+
+        self.dense.clone_from(&snapshot.dense);
+        self.data.clone_from(&snapshot.data);
+        self.disabled.clone_from(&snapshot.disabled);
+
+        for (index, &id) in self.dense.iter().enumerate() {
+            self.sparse.allocate_at(id);
+
+            unsafe {
+                *self.sparse.get_mut_unchecked(id) =
+                    EntityId::new_from_index_and_gen(index as u64, id.gen());
+            }
+        }
+
+        // tracking could have been enabled after the snapshot was taken
+        self.insertion_data.clear();
+        if self.is_tracking_insertion {
+            if snapshot.insertion_data.len() == self.dense.len() {
+                self.insertion_data
+                    .extend_from_slice(&snapshot.insertion_data);
+            } else {
+                self.insertion_data.resize(self.dense.len(), current);
+            }
+        }
+        self.modification_data.clear();
+        if self.is_tracking_modification {
+            if snapshot.modification_data.len() == self.dense.len() {
+                self.modification_data
+                    .extend_from_slice(&snapshot.modification_data);
+            } else {
+                self.modification_data
+                    .resize(self.dense.len(), current.furthest_from());
+            }
+        }
+    }
+}
+
+fn restore_sparse_set<T: Send + Sync + Component + Clone>(
+    all_storages: &mut AllStorages,
+    snapshot: &(dyn Any + Send + Sync),
+    current: TrackingTimestamp,
+) {
+    let snapshot = snapshot.downcast_ref::<SparseSetSnapshot<T>>().unwrap();
+
+    all_storages
+        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+        .unwrap()
+        .restore(snapshot, current);
+}
+
+/// Trait used as bound for [`World::snapshot`] and [`AllStorages::snapshot`].
+pub trait TupleSnapshot {
+    #[allow(missing_docs)]
+    fn snapshot(
+        all_storages: &AllStorages,
+        snapshot: &mut PartialSnapshot,
+    ) -> Result<(), error::GetStorage>;
+}
+
+impl<T: Send + Sync + Component + Clone> TupleSnapshot for T {
+    #[inline]
+    fn snapshot(
+        all_storages: &AllStorages,
+        snapshot: &mut PartialSnapshot,
+    ) -> Result<(), error::GetStorage> {
+        let sparse_set: ARef<'_, &SparseSet<T>> = all_storages.storage_or_insert(
+            StorageId::of::<SparseSet<T>>(),
+            SparseSet::<T>::new,
+            false,
+        )?;
+
+        snapshot.storages.push(StorageSnapshot {
+            storage_id: StorageId::of::<SparseSet<T>>(),
+            snapshot: Box::new(sparse_set.snapshot()),
+            restore: restore_sparse_set::<T>,
+        });
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_snapshot {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: Send + Sync + Component + Clone,)+> TupleSnapshot for ($($type,)+) {
+            #[inline]
+            fn snapshot(
+                all_storages: &AllStorages,
+                snapshot: &mut PartialSnapshot,
+            ) -> Result<(), error::GetStorage> {
+                $(
+                    $type::snapshot(all_storages, snapshot)?;
+                )+
+
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! snapshot {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_snapshot![$(($type, $index))*];
+        snapshot![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_snapshot![$(($type, $index))*];
+    }
+}
+
+snapshot![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
@@ -0,0 +1,294 @@
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::{type_name, Any};
+#[cfg(feature = "serde1")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Components of an entity taken out of a `World`.
+///
+/// Created with [`World::archive`] or [`AllStorages::archive`], brought back with [`World::unarchive`] or [`AllStorages::unarchive`].\
+/// The archive doesn't need the `World` to stay alive, it can be kept around as long as needed.
+///
+/// Only components stored in a [`SparseSet`] requiring `Send + Sync` can be archived.\
+/// Components are kept as is, [`get`] and [`remove`] give access to them.\
+/// With the `serde1` feature, `serialize` and `deserialize` write and read the archive, to disk for example.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, World};
+///
+/// #[derive(Component, Debug, PartialEq, Eq)]
+/// struct Pos(u32, u32);
+///
+/// #[derive(Component, Debug, PartialEq, Eq)]
+/// struct Health(u32);
+///
+/// let mut world = World::new();
+///
+/// let entity = world.add_entity((Pos(10, 20), Health(100)));
+///
+/// let archived = world.archive(entity).unwrap();
+/// assert!(!world.is_entity_alive(entity));
+/// assert_eq!(archived.get::<Health>(), Some(&Health(100)));
+///
+/// let entity = world.unarchive(archived);
+/// assert_eq!(world.get::<&Pos>(entity).as_deref(), Ok(&&Pos(10, 20)));
+/// ```
+///
+/// [`get`]: ArchivedEntity::get
+/// [`remove`]: ArchivedEntity::remove
+/// [`World::archive`]: crate::World::archive
+/// [`World::unarchive`]: crate::World::unarchive
+#[derive(Default)]
+pub struct ArchivedEntity {
+    pub(crate) components: Vec<ArchivedComponent>,
+}
+
+impl ArchivedEntity {
+    /// Returns the number of components in the archive.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+    /// Returns `true` if the archive doesn't contain any component.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+    /// Returns the ids of the storages the components were archived from.
+    pub fn storage_ids(&self) -> impl Iterator<Item = StorageId> + '_ {
+        self.components.iter().map(|component| component.storage_id)
+    }
+    /// Returns `true` if the archive contains a `T` component.
+    pub fn contains<T: Component + Send + Sync>(&self) -> bool {
+        self.get::<T>().is_some()
+    }
+    /// Returns the archived `T` component, if any.
+    pub fn get<T: Component + Send + Sync>(&self) -> Option<&T> {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        self.components
+            .iter()
+            .find(|component| component.storage_id == storage_id)
+            .and_then(|component| component.component.downcast_ref())
+    }
+    /// Returns the archived `T` component mutably, if any.
+    pub fn get_mut<T: Component + Send + Sync>(&mut self) -> Option<&mut T> {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        self.components
+            .iter_mut()
+            .find(|component| component.storage_id == storage_id)
+            .and_then(|component| component.component.downcast_mut())
+    }
+    /// Adds a component to the archive, it will be added to the entity when unarchived.\
+    /// Returns `false` if the archive already contained a `T` component, it is then replaced.
+    pub fn insert<T: Component + Send + Sync>(&mut self, component: T) -> bool {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        if let Some(archived) = self
+            .components
+            .iter_mut()
+            .find(|component| component.storage_id == storage_id)
+        {
+            archived.component = Box::new(component);
+
+            false
+        } else {
+            self.push(component);
+
+            true
+        }
+    }
+    /// Removes the `T` component from the archive and returns it.
+    pub fn remove<T: Component + Send + Sync>(&mut self) -> Option<T> {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        let index = self
+            .components
+            .iter()
+            .position(|component| component.storage_id == storage_id)?;
+
+        self.components
+            .swap_remove(index)
+            .component
+            .downcast()
+            .ok()
+            .map(|component| *component)
+    }
+    /// Adds `component` without checking if the archive already has one of the same type.
+    pub(crate) fn push<T: Component + Send + Sync>(&mut self, component: T) {
+        self.components.push(ArchivedComponent {
+            storage_id: StorageId::of::<SparseSet<T>>(),
+            name: type_name::<T>(),
+            component: Box::new(component),
+            restore: restore::<T>,
+        });
+    }
+}
+
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+impl ArchivedEntity {
+    /// Serializes the `C` components of the archive.
+    ///
+    /// ### Errors
+    ///
+    /// - The archive contains a component not listed in `C`.
+    ///
+    /// ### Example
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use shipyard::{ArchivedEntity, Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    /// struct Pos(u32, u32);
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((Pos(10, 20),));
+    /// let archived = world.archive(entity).unwrap();
+    ///
+    /// let mut save = Vec::new();
+    /// archived
+    ///     .serialize::<(Pos, Health), _>(&mut serde_json::Serializer::new(&mut save))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(save, br#"[[10,20],null]"#);
+    ///
+    /// let archived = ArchivedEntity::deserialize::<(Pos, Health), _>(
+    ///     &mut serde_json::Deserializer::from_slice(&save),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(archived.get::<Pos>(), Some(&Pos(10, 20)));
+    /// assert!(!archived.contains::<Health>());
+    /// ```
+    pub fn serialize<C: TupleArchive, S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        C::serialize(self, serializer)
+    }
+    /// Deserializes an archive written by [`ArchivedEntity::serialize`] with the same `C`.
+    pub fn deserialize<'de, C: TupleArchive, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ArchivedEntity, D::Error> {
+        C::deserialize(deserializer)
+    }
+}
+
+impl core::fmt::Debug for ArchivedEntity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.components.iter().map(|component| component.name))
+            .finish()
+    }
+}
+
+pub(crate) struct ArchivedComponent {
+    storage_id: StorageId,
+    name: &'static str,
+    component: Box<dyn Any + Send + Sync>,
+    restore: fn(&mut AllStorages, EntityId, Box<dyn Any + Send + Sync>, TrackingTimestamp),
+}
+
+impl ArchivedComponent {
+    pub(crate) fn restore(
+        self,
+        all_storages: &mut AllStorages,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) {
+        (self.restore)(all_storages, entity, self.component, current);
+    }
+}
+
+fn restore<T: Component + Send + Sync>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+    component: Box<dyn Any + Send + Sync>,
+    current: TrackingTimestamp,
+) {
+    if let Ok(component) = component.downcast::<T>() {
+        let _ = all_storages
+            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .unwrap()
+            .insert(entity, *component, current);
+    }
+}
+
+/// Trait used as bound for the components of [`ArchivedEntity::serialize`] and [`ArchivedEntity::deserialize`].
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub trait TupleArchive {
+    /// Serializes the components of `archived`.
+    fn serialize<S: Serializer>(
+        archived: &ArchivedEntity,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+    /// Deserializes an archive.
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ArchivedEntity, D::Error>;
+}
+
+#[cfg(feature = "serde1")]
+macro_rules! impl_tuple_archive {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: Send + Sync + Component + Serialize + DeserializeOwned,)+> TupleArchive for ($($type,)+) {
+            fn serialize<S: Serializer>(archived: &ArchivedEntity, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::Error;
+
+                let storage_ids = [$(StorageId::of::<SparseSet<$type>>()),+];
+
+                // components not listed would be lost
+                if let Some(component) = archived
+                    .components
+                    .iter()
+                    .find(|component| !storage_ids.contains(&component.storage_id))
+                {
+                    return Err(S::Error::custom(format_args!(
+                        "{} is not one of the serialized components.",
+                        component.name
+                    )));
+                }
+
+                Serialize::serialize(&($(archived.get::<$type>(),)+), serializer)
+            }
+            fn deserialize<'de, De: Deserializer<'de>>(deserializer: De) -> Result<ArchivedEntity, De::Error> {
+                let components = <($(Option<$type>,)+)>::deserialize(deserializer)?;
+
+                let mut archived = ArchivedEntity::default();
+                $(
+                    if let Some(component) = components.$index {
+                        archived.push(component);
+                    }
+                )+
+
+                Ok(archived)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde1")]
+macro_rules! tuple_archive {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_tuple_archive![$(($type, $index))*];
+        tuple_archive![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_tuple_archive![$(($type, $index))*];
+    }
+}
+
+#[cfg(feature = "serde1")]
+tuple_archive![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
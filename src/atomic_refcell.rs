@@ -160,8 +160,12 @@ impl<T: ?Sized> AtomicRefCell<T> {
                 }
                 (Some(thread_id), false) => {
                     // accessible from world's thread only
-                    if thread_id != (self.thread_id)() {
-                        return Err(error::Borrow::WrongThread);
+                    let current_thread_id = (self.thread_id)();
+                    if thread_id != current_thread_id {
+                        return Err(error::Borrow::WrongThread {
+                            expected: thread_id,
+                            actual: current_thread_id,
+                        });
                     }
 
                     match self.borrow_state.read() {
@@ -178,6 +182,35 @@ impl<T: ?Sized> AtomicRefCell<T> {
             }
         }
     }
+    /// Leaks a shared borrow, the value can't be borrowed exclusively until [`AtomicRefCell::release_leaked_borrow`] is called.
+    #[inline]
+    pub(crate) fn leak_borrow(&self) -> Result<(), error::Borrow> {
+        let borrow = self.borrow()?;
+
+        // SAFE the value isn't accessed through the borrow
+        let (_, borrow) = unsafe { ARef::destructure(borrow) };
+        core::mem::forget(borrow);
+
+        Ok(())
+    }
+    /// Releases a shared borrow leaked with [`AtomicRefCell::leak_borrow`].
+    ///
+    /// # Safety
+    ///
+    /// A borrow must have been leaked and nothing can still rely on it.
+    #[inline]
+    pub(crate) unsafe fn release_leaked_borrow(&self) {
+        self.borrow_state.release_forgotten_read();
+    }
+    /// Returns a reference to the wrapped value without borrowing it.
+    ///
+    /// # Safety
+    ///
+    /// The value must not be borrowed exclusively while the reference is alive.
+    #[inline]
+    pub(crate) unsafe fn get_unchecked(&self) -> &T {
+        &*self.inner.get()
+    }
     /// Mutably borrows the wrapped value, returning an error if the value is currently borrowed.
     ///
     /// The borrow lasts until the returned `RefMut` exits scope. The value cannot be borrowed while this borrow is
@@ -189,8 +222,12 @@ impl<T: ?Sized> AtomicRefCell<T> {
             // if Send - accessible from any thread, shared only if not world thread
             // if !Send - accessible from world thread only
             if let Some(thread_id) = self.send {
-                if thread_id != (self.thread_id)() {
-                    return Err(error::Borrow::WrongThread);
+                let current_thread_id = (self.thread_id)();
+                if thread_id != current_thread_id {
+                    return Err(error::Borrow::WrongThread {
+                        expected: thread_id,
+                        actual: current_thread_id,
+                    });
                 }
             }
         }
@@ -206,6 +243,26 @@ impl<T: ?Sized> AtomicRefCell<T> {
             Err(err) => Err(err),
         }
     }
+    /// Returns the id of the thread a `!Send` value is bound to.
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    pub(crate) fn bound_thread(&self) -> Option<u64> {
+        self.send
+    }
+    /// Returns `true` if the value can be borrowed from multiple threads at the same time.
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    pub(crate) fn is_sync(&self) -> bool {
+        self.is_sync
+    }
+    /// Binds a `!Send` value to the current thread.
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    pub(crate) fn rebind_thread(&mut self) {
+        if self.send.is_some() {
+            self.send = Some((self.thread_id)());
+        }
+    }
     #[inline]
     #[track_caller]
     pub(crate) fn get_mut(&mut self) -> &'_ mut T {
@@ -214,8 +271,15 @@ impl<T: ?Sized> AtomicRefCell<T> {
             // if Send - accessible from any thread, shared only if not world thread
             // if !Send - accessible from world thread only
             if let Some(thread_id) = self.send {
-                if thread_id != (self.thread_id)() {
-                    panic!("{:?}", error::Borrow::WrongThread);
+                let current_thread_id = (self.thread_id)();
+                if thread_id != current_thread_id {
+                    panic!(
+                        "{:?}",
+                        error::Borrow::WrongThread {
+                            expected: thread_id,
+                            actual: current_thread_id,
+                        }
+                    );
                 }
             }
         }
@@ -244,7 +308,7 @@ impl<'a, T> ARef<'a, T> {
 
 impl<'a, T: ?Sized> ARef<'a, &'a T> {
     #[inline]
-    pub(crate) fn map<U, F: FnOnce(&T) -> &U>(this: Self, f: F) -> ARef<'a, &'a U> {
+    pub(crate) fn map<U: ?Sized, F: FnOnce(&T) -> &U>(this: Self, f: F) -> ARef<'a, &'a U> {
         ARef {
             inner: f(this.inner),
             borrow: this.borrow,
@@ -291,7 +355,10 @@ impl<'a, T> ARefMut<'a, T> {
 
 impl<'a, T: ?Sized> ARefMut<'a, &'a mut T> {
     #[inline]
-    pub(crate) fn map<U, F: FnOnce(&mut T) -> &mut U>(this: Self, f: F) -> ARefMut<'a, &'a mut U> {
+    pub(crate) fn map<U: ?Sized, F: FnOnce(&mut T) -> &mut U>(
+        this: Self,
+        f: F,
+    ) -> ARefMut<'a, &'a mut U> {
         ARefMut {
             inner: f(this.inner),
             borrow: this.borrow,
@@ -399,12 +466,12 @@ fn non_send() {
         (&*(refcell_ptr as *const AtomicRefCell<u32>))
             .borrow()
             .unwrap();
-        assert_eq!(
+        assert!(matches!(
             (&*(refcell_ptr as *const AtomicRefCell<u32>))
                 .borrow_mut()
                 .err(),
-            Some(error::Borrow::WrongThread)
-        );
+            Some(error::Borrow::WrongThread { .. })
+        ));
     })
     .join()
     .unwrap();
@@ -413,6 +480,38 @@ fn non_send() {
     refcell.borrow_mut().unwrap();
 }
 
+#[cfg(feature = "thread_local")]
+#[test]
+fn rebind_thread() {
+    use crate::std_thread_id_generator;
+
+    let mut refcell = AtomicRefCell::new_non_send_sync(0u32, Arc::new(std_thread_id_generator));
+    let main_thread_id = refcell.bound_thread().unwrap();
+    let refcell_ptr: *mut _ = &mut refcell;
+    let refcell_ptr = refcell_ptr as usize;
+
+    std::thread::spawn(move || unsafe {
+        let refcell = &mut *(refcell_ptr as *mut AtomicRefCell<u32>);
+        let thread_id = std_thread_id_generator();
+
+        assert_eq!(
+            refcell.borrow().err(),
+            Some(error::Borrow::WrongThread {
+                expected: main_thread_id,
+                actual: thread_id
+            })
+        );
+
+        refcell.rebind_thread();
+        assert_eq!(refcell.bound_thread(), Some(thread_id));
+        refcell.borrow_mut().unwrap();
+    })
+    .join()
+    .unwrap();
+
+    assert!(refcell.borrow().is_err());
+}
+
 #[cfg(feature = "thread_local")]
 #[test]
 fn non_sync() {
@@ -446,18 +545,18 @@ fn non_send_sync() {
     let refcell_ptr = refcell_ptr as usize;
 
     std::thread::spawn(move || unsafe {
-        assert_eq!(
+        assert!(matches!(
             (&*(refcell_ptr as *const AtomicRefCell<u32>))
                 .borrow()
                 .err(),
-            Some(error::Borrow::WrongThread)
-        );
-        assert_eq!(
+            Some(error::Borrow::WrongThread { .. })
+        ));
+        assert!(matches!(
             (&*(refcell_ptr as *const AtomicRefCell<u32>))
                 .borrow_mut()
                 .err(),
-            Some(error::Borrow::WrongThread)
-        );
+            Some(error::Borrow::WrongThread { .. })
+        ));
     })
     .join()
     .unwrap();
@@ -88,6 +88,16 @@ impl BorrowState {
         SharedBorrow(self)
     }
 
+    /// Releases a shared borrow that was forgotten instead of dropped.
+    ///
+    /// # Safety
+    ///
+    /// A `SharedBorrow` of this state must have been forgotten and not released yet.
+    #[inline]
+    pub(super) unsafe fn release_forgotten_read(&self) {
+        self.0.fetch_sub(1, Ordering::Release);
+    }
+
     #[inline]
     pub(super) fn write(&self) -> Result<ExclusiveBorrow<'_>, error::Borrow> {
         let old = match self
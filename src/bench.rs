@@ -0,0 +1,274 @@
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::scheduler::{AsLabel, WorkloadSystem};
+use crate::sparse_set::TupleAddComponent;
+use crate::world::World;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::time::Duration;
+use std::time::Instant;
+
+/// Describes the entities of a benchmark [`World`], as a weighted mix of archetypes.
+///
+/// Entities are spread deterministically: with weights `3` and `1`, out of every 4 entities,
+/// the first 3 get the first archetype and the last one gets the second.
+///
+/// ### Example
+/// ```
+/// use shipyard::bench::Population;
+/// use shipyard::{Component, IntoIter, View};
+///
+/// #[derive(Component)]
+/// struct Pos(f32);
+///
+/// #[derive(Component)]
+/// struct Vel(f32);
+///
+/// let world = Population::new()
+///     .with_archetype(3, |i| (Pos(i as f32), Vel(1.0)))
+///     .with_archetype(1, |i| (Pos(i as f32),))
+///     .build(1000);
+///
+/// let moving = world.run(|positions: View<Pos>, velocities: View<Vel>| {
+///     (&positions, &velocities).iter().count()
+/// });
+///
+/// assert_eq!(world.entity_count(), 1000);
+/// assert_eq!(moving, 750);
+/// ```
+#[allow(clippy::type_complexity)]
+pub struct Population {
+    archetypes: Vec<(u32, Box<dyn Fn(&mut World, usize) -> EntityId>)>,
+}
+
+impl Population {
+    /// Creates a `Population` without any archetype.
+    pub fn new() -> Population {
+        Population {
+            archetypes: Vec::new(),
+        }
+    }
+    /// Adds an archetype, `components` is called with the index of the entity being added.
+    pub fn with_archetype<C: TupleAddComponent, F: Fn(usize) -> C + 'static>(
+        mut self,
+        weight: u32,
+        components: F,
+    ) -> Population {
+        self.archetypes.push((
+            weight,
+            Box::new(move |world: &mut World, index| world.add_entity(components(index))),
+        ));
+
+        self
+    }
+    /// Creates a new [`World`] with `count` entities.
+    ///
+    /// ### Panics
+    ///
+    /// - `count` isn't zero and the total weight of the archetypes is zero.
+    #[track_caller]
+    pub fn build(&self, count: usize) -> World {
+        let mut world = World::new();
+
+        self.populate(&mut world, count);
+
+        world
+    }
+    /// Adds `count` entities to `world` and returns their ids.
+    ///
+    /// ### Panics
+    ///
+    /// - `count` isn't zero and the total weight of the archetypes is zero.
+    #[track_caller]
+    pub fn populate(&self, world: &mut World, count: usize) -> Vec<EntityId> {
+        let total_weight: u64 = self
+            .archetypes
+            .iter()
+            .map(|(weight, _)| u64::from(*weight))
+            .sum();
+
+        assert!(
+            count == 0 || total_weight > 0,
+            "Cannot populate a World without any weighted archetype."
+        );
+
+        let mut entities = Vec::with_capacity(count);
+
+        for index in 0..count {
+            let mut slot = index as u64 % total_weight.max(1);
+
+            for (weight, add_entity) in &self.archetypes {
+                let weight = u64::from(*weight);
+
+                if slot < weight {
+                    entities.push(add_entity(world, index));
+                    break;
+                }
+
+                slot -= weight;
+            }
+        }
+
+        entities
+    }
+}
+
+impl Default for Population {
+    fn default() -> Self {
+        Population::new()
+    }
+}
+
+impl core::fmt::Debug for Population {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Population")
+            .field(
+                "weights",
+                &self
+                    .archetypes
+                    .iter()
+                    .map(|(weight, _)| *weight)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Duration of each iteration of a benchmark.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BenchStats {
+    samples: Vec<Duration>,
+}
+
+impl BenchStats {
+    /// Returns the duration of each iteration, in the order they ran.
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+    /// Returns the number of iterations.
+    pub fn iterations(&self) -> usize {
+        self.samples.len()
+    }
+    /// Returns the sum of all iterations.\
+    /// Can be returned as is from criterion's `iter_custom`.
+    pub fn total(&self) -> Duration {
+        self.samples.iter().sum()
+    }
+    /// Returns the shortest iteration.
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+    /// Returns the longest iteration.
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+    /// Returns the average duration of an iteration.
+    pub fn mean(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+
+        Duration::from_nanos((self.total().as_nanos() / self.samples.len() as u128) as u64)
+    }
+    /// Returns the median duration of an iteration.
+    pub fn median(&self) -> Duration {
+        let mut samples = self.samples.clone();
+        samples.sort_unstable();
+
+        match samples.len() {
+            0 => Duration::ZERO,
+            len if len % 2 == 1 => samples[len / 2],
+            len => (samples[len / 2 - 1] + samples[len / 2]) / 2,
+        }
+    }
+}
+
+impl core::fmt::Display for BenchStats {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} iterations: mean {:?}, median {:?}, min {:?}, max {:?}",
+            self.iterations(),
+            self.mean(),
+            self.median(),
+            self.min(),
+            self.max()
+        )
+    }
+}
+
+/// Runs `f` `iterations` times and times each run.
+///
+/// Stops at the first error.
+pub fn measure<E>(
+    iterations: usize,
+    mut f: impl FnMut() -> Result<(), E>,
+) -> Result<BenchStats, E> {
+    let mut samples = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+
+    Ok(BenchStats { samples })
+}
+
+/// Runs `system` `iterations` times and times each run.\
+/// The tracking required by `system` is enabled before the first run, like when it's added to a workload.
+///
+/// ### Example
+/// ```
+/// use shipyard::bench::{self, Population};
+/// use shipyard::{Component, IntoIter, IntoWorkloadSystem, View, ViewMut};
+///
+/// #[derive(Component)]
+/// struct Pos(f32);
+///
+/// #[derive(Component)]
+/// struct Vel(f32);
+///
+/// fn movement(mut positions: ViewMut<Pos>, velocities: View<Vel>) {
+///     for (pos, vel) in (&mut positions, &velocities).iter() {
+///         pos.0 += vel.0;
+///     }
+/// }
+///
+/// let world = Population::new()
+///     .with_archetype(1, |_| (Pos(0.0), Vel(1.0)))
+///     .build(100);
+///
+/// let movement = movement.into_workload_system().unwrap();
+/// let stats = bench::run_system(&world, &movement, 10).unwrap();
+///
+/// assert_eq!(stats.iterations(), 10);
+/// ```
+pub fn run_system(
+    world: &World,
+    system: &WorkloadSystem,
+    iterations: usize,
+) -> Result<BenchStats, error::Run> {
+    {
+        let all_storages = world
+            .all_storages()
+            .map_err(|err| error::Run::GetStorage(error::GetStorage::AllStoragesBorrow(err)))?;
+
+        for enable_tracking_fn in &system.tracking_to_enable {
+            (enable_tracking_fn)(&all_storages).map_err(error::Run::GetStorage)?;
+        }
+    }
+
+    measure(iterations, || (system.system_fn)(world))
+}
+
+/// Runs the workload `label` `iterations` times and times each run.
+pub fn run_workload<T>(
+    world: &World,
+    label: impl AsLabel<T>,
+    iterations: usize,
+) -> Result<BenchStats, error::RunWorkload> {
+    let label = label.as_label();
+
+    measure(iterations, || world.run_workload(label.clone()))
+}
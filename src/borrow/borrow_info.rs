@@ -5,7 +5,7 @@ use super::non_send_sync::NonSendSync;
 #[cfg(feature = "thread_local")]
 use super::non_sync::NonSync;
 use super::Mutability;
-use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::all_storages::AllStorages;
 use crate::component::{Component, Unique};
 use crate::entities::Entities;
 use crate::error;
@@ -19,6 +19,8 @@ use crate::views::{
     AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut,
     View, ViewMut,
 };
+#[cfg(feature = "std")]
+use crate::views::{Rng, RngUnique, SystemContext};
 use alloc::vec::Vec;
 use core::any::type_name;
 
@@ -67,6 +69,10 @@ pub unsafe trait BorrowInfo {
     fn enable_tracking(
         enable_tracking_fn: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
     );
+    /// Lists the storages that have to be present in the `World` for the borrow to succeed.\
+    /// Used by [`Workload::validate`](crate::Workload::validate).
+    #[allow(unused_variables)]
+    fn required_storages(required: &mut Vec<StorageId>) {}
 }
 
 // this is needed for downstream crates to impl IntoWorkloadSystem
@@ -90,6 +96,7 @@ unsafe impl<'a> BorrowInfo for AllStoragesView<'a> {
             thread_safe: true,
             #[cfg(feature = "thread_local")]
             thread_safe: false,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
@@ -105,11 +112,26 @@ unsafe impl<'a> BorrowInfo for AllStoragesViewMut<'a> {
             thread_safe: true,
             #[cfg(feature = "thread_local")]
             thread_safe: false,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
 }
 
+#[cfg(feature = "std")]
+unsafe impl BorrowInfo for SystemContext<'_> {
+    fn borrow_info(_: &mut Vec<TypeInfo>) {}
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+#[cfg(feature = "std")]
+unsafe impl BorrowInfo for Rng<'_> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        UniqueView::<RngUnique>::borrow_info(info);
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
 unsafe impl<'a> BorrowInfo for EntitiesView<'a> {
     fn borrow_info(info: &mut Vec<TypeInfo>) {
         info.push(TypeInfo {
@@ -117,6 +139,7 @@ unsafe impl<'a> BorrowInfo for EntitiesView<'a> {
             mutability: Mutability::Shared,
             storage_id: StorageId::of::<Entities>(),
             thread_safe: true,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
@@ -129,6 +152,7 @@ unsafe impl<'a> BorrowInfo for EntitiesViewMut<'a> {
             mutability: Mutability::Exclusive,
             storage_id: StorageId::of::<Entities>(),
             thread_safe: true,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
@@ -144,6 +168,7 @@ where
             mutability: Mutability::Shared,
             storage_id: StorageId::of::<SparseSet<T>>(),
             thread_safe: true,
+            optional: false,
         });
     }
     fn enable_tracking(
@@ -151,7 +176,7 @@ where
     ) {
         enable_tracking_fn.push(|all_storages| {
             all_storages
-                .custom_storage_or_insert_mut(SparseSet::<T>::new)?
+                .storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new, false)?
                 .enable_tracking::<Track>();
 
             Ok(())
@@ -170,6 +195,7 @@ where
             mutability: Mutability::Shared,
             storage_id: StorageId::of::<SparseSet<T>>(),
             thread_safe: true,
+            optional: false,
         });
     }
 
@@ -178,7 +204,11 @@ where
     ) {
         enable_tracking_fn.push(|all_storages| {
             all_storages
-                .custom_storage_or_insert_non_send_mut(|| NonSend(SparseSet::<T>::new()))?
+                .storage_or_insert_non_send_mut(
+                    StorageId::of::<NonSend<SparseSet<T>>>(),
+                    || NonSend(SparseSet::<T>::new()),
+                    false,
+                )?
                 .enable_tracking::<Track>();
 
             Ok(())
@@ -197,6 +227,7 @@ where
             mutability: Mutability::Shared,
             storage_id: StorageId::of::<SparseSet<T>>(),
             thread_safe: false,
+            optional: false,
         });
     }
 
@@ -205,7 +236,11 @@ where
     ) {
         enable_tracking_fn.push(|all_storages| {
             all_storages
-                .custom_storage_or_insert_non_sync_mut(|| NonSync(SparseSet::<T>::new()))?
+                .storage_or_insert_non_sync_mut(
+                    StorageId::of::<NonSync<SparseSet<T>>>(),
+                    || NonSync(SparseSet::<T>::new()),
+                    false,
+                )?
                 .enable_tracking::<Track>();
 
             Ok(())
@@ -224,6 +259,7 @@ where
             mutability: Mutability::Shared,
             storage_id: StorageId::of::<SparseSet<T>>(),
             thread_safe: false,
+            optional: false,
         });
     }
 
@@ -232,7 +268,11 @@ where
     ) {
         enable_tracking_fn.push(|all_storages| {
             all_storages
-                .custom_storage_or_insert_non_send_sync_mut(|| NonSendSync(SparseSet::<T>::new()))?
+                .storage_or_insert_non_send_sync_mut(
+                    StorageId::of::<NonSendSync<SparseSet<T>>>(),
+                    || NonSendSync(SparseSet::<T>::new()),
+                    false,
+                )?
                 .enable_tracking::<Track>();
 
             Ok(())
@@ -250,6 +290,7 @@ where
             mutability: Mutability::Exclusive,
             storage_id: StorageId::of::<SparseSet<T>>(),
             thread_safe: true,
+            optional: false,
         });
     }
     fn enable_tracking(
@@ -257,7 +298,7 @@ where
     ) {
         enable_tracking_fn.push(|all_storages| {
             all_storages
-                .custom_storage_or_insert_mut(SparseSet::<T>::new)?
+                .storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new, false)?
                 .enable_tracking::<Track>();
 
             Ok(())
@@ -276,6 +317,7 @@ where
             mutability: Mutability::Exclusive,
             storage_id: StorageId::of::<SparseSet<T>>(),
             thread_safe: false,
+            optional: false,
         });
     }
 
@@ -284,7 +326,11 @@ where
     ) {
         enable_tracking_fn.push(|all_storages| {
             all_storages
-                .custom_storage_or_insert_non_send_mut(|| NonSend(SparseSet::<T>::new()))?
+                .storage_or_insert_non_send_mut(
+                    StorageId::of::<NonSend<SparseSet<T>>>(),
+                    || NonSend(SparseSet::<T>::new()),
+                    false,
+                )?
                 .enable_tracking::<Track>();
 
             Ok(())
@@ -303,6 +349,7 @@ where
             mutability: Mutability::Exclusive,
             storage_id: StorageId::of::<SparseSet<T>>(),
             thread_safe: true,
+            optional: false,
         });
     }
 
@@ -311,7 +358,11 @@ where
     ) {
         enable_tracking_fn.push(|all_storages| {
             all_storages
-                .custom_storage_or_insert_non_sync_mut(|| NonSync(SparseSet::<T>::new()))?
+                .storage_or_insert_non_sync_mut(
+                    StorageId::of::<NonSync<SparseSet<T>>>(),
+                    || NonSync(SparseSet::<T>::new()),
+                    false,
+                )?
                 .enable_tracking::<Track>();
 
             Ok(())
@@ -330,6 +381,7 @@ where
             mutability: Mutability::Exclusive,
             storage_id: StorageId::of::<SparseSet<T>>(),
             thread_safe: false,
+            optional: false,
         });
     }
 
@@ -338,7 +390,11 @@ where
     ) {
         enable_tracking_fn.push(|all_storages| {
             all_storages
-                .custom_storage_or_insert_non_send_sync_mut(|| NonSendSync(SparseSet::<T>::new()))?
+                .storage_or_insert_non_send_sync_mut(
+                    StorageId::of::<NonSendSync<SparseSet<T>>>(),
+                    || NonSendSync(SparseSet::<T>::new()),
+                    false,
+                )?
                 .enable_tracking::<Track>();
 
             Ok(())
@@ -353,9 +409,13 @@ unsafe impl<'a, T: Send + Sync + Unique> BorrowInfo for UniqueView<'a, T> {
             mutability: Mutability::Shared,
             storage_id: StorageId::of::<UniqueStorage<T>>(),
             thread_safe: true,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+    fn required_storages(required: &mut Vec<StorageId>) {
+        required.push(StorageId::of::<UniqueStorage<T>>());
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -366,9 +426,13 @@ unsafe impl<'a, T: Sync + Unique> BorrowInfo for NonSend<UniqueView<'a, T>> {
             mutability: Mutability::Shared,
             storage_id: StorageId::of::<UniqueStorage<T>>(),
             thread_safe: true,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+    fn required_storages(required: &mut Vec<StorageId>) {
+        required.push(StorageId::of::<UniqueStorage<T>>());
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -379,9 +443,13 @@ unsafe impl<'a, T: Send + Unique> BorrowInfo for NonSync<UniqueView<'a, T>> {
             mutability: Mutability::Shared,
             storage_id: StorageId::of::<UniqueStorage<T>>(),
             thread_safe: false,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+    fn required_storages(required: &mut Vec<StorageId>) {
+        required.push(StorageId::of::<UniqueStorage<T>>());
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -392,9 +460,13 @@ unsafe impl<'a, T: Unique> BorrowInfo for NonSendSync<UniqueView<'a, T>> {
             mutability: Mutability::Shared,
             storage_id: StorageId::of::<UniqueStorage<T>>(),
             thread_safe: false,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+    fn required_storages(required: &mut Vec<StorageId>) {
+        required.push(StorageId::of::<UniqueStorage<T>>());
+    }
 }
 
 unsafe impl<'a, T: Send + Sync + Unique> BorrowInfo for UniqueViewMut<'a, T> {
@@ -404,9 +476,13 @@ unsafe impl<'a, T: Send + Sync + Unique> BorrowInfo for UniqueViewMut<'a, T> {
             mutability: Mutability::Exclusive,
             storage_id: StorageId::of::<UniqueStorage<T>>(),
             thread_safe: true,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+    fn required_storages(required: &mut Vec<StorageId>) {
+        required.push(StorageId::of::<UniqueStorage<T>>());
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -417,9 +493,13 @@ unsafe impl<'a, T: Sync + Unique> BorrowInfo for NonSend<UniqueViewMut<'a, T>> {
             mutability: Mutability::Exclusive,
             storage_id: StorageId::of::<UniqueStorage<T>>(),
             thread_safe: false,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+    fn required_storages(required: &mut Vec<StorageId>) {
+        required.push(StorageId::of::<UniqueStorage<T>>());
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -430,9 +510,13 @@ unsafe impl<'a, T: Send + Unique> BorrowInfo for NonSync<UniqueViewMut<'a, T>> {
             mutability: Mutability::Exclusive,
             storage_id: StorageId::of::<UniqueStorage<T>>(),
             thread_safe: true,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+    fn required_storages(required: &mut Vec<StorageId>) {
+        required.push(StorageId::of::<UniqueStorage<T>>());
+    }
 }
 
 #[cfg(feature = "thread_local")]
@@ -443,14 +527,24 @@ unsafe impl<'a, T: Unique> BorrowInfo for NonSendSync<UniqueViewMut<'a, T>> {
             mutability: Mutability::Exclusive,
             storage_id: StorageId::of::<UniqueStorage<T>>(),
             thread_safe: false,
+            optional: false,
         });
     }
     fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+    fn required_storages(required: &mut Vec<StorageId>) {
+        required.push(StorageId::of::<UniqueStorage<T>>());
+    }
 }
 
 unsafe impl<T: BorrowInfo> BorrowInfo for Option<T> {
     fn borrow_info(info: &mut Vec<TypeInfo>) {
+        let start = info.len();
+
         T::borrow_info(info);
+
+        for type_info in &mut info[start..] {
+            type_info.optional = true;
+        }
     }
     fn enable_tracking(
         enable_tracking: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
@@ -472,6 +566,11 @@ macro_rules! impl_borrow_info {
                     $type::enable_tracking(enable_tracking_fn);
                 )+
             }
+            fn required_storages(required: &mut Vec<StorageId>) {
+                $(
+                    $type::required_storages(required);
+                )+
+            }
         }
     }
 }
@@ -25,16 +25,18 @@ use crate::system::Nothing;
 use crate::tracking::{Tracking, TrackingTimestamp};
 use crate::unique::UniqueStorage;
 use crate::views::{EntitiesView, EntitiesViewMut, UniqueView, UniqueViewMut, View, ViewMut};
+#[cfg(feature = "std")]
+use crate::views::{Rng, RngUnique};
 use core::marker::PhantomData;
 
 /// Describes if a storage is borrowed exclusively or not.  
-/// It is used to display workloads' borrowing information.
+/// It is used to describe workloads' borrowing information.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mutability {
-    #[allow(missing_docs)]
+    /// The storage can be borrowed by other systems at the same time.
     Shared,
-    #[allow(missing_docs)]
+    /// Nothing else can borrow the storage at the same time.
     Exclusive,
 }
 
@@ -120,6 +122,23 @@ impl Borrow for () {
     }
 }
 
+#[cfg(feature = "std")]
+impl Borrow for Rng<'_> {
+    type View<'a> = Rng<'a>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let rng = UniqueView::<RngUnique>::borrow(all_storages, all_borrow, last_run, current)?;
+
+        Ok(Rng::new(rng))
+    }
+}
+
 impl Borrow for EntitiesView<'_> {
     type View<'a> = EntitiesView<'a>;
 
@@ -171,6 +190,7 @@ where
     type View<'a> = View<'a, T, Track>;
 
     #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn borrow<'a>(
         all_storages: &'a AllStorages,
         all_borrow: Option<SharedBorrow<'a>>,
@@ -183,7 +203,14 @@ where
 
         sparse_set.check_tracking::<Track>()?;
 
-        Ok(View::new(sparse_set, borrow, all_borrow, last_run, current))
+        Ok(View::new(
+            sparse_set,
+            borrow,
+            all_borrow,
+            last_run,
+            current,
+            all_storages.entity_names(),
+        ))
     }
 }
 
@@ -195,6 +222,7 @@ where
     type View<'a> = NonSend<View<'a, T, Track>>;
 
     #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn borrow<'a>(
         all_storages: &'a AllStorages,
         all_borrow: Option<SharedBorrow<'a>>,
@@ -213,8 +241,9 @@ where
             last_removal_or_deletion: last_run.unwrap_or(current),
             current,
             sparse_set,
-            borrow,
+            borrow: Some(borrow),
             all_borrow,
+            entity_names: all_storages.entity_names(),
             phantom: PhantomData,
         }))
     }
@@ -228,6 +257,7 @@ where
     type View<'a> = NonSync<View<'a, T, Track>>;
 
     #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn borrow<'a>(
         all_storages: &'a AllStorages,
         all_borrow: Option<SharedBorrow<'a>>,
@@ -246,8 +276,9 @@ where
             last_removal_or_deletion: last_run.unwrap_or(current),
             current,
             sparse_set,
-            borrow,
+            borrow: Some(borrow),
             all_borrow,
+            entity_names: all_storages.entity_names(),
             phantom: PhantomData,
         }))
     }
@@ -261,6 +292,7 @@ where
     type View<'a> = NonSendSync<View<'a, T, Track>>;
 
     #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn borrow<'a>(
         all_storages: &'a AllStorages,
         all_borrow: Option<SharedBorrow<'a>>,
@@ -280,8 +312,9 @@ where
             last_removal_or_deletion: last_run.unwrap_or(current),
             current,
             sparse_set,
-            borrow,
+            borrow: Some(borrow),
             all_borrow,
+            entity_names: all_storages.entity_names(),
             phantom: PhantomData,
         }))
     }
@@ -294,6 +327,7 @@ where
     type View<'a> = ViewMut<'a, T, Track>;
 
     #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn borrow<'a>(
         all_storages: &'a AllStorages,
         all_borrow: Option<SharedBorrow<'a>>,
@@ -305,6 +339,7 @@ where
         let (sparse_set, borrow) = unsafe { ARefMut::destructure(view) };
 
         sparse_set.check_tracking::<Track>()?;
+        sparse_set.last_change = current;
 
         Ok(ViewMut {
             last_insertion: last_run.unwrap_or(sparse_set.last_insert),
@@ -314,6 +349,7 @@ where
             sparse_set,
             borrow,
             all_borrow,
+            entity_names: all_storages.entity_names(),
             phantom: PhantomData,
         })
     }
@@ -327,6 +363,7 @@ where
     type View<'a> = NonSend<ViewMut<'a, T, Track>>;
 
     #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn borrow<'a>(
         all_storages: &'a AllStorages,
         all_borrow: Option<SharedBorrow<'a>>,
@@ -339,6 +376,7 @@ where
         let (sparse_set, borrow) = unsafe { ARefMut::destructure(view) };
 
         sparse_set.check_tracking::<Track>()?;
+        sparse_set.last_change = current;
 
         Ok(NonSend(ViewMut {
             last_insertion: last_run.unwrap_or(sparse_set.last_insert),
@@ -348,6 +386,7 @@ where
             sparse_set,
             borrow: borrow,
             all_borrow: all_borrow,
+            entity_names: all_storages.entity_names(),
             phantom: PhantomData,
         }))
     }
@@ -361,6 +400,7 @@ where
     type View<'a> = NonSync<ViewMut<'a, T, Track>>;
 
     #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn borrow<'a>(
         all_storages: &'a AllStorages,
         all_borrow: Option<SharedBorrow<'a>>,
@@ -373,6 +413,7 @@ where
         let (sparse_set, borrow) = unsafe { ARefMut::destructure(view) };
 
         sparse_set.check_tracking::<Track>()?;
+        sparse_set.last_change = current;
 
         Ok(NonSync(ViewMut {
             last_insertion: last_run.unwrap_or(sparse_set.last_insert),
@@ -382,6 +423,7 @@ where
             sparse_set,
             borrow: borrow,
             all_borrow: all_borrow,
+            entity_names: all_storages.entity_names(),
             phantom: PhantomData,
         }))
     }
@@ -395,6 +437,7 @@ where
     type View<'a> = NonSendSync<ViewMut<'a, T, Track>>;
 
     #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn borrow<'a>(
         all_storages: &'a AllStorages,
         all_borrow: Option<SharedBorrow<'a>>,
@@ -407,6 +450,7 @@ where
         let (sparse_set, borrow) = unsafe { ARefMut::destructure(view) };
 
         sparse_set.check_tracking::<Track>()?;
+        sparse_set.last_change = current;
 
         Ok(NonSendSync(ViewMut {
             last_insertion: last_run.unwrap_or(sparse_set.last_insert),
@@ -416,6 +460,7 @@ where
             sparse_set,
             borrow: borrow,
             all_borrow: all_borrow,
+            entity_names: all_storages.entity_names(),
             phantom: PhantomData,
         }))
     }
@@ -631,6 +676,7 @@ impl<T: Borrow> Borrow for Option<T> {
     type View<'a> = Option<T::View<'a>>;
 
     #[inline]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn borrow<'a>(
         all_storages: &'a AllStorages,
         all_borrow: Option<SharedBorrow<'a>>,
@@ -647,6 +693,7 @@ macro_rules! impl_borrow {
             type View<'a> = ($($type::View<'a>,)+);
 
             #[inline]
+            #[cfg_attr(feature = "storage_location", track_caller)]
             fn borrow<'a>(
                 all_storages: &'a AllStorages,
                 all_borrow: Option<SharedBorrow<'a>>,
@@ -2,6 +2,8 @@ use crate::atomic_refcell::ARef;
 use crate::borrow::Borrow;
 use crate::error;
 use crate::tracking::TrackingTimestamp;
+#[cfg(feature = "std")]
+use crate::views::SystemContext;
 use crate::views::{AllStoragesView, AllStoragesViewMut};
 use crate::world::World;
 
@@ -21,6 +23,7 @@ pub trait WorldBorrow {
 impl<T: Borrow> WorldBorrow for T {
     type WorldView<'a> = <T as Borrow>::View<'a>;
 
+    #[cfg_attr(feature = "storage_location", track_caller)]
     fn world_borrow(
         world: &World,
         last_run: Option<TrackingTimestamp>,
@@ -71,3 +74,17 @@ impl WorldBorrow for AllStoragesViewMut<'_> {
             .map_err(error::GetStorage::AllStoragesBorrow)
     }
 }
+
+#[cfg(feature = "std")]
+impl WorldBorrow for SystemContext<'_> {
+    type WorldView<'a> = SystemContext<'a>;
+
+    #[inline]
+    fn world_borrow(
+        world: &World,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::WorldView<'_>, error::GetStorage> {
+        Ok(SystemContext::new(world, last_run, current))
+    }
+}
@@ -0,0 +1,153 @@
+//! Deprecated wrappers for APIs of previous versions.
+//!
+//! They forward to the current API and emit a deprecation warning pointing to the replacement,
+//! allowing a codebase to be migrated a few call sites at a time.
+//!
+//! Only enabled with the `compat` feature.
+//!
+//! Components and uniques now have to implement [`Component`] and [`Unique`].
+//! The [`compat_component!`](crate::compat_component) and [`compat_unique!`](crate::compat_unique) macros
+//! implement them without tracking for a list of types.
+//!
+//! [`Component`]: crate::Component
+//! [`Unique`]: crate::Unique
+
+use crate::all_storages::AllStorages;
+use crate::borrow::WorldBorrow;
+use crate::error;
+use crate::scheduler::{AsLabel, Workload};
+use crate::system::{AllSystem, System};
+use crate::world::World;
+
+/// Previous name of [`Workload`].
+#[deprecated(since = "0.7.0", note = "use `Workload` instead")]
+pub type WorkloadBuilder = Workload;
+
+impl World {
+    /// Borrows the requested views.
+    #[deprecated(since = "0.7.0", note = "use `World::borrow` instead")]
+    pub fn try_borrow<V: WorldBorrow>(&self) -> Result<V::WorldView<'_>, error::GetStorage> {
+        self.borrow::<V>()
+    }
+    /// Borrows the requested views and runs `system`.\
+    /// Unlike [`World::run`], borrow errors are returned instead of panicking.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `World::run` which panics on borrow errors, or `World::borrow` to handle them"
+    )]
+    pub fn try_run<B, S: System<(), B>>(&self, system: S) -> Result<S::Return, error::Run> {
+        system.run((), self).map_err(error::Run::GetStorage)
+    }
+    /// Borrows the requested views and runs `system` with `data`.\
+    /// Unlike [`World::run_with_data`], borrow errors are returned instead of panicking.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `World::run_with_data` which panics on borrow errors, or `World::borrow` to handle them"
+    )]
+    pub fn try_run_with_data<Data, B, S: System<(Data,), B>>(
+        &self,
+        system: S,
+        data: Data,
+    ) -> Result<S::Return, error::Run> {
+        system.run((data,), self).map_err(error::Run::GetStorage)
+    }
+    /// Runs the workload with the given label.
+    #[deprecated(since = "0.7.0", note = "use `World::run_workload` instead")]
+    pub fn try_run_workload<T>(&self, label: impl AsLabel<T>) -> Result<(), error::RunWorkload> {
+        self.run_workload(label)
+    }
+    /// Runs the default workload.
+    #[deprecated(since = "0.7.0", note = "use `World::run_default_workload` instead")]
+    pub fn run_default(&self) -> Result<(), error::RunWorkload> {
+        self.run_default_workload()
+    }
+    /// Runs the default workload.
+    #[deprecated(since = "0.7.0", note = "use `World::run_default_workload` instead")]
+    pub fn try_run_default(&self) -> Result<(), error::RunWorkload> {
+        self.run_default_workload()
+    }
+}
+
+impl AllStorages {
+    /// Borrows the requested views and runs `system`.\
+    /// Unlike [`AllStorages::run`], borrow errors are returned instead of panicking.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `AllStorages::run` which panics on borrow errors, or `AllStorages::borrow` to handle them"
+    )]
+    pub fn try_run<B, S: AllSystem<(), B>>(&self, system: S) -> Result<S::Return, error::Run> {
+        system.run((), self).map_err(error::Run::GetStorage)
+    }
+    /// Borrows the requested views and runs `system` with `data`.\
+    /// Unlike [`AllStorages::run_with_data`], borrow errors are returned instead of panicking.
+    #[deprecated(
+        since = "0.7.0",
+        note = "use `AllStorages::run_with_data` which panics on borrow errors, or `AllStorages::borrow` to handle them"
+    )]
+    pub fn try_run_with_data<Data, B, S: AllSystem<(Data,), B>>(
+        &self,
+        system: S,
+        data: Data,
+    ) -> Result<S::Return, error::Run> {
+        system.run((data,), self).map_err(error::Run::GetStorage)
+    }
+}
+
+impl Workload {
+    /// Creates a new empty [`Workload`].
+    #[deprecated(since = "0.7.0", note = "use `Workload::new` instead")]
+    pub fn builder<T>(label: impl AsLabel<T>) -> Workload {
+        Workload::new(label)
+    }
+}
+
+/// Implements [`Component`](crate::Component) without tracking for each type.
+///
+/// ### Example
+/// ```
+/// use shipyard::{compat_component, World};
+///
+/// struct Position(f32);
+/// struct Velocity(f32);
+///
+/// compat_component!(Position, Velocity);
+///
+/// let mut world = World::new();
+///
+/// world.add_entity((Position(0.0), Velocity(1.0)));
+/// ```
+#[macro_export]
+macro_rules! compat_component {
+    ($($type: ty),+ $(,)?) => {
+        $(
+            impl $crate::Component for $type {
+                type Tracking = $crate::track::Untracked;
+            }
+        )+
+    };
+}
+
+/// Implements [`Unique`](crate::Unique) without tracking for each type.
+///
+/// ### Example
+/// ```
+/// use shipyard::{compat_unique, UniqueView, World};
+///
+/// struct Camera(f32);
+///
+/// compat_unique!(Camera);
+///
+/// let world = World::new();
+///
+/// world.add_unique(Camera(1.0));
+///
+/// assert_eq!(world.borrow::<UniqueView<Camera>>().unwrap().0, 1.0);
+/// ```
+#[macro_export]
+macro_rules! compat_unique {
+    ($($type: ty),+ $(,)?) => {
+        $(
+            impl $crate::Unique for $type {}
+        )+
+    };
+}
@@ -1,12 +1,18 @@
 use crate::tracking::Tracking;
 
 /// Indicates that a `struct` or `enum` can be store in the `World`.
+///
+/// When derived, `#[component(deref)]` implements `Deref` and `DerefMut` to the field of single field structs.\
+/// Dereferencing through [`Mut`](crate::Mut) still flags the component as modified.
 #[cfg(feature = "thread_local")]
 pub trait Component: Sized + 'static {
     /// Kind of event to track for this component.
     type Tracking: Tracking;
 }
 /// Indicates that a `struct` or `enum` can be store in the `World`.
+///
+/// When derived, `#[component(deref)]` implements `Deref` and `DerefMut` to the field of single field structs.\
+/// Dereferencing through [`Mut`](crate::Mut) still flags the component as modified.
 #[cfg(not(feature = "thread_local"))]
 pub trait Component: Sized + Send + Sync + 'static {
     /// Kind of event to track for this component.
@@ -19,3 +25,8 @@ pub trait Unique: Sized + 'static {}
 /// Indicates that a `struct` or `enum` can be store a single time in the `World`.
 #[cfg(not(feature = "thread_local"))]
 pub trait Unique: Sized + Send + Sync + 'static {}
+
+/// Marks a [`Unique`] only updated through shared references, like atomics.
+///
+/// Allows the unique to be borrowed with [`UniqueAtomicView`](crate::UniqueAtomicView).
+pub trait AtomicUnique: Unique + Sync {}
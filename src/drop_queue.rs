@@ -0,0 +1,217 @@
+use crate::component::Unique;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Components waiting to be dropped.
+///
+/// Storages with [`SparseSet::defer_drops`] enabled keep the components they delete instead of dropping them right away.\
+/// [`World::take_deferred_drops`] collects them in a `DropQueue`, their destructors then run when the queue says so.
+///
+/// The queue can be stored as a unique and drained a few components per frame,
+/// or sent to another thread to be dropped in the background.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, DropQueue, UniqueViewMut, World};
+///
+/// #[derive(Component)]
+/// struct Inventory(Vec<u32>);
+///
+/// let mut world = World::new();
+/// world.defer_drops::<Inventory>();
+/// world.add_unique(DropQueue::new());
+///
+/// for _ in 0..100 {
+///     world.add_entity((Inventory(vec![0; 100]),));
+/// }
+///
+/// world.clear();
+///
+/// let mut deferred = world.take_deferred_drops();
+/// assert_eq!(deferred.len(), 100);
+///
+/// world.run(|mut drop_queue: UniqueViewMut<DropQueue>| {
+///     drop_queue.append(&mut deferred);
+///
+///     // drops a few components each frame
+///     assert_eq!(drop_queue.drop_some(30), 30);
+///     assert_eq!(drop_queue.len(), 70);
+/// });
+/// ```
+///
+/// [`SparseSet::defer_drops`]: crate::SparseSet::defer_drops
+/// [`World::take_deferred_drops`]: crate::World::take_deferred_drops
+#[derive(Default)]
+pub struct DropQueue {
+    batches: VecDeque<Box<dyn DropBatch + Send + Sync>>,
+}
+
+impl Unique for DropQueue {}
+
+impl DropQueue {
+    /// Creates an empty `DropQueue`.
+    pub fn new() -> DropQueue {
+        DropQueue {
+            batches: VecDeque::new(),
+        }
+    }
+    /// Returns the number of components waiting to be dropped.
+    pub fn len(&self) -> usize {
+        self.batches.iter().map(|batch| batch.len()).sum()
+    }
+    /// Returns `true` if no component is waiting to be dropped.
+    pub fn is_empty(&self) -> bool {
+        self.batches.is_empty()
+    }
+    /// Drops up to `count` components, oldest batch first.\
+    /// Returns how many components were dropped.
+    pub fn drop_some(&mut self, count: usize) -> usize {
+        drop_some(&mut self.batches, count)
+    }
+    /// Drops all components.
+    pub fn drop_all(&mut self) {
+        self.batches.clear();
+    }
+    /// Moves all components of `other` at the end of this queue, leaving `other` empty.
+    pub fn append(&mut self, other: &mut DropQueue) {
+        self.batches.append(&mut other.batches);
+    }
+    /// Adds `components` at the end of the queue.
+    pub(crate) fn push<T: Send + Sync + 'static>(&mut self, components: Vec<T>) {
+        if !components.is_empty() {
+            self.batches.push_back(Box::new(components));
+        }
+    }
+    /// Adds `!Sync` `components` at the end of the queue.
+    #[cfg(feature = "thread_local")]
+    pub(crate) fn push_non_sync<T: Send + 'static>(&mut self, components: Vec<T>) {
+        if !components.is_empty() {
+            self.batches.push_back(Box::new(NonSyncBatch(components)));
+        }
+    }
+}
+
+impl core::fmt::Debug for DropQueue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DropQueue")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// `!Send` components waiting to be dropped on the `World`'s thread.
+///
+/// Storages of `!Send` components can't give them to a [`DropQueue`] since it can be sent to another thread.\
+/// [`World::take_local_deferred_drops`] collects them in a `LocalDropQueue` instead.
+///
+/// [`World::take_local_deferred_drops`]: crate::World::take_local_deferred_drops
+#[cfg(feature = "thread_local")]
+#[derive(Default)]
+pub struct LocalDropQueue {
+    batches: VecDeque<Box<dyn DropBatch>>,
+}
+
+#[cfg(feature = "thread_local")]
+impl LocalDropQueue {
+    /// Creates an empty `LocalDropQueue`.
+    pub fn new() -> LocalDropQueue {
+        LocalDropQueue {
+            batches: VecDeque::new(),
+        }
+    }
+    /// Returns the number of components waiting to be dropped.
+    pub fn len(&self) -> usize {
+        self.batches.iter().map(|batch| batch.len()).sum()
+    }
+    /// Returns `true` if no component is waiting to be dropped.
+    pub fn is_empty(&self) -> bool {
+        self.batches.is_empty()
+    }
+    /// Drops up to `count` components, oldest batch first.\
+    /// Returns how many components were dropped.
+    pub fn drop_some(&mut self, count: usize) -> usize {
+        drop_some(&mut self.batches, count)
+    }
+    /// Drops all components.
+    pub fn drop_all(&mut self) {
+        self.batches.clear();
+    }
+    /// Moves all components of `other` at the end of this queue, leaving `other` empty.
+    pub fn append(&mut self, other: &mut LocalDropQueue) {
+        self.batches.append(&mut other.batches);
+    }
+    /// Adds `components` at the end of the queue.
+    pub(crate) fn push<T: 'static>(&mut self, components: Vec<T>) {
+        if !components.is_empty() {
+            self.batches.push_back(Box::new(components));
+        }
+    }
+}
+
+#[cfg(feature = "thread_local")]
+impl core::fmt::Debug for LocalDropQueue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LocalDropQueue")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Drops up to `count` components from `batches`, oldest batch first.
+fn drop_some<B: DropBatch + ?Sized>(batches: &mut VecDeque<Box<B>>, count: usize) -> usize {
+    let mut dropped = 0;
+
+    while dropped < count {
+        let batch = match batches.front_mut() {
+            Some(batch) => batch,
+            None => break,
+        };
+
+        dropped += batch.drop_some(count - dropped);
+
+        if batch.len() == 0 {
+            batches.pop_front();
+        }
+    }
+
+    dropped
+}
+
+/// Components of a single type waiting to be dropped.
+trait DropBatch {
+    fn len(&self) -> usize;
+    /// Drops up to `count` components and returns how many were dropped.
+    fn drop_some(&mut self, count: usize) -> usize;
+}
+
+impl<T> DropBatch for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+    fn drop_some(&mut self, count: usize) -> usize {
+        let count = count.min(Vec::len(self));
+
+        self.truncate(Vec::len(self) - count);
+
+        count
+    }
+}
+
+/// `!Sync` components, only accessed through `&mut`.
+#[cfg(feature = "thread_local")]
+struct NonSyncBatch<T>(Vec<T>);
+
+// SAFE a shared reference only gives access to the length, never to a component
+#[cfg(feature = "thread_local")]
+unsafe impl<T: Send> Sync for NonSyncBatch<T> {}
+
+#[cfg(feature = "thread_local")]
+impl<T> DropBatch for NonSyncBatch<T> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    fn drop_some(&mut self, count: usize) -> usize {
+        self.0.drop_some(count)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::entity_id::EntityId;
+use alloc::vec::Vec;
+
+/// Snapshot of the entities alive when it was taken, created with [`Entities::liveness_bitmap`].
+///
+/// Bit `i` of the bitmap is set when the entity at index `i` is alive.\
+/// The words can be handed to external code to check many indices at once,
+/// the generations are needed on top of them to check full [`EntityId`]s.
+///
+/// The snapshot doesn't follow later changes to the `World`.
+///
+/// ### Example
+/// ```
+/// use shipyard::{EntitiesView, World};
+///
+/// let mut world = World::new();
+///
+/// let entity0 = world.add_entity(());
+/// let entity1 = world.add_entity(());
+/// world.delete_entity(entity0);
+///
+/// let bitmap = world.borrow::<EntitiesView>().unwrap().liveness_bitmap();
+///
+/// assert_eq!(bitmap.as_words(), [0b10]);
+/// assert!(!bitmap.is_alive(entity0));
+/// assert!(bitmap.is_alive(entity1));
+/// ```
+///
+/// [`Entities::liveness_bitmap`]: crate::Entities::liveness_bitmap
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LivenessBitmap {
+    words: Vec<u64>,
+    generations: Vec<u16>,
+}
+
+impl LivenessBitmap {
+    pub(super) fn new(data: &[EntityId]) -> LivenessBitmap {
+        let mut words = alloc::vec![0; data.len().div_ceil(64)];
+        let mut generations = Vec::with_capacity(data.len());
+
+        for (index, entity) in data.iter().enumerate() {
+            if entity.uindex() == index {
+                words[index / 64] |= 1 << (index % 64);
+            }
+
+            generations.push(entity.gen());
+        }
+
+        LivenessBitmap { words, generations }
+    }
+    /// Returns the bitmap, bit `i` of word `i / 64` is set when the entity at index `i` is alive.
+    #[inline]
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+    /// Returns the generation of the entity at each index.\
+    /// Only relevant for indices alive in the bitmap.
+    #[inline]
+    pub fn generations(&self) -> &[u16] {
+        &self.generations
+    }
+    /// Returns the number of indices covered by the snapshot.\
+    /// Indices past this point were never used when the snapshot was taken.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.generations.len()
+    }
+    /// Returns `true` if the snapshot doesn't cover any index.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.generations.is_empty()
+    }
+    /// Returns `true` if an entity was alive at `index`, regardless of its generation.
+    #[inline]
+    pub fn is_index_alive(&self, index: u64) -> bool {
+        let index = index as usize;
+
+        self.words
+            .get(index / 64)
+            .is_some_and(|word| word & (1 << (index % 64)) != 0)
+    }
+    /// Returns `true` if `entity` was alive.
+    #[inline]
+    pub fn is_alive(&self, entity: EntityId) -> bool {
+        self.is_index_alive(entity.index()) && self.generations[entity.uindex()] == entity.gen()
+    }
+}
@@ -1,13 +1,17 @@
 mod iterator;
+mod liveness;
+mod reservation;
 
 pub use iterator::EntitiesIter;
+pub use liveness::LivenessBitmap;
+pub use reservation::ReservationTicket;
 
 use crate::add_component::AddComponent;
 use crate::add_distinct_component::AddDistinctComponent;
 use crate::add_entity::AddEntity;
 use crate::entity_id::EntityId;
 use crate::error;
-use crate::memory_usage::StorageMemoryUsage;
+use crate::memory_usage::{StorageCategory, StorageMemoryUsage};
 use crate::reserve::{BulkEntityIter, BulkReserve};
 use crate::storage::Storage;
 use crate::tracking::TrackingTimestamp;
@@ -16,6 +20,7 @@ use alloc::vec::Vec;
 use core::any::type_name;
 use core::iter::repeat_with;
 use core::mem::size_of;
+use core::sync::atomic::AtomicUsize;
 
 /// Entities holds the EntityIds to all entities: living, removed and dead.
 ///
@@ -35,10 +40,27 @@ use core::mem::size_of;
 // Removed entities form a linked list inside the vector, using their index part to point to the next.
 // Removed entities are added to one end and removed from the other.
 // Dead entities are simply never added to the linked list.
+// `reserved` is the length `data` will have once all reserved entities are added,
+// it is atomic to reserve indices from any thread with a shared borrow.
 pub struct Entities {
     pub(crate) data: Vec<EntityId>,
     list: Option<(usize, usize)>,
+    reserved: AtomicUsize,
     on_deletion: Option<Box<dyn FnMut(EntityId) + Send + Sync>>,
+    add_component_policy: AddComponentPolicy,
+}
+
+/// What happens when adding components to an entity that is not alive.
+///
+/// Only affects the panicking `add_component`/`add_distinct_component` functions.\
+/// `try_add_component` always returns an error.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AddComponentPolicy {
+    /// Panic with [`error::AddComponent::EntityIsNotAlive`].
+    #[default]
+    Panic,
+    /// Do nothing, the components are dropped.
+    Ignore,
 }
 
 impl Entities {
@@ -47,7 +69,9 @@ impl Entities {
         Entities {
             data: Vec::new(),
             list: None,
+            reserved: AtomicUsize::new(0),
             on_deletion: None,
+            add_component_policy: AddComponentPolicy::Panic,
         }
     }
     /// Returns `true` if `entity` matches a living entity.
@@ -64,7 +88,7 @@ impl Entities {
     ///
     /// ### Panics
     ///
-    /// - `entity` is not alive.
+    /// - `entity` is not alive and the [`AddComponentPolicy`] is `Panic`.
     ///
     /// ### Example
     /// ```
@@ -86,13 +110,55 @@ impl Entities {
     pub fn add_component<C, S: AddComponent<C>>(
         &self,
         entity: EntityId,
-        mut storages: S,
+        storages: S,
         component: C,
     ) {
+        if let Err(err) = self.try_add_component(entity, storages, component) {
+            if self.add_component_policy == AddComponentPolicy::Panic {
+                panic!("{:?}: {:?}", entity, err);
+            }
+        }
+    }
+    /// Adds `component` to `entity`, multiple components can be added at the same time using a tuple.  
+    /// `Entities` is only borrowed immutably.  
+    ///
+    /// Returns an error if `entity` is not alive, regardless of the [`AddComponentPolicy`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Component, EntitiesViewMut, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    ///
+    /// let (mut entities, mut u32s) = world.borrow::<(EntitiesViewMut, ViewMut<U32>)>().unwrap();
+    ///
+    /// assert_eq!(entities.try_add_component(entity, &mut u32s, U32(0)), Ok(()));
+    ///
+    /// entities.delete_unchecked(entity);
+    ///
+    /// assert_eq!(
+    ///     entities.try_add_component(entity, &mut u32s, U32(1)),
+    ///     Err(error::AddComponent::EntityIsNotAlive)
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_add_component<C, S: AddComponent<C>>(
+        &self,
+        entity: EntityId,
+        mut storages: S,
+        component: C,
+    ) -> Result<(), error::AddComponent> {
         if self.is_alive(entity) {
             storages.add_component_unchecked(entity, component);
+
+            Ok(())
         } else {
-            panic!("{:?}", error::AddComponent::EntityIsNotAlive);
+            Err(error::AddComponent::EntityIsNotAlive)
         }
     }
     /// Adds `component` to `entity`, multiple components can be added at the same time using a tuple.  
@@ -103,7 +169,7 @@ impl Entities {
     ///
     /// ### Panics
     ///
-    /// - `entity` is not alive.
+    /// - `entity` is not alive and the [`AddComponentPolicy`] is `Panic`.
     ///
     /// ### Example
     /// ```
@@ -131,11 +197,15 @@ impl Entities {
     ) -> bool {
         if self.is_alive(entity) {
             storages.add_distinct_component_unchecked(entity, component)
+        } else if self.add_component_policy == AddComponentPolicy::Ignore {
+            false
         } else {
-            panic!("{:?}", error::AddComponent::EntityIsNotAlive);
+            panic!("{:?}: {:?}", entity, error::AddComponent::EntityIsNotAlive);
         }
     }
     pub(crate) fn generate(&mut self) -> EntityId {
+        self.flush_reserved();
+
         if let Some((new, ref mut old)) = self.list {
             let old_index = *old;
 
@@ -155,18 +225,65 @@ impl Entities {
         } else {
             let entity_id = EntityId::new(self.data.len() as u64);
             self.data.push(entity_id);
+            *self.reserved.get_mut() += 1;
             entity_id
         }
     }
     pub(crate) fn bulk_generate(&mut self, count: usize) -> &[EntityId] {
+        self.flush_reserved();
+
         self.data
             .extend((self.data.len() as u64..(self.data.len() + count) as u64).map(EntityId::new));
+        *self.reserved.get_mut() = self.data.len();
 
         &self.data[self.data.len() - count..self.data.len()]
     }
+    /// Reserves the next index, the entity is added to `data` by the next flush.
+    pub(crate) fn reserve(&self) -> ReservationTicket {
+        ReservationTicket::new(&self.reserved)
+    }
+    /// Returns a copy of all entities, alive or not, and the list of deleted ones.
+    pub(crate) fn liveness(&self) -> (Vec<EntityId>, Option<(usize, usize)>) {
+        (self.data.clone(), self.list)
+    }
+    /// Replaces all entities with the output of [`Entities::liveness`].\
+    /// Reservations restart after the last entity.
+    pub(crate) fn restore_liveness(&mut self, data: &[EntityId], list: Option<(usize, usize)>) {
+        self.data.clear();
+        self.data.extend_from_slice(data);
+        self.list = list;
+        *self.reserved.get_mut() = self.data.len();
+    }
+    /// Makes all reserved entities alive.
+    pub(crate) fn flush_reserved(&mut self) {
+        let len = *self.reserved.get_mut();
+
+        if len > self.data.len() {
+            self.data
+                .extend((self.data.len() as u64..len as u64).map(EntityId::new));
+        }
+    }
+    /// Increments the generation of `entity`, the previous id is no longer alive.\
+    /// Returns the new id, `None` if `entity` is not alive or its generation can't be increased.
+    ///
+    /// Storages still refer to the previous id, [`AllStorages::bump_generation`] updates them.
+    ///
+    /// [`AllStorages::bump_generation`]: crate::AllStorages::bump_generation
+    pub(crate) fn bump_generation(&mut self, entity: EntityId) -> Option<EntityId> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+
+        let entity_id = &mut self.data[entity.uindex()];
+        entity_id.bump_gen().ok()?;
+
+        Some(*entity_id)
+    }
     /// Deletes an entity, returns true if the entity was alive.  
     /// If the entity has components, they will not be deleted and still be accessible using this id.
     pub fn delete_unchecked(&mut self, entity_id: EntityId) -> bool {
+        self.flush_reserved();
+
         if self.is_alive(entity_id) {
             // SAFE we checked for OOB
             if unsafe {
@@ -275,6 +392,8 @@ impl Entities {
         let mut iter = component.into_iter();
         let len = iter.size_hint().0;
 
+        // reserved entities are added before the new ones
+        self.flush_reserved();
         let entities_len = self.data.len();
         let new_entities = self.bulk_generate(len);
 
@@ -293,15 +412,45 @@ impl Entities {
             slice: &self.data[entities_len..],
         }
     }
-    /// Creates an iterator over all entities.
+    /// Creates an iterator over all alive entities, in index order.\
+    /// Each `EntityId` carries its generation, accessible with [`EntityId::gen`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{EntitiesView, World};
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    /// world.delete_entity(entity);
+    /// let entity = world.add_entity(());
+    ///
+    /// world.run(|entities: EntitiesView| {
+    ///     let alive = entities
+    ///         .iter()
+    ///         .map(|entity| (entity.index(), entity.gen()))
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     assert_eq!(alive, [(entity.index(), 1)]);
+    /// });
+    /// ```
     #[inline]
     pub fn iter(&self) -> EntitiesIter<'_> {
         self.into_iter()
     }
+    /// Returns a snapshot of the entities currently alive, one bit per index.
+    ///
+    /// Checking a lot of ids against the bitmap is cheaper than calling [`is_alive`](Entities::is_alive) for each of them.
+    #[inline]
+    pub fn liveness_bitmap(&self) -> LivenessBitmap {
+        LivenessBitmap::new(&self.data)
+    }
     /// Make the given entity alive.  
     /// Does nothing if an entity with a greater generation is already at this index.  
     /// Returns `true` if the entity is successfully spawned.
     pub fn spawn(&mut self, entity: EntityId) -> bool {
+        self.flush_reserved();
+
         if let Some(&old_entity) = self.data.get(entity.index() as usize) {
             if self.is_alive(old_entity) {
                 if old_entity.gen() <= entity.gen() {
@@ -349,6 +498,8 @@ impl Entities {
             }
         } else {
             let old_len = self.data.len();
+
+            *self.reserved.get_mut() = entity.uindex() + 1;
             self.data.resize(entity.uindex() + 1, EntityId::new(0));
 
             if self.data.len() - old_len > 1 {
@@ -382,6 +533,16 @@ impl Entities {
         self.on_deletion = Some(Box::new(f));
     }
 
+    /// Sets what happens when adding components to an entity that is not alive.
+    pub fn set_add_component_policy(&mut self, policy: AddComponentPolicy) {
+        self.add_component_policy = policy;
+    }
+
+    /// Returns what happens when adding components to an entity that is not alive.
+    pub fn add_component_policy(&self) -> AddComponentPolicy {
+        self.add_component_policy
+    }
+
     /// Remove the on entity deletion callback.
     pub fn take_on_deletion(&mut self) -> Option<Box<dyn FnMut(EntityId) + Send + Sync + 'static>> {
         self.on_deletion.take()
@@ -390,6 +551,8 @@ impl Entities {
 
 impl Storage for Entities {
     fn clear(&mut self, _current: TrackingTimestamp) {
+        self.flush_reserved();
+
         if self.data.is_empty() {
             return;
         }
@@ -435,7 +598,10 @@ impl Storage for Entities {
             allocated_memory_bytes: (self.data.capacity() * size_of::<EntityId>())
                 + size_of::<Entities>(),
             used_memory_bytes: (self.data.len() * size_of::<EntityId>()) + size_of::<Entities>(),
+            used_tracking_memory_bytes: 0,
+            allocated_tracking_memory_bytes: 0,
             component_count: self.data.len(),
+            category: StorageCategory::Entities,
         })
     }
     fn is_empty(&self) -> bool {
@@ -0,0 +1,28 @@
+use crate::entity_id::EntityId;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// An [`EntityId`] reserved with [`World::reserve_entity`](crate::World::reserve_entity), possibly from another thread.
+///
+/// The id can be referenced right away, for example in other components.\
+/// The entity becomes alive when the ticket is redeemed with [`World::redeem`](crate::World::redeem)
+/// or, without any component, as soon as the `World` creates or spawns another entity.
+#[must_use]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ReservationTicket {
+    entity: EntityId,
+}
+
+impl ReservationTicket {
+    /// Reserves the next index, it will be added to `Entities` by the next flush.
+    #[inline]
+    pub(crate) fn new(reserved: &AtomicUsize) -> ReservationTicket {
+        ReservationTicket {
+            entity: EntityId::new(reserved.fetch_add(1, Ordering::Relaxed) as u64),
+        }
+    }
+    /// Returns the reserved [`EntityId`].
+    #[inline]
+    pub fn id(&self) -> EntityId {
+        self.entity
+    }
+}
@@ -1,5 +1,8 @@
 #[cfg(feature = "serde1")]
 mod serde;
+mod set;
+
+pub use set::EntityIdSet;
 
 use core::num::NonZeroU64;
 
@@ -93,19 +96,46 @@ impl EntityId {
     pub(crate) fn zero() -> Self {
         EntityId(NonZeroU64::new(1).unwrap())
     }
-    /// Returns a dead `EntityId`, it can be used as a null entity.
+    /// Returns a dead `EntityId`, it can be used as a null entity.\
+    /// Same as [`EntityId::placeholder`].
     #[inline]
     pub fn dead() -> Self {
         // SAFE not zero
         EntityId(unsafe { NonZeroU64::new_unchecked(!0) })
     }
-    #[inline]
-    pub(crate) fn bucket(self) -> usize {
-        self.uindex() / crate::sparse_set::BUCKET_SIZE
-    }
-    #[inline]
-    pub(crate) fn bucket_index(self) -> usize {
-        self.uindex() % crate::sparse_set::BUCKET_SIZE
+    /// Returns an `EntityId` that is never alive, to be used when an entity is not assigned yet.
+    ///
+    /// Accessing a component with a placeholder returns a [`MissingComponent`](crate::error::MissingComponent) error
+    /// with a [`Placeholder`](crate::error::MissingComponentCause::Placeholder) [`cause`](crate::error::MissingComponent::cause).
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Component, EntitiesView, EntityId, Get, View, World};
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct Target(EntityId);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(Target(EntityId::placeholder()));
+    ///
+    /// let (entities, targets) = world.borrow::<(EntitiesView, View<Target>)>().unwrap();
+    /// let target = targets.get(entity).unwrap().0;
+    ///
+    /// assert!(target.is_placeholder());
+    /// assert_eq!(
+    ///     targets.get(target).unwrap_err().cause(&entities),
+    ///     error::MissingComponentCause::Placeholder
+    /// );
+    /// ```
+    #[inline]
+    pub fn placeholder() -> Self {
+        EntityId::dead()
+    }
+    /// Returns `true` if `self` is [`EntityId::placeholder`].
+    #[inline]
+    pub fn is_placeholder(self) -> bool {
+        self == EntityId::placeholder()
     }
     #[inline]
     pub(crate) fn max_index() -> u64 {
@@ -0,0 +1,442 @@
+use super::EntityId;
+use crate::error;
+use alloc::collections::btree_map::{self, BTreeMap};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Indices are listed as deltas from the previous index.
+const SPARSE_ENCODING: u8 = 0;
+/// Indices are listed as a bitmap, starting at the first non-empty word.
+const BITMAP_ENCODING: u8 = 1;
+
+/// Set of [`EntityId`]s with a compact binary encoding.
+///
+/// The set holds at most one generation per index, inserting an entity replaces any entity with the same index.\
+/// Entities are iterated in index order.
+///
+/// [`encode`](EntityIdSet::encode) picks between a list of index deltas and a bitmap, whichever is smaller.\
+/// Generations are written with a variable length, most entities fit in a couple bytes, far less than 8 bytes per `EntityId`.\
+/// With the `serde1` feature the set is serialized with this encoding in binary formats and as a list of `EntityId` in human readable ones.
+///
+/// ### Example
+/// ```
+/// use shipyard::{EntityIdSet, World};
+///
+/// let mut world = World::new();
+///
+/// let entities: Vec<_> = (0..100).map(|_| world.add_entity(())).collect();
+///
+/// let visible: EntityIdSet = entities[..60].iter().copied().collect();
+/// let nearby: EntityIdSet = entities[40..].iter().copied().collect();
+///
+/// let interest = visible.intersection(&nearby);
+/// assert_eq!(interest.len(), 20);
+///
+/// let bytes = interest.encode();
+/// assert!(bytes.len() < interest.len() * 8);
+///
+/// assert_eq!(EntityIdSet::decode(&bytes), Ok(interest));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct EntityIdSet {
+    entities: BTreeMap<u64, u16>,
+}
+
+impl EntityIdSet {
+    /// Creates an empty set.
+    #[inline]
+    pub fn new() -> EntityIdSet {
+        EntityIdSet {
+            entities: BTreeMap::new(),
+        }
+    }
+    /// Returns the number of entities in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+    /// Returns `true` if the set contains no entity.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+    /// Adds `entity` to the set, replacing any entity with the same index.\
+    /// Returns `false` if `entity` was already present.
+    #[inline]
+    pub fn insert(&mut self, entity: EntityId) -> bool {
+        self.entities.insert(entity.index(), entity.gen()) != Some(entity.gen())
+    }
+    /// Removes `entity` from the set.\
+    /// Returns `false` if `entity` wasn't present.
+    #[inline]
+    pub fn remove(&mut self, entity: EntityId) -> bool {
+        match self.entities.entry(entity.index()) {
+            btree_map::Entry::Occupied(occupied) if *occupied.get() == entity.gen() => {
+                occupied.remove();
+
+                true
+            }
+            _ => false,
+        }
+    }
+    /// Returns `true` if `entity` is in the set.
+    #[inline]
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.entities.get(&entity.index()) == Some(&entity.gen())
+    }
+    /// Removes all entities.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entities.clear();
+    }
+    /// Iterates the entities in index order.
+    pub fn iter(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.entities
+            .iter()
+            .map(|(&index, &gen)| EntityId::new_from_parts(index, gen))
+    }
+    /// Returns the entities present in `self` or `other`.\
+    /// When both sets have a different generation for the same index, the most recent one is kept.
+    pub fn union(&self, other: &EntityIdSet) -> EntityIdSet {
+        let mut union = self.clone();
+
+        for (&index, &gen) in &other.entities {
+            let union_gen = union.entities.entry(index).or_insert(gen);
+            *union_gen = (*union_gen).max(gen);
+        }
+
+        union
+    }
+    /// Returns the entities present in both `self` and `other`.
+    pub fn intersection(&self, other: &EntityIdSet) -> EntityIdSet {
+        let (small, large) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        EntityIdSet {
+            entities: small
+                .entities
+                .iter()
+                .filter(|&(index, gen)| large.entities.get(index) == Some(gen))
+                .map(|(&index, &gen)| (index, gen))
+                .collect(),
+        }
+    }
+    /// Returns the entities present in `self` but not in `other`.
+    pub fn difference(&self, other: &EntityIdSet) -> EntityIdSet {
+        EntityIdSet {
+            entities: self
+                .entities
+                .iter()
+                .filter(|&(index, gen)| other.entities.get(index) != Some(gen))
+                .map(|(&index, &gen)| (index, gen))
+                .collect(),
+        }
+    }
+    /// Returns the set's compact binary representation.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.encode_into(&mut bytes);
+        bytes
+    }
+    /// Appends the set's compact binary representation to `bytes`.
+    pub fn encode_into(&self, bytes: &mut Vec<u8>) {
+        let (first_word, word_count) = match (
+            self.entities.keys().next(),
+            self.entities.keys().next_back(),
+        ) {
+            (Some(first), Some(last)) => (first / 64, last / 64 - first / 64 + 1),
+            _ => (0, 0),
+        };
+
+        let mut sparse_len = 0;
+        let mut previous = None;
+        for &index in self.entities.keys() {
+            sparse_len += varint_len(delta(previous, index));
+            previous = Some(index);
+        }
+
+        let bitmap_len = varint_len(first_word) + varint_len(word_count) + word_count as usize * 8;
+
+        if sparse_len <= bitmap_len {
+            bytes.push(SPARSE_ENCODING);
+            write_varint(bytes, self.len() as u64);
+
+            let mut previous = None;
+            for &index in self.entities.keys() {
+                write_varint(bytes, delta(previous, index));
+                previous = Some(index);
+            }
+        } else {
+            bytes.push(BITMAP_ENCODING);
+            write_varint(bytes, self.len() as u64);
+            write_varint(bytes, first_word);
+            write_varint(bytes, word_count);
+
+            let mut indices = self.entities.keys().peekable();
+            for word_index in first_word..first_word + word_count {
+                let mut word = 0u64;
+
+                while let Some(index) = indices.next_if(|&&index| index / 64 == word_index) {
+                    word |= 1 << (index % 64);
+                }
+
+                bytes.extend_from_slice(&word.to_le_bytes());
+            }
+        }
+
+        for &gen in self.entities.values() {
+            write_varint(bytes, gen as u64);
+        }
+    }
+    /// Reads a set written by [`encode`](EntityIdSet::encode).
+    ///
+    /// ### Errors
+    ///
+    /// - `bytes` isn't a valid set or contains more than a set.
+    pub fn decode(bytes: &[u8]) -> Result<EntityIdSet, error::DecodeEntityIdSet> {
+        let mut reader = Reader { bytes };
+
+        let set = EntityIdSet::decode_from(&mut reader)?;
+
+        if reader.bytes.is_empty() {
+            Ok(set)
+        } else {
+            Err(error::DecodeEntityIdSet::TrailingBytes)
+        }
+    }
+    fn decode_from(reader: &mut Reader<'_>) -> Result<EntityIdSet, error::DecodeEntityIdSet> {
+        let encoding = reader.byte()?;
+        let len = reader.varint()?;
+        // each entity takes at least one byte, this bounds allocations with malicious lengths
+        let mut indices = Vec::with_capacity((len as usize).min(reader.bytes.len()));
+
+        match encoding {
+            SPARSE_ENCODING => {
+                let mut previous: Option<u64> = None;
+
+                for _ in 0..len {
+                    let delta = reader.varint()?;
+                    let index = match previous {
+                        Some(previous) => previous
+                            .checked_add(delta)
+                            .and_then(|index| index.checked_add(1)),
+                        None => Some(delta),
+                    }
+                    .ok_or(error::DecodeEntityIdSet::Overflow)?;
+
+                    indices.push(index);
+                    previous = Some(index);
+                }
+            }
+            BITMAP_ENCODING => {
+                let first_word = reader.varint()?;
+                let word_count = reader.varint()?;
+
+                for word_index in 0..word_count {
+                    let mut word = u64::from_le_bytes(reader.word()?);
+                    let base = first_word
+                        .checked_add(word_index)
+                        .and_then(|word| word.checked_mul(64))
+                        .ok_or(error::DecodeEntityIdSet::Overflow)?;
+
+                    while word != 0 {
+                        if indices.len() as u64 == len {
+                            return Err(error::DecodeEntityIdSet::LengthMismatch);
+                        }
+
+                        indices.push(base + word.trailing_zeros() as u64);
+                        word &= word - 1;
+                    }
+                }
+
+                if indices.len() as u64 != len {
+                    return Err(error::DecodeEntityIdSet::LengthMismatch);
+                }
+            }
+            encoding => return Err(error::DecodeEntityIdSet::UnknownEncoding(encoding)),
+        }
+
+        let mut set = EntityIdSet::new();
+
+        for index in indices {
+            if index > EntityId::max_index() {
+                return Err(error::DecodeEntityIdSet::Overflow);
+            }
+
+            let gen =
+                u16::try_from(reader.varint()?).map_err(|_| error::DecodeEntityIdSet::Overflow)?;
+
+            set.entities.insert(index, gen);
+        }
+
+        Ok(set)
+    }
+}
+
+/// Returns the value written for `index` in the sparse encoding.
+fn delta(previous: Option<u64>, index: u64) -> u64 {
+    match previous {
+        Some(previous) => index - previous - 1,
+        None => index,
+    }
+}
+
+fn varint_len(mut value: u64) -> usize {
+    let mut len = 1;
+
+    while value >= 0x80 {
+        value >>= 7;
+        len += 1;
+    }
+
+    len
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+
+    bytes.push(value as u8);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    fn byte(&mut self) -> Result<u8, error::DecodeEntityIdSet> {
+        let (&byte, rest) = self
+            .bytes
+            .split_first()
+            .ok_or(error::DecodeEntityIdSet::UnexpectedEnd)?;
+
+        self.bytes = rest;
+
+        Ok(byte)
+    }
+    fn word(&mut self) -> Result<[u8; 8], error::DecodeEntityIdSet> {
+        if self.bytes.len() < 8 {
+            return Err(error::DecodeEntityIdSet::UnexpectedEnd);
+        }
+
+        let (word, rest) = self.bytes.split_at(8);
+        self.bytes = rest;
+
+        Ok(word.try_into().unwrap())
+    }
+    fn varint(&mut self) -> Result<u64, error::DecodeEntityIdSet> {
+        let mut value = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            let bits = (byte & 0x7f) as u64;
+
+            if shift == 63 && bits > 1 {
+                return Err(error::DecodeEntityIdSet::Overflow);
+            }
+
+            value |= bits << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(error::DecodeEntityIdSet::Overflow)
+    }
+}
+
+impl FromIterator<EntityId> for EntityIdSet {
+    fn from_iter<I: IntoIterator<Item = EntityId>>(iter: I) -> Self {
+        let mut set = EntityIdSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<EntityId> for EntityIdSet {
+    fn extend<I: IntoIterator<Item = EntityId>>(&mut self, iter: I) {
+        for entity in iter {
+            self.insert(entity);
+        }
+    }
+}
+
+impl fmt::Debug for EntityIdSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl serde::Serialize for EntityIdSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_seq(self.iter())
+        } else {
+            serializer.serialize_bytes(&self.encode())
+        }
+    }
+}
+
+#[cfg(feature = "serde1")]
+impl<'de> serde::Deserialize<'de> for EntityIdSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct EntityIdSetVisitor {
+            human_readable: bool,
+        }
+
+        impl<'de> Visitor<'de> for EntityIdSetVisitor {
+            type Value = EntityIdSet;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("an encoded EntityIdSet")
+            }
+
+            fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<EntityIdSet, E> {
+                EntityIdSet::decode(bytes).map_err(E::custom)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<EntityIdSet, A::Error> {
+                if self.human_readable {
+                    let mut set = EntityIdSet::new();
+
+                    while let Some(entity) = seq.next_element::<EntityId>()? {
+                        set.insert(entity);
+                    }
+
+                    Ok(set)
+                } else {
+                    let mut bytes = Vec::new();
+
+                    while let Some(byte) = seq.next_element::<u8>()? {
+                        bytes.push(byte);
+                    }
+
+                    EntityIdSet::decode(&bytes).map_err(A::Error::custom)
+                }
+            }
+        }
+
+        let human_readable = deserializer.is_human_readable();
+
+        if human_readable {
+            deserializer.deserialize_seq(EntityIdSetVisitor { human_readable })
+        } else {
+            deserializer.deserialize_bytes(EntityIdSetVisitor { human_readable })
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::all_storages::AllStorages;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::get_component::GetComponent;
+use crate::sparse_set::{TupleAddComponent, TupleDelete, TupleRemove};
+
+/// Newly added entity, returned by [`World::add_entity_mut`] and [`AllStorages::add_entity_mut`].
+///
+/// Gives access to the entity's components without having to pass its `EntityId` around.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, World};
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// #[derive(Component)]
+/// struct Shield(u32);
+///
+/// let mut world = World::new();
+///
+/// let mut entity = world.add_entity_mut((Health(100),));
+/// entity.add_component((Shield(50),));
+/// entity.get::<&mut Health>().unwrap().0 -= 10;
+///
+/// let id = entity.id();
+///
+/// assert_eq!(world.get::<&Health>(id).unwrap().0, 90);
+/// assert_eq!(world.get::<&Shield>(id).unwrap().0, 50);
+/// ```
+///
+/// [`World::add_entity_mut`]: crate::World::add_entity_mut
+pub struct EntityMut<'a> {
+    all_storages: &'a mut AllStorages,
+    entity: EntityId,
+}
+
+impl<'a> EntityMut<'a> {
+    pub(crate) fn new(all_storages: &'a mut AllStorages, entity: EntityId) -> EntityMut<'a> {
+        EntityMut {
+            all_storages,
+            entity,
+        }
+    }
+    /// Returns the entity's `EntityId`.
+    #[inline]
+    pub fn id(&self) -> EntityId {
+        self.entity
+    }
+    /// Retrieves components of the entity.\
+    /// Multiple components can be queried at the same time using a tuple.
+    ///
+    /// See [`AllStorages::get`] for the list of components that can be requested.
+    ///
+    /// ### Errors
+    ///
+    /// - Storage borrow failed.
+    /// - The entity does not have the component.
+    #[inline]
+    pub fn get<T: GetComponent>(&self) -> Result<T::Out<'_>, error::GetComponent> {
+        self.all_storages.get::<T>(self.entity)
+    }
+    /// Adds components to the entity.\
+    /// If the entity already owned a component it will be replaced.\
+    /// `component` must always be a tuple, even for a single component.
+    #[inline]
+    pub fn add_component<C: TupleAddComponent>(&mut self, component: C) -> &mut EntityMut<'a> {
+        self.all_storages.add_component(self.entity, component);
+        self
+    }
+    /// Deletes components from the entity. As opposed to `remove`, `delete` doesn't return anything.\
+    /// `C` must always be a tuple, even for a single component.
+    #[inline]
+    pub fn delete_component<C: TupleDelete>(&mut self) -> &mut EntityMut<'a> {
+        self.all_storages.delete_component::<C>(self.entity);
+        self
+    }
+    /// Removes components from the entity.\
+    /// `C` must always be a tuple, even for a single component.
+    #[inline]
+    pub fn remove<C: TupleRemove>(&mut self) -> C::Out {
+        self.all_storages.remove::<C>(self.entity)
+    }
+}
+
+impl core::fmt::Debug for EntityMut<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("EntityMut").field(&self.entity).finish()
+    }
+}
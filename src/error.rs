@@ -1,9 +1,11 @@
 //! All error types.
 
+use crate::entities::Entities;
 use crate::entity_id::EntityId;
 use crate::info::TypeInfo;
 use crate::scheduler::Label;
 use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
@@ -19,7 +21,12 @@ pub enum Borrow {
     /// The Storage was borrowed exclusively when a shared borrow occurred.
     Shared,
     /// The Storage of a `!Send` component was accessed from an other thread.
-    WrongThread,
+    WrongThread {
+        /// Id of the thread the storage is bound to.
+        expected: u64,
+        /// Id of the thread that tried to access the storage.
+        actual: u64,
+    },
     /// The Storage of a `!Sync` component was accessed from multiple threads at the same time.
     MultipleThreads,
 }
@@ -34,9 +41,10 @@ impl Debug for Borrow {
             Borrow::Shared => {
                 f.write_str("Cannot immutably borrow while already mutably borrowed.")
             }
-            Borrow::WrongThread => {
-                f.write_str("Can't access from another thread because it's !Send and !Sync.")
-            }
+            Borrow::WrongThread { expected, actual } => f.write_fmt(format_args!(
+                "Can't access from thread {} because it's !Send, it can only be accessed from thread {}.",
+                actual, expected
+            )),
             Borrow::MultipleThreads => f.write_str(
                 "Can't access from multiple threads at the same time because it's !Sync.",
             ),
@@ -151,6 +159,7 @@ impl Debug for GetStorage {
                 Borrow::Shared => {
                     f.write_str("Cannot immutably borrow AllStorages while it's already mutably borrowed.")
                 },
+                Borrow::WrongThread { expected, actual } => f.write_fmt(format_args!("Cannot borrow AllStorages from thread {} because it's !Send, it can only be borrowed from thread {}.", actual, expected)),
                 _ => unreachable!(),
             },
             GetStorage::StorageBorrow {name, id, borrow} => if let Some(name) = name {
@@ -160,7 +169,7 @@ impl Debug for GetStorage {
                         f.write_fmt(format_args!("Cannot immutably borrow {} storage while it's already mutably borrowed.", name))
                     },
                     Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {} storage from multiple thread at the same time because it's !Sync.", name)),
-                    Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
+                    Borrow::WrongThread { expected, actual } => f.write_fmt(format_args!("Cannot borrow {} storage from thread {} because it's !Send, it can only be borrowed from thread {}.", name, actual, expected)),
                 }
             } else {
                 match borrow {
@@ -169,7 +178,7 @@ impl Debug for GetStorage {
                         f.write_fmt(format_args!("Cannot immutably borrow {:?} storage while it's already mutably borrowed.", id))
                     },
                     Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {:?} storage from multiple thread at the same time because it's !Sync.", id)),
-                    Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {:?} storage from other thread than the one it was created in because it's !Send and !Sync.", id)),
+                    Borrow::WrongThread { expected, actual } => f.write_fmt(format_args!("Cannot borrow {:?} storage from thread {} because it's !Send, it can only be borrowed from thread {}.", id, actual, expected)),
                 }
             }
             GetStorage::Entities(borrow) => match borrow {
@@ -180,9 +189,9 @@ impl Debug for GetStorage {
                 _ => unreachable!(),
             },
             GetStorage::MissingStorage { name, id } => if let Some(name) = name {
-                f.write_fmt(format_args!("{} storage was not found in the World. You can register unique storage with: world.add_unique(your_unique);", name))
+                f.write_fmt(format_args!("{} storage was not found in the World. You can register unique storage with: world.add_unique(your_unique); and, in strict mode, component storage with: world.add_storage::<YourComponent>();", name))
             } else {
-                f.write_fmt(format_args!("{:?} storage was not found in the World. You can register unique storage with: world.add_unique(your_unique);", id))
+                f.write_fmt(format_args!("{:?} storage was not found in the World. You can register unique storage with: world.add_unique(your_unique); and, in strict mode, component storage with: world.add_storage::<YourComponent>();", id))
             }
             GetStorage::TrackingNotEnabled { name, id, tracking } => if let Some(name) = name {
                 f.write_fmt(format_args!("{} tracking is not enabled for {} storage.", tracking, name))
@@ -238,10 +247,11 @@ impl Display for NewEntity {
     }
 }
 
-/// Returned by [`AllStorages::add_component`] and [`World::add_component`] when trying to add components to an entity that is not alive.
+/// Returned by [`AllStorages::try_add_component`], [`World::try_add_component`] and [`Entities::try_add_component`] when trying to add components to an entity that is not alive.
 ///
-/// [`AllStorages::add_component`]: crate::all_storages::AllStorages::add_component()
-/// [`World::add_component`]: crate::world::World::add_component()
+/// [`AllStorages::try_add_component`]: crate::all_storages::AllStorages::try_add_component()
+/// [`World::try_add_component`]: crate::world::World::try_add_component()
+/// [`Entities::try_add_component`]: crate::entities::Entities::try_add_component()
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum AddComponent {
     #[allow(missing_docs)]
@@ -272,8 +282,11 @@ impl Display for AddComponent {
 /// [`Workload::add_to_world`]: crate::Workload::add_to_world()
 #[derive(Clone, Eq)]
 pub enum AddWorkload {
-    /// A workload with the same name already exists.
+    /// A workload with the same label already exists.\
+    /// Text labels collide when their text is the same, `"Render"` and `String::from("Render")` for example.
     AlreadyExists,
+    /// No workload with this name exists to be replaced.
+    MissingWorkload,
     /// The `Scheduler` is already borrowed.
     Borrow,
     /// This workload cannot be created.
@@ -334,6 +347,9 @@ impl Debug for AddWorkload {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             AddWorkload::AlreadyExists => f.write_str("A workload with this name already exists."),
+            AddWorkload::MissingWorkload => {
+                f.write_str("No workload with this name exists, it can't be replaced.")
+            }
             AddWorkload::Borrow => {
                 f.write_str("Cannot mutably borrow the scheduler while it's already borrowed.")
             }
@@ -363,7 +379,7 @@ impl Debug for AddWorkload {
                             f.write_fmt(format_args!("Cannot immutably borrow {} storage while it's already mutably borrowed.", name))
                         },
                         Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {} storage from multiple thread at the same time because it's !Sync.", name)),
-                        Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
+                        Borrow::WrongThread { expected, actual } => f.write_fmt(format_args!("Cannot borrow {} storage from thread {} because it's !Send, it can only be borrowed from thread {}.", name, actual, expected)),
                     }
                 } else {
                     match borrow {
@@ -372,7 +388,7 @@ impl Debug for AddWorkload {
                             f.write_fmt(format_args!("Cannot immutably borrow {:?} storage while it's already mutably borrowed.", id))
                         },
                         Borrow::MultipleThreads => f.write_fmt(format_args!("Cannot borrow {:?} storage from multiple thread at the same time because it's !Sync.", id)),
-                        Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {:?} storage from other thread than the one it was created in because it's !Send and !Sync.", id)),
+                        Borrow::WrongThread { expected, actual } => f.write_fmt(format_args!("Cannot borrow {:?} storage from thread {} because it's !Send, it can only be borrowed from thread {}.", id, actual, expected)),
                     }
                 }
             }
@@ -422,6 +438,7 @@ impl Display for SetDefaultWorkload {
 ///
 /// [`run_default`]: crate::World#method::run_default()
 /// [`run_workload`]: crate::World#method::run_workload()
+#[non_exhaustive]
 pub enum RunWorkload {
     /// The `Scheduler` is exclusively borrowed.
     Scheduler,
@@ -429,6 +446,9 @@ pub enum RunWorkload {
     Run((Box<dyn Label>, Run)),
     /// Workload is not present in the world.
     MissingWorkload,
+    /// The deadline was reached before the end of the workload.\
+    /// Contains the systems that didn't run.
+    Timeout(Vec<Box<dyn Label>>),
 }
 
 impl RunWorkload {
@@ -451,7 +471,14 @@ impl RunWorkload {
 }
 
 #[cfg(feature = "std")]
-impl Error for RunWorkload {}
+impl Error for RunWorkload {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RunWorkload::Run((_, run)) => Some(run),
+            _ => None,
+        }
+    }
+}
 
 impl Debug for RunWorkload {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
@@ -463,6 +490,10 @@ impl Debug for RunWorkload {
             RunWorkload::Run((system_name, run)) => {
                 f.write_fmt(format_args!("System {:?} failed: {:?}", system_name, run))
             }
+            RunWorkload::Timeout(skipped) => f.write_fmt(format_args!(
+                "The workload ran out of time before running {:?}.",
+                skipped
+            )),
         }
     }
 }
@@ -520,7 +551,14 @@ impl PartialEq for Run {
 }
 
 #[cfg(feature = "std")]
-impl Error for Run {}
+impl Error for Run {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Run::GetStorage(get_storage) => Some(get_storage),
+            Run::Custom(err) => Some(&**err),
+        }
+    }
+}
 
 impl Debug for Run {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
@@ -539,6 +577,124 @@ impl Display for Run {
     }
 }
 
+/// Error with a message describing what was being done when it happened.\
+/// Created with [`ErrorContext::context`] and [`ErrorContext::with_context`].
+///
+/// The original error is returned by [`Error::source`], contexts can be chained.
+#[cfg(feature = "std")]
+pub struct Context {
+    /// What was being done when the error happened.
+    pub context: Cow<'static, str>,
+    /// The original error.
+    pub source: Box<dyn Error + Send + Sync>,
+}
+
+#[cfg(feature = "std")]
+impl Error for Context {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Debug for Context {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_fmt(format_args!("{}: {:?}", self.context, self.source))
+    }
+}
+
+/// Only writes the context, the rest of the chain can be accessed with [`Error::source`].
+#[cfg(feature = "std")]
+impl Display for Context {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_str(&self.context)
+    }
+}
+
+/// Attaches a [`Context`] to an error.
+///
+/// ### Example
+/// ```
+/// use shipyard::{error::ErrorContext, Workload, World};
+/// use std::error::Error;
+///
+/// fn load_level() -> Result<(), std::io::Error> {
+///     Err(std::io::Error::new(std::io::ErrorKind::NotFound, "level3.ron"))
+/// }
+///
+/// fn setup() -> Result<(), shipyard::error::Context> {
+///     load_level().context("loading level 3")
+/// }
+///
+/// let world = World::new();
+///
+/// Workload::new("Setup")
+///     .with_try_system(setup)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// let err = world.run_workload("Setup").unwrap_err();
+///
+/// let run = err.source().unwrap();
+/// let context = run.source().unwrap();
+/// assert_eq!(context.to_string(), "loading level 3");
+/// assert_eq!(context.source().unwrap().to_string(), "level3.ron");
+/// ```
+#[cfg(feature = "std")]
+pub trait ErrorContext<T> {
+    /// Wraps the error with `context`.
+    fn context(self, context: impl Into<Cow<'static, str>>) -> Result<T, Context>;
+    /// Wraps the error with the context returned by `f`.\
+    /// `f` is only called when there is an error.
+    fn with_context<C: Into<Cow<'static, str>>, F: FnOnce() -> C>(self, f: F)
+        -> Result<T, Context>;
+}
+
+#[cfg(feature = "std")]
+impl<T, E: Into<Box<dyn Error + Send + Sync>>> ErrorContext<T> for Result<T, E> {
+    #[inline]
+    fn context(self, context: impl Into<Cow<'static, str>>) -> Result<T, Context> {
+        self.map_err(|err| Context {
+            context: context.into(),
+            source: err.into(),
+        })
+    }
+    #[inline]
+    fn with_context<C: Into<Cow<'static, str>>, F: FnOnce() -> C>(
+        self,
+        f: F,
+    ) -> Result<T, Context> {
+        self.map_err(|err| Context {
+            context: f().into(),
+            source: err.into(),
+        })
+    }
+}
+
+/// Returned by systems added with [`Workload::with_option_system`] when they return `None`.
+///
+/// [`Workload::with_option_system`]: crate::Workload::with_option_system()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NoneReturned {
+    /// Name of the system.
+    pub system: &'static str,
+}
+
+#[cfg(feature = "std")]
+impl Error for NoneReturned {}
+
+impl Debug for NoneReturned {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_fmt(format_args!("{} returned None.", self.system))
+    }
+}
+
+impl Display for NoneReturned {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Returned by [`get`] when an entity does not have a component in the requested storage(s).
 ///
 /// [`get`]: crate::Get
@@ -550,15 +706,68 @@ pub struct MissingComponent {
     pub name: &'static str,
 }
 
+impl MissingComponent {
+    /// Returns why the component could not be found.\
+    /// `entities` tells if the entity is dead, it has to come from the `World` the error was returned by.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error::MissingComponentCause, Component, EntitiesView, EntityId, Get, View, World};
+    ///
+    /// #[derive(Component, Debug)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    /// world.delete_entity(entity);
+    ///
+    /// world.run(|entities: EntitiesView, u32s: View<U32>| {
+    ///     let err = u32s.get(entity).unwrap_err();
+    ///     assert_eq!(err.cause(&entities), MissingComponentCause::DeadEntity);
+    ///
+    ///     let err = u32s.get(EntityId::placeholder()).unwrap_err();
+    ///     assert_eq!(err.cause(&entities), MissingComponentCause::Placeholder);
+    /// });
+    /// ```
+    pub fn cause(&self, entities: &Entities) -> MissingComponentCause {
+        if self.id.is_placeholder() {
+            MissingComponentCause::Placeholder
+        } else if !entities.is_alive(self.id) {
+            MissingComponentCause::DeadEntity
+        } else {
+            MissingComponentCause::Missing
+        }
+    }
+}
+
+/// Reason why a [`MissingComponent`] error was returned, see [`MissingComponent::cause`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MissingComponentCause {
+    /// The `EntityId` is [`EntityId::placeholder`], it was never assigned to an entity.
+    Placeholder,
+    /// The entity was deleted.
+    DeadEntity,
+    /// The entity doesn't have this component.
+    Missing,
+}
+
 #[cfg(feature = "std")]
 impl Error for MissingComponent {}
 
 impl Debug for MissingComponent {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
-        f.write_fmt(format_args!(
-            "{:?} does not have a {} component.",
-            self.id, self.name
-        ))
+        if self.id.is_placeholder() {
+            f.write_fmt(format_args!(
+                "Tried to get a {} component using a placeholder EntityId, it was never assigned to an entity.",
+                self.name
+            ))
+        } else {
+            f.write_fmt(format_args!(
+                "{:?} does not have a {} component.",
+                self.id, self.name
+            ))
+        }
     }
 }
 
@@ -625,7 +834,7 @@ impl Debug for UniqueRemove {
             UniqueRemove::MissingUnique(name) => f.write_fmt(format_args!("No unique storage exists for {}.\n", name)),
             UniqueRemove::StorageBorrow((name, borrow)) => match borrow {
                 Borrow::Unique => f.write_fmt(format_args!("Cannot mutably borrow {} storage while it's already borrowed.", name)),
-                Borrow::WrongThread => f.write_fmt(format_args!("Cannot borrow {} storage from other thread than the one it was created in because it's !Send and !Sync.", name)),
+                Borrow::WrongThread { expected, actual } => f.write_fmt(format_args!("Cannot borrow {} storage from thread {} because it's !Send, it can only be borrowed from thread {}.", name, actual, expected)),
                 _ => unreachable!()
             }
         }
@@ -671,6 +880,40 @@ impl Display for Apply {
     }
 }
 
+/// Error returned by [`World::try_apply`](crate::World::try_apply).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct WorldApply {
+    /// Why `AllStorages` couldn't be borrowed exclusively.
+    pub borrow: Borrow,
+    /// Where the `World::apply` call holding `AllStorages` was made, if it's held by one.
+    pub held_at: Option<&'static core::panic::Location<'static>>,
+}
+
+#[cfg(feature = "std")]
+impl Error for WorldApply {}
+
+impl Debug for WorldApply {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        if let Some(held_at) = self.held_at {
+            f.write_fmt(format_args!(
+                "AllStorages is already borrowed by World::apply at {}.",
+                held_at
+            ))
+        } else {
+            f.write_fmt(format_args!(
+                "AllStorages is already borrowed: {:?}",
+                self.borrow
+            ))
+        }
+    }
+}
+
+impl Display for WorldApply {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Error returned by [`are_all_uniques_present_in_world`].
 ///
 /// [`are_all_uniques_present_in_world`]: crate::Workload::are_all_uniques_present_in_world()
@@ -727,6 +970,113 @@ impl Display for UniquePresence {
     }
 }
 
+/// Raised when a unique is added with a different thread safety than the unique of the same type already present.\
+/// For example when a unique added with `add_unique` is added again with `add_unique_non_send`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateUnique {
+    /// Name of the unique.
+    pub name: &'static str,
+    /// Thread safety of the unique already present.
+    pub existing: ThreadSafety,
+    /// Thread safety of the unique being added.
+    pub added: ThreadSafety,
+    /// Where the unique already present was added.
+    pub location: Option<&'static core::panic::Location<'static>>,
+}
+
+#[cfg(feature = "std")]
+impl Error for DuplicateUnique {}
+
+impl Debug for DuplicateUnique {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        if let Some(location) = self.location {
+            f.write_fmt(format_args!(
+                "{} unique was added as {:?} at {}, it can't be added again as {:?}.",
+                self.name, self.existing, location, self.added
+            ))
+        } else {
+            f.write_fmt(format_args!(
+                "{} unique was added as {:?}, it can't be added again as {:?}.",
+                self.name, self.existing, self.added
+            ))
+        }
+    }
+}
+
+impl Display for DuplicateUnique {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Thread safety a storage was added with.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ThreadSafety {
+    /// Added with `add_unique`.
+    SendSync,
+    /// Added with `add_unique_non_send`.
+    NonSend,
+    /// Added with `add_unique_non_sync`.
+    NonSync,
+    /// Added with `add_unique_non_send_sync`.
+    NonSendSync,
+}
+
+impl Debug for ThreadSafety {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            ThreadSafety::SendSync => f.write_str("Send + Sync"),
+            ThreadSafety::NonSend => f.write_str("NonSend"),
+            ThreadSafety::NonSync => f.write_str("NonSync"),
+            ThreadSafety::NonSendSync => f.write_str("NonSendSync"),
+        }
+    }
+}
+
+impl Display for ThreadSafety {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by [`Workload::validate`].
+///
+/// [`Workload::validate`]: crate::Workload::validate()
+pub enum Validation {
+    /// A system borrows a unique storage that is not present in the `World`.
+    MissingUnique {
+        #[allow(missing_docs)]
+        system: Box<dyn Label>,
+        #[allow(missing_docs)]
+        type_info: TypeInfo,
+    },
+    #[allow(missing_docs)]
+    AllStorages,
+}
+
+#[cfg(feature = "std")]
+impl Error for Validation {}
+
+impl Debug for Validation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Validation::MissingUnique { system, type_info } => f.write_fmt(format_args!(
+                "System {:?} borrows {} unique storage but it is not present in the World.",
+                system, type_info.name
+            )),
+            Validation::AllStorages => f.write_str(
+                "Cannot immutably borrow AllStorages while it is already mutably borrowed.",
+            ),
+        }
+    }
+}
+
+impl Display for Validation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
 /// Returned when trying to create views for custom storages.
 pub enum CustomStorageView {
     #[allow(missing_docs)]
@@ -857,3 +1207,229 @@ impl Display for GetComponent {
         Debug::fmt(self, f)
     }
 }
+
+/// Inconsistency found by [`World::validate_integrity`](crate::World::validate_integrity).
+#[derive(Clone)]
+pub enum IntegrityError {
+    /// A storage contains a component owned by an entity that is not alive.
+    DeadEntity {
+        #[allow(missing_docs)]
+        storage_name: Cow<'static, str>,
+        #[allow(missing_docs)]
+        entity: EntityId,
+    },
+    /// The sparse array of a storage doesn't point to the index of an entity in the dense array.
+    SparseDenseMismatch {
+        #[allow(missing_docs)]
+        storage_name: Cow<'static, str>,
+        #[allow(missing_docs)]
+        entity: EntityId,
+        /// Index of the entity in the dense array.
+        index: usize,
+    },
+    /// The sparse array of a storage points to an index of the dense array that doesn't hold the entity.
+    DanglingSparseEntry {
+        #[allow(missing_docs)]
+        storage_name: Cow<'static, str>,
+        #[allow(missing_docs)]
+        entity: EntityId,
+        /// Index the sparse array points to.
+        index: usize,
+    },
+    /// The components or tracking information of a storage don't have the same length as its dense array.
+    LengthMismatch {
+        #[allow(missing_docs)]
+        storage_name: Cow<'static, str>,
+        /// Length of the dense array.
+        dense_len: usize,
+        /// Length of the mismatched array.
+        len: usize,
+    },
+    /// A tracking timestamp is more recent than the `World`'s current timestamp.
+    FutureTimestamp {
+        #[allow(missing_docs)]
+        storage_name: Cow<'static, str>,
+        #[allow(missing_docs)]
+        entity: EntityId,
+        #[allow(missing_docs)]
+        timestamp: TrackingTimestamp,
+    },
+}
+
+#[cfg(feature = "std")]
+impl Error for IntegrityError {}
+
+impl Debug for IntegrityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            IntegrityError::DeadEntity {
+                storage_name,
+                entity,
+            } => f.write_fmt(format_args!(
+                "{} contains a component owned by {:?} which is not alive.",
+                storage_name, entity
+            )),
+            IntegrityError::SparseDenseMismatch {
+                storage_name,
+                entity,
+                index,
+            } => f.write_fmt(format_args!(
+                "{}'s sparse array doesn't point to index {} for {:?}.",
+                storage_name, index, entity
+            )),
+            IntegrityError::DanglingSparseEntry {
+                storage_name,
+                entity,
+                index,
+            } => f.write_fmt(format_args!(
+                "{}'s sparse array points to index {} for {:?} but the dense array doesn't hold it.",
+                storage_name, index, entity
+            )),
+            IntegrityError::LengthMismatch {
+                storage_name,
+                dense_len,
+                len,
+            } => f.write_fmt(format_args!(
+                "{} has {} entities but {} components or tracking information.",
+                storage_name, dense_len, len
+            )),
+            IntegrityError::FutureTimestamp {
+                storage_name,
+                entity,
+                timestamp,
+            } => f.write_fmt(format_args!(
+                "{}'s tracking information for {:?} is in the future ({:?}).",
+                storage_name, entity, timestamp
+            )),
+        }
+    }
+}
+
+impl Display for IntegrityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by [`EntityIdSet::decode`](crate::EntityIdSet::decode).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DecodeEntityIdSet {
+    /// The data ended before the whole set was read.
+    UnexpectedEnd,
+    /// The first byte isn't a known encoding.
+    UnknownEncoding(u8),
+    /// A number doesn't fit in an entity index or generation.
+    Overflow,
+    /// The bitmap doesn't contain as many entities as announced, or an index is repeated.
+    LengthMismatch,
+    /// Some bytes are left after the set.
+    TrailingBytes,
+}
+
+#[cfg(feature = "std")]
+impl Error for DecodeEntityIdSet {}
+
+impl Debug for DecodeEntityIdSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            DecodeEntityIdSet::UnexpectedEnd => {
+                f.write_str("The data ended before the whole set was read.")
+            }
+            DecodeEntityIdSet::UnknownEncoding(encoding) => {
+                f.write_fmt(format_args!("Unknown set encoding: {}.", encoding))
+            }
+            DecodeEntityIdSet::Overflow => {
+                f.write_str("A number doesn't fit in an entity index or generation.")
+            }
+            DecodeEntityIdSet::LengthMismatch => {
+                f.write_str("The set doesn't contain as many entities as announced.")
+            }
+            DecodeEntityIdSet::TrailingBytes => f.write_str("Some bytes are left after the set."),
+        }
+    }
+}
+
+impl Display for DecodeEntityIdSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by [`World::bump_generation`] and [`AllStorages::bump_generation`].
+///
+/// [`World::bump_generation`]: crate::World::bump_generation()
+/// [`AllStorages::bump_generation`]: crate::AllStorages::bump_generation()
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BumpGeneration {
+    #[allow(missing_docs)]
+    EntityIsNotAlive,
+    /// The entity's generation can't be increased.
+    MaxGeneration,
+    /// The entity has a component in an immutable storage.
+    ImmutableStorage(StorageId),
+}
+
+#[cfg(feature = "std")]
+impl Error for BumpGeneration {}
+
+impl Debug for BumpGeneration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            BumpGeneration::EntityIsNotAlive => {
+                f.write_str("Entity has to be alive to bump its generation.")
+            }
+            BumpGeneration::MaxGeneration => {
+                f.write_str("Entity's generation can't be increased any further.")
+            }
+            BumpGeneration::ImmutableStorage(id) => f.write_fmt(format_args!(
+                "Entity has a component in immutable storage {:?}.",
+                id
+            )),
+        }
+    }
+}
+
+impl Display for BumpGeneration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
+
+/// Error returned by [`World::archive`] and [`AllStorages::archive`].
+///
+/// [`World::archive`]: crate::World::archive()
+/// [`AllStorages::archive`]: crate::AllStorages::archive()
+#[derive(Clone, PartialEq, Eq)]
+pub enum Archive {
+    #[allow(missing_docs)]
+    EntityIsNotAlive,
+    /// The entity has components that can't be archived, like `!Send` or `!Sync` components.\
+    /// Contains the name of the storages holding them.
+    Unarchivable(Vec<Cow<'static, str>>),
+}
+
+#[cfg(feature = "std")]
+impl Error for Archive {}
+
+impl Debug for Archive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Archive::EntityIsNotAlive => f.write_str("Entity has to be alive to archive it."),
+            Archive::Unarchivable(storage_names) => {
+                f.write_str("Entity has components that can't be archived in:")?;
+
+                for storage_name in storage_names {
+                    f.write_fmt(format_args!("\n- {}", storage_name))?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for Archive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        Debug::fmt(self, f)
+    }
+}
@@ -0,0 +1,255 @@
+use crate::component::Unique;
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use alloc::vec::Vec;
+use core::ptr::NonNull;
+use std::sync::{Mutex, PoisonError};
+
+/// Size of the first chunk allocated by a [`FrameArena`] created with [`FrameArena::new`].
+const DEFAULT_CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+/// Bump allocator for temporary data, reset at the start of each workload run.
+///
+/// Systems can allocate from a shared [`UniqueView`](crate::UniqueView) and run in parallel.\
+/// Allocations are only valid while the view is borrowed, they are all freed at once when the arena is reset.\
+/// The memory is kept between resets, once the arena grew large enough for a frame the following ones don't allocate.
+///
+/// The arena is reset by [`World::run_workload`](crate::World::run_workload) and the other workload runners, before running any system.\
+/// It can also be reset manually with [`reset`](FrameArena::reset).
+///
+/// Only `Copy` types can be allocated, destructors are never run.
+///
+/// ### Example
+/// ```
+/// use shipyard::{FrameArena, UniqueView, Workload, World};
+///
+/// fn sort_keys(arena: UniqueView<FrameArena>) {
+///     let keys = arena.alloc_slice_fill_with(100, |i| (i * 7) % 100);
+///     keys.sort_unstable();
+///
+///     assert_eq!(keys[0], 0);
+/// }
+///
+/// let world = World::new();
+/// world.add_unique(FrameArena::new());
+///
+/// Workload::new("Render")
+///     .with_system(sort_keys)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Render").unwrap();
+/// world.run_workload("Render").unwrap();
+///
+/// assert!(world.borrow::<UniqueView<FrameArena>>().unwrap().capacity() > 0);
+/// ```
+pub struct FrameArena {
+    state: Mutex<ArenaState>,
+}
+
+impl Unique for FrameArena {}
+
+struct ArenaState {
+    chunks: Vec<Chunk>,
+    /// Chunk allocations are currently made from.
+    current: usize,
+    /// Offset of the first free byte in the current chunk.
+    offset: usize,
+    allocated: usize,
+}
+
+struct Chunk {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+// SAFE the chunk owns its memory, access is synchronized by the arena
+unsafe impl Send for Chunk {}
+
+impl Chunk {
+    fn new(size: usize, align: usize) -> Chunk {
+        let layout = Layout::from_size_align(size, align).expect("Frame arena chunk too large.");
+
+        // SAFE size is never 0
+        let ptr = unsafe { alloc(layout) };
+
+        match NonNull::new(ptr) {
+            Some(ptr) => Chunk { ptr, layout },
+            None => handle_alloc_error(layout),
+        }
+    }
+}
+
+impl Drop for Chunk {
+    fn drop(&mut self) {
+        // SAFE ptr was allocated with this layout
+        unsafe { dealloc(self.ptr.as_ptr(), self.layout) }
+    }
+}
+
+impl ArenaState {
+    fn alloc(&mut self, layout: Layout) -> NonNull<u8> {
+        while let Some(chunk) = self.chunks.get(self.current) {
+            let base = chunk.ptr.as_ptr() as usize;
+            let start = (base + self.offset + layout.align() - 1) & !(layout.align() - 1);
+
+            if start + layout.size() <= base + chunk.layout.size() {
+                self.offset = start + layout.size() - base;
+                self.allocated += layout.size();
+
+                // SAFE start is within the chunk
+                return unsafe { NonNull::new_unchecked(chunk.ptr.as_ptr().add(start - base)) };
+            }
+
+            self.current += 1;
+            self.offset = 0;
+        }
+
+        let size = self
+            .chunks
+            .last()
+            .map_or(DEFAULT_CHUNK_SIZE, |chunk| chunk.layout.size() * 2)
+            .max(layout.size() + layout.align());
+
+        self.chunks
+            .push(Chunk::new(size, layout.align().max(CHUNK_ALIGN)));
+        self.current = self.chunks.len() - 1;
+        self.offset = 0;
+
+        self.alloc(layout)
+    }
+}
+
+impl FrameArena {
+    /// Creates an empty arena, the first allocation will allocate a chunk of memory.
+    pub fn new() -> FrameArena {
+        FrameArena {
+            state: Mutex::new(ArenaState {
+                chunks: Vec::new(),
+                current: 0,
+                offset: 0,
+                allocated: 0,
+            }),
+        }
+    }
+    /// Creates an arena able to hold `capacity` bytes without allocating.
+    pub fn with_capacity(capacity: usize) -> FrameArena {
+        let arena = FrameArena::new();
+
+        if capacity > 0 {
+            arena.lock().chunks.push(Chunk::new(capacity, CHUNK_ALIGN));
+        }
+
+        arena
+    }
+    fn lock(&self) -> std::sync::MutexGuard<'_, ArenaState> {
+        // the state is always valid, even if a panic happened while it was locked
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        if layout.size() == 0 {
+            // SAFE alignments are never 0
+            return unsafe { NonNull::new_unchecked(layout.align() as *mut u8) };
+        }
+
+        self.lock().alloc(layout)
+    }
+    /// Moves `value` to the arena.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T: Copy>(&self, value: T) -> &mut T {
+        let ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+
+        // SAFE the memory is valid, aligned and not handed out to anyone else
+        unsafe {
+            ptr.as_ptr().write(value);
+
+            &mut *ptr.as_ptr()
+        }
+    }
+    /// Copies `slice` to the arena.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice<T: Copy>(&self, slice: &[T]) -> &mut [T] {
+        let ptr = self.alloc_layout(Layout::for_value(slice)).cast::<T>();
+
+        // SAFE the memory is valid, aligned and not handed out to anyone else
+        unsafe {
+            core::ptr::copy_nonoverlapping(slice.as_ptr(), ptr.as_ptr(), slice.len());
+
+            core::slice::from_raw_parts_mut(ptr.as_ptr(), slice.len())
+        }
+    }
+    /// Allocates a slice of `len` elements, each initialized with `f(index)`.
+    ///
+    /// ### Panics
+    ///
+    /// - The slice's size overflows `isize::MAX`.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_with<T: Copy, F: FnMut(usize) -> T>(
+        &self,
+        len: usize,
+        mut f: F,
+    ) -> &mut [T] {
+        let layout = Layout::array::<T>(len).expect("Frame arena allocation too large.");
+        let ptr = self.alloc_layout(layout).cast::<T>();
+
+        // SAFE the memory is valid, aligned and not handed out to anyone else
+        unsafe {
+            for index in 0..len {
+                ptr.as_ptr().add(index).write(f(index));
+            }
+
+            core::slice::from_raw_parts_mut(ptr.as_ptr(), len)
+        }
+    }
+    /// Returns the number of bytes allocated since the last reset, padding excluded.
+    pub fn allocated_bytes(&self) -> usize {
+        self.lock().allocated
+    }
+    /// Returns the number of bytes the arena can hold across all its chunks.
+    pub fn capacity(&self) -> usize {
+        self.lock()
+            .chunks
+            .iter()
+            .map(|chunk| chunk.layout.size())
+            .sum()
+    }
+    /// Frees all allocations at once.\
+    /// The memory is kept, if the last frame needed multiple chunks they are merged into a single one.
+    pub fn reset(&mut self) {
+        let state = self.state.get_mut().unwrap_or_else(PoisonError::into_inner);
+
+        if state.chunks.len() > 1 {
+            let capacity = state.chunks.iter().map(|chunk| chunk.layout.size()).sum();
+            let align = state
+                .chunks
+                .iter()
+                .map(|chunk| chunk.layout.align())
+                .max()
+                .unwrap_or(CHUNK_ALIGN);
+
+            state.chunks.clear();
+            state.chunks.push(Chunk::new(capacity, align));
+        }
+
+        state.current = 0;
+        state.offset = 0;
+        state.allocated = 0;
+    }
+}
+
+impl Default for FrameArena {
+    fn default() -> Self {
+        FrameArena::new()
+    }
+}
+
+impl core::fmt::Debug for FrameArena {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let state = self.lock();
+
+        f.debug_struct("FrameArena")
+            .field("chunks", &state.chunks.len())
+            .field("allocated_bytes", &state.allocated)
+            .finish()
+    }
+}
@@ -264,9 +264,12 @@ impl<T: Component + Send + Sync> GetComponent for &'_ mut T {
             data,
             modification_data,
             is_tracking_modification,
+            last_change,
             ..
         } = sparse_set;
 
+        *last_change = current;
+
         Ok(RefMut {
             inner: unsafe { data.get_unchecked_mut(index) },
             flag: is_tracking_modification
@@ -305,9 +308,12 @@ impl<T: Component + Sync> GetComponent for NonSend<&'_ mut T> {
             data,
             modification_data,
             is_tracking_modification,
+            last_change,
             ..
         }) = sparse_set;
 
+        *last_change = current;
+
         Ok(RefMut {
             inner: unsafe { data.get_unchecked_mut(index) },
             flag: is_tracking_modification
@@ -346,9 +352,12 @@ impl<T: Component + Send> GetComponent for NonSync<&'_ mut T> {
             data,
             modification_data,
             is_tracking_modification,
+            last_change,
             ..
         }) = sparse_set;
 
+        *last_change = current;
+
         Ok(RefMut {
             inner: unsafe { data.get_unchecked_mut(index) },
             flag: is_tracking_modification
@@ -387,9 +396,12 @@ impl<T: Component> GetComponent for NonSendSync<&'_ mut T> {
             data,
             modification_data,
             is_tracking_modification,
+            last_change,
             ..
         }) = sparse_set;
 
+        *last_change = current;
+
         Ok(RefMut {
             inner: unsafe { data.get_unchecked_mut(index) },
             flag: is_tracking_modification
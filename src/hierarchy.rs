@@ -0,0 +1,137 @@
+use crate::add_component::AddComponent;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::tracking::Tracking;
+use crate::views::{View, ViewMut};
+use crate::ShipHashMap;
+use alloc::vec::Vec;
+
+/// Component linking an entity to its parent.
+///
+/// Used by [`propagate_dirty`] to walk the hierarchy from children to their ancestors.
+pub trait HierarchyParent: Component {
+    /// Returns the parent of the entity owning this component.
+    fn parent(&self) -> EntityId;
+}
+
+/// Marks dirty all entities whose `T` component changed and all their descendants.
+///
+/// An entity's `T` component changed if it was inserted or modified, based on `changed`'s tracking window.\
+/// Dirty entities get a `D::default()` component, entities already having a `D` component are left untouched.\
+/// Each entity's ancestors are only walked once, the whole propagation is linear in the number of entities in `changed` and `parents`.\
+/// Cycles in the hierarchy are tolerated, they simply stop the walk.
+///
+/// Returns the number of `D` components added.
+///
+/// ### Example
+/// ```
+/// use shipyard::{
+///     propagate_dirty, Component, EntityId, HierarchyParent, View, ViewMut, World,
+/// };
+///
+/// #[derive(Component)]
+/// #[track(Modification)]
+/// struct Transform(f32);
+///
+/// #[derive(Component)]
+/// struct ChildOf(EntityId);
+///
+/// impl HierarchyParent for ChildOf {
+///     fn parent(&self) -> EntityId {
+///         self.0
+///     }
+/// }
+///
+/// #[derive(Component, Default)]
+/// struct Dirty;
+///
+/// let mut world = World::new();
+///
+/// let root = world.add_entity(Transform(0.0));
+/// let child = world.add_entity((Transform(0.0), ChildOf(root)));
+/// let grand_child = world.add_entity(ChildOf(child));
+/// let other = world.add_entity(Transform(0.0));
+///
+/// world.run(|transforms: ViewMut<Transform>| transforms.clear_all_modified());
+/// world.run(|mut transforms: ViewMut<Transform>| transforms[root].0 += 1.0);
+///
+/// world.run(
+///     |transforms: View<Transform>, parents: View<ChildOf>, mut dirty: ViewMut<Dirty>| {
+///         assert_eq!(propagate_dirty(&transforms, &parents, &mut dirty), 3);
+///
+///         assert!(dirty.contains(root));
+///         assert!(dirty.contains(child));
+///         assert!(dirty.contains(grand_child));
+///         assert!(!dirty.contains(other));
+///     },
+/// );
+/// ```
+pub fn propagate_dirty<T, Track, P, D>(
+    changed: &View<'_, T, Track>,
+    parents: &View<'_, P>,
+    dirty: &mut ViewMut<'_, D>,
+) -> usize
+where
+    T: Component,
+    Track: Tracking,
+    P: HierarchyParent,
+    D: Component + Default,
+{
+    let is_changed = |entity: EntityId| {
+        Track::is_inserted(
+            changed.sparse_set,
+            entity,
+            changed.last_insertion,
+            changed.current,
+        ) || Track::is_modified(
+            changed.sparse_set,
+            entity,
+            changed.last_modification,
+            changed.current,
+        )
+    };
+
+    // Once an entity is resolved its dirtiness is stored here.
+    // Entities on the path being walked are stored as `false` to stop on cycles.
+    let mut resolved: ShipHashMap<EntityId, bool> = ShipHashMap::default();
+    let mut path = Vec::new();
+    let mut added = 0;
+
+    for &entity in changed
+        .sparse_set
+        .dense
+        .iter()
+        .chain(&parents.sparse_set.dense)
+    {
+        let mut current = entity;
+
+        let is_dirty = loop {
+            if let Some(&is_dirty) = resolved.get(&current) {
+                break is_dirty;
+            }
+
+            resolved.insert(current, false);
+            path.push(current);
+
+            if is_changed(current) {
+                break true;
+            }
+
+            match parents.sparse_set.private_get(current) {
+                Some(parent) => current = parent.parent(),
+                None => break false,
+            }
+        };
+
+        for entity in path.drain(..) {
+            resolved.insert(entity, is_dirty);
+
+            if is_dirty && !dirty.sparse_set.contains(entity) {
+                dirty.add_component_unchecked(entity, D::default());
+                added += 1;
+            }
+        }
+    }
+
+    added
+}
@@ -42,17 +42,20 @@ impl<'tmp, T: Component> AbstractMut for Inserted<FullRawWindow<'tmp, T>> {
     }
 }
 
-impl<'tmp, T: Component, Track> AbstractMut for Inserted<FullRawWindowMut<'tmp, T, Track>> {
-    type Out = &'tmp mut T;
+impl<'tmp, T: Component, Track> AbstractMut for Inserted<FullRawWindowMut<'tmp, T, Track>>
+where
+    FullRawWindowMut<'tmp, T, Track>: AbstractMut<Index = usize>,
+{
+    type Out = <FullRawWindowMut<'tmp, T, Track> as AbstractMut>::Out;
     type Index = usize;
 
     #[inline]
     unsafe fn get_data(&self, index: usize) -> Self::Out {
-        &mut *self.0.data.add(index)
+        self.0.get_data(index)
     }
     #[inline]
     unsafe fn get_datas(&self, index: Self::Index) -> Self::Out {
-        &mut *self.0.data.add(index)
+        self.0.get_datas(index)
     }
     #[inline]
     fn indices_of(&self, entity_id: EntityId, _: usize, _: u16) -> Option<Self::Index> {
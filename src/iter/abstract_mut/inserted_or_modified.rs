@@ -44,19 +44,20 @@ impl<'tmp, T: Component> AbstractMut for InsertedOrModified<FullRawWindow<'tmp,
     }
 }
 
-impl<'tmp, T: Component, Track> AbstractMut
-    for InsertedOrModified<FullRawWindowMut<'tmp, T, Track>>
+impl<'tmp, T: Component, Track> AbstractMut for InsertedOrModified<FullRawWindowMut<'tmp, T, Track>>
+where
+    FullRawWindowMut<'tmp, T, Track>: AbstractMut<Index = usize>,
 {
-    type Out = &'tmp mut T;
+    type Out = <FullRawWindowMut<'tmp, T, Track> as AbstractMut>::Out;
     type Index = usize;
 
     #[inline]
     unsafe fn get_data(&self, index: usize) -> Self::Out {
-        &mut *self.0.data.add(index)
+        self.0.get_data(index)
     }
     #[inline]
     unsafe fn get_datas(&self, index: Self::Index) -> Self::Out {
-        &mut *self.0.data.add(index)
+        self.0.get_datas(index)
     }
     #[inline]
     fn indices_of(&self, entity_id: EntityId, _: usize, _: u16) -> Option<Self::Index> {
@@ -31,6 +31,10 @@ where
     fn dense(&self) -> *const EntityId {
         self.0.dense.as_ptr()
     }
+    #[cfg(feature = "parallel")]
+    fn par_min_len(&self) -> Option<usize> {
+        Some(SparseSet::par_min_len(self.0))
+    }
 }
 
 impl<'a: 'b, 'b, T: Component, Track> IntoAbstract for Inserted<&'b ViewMut<'a, T, Track>>
@@ -58,6 +62,10 @@ where
     fn dense(&self) -> *const EntityId {
         self.0.dense.as_ptr()
     }
+    #[cfg(feature = "parallel")]
+    fn par_min_len(&self) -> Option<usize> {
+        Some(SparseSet::par_min_len(self.0))
+    }
 }
 
 impl<'a: 'b, 'b, T: Component, Track> IntoAbstract for Inserted<&'b mut ViewMut<'a, T, Track>>
@@ -85,4 +93,8 @@ where
     fn dense(&self) -> *const EntityId {
         self.0.dense.as_ptr()
     }
+    #[cfg(feature = "parallel")]
+    fn par_min_len(&self) -> Option<usize> {
+        Some(SparseSet::par_min_len(self.0))
+    }
 }
@@ -6,8 +6,8 @@ mod or;
 
 use crate::component::Component;
 use crate::entity_id::EntityId;
+use crate::sparse_set::SparseArray;
 use crate::sparse_set::{FullRawWindow, FullRawWindowMut, SparseSet};
-use crate::sparse_set::{SparseArray, BUCKET_SIZE};
 use crate::tracking::Tracking;
 use crate::type_id::TypeId;
 use crate::views::{View, ViewMut};
@@ -32,7 +32,7 @@ pub trait IntoAbstract {
     fn dense(&self) -> *const EntityId;
     #[inline]
     #[doc(hidden)]
-    fn sparse(&self) -> *const SparseArray<EntityId, BUCKET_SIZE> {
+    fn sparse(&self) -> *const SparseArray<EntityId> {
         core::ptr::null()
     }
     #[doc(hidden)]
@@ -51,6 +51,11 @@ pub trait IntoAbstract {
     fn other_dense(&self) -> Vec<core::slice::Iter<'static, EntityId>> {
         Vec::new()
     }
+    #[cfg(feature = "parallel")]
+    #[doc(hidden)]
+    fn par_min_len(&self) -> Option<usize> {
+        None
+    }
 }
 
 impl<'a: 'b, 'b, T: Component, Track: Tracking> IntoAbstract for &'b View<'a, T, Track> {
@@ -76,6 +81,11 @@ impl<'a: 'b, 'b, T: Component, Track: Tracking> IntoAbstract for &'b View<'a, T,
     fn dense(&self) -> *const EntityId {
         self.dense.as_ptr()
     }
+    #[cfg(feature = "parallel")]
+    #[inline]
+    fn par_min_len(&self) -> Option<usize> {
+        Some(SparseSet::par_min_len(self))
+    }
 }
 
 impl<'a: 'b, 'b, T: Component, Track: Tracking> IntoAbstract for &'b ViewMut<'a, T, Track> {
@@ -101,6 +111,11 @@ impl<'a: 'b, 'b, T: Component, Track: Tracking> IntoAbstract for &'b ViewMut<'a,
     fn dense(&self) -> *const EntityId {
         self.dense.as_ptr()
     }
+    #[cfg(feature = "parallel")]
+    #[inline]
+    fn par_min_len(&self) -> Option<usize> {
+        Some(SparseSet::par_min_len(self))
+    }
 }
 
 impl<'a: 'b, 'b, T: Component, Track> IntoAbstract for &'b mut ViewMut<'a, T, Track> {
@@ -126,4 +141,9 @@ impl<'a: 'b, 'b, T: Component, Track> IntoAbstract for &'b mut ViewMut<'a, T, Tr
     fn dense(&self) -> *const EntityId {
         self.dense.as_ptr()
     }
+    #[cfg(feature = "parallel")]
+    #[inline]
+    fn par_min_len(&self) -> Option<usize> {
+        Some(SparseSet::par_min_len(self))
+    }
 }
@@ -32,4 +32,8 @@ where
     fn is_not(&self) -> bool {
         true
     }
+    #[cfg(feature = "parallel")]
+    fn par_min_len(&self) -> Option<usize> {
+        self.0.par_min_len()
+    }
 }
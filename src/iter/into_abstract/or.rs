@@ -38,4 +38,11 @@ where
     fn is_or(&self) -> bool {
         true
     }
+    #[cfg(feature = "parallel")]
+    fn par_min_len(&self) -> Option<usize> {
+        match ((self.0).0.par_min_len(), (self.0).1.par_min_len()) {
+            (Some(first), Some(second)) => Some(first.min(second)),
+            (first, second) => first.or(second),
+        }
+    }
 }
@@ -76,6 +76,47 @@ pub trait IntoIter {
     #[cfg(feature = "parallel")]
     #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
     fn par_iter(self) -> Self::IntoParIter;
+    /// Returns the number of entities the iterator would yield.\
+    /// Only checks which entities match, components are not accessed and no tracking information is modified.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(Component)]
+    /// struct Online;
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Player, Online));
+    /// world.add_entity((Player,));
+    /// world.add_entity((Online,));
+    ///
+    /// let (players, online) = world.borrow::<(View<Player>, View<Online>)>().unwrap();
+    ///
+    /// assert_eq!((&players, &online).count_matches(), 1);
+    /// assert!(!(&players, &online).is_empty());
+    /// ```
+    #[inline]
+    fn count_matches(self) -> usize
+    where
+        Self: Sized,
+    {
+        self.iter().count()
+    }
+    /// Returns `true` if the iterator wouldn't yield any entity.\
+    /// Stops at the first match, components are not accessed and no tracking information is modified.
+    #[allow(clippy::wrong_self_convention)]
+    #[inline]
+    fn is_empty(self) -> bool
+    where
+        Self: Sized,
+    {
+        self.iter().next().is_none()
+    }
 }
 
 impl<T: IntoAbstract> IntoIter for T
@@ -122,7 +163,17 @@ where
     #[cfg(feature = "parallel")]
     #[inline]
     fn par_iter(self) -> Self::IntoParIter {
-        self.iter().into()
+        let min_len = self.par_min_len().unwrap_or(1);
+
+        ParIter::from(self.iter()).with_min_len(min_len)
+    }
+    #[inline]
+    fn count_matches(self) -> usize {
+        self.iter().count_matches()
+    }
+    #[inline]
+    fn is_empty(self) -> bool {
+        self.iter().is_empty()
     }
 }
 
@@ -170,7 +221,17 @@ where
     #[cfg(feature = "parallel")]
     #[inline]
     fn par_iter(self) -> Self::IntoParIter {
-        self.iter().into()
+        let min_len = self.0.par_min_len().unwrap_or(1);
+
+        ParIter::from(self.iter()).with_min_len(min_len)
+    }
+    #[inline]
+    fn count_matches(self) -> usize {
+        self.iter().count_matches()
+    }
+    #[inline]
+    fn is_empty(self) -> bool {
+        self.iter().is_empty()
     }
 }
 
@@ -336,7 +397,21 @@ macro_rules! impl_into_iter {
             #[cfg(feature = "parallel")]
             #[inline]
             fn par_iter(self) -> Self::IntoParIter {
-                self.iter().into()
+                let min_len = [self.$index1.par_min_len(), $(self.$index.par_min_len()),+]
+                    .into_iter()
+                    .flatten()
+                    .min()
+                    .unwrap_or(1);
+
+                ParIter::from(self.iter()).with_min_len(min_len)
+            }
+            #[inline]
+            fn count_matches(self) -> usize {
+                self.iter().count_matches()
+            }
+            #[inline]
+            fn is_empty(self) -> bool {
+                self.iter().is_empty()
             }
         }
     }
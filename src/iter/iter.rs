@@ -10,6 +10,25 @@ pub enum Iter<Storage> {
     Mixed(Mixed<Storage>),
 }
 
+impl<Storage: AbstractMut> Iter<Storage> {
+    /// Returns the number of remaining items, without creating them.
+    #[inline]
+    pub fn count_matches(self) -> usize {
+        match self {
+            Iter::Tight(tight) => tight.len(),
+            Iter::Mixed(mixed) => mixed.count_matches(),
+        }
+    }
+    /// Returns `true` if there are no remaining items, without creating them.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        match self {
+            Iter::Tight(tight) => tight.len() == 0,
+            Iter::Mixed(mixed) => !mixed.has_match(),
+        }
+    }
+}
+
 impl<Storage: AbstractMut> Iterator for Iter<Storage>
 where
     <Storage as AbstractMut>::Index: Clone,
@@ -18,6 +18,55 @@ pub struct Mixed<Storage> {
 
 unsafe impl<Storage: Send> Send for Mixed<Storage> {}
 
+impl<Storage: AbstractMut> Mixed<Storage> {
+    /// Returns the number of remaining entities matching all storages, without creating the items.
+    pub(crate) fn count_matches(mut self) -> usize {
+        let mut matches = 0;
+
+        loop {
+            for &id in self.indices.by_ref() {
+                self.count += 1;
+
+                if self
+                    .storage
+                    .indices_of(id, self.count - 1, self.mask)
+                    .is_some()
+                {
+                    matches += 1;
+                }
+            }
+
+            if let Some(next_indices) = self.rev_next_storage.pop() {
+                self.indices = next_indices;
+            } else {
+                return matches;
+            }
+        }
+    }
+    /// Returns `true` if at least one remaining entity matches all storages, without creating the item.
+    pub(crate) fn has_match(mut self) -> bool {
+        loop {
+            for &id in self.indices.by_ref() {
+                self.count += 1;
+
+                if self
+                    .storage
+                    .indices_of(id, self.count - 1, self.mask)
+                    .is_some()
+                {
+                    return true;
+                }
+            }
+
+            if let Some(next_indices) = self.rev_next_storage.pop() {
+                self.indices = next_indices;
+            } else {
+                return false;
+            }
+        }
+    }
+}
+
 impl<Storage: AbstractMut> Iterator for Mixed<Storage> {
     type Item = <Storage as AbstractMut>::Out;
 
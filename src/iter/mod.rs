@@ -35,3 +35,26 @@ pub use rayon::iter::{
 };
 pub use tight::Tight;
 pub use with_id::{IntoWithId, LastId, WithId};
+
+/// Iterator returned by [`IntoIter::iter`] for `T`.\
+/// Makes the return type of helper functions writable.
+///
+/// ### Example
+/// ```
+/// use shipyard::{iter::IterOf, Component, InsertedOrModified, IntoIter, View, World};
+///
+/// #[derive(Component)]
+/// #[track(Insertion, Modification)]
+/// struct Pos(f32);
+///
+/// fn changed<'a, 'v>(positions: &'a View<'v, Pos>) -> IterOf<InsertedOrModified<&'a View<'v, Pos>>> {
+///     positions.inserted_or_modified().iter()
+/// }
+///
+/// let mut world = World::new();
+///
+/// world.add_entity(Pos(0.0));
+///
+/// assert_eq!(changed(&world.borrow::<View<Pos>>().unwrap()).count(), 1);
+/// ```
+pub type IterOf<T> = <T as IntoIter>::IntoIter;
@@ -5,9 +5,40 @@ use super::par_tight::ParTight;
 use rayon::iter::plumbing::UnindexedConsumer;
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 
-#[allow(missing_docs)]
+/// Parallel iterator over one or multiple storages.
+///
+/// Short-circuiting methods like [`find_any`](ParallelIterator::find_any) and [`any`](ParallelIterator::any)
+/// stop splitting the iterator and skip the remaining components of all tasks once a match is found.
+///
+/// ### Example
+/// ```
+/// use rayon::prelude::ParallelIterator;
+/// use shipyard::{Component, IntoIter, View, World};
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// #[derive(Component)]
+/// struct Poisoned;
+///
+/// let mut world = World::new();
+///
+/// world.bulk_add_entity((1..100).map(Health));
+/// world.add_entity((Health(0), Poisoned));
+///
+/// let (healths, poisoned) = world.borrow::<(View<Health>, View<Poisoned>)>().unwrap();
+///
+/// assert!(healths.par_iter().any(|health| health.0 == 50));
+/// assert!(healths.par_iter().find_any(|health| health.0 > 100).is_none());
+/// assert!((&healths, &poisoned)
+///     .par_iter()
+///     .find_any(|(health, _)| health.0 == 0)
+///     .is_some());
+/// ```
 pub enum ParIter<Storage> {
+    #[allow(missing_docs)]
     Tight(ParTight<Storage>),
+    #[allow(missing_docs)]
     Mixed(ParMixed<Storage>),
 }
 
@@ -20,6 +51,83 @@ impl<Storage: AbstractMut> From<Iter<Storage>> for ParIter<Storage> {
     }
 }
 
+impl<Storage> ParIter<Storage> {
+    /// Sets the minimum number of entities a single task will go through.\
+    /// Small tasks have an overhead that can exceed the work they do, this limits how much the iterator is split.
+    ///
+    /// By default the iterator uses the smallest [`par_min_len`](crate::SparseSet::par_min_len) of the storages it iterates.
+    ///
+    /// ### Example
+    /// ```
+    /// use rayon::prelude::ParallelIterator;
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.bulk_add_entity((0..100).map(U32));
+    ///
+    /// let u32s = world.borrow::<View<U32>>().unwrap();
+    ///
+    /// let sum: u32 = u32s.par_iter().with_min_len(25).map(|u32| u32.0).sum();
+    ///
+    /// assert_eq!(sum, 4950);
+    /// ```
+    #[inline]
+    pub fn with_min_len(self, min_len: usize) -> Self {
+        match self {
+            ParIter::Tight(tight) => ParIter::Tight(tight.with_min_len(min_len)),
+            ParIter::Mixed(mixed) => ParIter::Mixed(mixed.with_min_len(min_len)),
+        }
+    }
+    /// Returns the minimum number of entities a single task will go through.
+    #[inline]
+    pub fn min_len(&self) -> usize {
+        match self {
+            ParIter::Tight(tight) => tight.min_len(),
+            ParIter::Mixed(mixed) => mixed.min_len(),
+        }
+    }
+    /// Returns an [`IndexedParallelIterator`] if the iterator doesn't have to filter any component.\
+    /// This is the case when iterating a single storage without tracking, `Not` or `Or`.\
+    /// Otherwise returns the iterator unchanged.
+    ///
+    /// ### Example
+    /// ```
+    /// use rayon::prelude::{IndexedParallelIterator, ParallelIterator};
+    /// use shipyard::{Component, IntoIter, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(U32(0));
+    /// world.add_entity(U32(1));
+    ///
+    /// let u32s = world.borrow::<View<U32>>().unwrap();
+    ///
+    /// let mut values = Vec::new();
+    /// if let Ok(par_iter) = u32s.par_iter().into_indexed() {
+    ///     par_iter
+    ///         .enumerate()
+    ///         .map(|(i, u32)| i as u32 + u32.0)
+    ///         .collect_into_vec(&mut values);
+    /// }
+    ///
+    /// assert_eq!(values, vec![0, 2]);
+    /// ```
+    #[inline]
+    pub fn into_indexed(self) -> Result<ParTight<Storage>, Self> {
+        match self {
+            ParIter::Tight(tight) => Ok(tight),
+            ParIter::Mixed(_) => Err(self),
+        }
+    }
+}
+
 impl<Storage: AbstractMut> ParallelIterator for ParIter<Storage>
 where
     Storage: Clone + Send,
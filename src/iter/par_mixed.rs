@@ -1,14 +1,32 @@
 use super::abstract_mut::AbstractMut;
 use super::mixed::Mixed;
-use rayon::iter::plumbing::{bridge_unindexed, UnindexedConsumer};
+use rayon::iter::plumbing::{bridge_unindexed, Folder, UnindexedConsumer, UnindexedProducer};
 use rayon::iter::ParallelIterator;
 
 #[allow(missing_docs)]
-pub struct ParMixed<Storage>(Mixed<Storage>);
+pub struct ParMixed<Storage> {
+    iter: Mixed<Storage>,
+    min_len: usize,
+}
 
 impl<Storage: AbstractMut> From<Mixed<Storage>> for ParMixed<Storage> {
     fn from(iter: Mixed<Storage>) -> Self {
-        ParMixed(iter)
+        ParMixed { iter, min_len: 1 }
+    }
+}
+
+impl<Storage> ParMixed<Storage> {
+    /// Sets the minimum number of entities a single task will go through.\
+    /// Overrides the default based on the storages' [`par_min_len`](crate::SparseSet::par_min_len).
+    #[inline]
+    pub fn with_min_len(mut self, min_len: usize) -> Self {
+        self.min_len = min_len.max(1);
+        self
+    }
+    /// Returns the minimum number of entities a single task will go through.
+    #[inline]
+    pub fn min_len(&self) -> usize {
+        self.min_len
     }
 }
 
@@ -24,6 +42,48 @@ where
     where
         C: UnindexedConsumer<Self::Item>,
     {
-        bridge_unindexed(self.0, consumer)
+        bridge_unindexed(
+            MixedProducer {
+                iter: self.iter,
+                min_len: self.min_len,
+            },
+            consumer,
+        )
+    }
+}
+
+/// Only splits `Mixed` if both halves would have at least `min_len` entities.
+struct MixedProducer<Storage> {
+    iter: Mixed<Storage>,
+    min_len: usize,
+}
+
+impl<Storage: AbstractMut + Clone + Send> UnindexedProducer for MixedProducer<Storage> {
+    type Item = <Storage as AbstractMut>::Out;
+
+    #[inline]
+    fn split(self) -> (Self, Option<Self>) {
+        if self.iter.indices.len() < self.min_len * 2 && self.iter.rev_next_storage.is_empty() {
+            return (self, None);
+        }
+
+        let min_len = self.min_len;
+        let (first, second) = self.iter.split();
+
+        (
+            MixedProducer {
+                iter: first,
+                min_len,
+            },
+            second.map(|iter| MixedProducer { iter, min_len }),
+        )
+    }
+
+    #[inline]
+    fn fold_with<F>(self, folder: F) -> F
+    where
+        F: Folder<Self::Item>,
+    {
+        folder.consume_iter(self.iter)
     }
 }
@@ -1,14 +1,32 @@
 use super::abstract_mut::AbstractMut;
 use super::tight::Tight;
-use rayon::iter::plumbing::{bridge, Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 
 #[allow(missing_docs)]
-pub struct ParTight<Storage>(Tight<Storage>);
+pub struct ParTight<Storage> {
+    iter: Tight<Storage>,
+    min_len: usize,
+}
 
 impl<Storage: AbstractMut> From<Tight<Storage>> for ParTight<Storage> {
     fn from(iter: Tight<Storage>) -> Self {
-        ParTight(iter)
+        ParTight { iter, min_len: 1 }
+    }
+}
+
+impl<Storage> ParTight<Storage> {
+    /// Sets the minimum number of components a single task will process.\
+    /// Overrides the default based on the storages' [`par_min_len`](crate::SparseSet::par_min_len).
+    #[inline]
+    pub fn with_min_len(mut self, min_len: usize) -> Self {
+        self.min_len = min_len.max(1);
+        self
+    }
+    /// Returns the minimum number of components a single task will process.
+    #[inline]
+    pub fn min_len(&self) -> usize {
+        self.min_len
     }
 }
 
@@ -40,7 +58,7 @@ where
 {
     #[inline]
     fn len(&self) -> usize {
-        self.0.len()
+        self.iter.len()
     }
 
     #[inline]
@@ -50,6 +68,44 @@ where
 
     #[inline]
     fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
-        callback.callback(self.0)
+        callback.callback(TightProducer {
+            iter: self.iter,
+            min_len: self.min_len,
+        })
+    }
+}
+
+/// `Tight` producer that won't split below `min_len` components.
+struct TightProducer<Storage> {
+    iter: Tight<Storage>,
+    min_len: usize,
+}
+
+impl<Storage: AbstractMut + Clone + Send> Producer for TightProducer<Storage> {
+    type Item = <Tight<Storage> as Iterator>::Item;
+    type IntoIter = Tight<Storage>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+    #[inline]
+    fn min_len(&self) -> usize {
+        self.min_len
+    }
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (first, second) = self.iter.split_at(index);
+
+        (
+            TightProducer {
+                iter: first,
+                min_len: self.min_len,
+            },
+            TightProducer {
+                iter: second,
+                min_len: self.min_len,
+            },
+        )
     }
 }
@@ -66,7 +66,7 @@ pub trait IterComponent {
 
 impl<T: Component + Send + Sync> IterComponent for &'_ T {
     type Storage<'a> = FullRawWindow<'a, T>;
-    type Borrow<'a> = SharedBorrow<'a>;
+    type Borrow<'a> = Option<SharedBorrow<'a>>;
 
     fn into_abtract_mut<'a>(
         all_storages: &'a AllStorages,
@@ -120,7 +120,7 @@ impl<T: Component + Send + Sync> IterComponent for &'_ T {
 #[cfg(feature = "thread_local")]
 impl<T: Component + Sync> IterComponent for NonSend<&'_ T> {
     type Storage<'a> = FullRawWindow<'a, T>;
-    type Borrow<'a> = SharedBorrow<'a>;
+    type Borrow<'a> = Option<SharedBorrow<'a>>;
 
     fn into_abtract_mut<'a>(
         all_storages: &'a AllStorages,
@@ -174,7 +174,7 @@ impl<T: Component + Sync> IterComponent for NonSend<&'_ T> {
 #[cfg(feature = "thread_local")]
 impl<T: Component + Send> IterComponent for NonSync<&'_ T> {
     type Storage<'a> = FullRawWindow<'a, T>;
-    type Borrow<'a> = SharedBorrow<'a>;
+    type Borrow<'a> = Option<SharedBorrow<'a>>;
 
     fn into_abtract_mut<'a>(
         all_storages: &'a AllStorages,
@@ -228,7 +228,7 @@ impl<T: Component + Send> IterComponent for NonSync<&'_ T> {
 #[cfg(feature = "thread_local")]
 impl<T: Component> IterComponent for NonSendSync<&'_ T> {
     type Storage<'a> = FullRawWindow<'a, T>;
-    type Borrow<'a> = SharedBorrow<'a>;
+    type Borrow<'a> = Option<SharedBorrow<'a>>;
 
     fn into_abtract_mut<'a>(
         all_storages: &'a AllStorages,
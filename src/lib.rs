@@ -4,12 +4,14 @@
 //!
 //! ## Features
 //!
+//! - **bytemuck** &mdash; views over [`Pod`](https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html) components can be read and written as bytes
 //! - **parallel** *(default)* &mdash; enables workload threading and add parallel iterators
 //! - **proc** *(default)* &mdash; re-exports macros from `shipyard_proc`, mainly to derive `Component`
 //! - **serde1** &mdash; adds (de)serialization support with [serde](https://github.com/serde-rs/serde)
 //! - **std** *(default)* &mdash; lets Shipyard use the standard library
+//! - **storage_location** &mdash; records where each storage was created, see `World::storages_info`
 //! - **thread_local** &mdash; adds methods and types required to work with `!Send` and `!Sync` components
-//! - **tracing** &mdash; reports workload and system execution
+//! - **tracing** &mdash; reports workload, batch and system execution
 
 #![warn(elided_lifetimes_in_paths)]
 #![warn(trivial_casts)]
@@ -33,34 +35,55 @@
 
 extern crate alloc;
 
+#[cfg(feature = "access_stats")]
+mod access_stats;
 mod add_component;
 mod add_distinct_component;
 mod add_entity;
 mod all_storages;
+mod archive;
 mod atomic_refcell;
+/// Helpers to build worlds and time systems or workloads in benchmarks.
+#[cfg(feature = "bench")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bench")))]
+pub mod bench;
 /// Allows access to helper types needed to implement `Borrow`.
 pub mod borrow;
+#[cfg(feature = "compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
+pub mod compat;
 mod component;
 mod contains;
 mod delete;
+mod drop_queue;
 mod entities;
 mod entity_id;
+mod entity_mut;
 pub mod error;
+#[cfg(feature = "std")]
+mod frame_arena;
 mod get;
 mod get_component;
 mod get_unique;
+mod hierarchy;
 pub mod iter;
 mod iter_component;
 mod memory_usage;
 mod r#mut;
+mod name;
 mod not;
 mod or;
+mod pinned;
+#[cfg(feature = "std")]
+mod profiler;
 mod public_transport;
 mod remove;
 mod reserve;
 mod scheduler;
 mod seal;
+mod shared_component;
 mod sparse_set;
+mod stable_names;
 mod storage;
 mod system;
 /// module related to storage tracking, like insertion or modification.
@@ -80,57 +103,100 @@ pub use crate::borrow::NonSendSync;
 #[cfg(feature = "thread_local")]
 #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
 pub use crate::borrow::NonSync;
+#[cfg(feature = "access_stats")]
+#[cfg_attr(docsrs, doc(cfg(feature = "access_stats")))]
+pub use access_stats::{AccessStats, AccessedTogether, StorageAccessStats, SystemAccessStats};
 pub use add_component::AddComponent;
 pub use add_distinct_component::AddDistinctComponent;
 pub use add_entity::AddEntity;
 pub use all_storages::{
-    AllStorages, CustomStorageAccess, LockPresent, MissingLock, MissingThreadId, ThreadIdPresent,
-    TupleDeleteAny, TupleRetainStorage,
+    AllStorages, CustomStorageAccess, LockPresent, MissingLock, MissingThreadId, PartialSnapshot,
+    ThreadIdPresent, TupleDeleteAny, TupleRetainStorage, TupleSnapshot,
 };
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub use all_storages::{TupleFilter, TupleSerialize};
+pub use archive::ArchivedEntity;
+#[cfg(feature = "serde1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+pub use archive::TupleArchive;
 pub use atomic_refcell::{ARef, ARefMut};
 #[doc(hidden)]
 pub use atomic_refcell::{ExclusiveBorrow, SharedBorrow};
 #[doc(inline)]
 pub use borrow::{Borrow, BorrowInfo, Mutability, WorldBorrow};
-pub use component::{Component, Unique};
+#[cfg(feature = "compat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
+#[allow(deprecated)]
+pub use compat::WorkloadBuilder;
+pub use component::{AtomicUnique, Component, Unique};
 pub use contains::Contains;
 pub use delete::Delete;
-pub use entities::Entities;
-pub use entity_id::EntityId;
+pub use drop_queue::DropQueue;
+#[cfg(feature = "thread_local")]
+#[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
+pub use drop_queue::LocalDropQueue;
+pub use entities::{AddComponentPolicy, Entities, LivenessBitmap, ReservationTicket};
+pub use entity_id::{EntityId, EntityIdSet};
+pub use entity_mut::EntityMut;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use frame_arena::FrameArena;
 pub use get::Get;
 pub use get_component::{GetComponent, Ref, RefMut};
 pub use get_unique::GetUnique;
+pub use hierarchy::{propagate_dirty, HierarchyParent};
 pub use iter::{IntoIter, IntoWithId};
 pub use iter_component::{IntoIterRef, IterComponent, IterRef};
-pub use memory_usage::StorageMemoryUsage;
+pub use memory_usage::{
+    AllStoragesMemoryUsage, MemoryBytes, MemoryUsageReport, StorageCategory, StorageMemoryUsage,
+    WorldMemoryUsage,
+};
+pub use name::Name;
 pub use not::Not;
 pub use or::{OneOfTwo, Or};
+pub use pinned::Pinned;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use profiler::{Profile, ProfileEvent, ProfileEventKind};
 pub use r#mut::Mut;
 pub use remove::Remove;
 pub use reserve::{BulkEntityIter, BulkReserve};
 pub use scheduler::{
-    info, AsLabel, IntoWorkload, IntoWorkloadSystem, IntoWorkloadTrySystem, Label,
-    ScheduledWorkload, SystemModificator, Workload, WorkloadModificator, WorkloadSystem,
+    info, not, AsLabel, ClearTrackingStorages, Condition, ErrorPolicy, IntoCondition, IntoWorkload,
+    IntoWorkloadOptionSystem, IntoWorkloadSystem, IntoWorkloadTrySystem, Label, PrefetchStorages,
+    ReactiveStorages, ScheduledWorkload, SystemModificator, Workload, WorkloadModificator,
+    WorkloadSystem,
 };
+pub use shared_component::SharedComponentStorage;
 #[cfg(feature = "proc")]
 pub use shipyard_proc::{Borrow, BorrowInfo, Component, IntoIter, Label, Unique, WorldBorrow};
 pub use sparse_set::{
-    BulkAddEntity, SparseArray, SparseSet, SparseSetDrain, TupleAddComponent, TupleDelete,
-    TupleRemove,
+    BulkAddEntity, Partition, PartitionMut, RawWindow, RawWindowMut, SecondaryMap, SparseArray,
+    SparseArrayStats, SparseSet, SparseSetDrain, TupleAddComponent, TupleDelete, TupleRemove,
 };
-pub use storage::{Storage, StorageId};
+pub use stable_names::StableNames;
+pub use storage::{Storage, StorageId, StorageInfo};
+pub use system::ParallelSystems;
 #[doc(hidden)]
-pub use system::{AllSystem, Nothing, System};
+pub use system::{AllSystem, ForEachSystem, Nothing, System};
 pub use tracking::{
     DeletionTracking, Inserted, InsertedOrModified, InsertionTracking, ModificationTracking,
     Modified, RemovalOrDeletionTracking, RemovalTracking, Tracking, TrackingTimestamp, TupleTrack,
 };
-pub use unique::UniqueStorage;
+pub use unique::{UniqueInfo, UniqueStorage};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use views::SystemContext;
 pub use views::{
-    AllStoragesView, AllStoragesViewMut, EntitiesView, EntitiesViewMut, UniqueOrDefaultView,
+    AllStoragesView, AllStoragesViewMut, AtomicViewMut, EntitiesView, EntitiesViewMut, MaybeView,
+    SharedComponentView, SharedComponentViewMut, UniqueAtomicView, UniqueOrDefaultView,
     UniqueOrDefaultViewMut, UniqueOrInitView, UniqueOrInitViewMut, UniqueView, UniqueViewMut, View,
-    ViewMut,
+    ViewLabeled, ViewLabeledMut, ViewMut, WorkloadData,
 };
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use views::{Rng, RngUnique};
 pub use world::{World, WorldBuilder};
 
 #[cfg(not(feature = "std"))]
@@ -1,12 +1,35 @@
 use crate::all_storages::AllStorages;
+use crate::error;
 use crate::world::World;
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 
+/// Displays storages memory information.\
+/// A [`MemoryUsageReport`] can be obtained with [`report`](WorldMemoryUsage::report).
 pub struct WorldMemoryUsage<'w>(pub(crate) &'w World);
 
+/// Displays storages memory information.\
+/// A [`MemoryUsageReport`] can be obtained with [`report`](AllStoragesMemoryUsage::report).
 pub struct AllStoragesMemoryUsage<'a>(pub(crate) &'a AllStorages);
 
+/// Kind of data a storage holds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageCategory {
+    /// The storage holding all entities.
+    Entities,
+    /// A component storage.
+    Components,
+    /// A unique storage.
+    Unique,
+    /// Any other storage.
+    Custom,
+}
+
 /// A trait to query the amount of memory a storage uses.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct StorageMemoryUsage {
     #[allow(missing_docs)]
     pub storage_name: Cow<'static, str>,
@@ -14,8 +37,35 @@ pub struct StorageMemoryUsage {
     pub used_memory_bytes: usize,
     /// Amount of memory allocated by the storage in bytes (including reserved memory).
     pub allocated_memory_bytes: usize,
+    /// Part of `used_memory_bytes` used by tracking information.
+    pub used_tracking_memory_bytes: usize,
+    /// Part of `allocated_memory_bytes` allocated for tracking information.
+    pub allocated_tracking_memory_bytes: usize,
     #[allow(missing_docs)]
     pub component_count: usize,
+    #[allow(missing_docs)]
+    pub category: StorageCategory,
+}
+
+impl StorageMemoryUsage {
+    /// Creates the memory usage of a [`Custom`](StorageCategory::Custom) storage without tracking information.\
+    /// The other fields can be modified after creation.
+    pub fn new(
+        storage_name: impl Into<Cow<'static, str>>,
+        used_memory_bytes: usize,
+        allocated_memory_bytes: usize,
+        component_count: usize,
+    ) -> StorageMemoryUsage {
+        StorageMemoryUsage {
+            storage_name: storage_name.into(),
+            used_memory_bytes,
+            allocated_memory_bytes,
+            used_tracking_memory_bytes: 0,
+            allocated_tracking_memory_bytes: 0,
+            component_count,
+            category: StorageCategory::Custom,
+        }
+    }
 }
 
 impl core::fmt::Debug for StorageMemoryUsage {
@@ -29,3 +79,172 @@ impl core::fmt::Debug for StorageMemoryUsage {
         ))
     }
 }
+
+/// Amount of memory used and allocated, in bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryBytes {
+    /// Amount of memory used in bytes.
+    pub used: usize,
+    /// Amount of memory allocated in bytes (including reserved memory).
+    pub allocated: usize,
+}
+
+impl core::ops::Add for MemoryBytes {
+    type Output = MemoryBytes;
+
+    #[inline]
+    fn add(self, rhs: MemoryBytes) -> MemoryBytes {
+        MemoryBytes {
+            used: self.used + rhs.used,
+            allocated: self.allocated + rhs.allocated,
+        }
+    }
+}
+
+impl core::ops::AddAssign for MemoryBytes {
+    #[inline]
+    fn add_assign(&mut self, rhs: MemoryBytes) {
+        *self = *self + rhs;
+    }
+}
+
+/// Memory used by a `World` broken down per category.
+///
+/// Storages that could not be borrowed when the report was made are not included, `borrowed_storages` counts them.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryUsageReport {
+    /// Memory used by the `Entities` storage.
+    pub entities: MemoryBytes,
+    /// Memory used by component storages, tracking information excluded.
+    pub components: MemoryBytes,
+    /// Memory used by the tracking information of component storages.
+    pub tracking: MemoryBytes,
+    /// Memory used by unique storages.
+    pub uniques: MemoryBytes,
+    /// Memory used by custom storages.
+    pub custom_storages: MemoryBytes,
+    /// Memory used by the workloads and systems.
+    pub scheduler: MemoryBytes,
+    /// Details of each storage.
+    pub storages: Vec<StorageMemoryUsage>,
+    /// Number of storages that could not be borrowed.
+    pub borrowed_storages: usize,
+}
+
+impl MemoryUsageReport {
+    /// Returns the sum of all categories.
+    pub fn total(&self) -> MemoryBytes {
+        self.entities
+            + self.components
+            + self.tracking
+            + self.uniques
+            + self.custom_storages
+            + self.scheduler
+    }
+    /// Returns the total amount of memory allocated in bytes.
+    pub fn total_bytes(&self) -> usize {
+        self.total().allocated
+    }
+    pub(crate) fn add_storage(&mut self, storage: StorageMemoryUsage) {
+        let memory = MemoryBytes {
+            used: storage.used_memory_bytes,
+            allocated: storage.allocated_memory_bytes,
+        };
+
+        match storage.category {
+            StorageCategory::Entities => self.entities += memory,
+            StorageCategory::Components => {
+                let tracking = MemoryBytes {
+                    used: storage.used_tracking_memory_bytes,
+                    allocated: storage.allocated_tracking_memory_bytes,
+                };
+
+                self.tracking += tracking;
+                self.components += MemoryBytes {
+                    used: memory.used - tracking.used,
+                    allocated: memory.allocated - tracking.allocated,
+                };
+            }
+            StorageCategory::Unique => self.uniques += memory,
+            StorageCategory::Custom => self.custom_storages += memory,
+        }
+
+        self.storages.push(storage);
+    }
+}
+
+impl WorldMemoryUsage<'_> {
+    /// Returns the memory used by the `World` broken down per category.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Scheduler (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - AllStorages borrow failed.
+    /// - Scheduler borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(U32(0));
+    ///
+    /// let report = world.memory_usage().report().unwrap();
+    ///
+    /// assert!(report.components.used > 0);
+    /// assert_eq!(report.total_bytes(), world.memory_usage().total_bytes().unwrap());
+    /// ```
+    pub fn report(&self) -> Result<MemoryUsageReport, error::Borrow> {
+        let mut report = self.0.all_storages.borrow()?.memory_usage().report();
+        report.scheduler = self.0.scheduler.borrow()?.memory_usage();
+
+        Ok(report)
+    }
+    /// Returns the total amount of memory allocated by the `World` in bytes.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Scheduler (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - AllStorages borrow failed.
+    /// - Scheduler borrow failed.
+    pub fn total_bytes(&self) -> Result<usize, error::Borrow> {
+        Ok(self.report()?.total_bytes())
+    }
+}
+
+impl AllStoragesMemoryUsage<'_> {
+    /// Returns the memory used by the storages broken down per category.\
+    /// The scheduler is not part of `AllStorages`, its memory is not included.
+    pub fn report(&self) -> MemoryUsageReport {
+        let mut report = MemoryUsageReport::default();
+
+        let storages = self.0.storages.read();
+
+        for storage in storages.values() {
+            match unsafe { &*(storage.ptr) }.borrow() {
+                Ok(storage) => {
+                    if let Some(memory_usage) = storage.memory_usage() {
+                        report.add_storage(memory_usage);
+                    }
+                }
+                Err(_) => report.borrowed_storages += 1,
+            }
+        }
+
+        report
+    }
+}
@@ -0,0 +1,108 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::component::Component;
+use crate::error;
+use crate::sparse_set::SparseSet;
+use crate::track;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt;
+use core::ops::Deref;
+
+/// Human readable name of an entity.
+///
+/// When enabled with [`World::set_entity_names_in_errors`](crate::World::set_entity_names_in_errors),
+/// panics from indexing a view with an entity missing the component include the name of the entity, `EId(42.0) ("Boss_Door")` for example.\
+/// Dead entities don't have components anymore, errors about them, like adding a component to a dead entity, only show their `EntityId`.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, Name, View, World};
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// let mut world = World::new();
+/// world.set_entity_names_in_errors(true);
+///
+/// let door = world.add_entity(Name::new("Boss_Door"));
+///
+/// let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+///     world.run(|healths: View<Health>| healths[door].0);
+/// }))
+/// .unwrap_err();
+///
+/// assert!(panic.downcast_ref::<String>().unwrap().contains("(\"Boss_Door\")"));
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Name(pub Cow<'static, str>);
+
+impl Name {
+    /// Creates a new name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Name {
+        Name(name.into())
+    }
+    /// Returns the name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Component for Name {
+    type Tracking = track::Untracked;
+}
+
+impl Deref for Name {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&'static str> for Name {
+    fn from(name: &'static str) -> Name {
+        Name(Cow::Borrowed(name))
+    }
+}
+
+impl From<String> for Name {
+    fn from(name: String) -> Name {
+        Name(Cow::Owned(name))
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Panics with `missing_component`, the entity's name is included when enabled.\
+/// The `Name` storage might be the one exclusively borrowed, the message then stays without name.
+#[cold]
+#[track_caller]
+pub(crate) fn missing_component_panic(
+    entity_names: Option<&AllStorages>,
+    missing_component: error::MissingComponent,
+) -> ! {
+    if let Some(all_storages) = entity_names {
+        if let Ok(names) = all_storages.custom_storage::<SparseSet<Name>>() {
+            if let Some(name) = names.private_get(missing_component.id) {
+                panic!(
+                    "{:?} ({:?}) does not have a {} component.",
+                    missing_component.id,
+                    name.as_str(),
+                    missing_component.name
+                );
+            }
+        }
+    }
+
+    panic!("{:?}", missing_component)
+}
@@ -1,7 +1,7 @@
 use crate::{
     component::Component,
     iter::IntoAbstract,
-    tracking::{Inserted, Tracking},
+    tracking::{Inserted, InsertedOrModified, Modified, Tracking},
     views::{View, ViewMut},
 };
 use core::ops::BitOr;
@@ -43,41 +43,34 @@ use core::ops::BitOr;
 #[derive(Copy, Clone)]
 pub struct Or<T>(pub(crate) T);
 
-impl<'a, T: Component, Track: Tracking, U: IntoAbstract> BitOr<U> for &'a View<'a, T, Track> {
-    type Output = Or<(Self, U)>;
+macro_rules! impl_bitor {
+    ($($view: ty),+) => {
+        $(
+            impl<'a: 'b, 'b, T: Component, Track: Tracking, U: IntoAbstract> BitOr<U> for $view {
+                type Output = Or<(Self, U)>;
 
-    fn bitor(self, rhs: U) -> Self::Output {
-        Or((self, rhs))
-    }
-}
-
-impl<'a, T: Component, Track: Tracking, U: IntoAbstract> BitOr<U>
-    for Inserted<&'a View<'a, T, Track>>
-{
-    type Output = Or<(Self, U)>;
-
-    fn bitor(self, rhs: U) -> Self::Output {
-        Or((self, rhs))
-    }
-}
-
-impl<'a, T: Component, Track: Tracking, U: IntoAbstract> BitOr<U> for &'a ViewMut<'a, T, Track> {
-    type Output = Or<(Self, U)>;
-
-    fn bitor(self, rhs: U) -> Self::Output {
-        Or((self, rhs))
-    }
+                fn bitor(self, rhs: U) -> Self::Output {
+                    Or((self, rhs))
+                }
+            }
+        )+
+    };
 }
 
-impl<'a, T: Component, Track: Tracking, U: IntoAbstract> BitOr<U>
-    for &'a mut ViewMut<'a, T, Track>
-{
-    type Output = Or<(Self, U)>;
-
-    fn bitor(self, rhs: U) -> Self::Output {
-        Or((self, rhs))
-    }
-}
+impl_bitor![
+    &'b View<'a, T, Track>,
+    &'b ViewMut<'a, T, Track>,
+    &'b mut ViewMut<'a, T, Track>,
+    Inserted<&'b View<'a, T, Track>>,
+    Inserted<&'b ViewMut<'a, T, Track>>,
+    Inserted<&'b mut ViewMut<'a, T, Track>>,
+    Modified<&'b View<'a, T, Track>>,
+    Modified<&'b ViewMut<'a, T, Track>>,
+    Modified<&'b mut ViewMut<'a, T, Track>>,
+    InsertedOrModified<&'b View<'a, T, Track>>,
+    InsertedOrModified<&'b ViewMut<'a, T, Track>>,
+    InsertedOrModified<&'b mut ViewMut<'a, T, Track>>
+];
 
 /// Returned when iterating with [`Or`](crate::Or) filter.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
@@ -0,0 +1,86 @@
+use crate::component::Component;
+use alloc::boxed::Box;
+use core::ops::{Deref, DerefMut};
+
+/// Component kept in its own heap allocation.
+///
+/// Storages move their components around, when a component is deleted the last one takes its place for example.\
+/// `Pinned` only moves the pointer, the component itself doesn't move until it is deleted, removed or replaced.\
+/// Its address can then be handed to code outside the `World`, like a physics engine.
+///
+/// `Pinned<T>` has its own storage, separate from `T`'s, and uses `T`'s tracking.\
+/// Iterating is slower than for an inline component since each component is behind a pointer.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, Pinned, View, World};
+///
+/// #[derive(Component)]
+/// struct RigidBody(f32);
+///
+/// let mut world = World::new();
+///
+/// let first = world.add_entity((Pinned::new(RigidBody(0.0)),));
+/// let second = world.add_entity((Pinned::new(RigidBody(1.0)),));
+///
+/// let address = world.run(|bodies: View<Pinned<RigidBody>>| bodies[second].as_ptr());
+///
+/// // `second`'s component takes `first`'s place in the storage
+/// world.delete_entity(first);
+///
+/// world.run(|bodies: View<Pinned<RigidBody>>| {
+///     assert_eq!(bodies[second].as_ptr(), address);
+/// });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pinned<T>(Box<T>);
+
+impl<T: Component> Component for Pinned<T> {
+    type Tracking = T::Tracking;
+}
+
+impl<T> Pinned<T> {
+    /// Moves `value` to the heap.
+    #[inline]
+    pub fn new(value: T) -> Pinned<T> {
+        Pinned(Box::new(value))
+    }
+    /// Returns the component's address.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        &*self.0
+    }
+    /// Returns the component's address.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        &mut *self.0
+    }
+    /// Moves the component out of its allocation.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        *self.0
+    }
+}
+
+impl<T> From<T> for Pinned<T> {
+    #[inline]
+    fn from(value: T) -> Pinned<T> {
+        Pinned::new(value)
+    }
+}
+
+impl<T> Deref for Pinned<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Pinned<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
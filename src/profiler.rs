@@ -0,0 +1,175 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write as _;
+use core::time::Duration;
+use std::path::Path;
+use std::sync::Mutex;
+use std::thread::ThreadId;
+use std::time::Instant;
+
+/// What a [`ProfileEvent`] measured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProfileEventKind {
+    /// A whole workload run.
+    Workload,
+    /// A batch of systems running in parallel.\
+    /// Only recorded with the `parallel` feature.
+    Batch,
+    /// A system run, including the time spent borrowing its views.
+    System,
+    /// The time a system spent borrowing its storages before running.
+    Borrow,
+    /// The time spent reading the storages a system [`prefetch`](crate::SystemModificator::prefetch)es, on the thread running it.
+    Prefetch,
+}
+
+impl ProfileEventKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ProfileEventKind::Workload => "workload",
+            ProfileEventKind::Batch => "batch",
+            ProfileEventKind::System => "system",
+            ProfileEventKind::Borrow => "borrow",
+            ProfileEventKind::Prefetch => "prefetch",
+        }
+    }
+}
+
+/// A single measurement recorded while profiling.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileEvent {
+    /// Name of the workload, batch or system.
+    pub name: String,
+    #[allow(missing_docs)]
+    pub kind: ProfileEventKind,
+    /// Index of the thread the event happened on, in order of first appearance.
+    pub thread: usize,
+    /// Time between the start of profiling and the start of the event.
+    pub start: Duration,
+    #[allow(missing_docs)]
+    pub duration: Duration,
+}
+
+/// Events recorded between [`World::start_profiling`](crate::World::start_profiling) and [`World::stop_profiling`](crate::World::stop_profiling).
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    events: Vec<ProfileEvent>,
+}
+
+impl Profile {
+    /// Returns all recorded events, in the order they ended.
+    pub fn events(&self) -> &[ProfileEvent] {
+        &self.events
+    }
+    /// Returns the profile in Chrome's tracing format.\
+    /// It can be opened with `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+    pub fn chrome_trace(&self) -> String {
+        let mut trace = String::from("{\"traceEvents\":[");
+
+        for (i, event) in self.events.iter().enumerate() {
+            if i != 0 {
+                trace.push(',');
+            }
+
+            trace.push_str("{\"name\":");
+            push_json_str(&mut trace, &event.name);
+            let _ = write!(
+                trace,
+                ",\"cat\":\"{}\",\"ph\":\"X\",\"ts\":{},\"dur\":{},\"pid\":0,\"tid\":{}}}",
+                event.kind.as_str(),
+                event.start.as_secs_f64() * 1_000_000.0,
+                event.duration.as_secs_f64() * 1_000_000.0,
+                event.thread
+            );
+        }
+
+        trace.push_str("],\"displayTimeUnit\":\"ms\"}");
+
+        trace
+    }
+    /// Writes the profile in Chrome's tracing format to `path`.\
+    /// It can be opened with `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+    ///
+    /// ### Errors
+    ///
+    /// - The file could not be written.
+    pub fn write_chrome_trace<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.chrome_trace())
+    }
+}
+
+fn push_json_str(buffer: &mut String, s: &str) {
+    buffer.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => buffer.push_str("\\\""),
+            '\\' => buffer.push_str("\\\\"),
+            '\n' => buffer.push_str("\\n"),
+            '\r' => buffer.push_str("\\r"),
+            '\t' => buffer.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(buffer, "\\u{:04x}", c as u32);
+            }
+            c => buffer.push(c),
+        }
+    }
+
+    buffer.push('"');
+}
+
+/// Collects events while profiling is active.
+pub(crate) struct Profiler {
+    start: Instant,
+    state: Mutex<ProfilerState>,
+}
+
+struct ProfilerState {
+    events: Vec<ProfileEvent>,
+    threads: Vec<ThreadId>,
+}
+
+impl Profiler {
+    pub(crate) fn new() -> Profiler {
+        Profiler {
+            start: Instant::now(),
+            state: Mutex::new(ProfilerState {
+                events: Vec::new(),
+                threads: Vec::new(),
+            }),
+        }
+    }
+    /// Records an event that started at `start` and ends now.
+    pub(crate) fn record(&self, kind: ProfileEventKind, name: String, start: Instant) {
+        let duration = start.elapsed();
+        let thread_id = std::thread::current().id();
+
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+        let thread = match state.threads.iter().position(|id| *id == thread_id) {
+            Some(thread) => thread,
+            None => {
+                state.threads.push(thread_id);
+                state.threads.len() - 1
+            }
+        };
+
+        state.events.push(ProfileEvent {
+            name,
+            kind,
+            thread,
+            start: start.saturating_duration_since(self.start),
+            duration,
+        });
+    }
+    pub(crate) fn finish(self) -> Profile {
+        let state = self
+            .state
+            .into_inner()
+            .unwrap_or_else(|err| err.into_inner());
+
+        Profile {
+            events: state.events,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::component::Component;
+use crate::scheduler::{IntoWorkloadSystem, WorkloadSystem};
+use crate::views::ViewMut;
+use alloc::vec::Vec;
+
+/// Component or tuple of components [`Workload::clear_tracking_after`](crate::Workload::clear_tracking_after) can clear.
+pub trait ClearTrackingStorages {
+    /// Adds a system clearing the *inserted* and *modified* flags of each storage.
+    fn clear_tracking_systems(systems: &mut Vec<WorkloadSystem>);
+}
+
+/// Clears the *inserted* and *modified* flags of `T`'s storage.
+///
+/// Only borrows `T`'s storage so the clearing systems of different storages can run in parallel.\
+/// Missing storages are skipped.
+fn clear_tracking<T: Component + Send + Sync>(sparse_set: Option<ViewMut<'_, T>>) {
+    if let Some(sparse_set) = sparse_set {
+        sparse_set
+            .sparse_set
+            .private_clear_all_inserted_and_modified(sparse_set.current);
+    }
+}
+
+impl<T: Component + Send + Sync> ClearTrackingStorages for T {
+    fn clear_tracking_systems(systems: &mut Vec<WorkloadSystem>) {
+        systems.push(clear_tracking::<T>.into_workload_system().unwrap());
+    }
+}
+
+macro_rules! impl_clear_tracking_storages {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: Component + Send + Sync,)+> ClearTrackingStorages for ($($type,)+) {
+            fn clear_tracking_systems(systems: &mut Vec<WorkloadSystem>) {
+                $(
+                    $type::clear_tracking_systems(systems);
+                )+
+            }
+        }
+    }
+}
+
+macro_rules! clear_tracking_storages {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_clear_tracking_storages![$(($type, $index))*];
+        clear_tracking_storages![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_clear_tracking_storages![$(($type, $index))*];
+    }
+}
+
+clear_tracking_storages![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
@@ -0,0 +1,225 @@
+use crate::error;
+use crate::scheduler::into_workload_run_if::{IntoRunIf, IntoWorkloadRunIf};
+use crate::scheduler::system::{RunIf, WorkloadRunIfFn};
+use crate::world::World;
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+/// Source of the ids identifying each workload run, shared by all worlds.
+static WORKLOAD_RUNS: AtomicU32 = AtomicU32::new(1);
+
+/// Returns an id identifying a workload run, never `0`.
+pub(crate) fn next_workload_run() -> u32 {
+    let run = WORKLOAD_RUNS.fetch_add(1, Ordering::Relaxed);
+
+    if run == 0 {
+        WORKLOAD_RUNS.fetch_add(1, Ordering::Relaxed)
+    } else {
+        run
+    }
+}
+
+type ConditionFn = dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static;
+
+/// Condition that can be composed with [`and`](IntoCondition::and), [`or`](IntoCondition::or) and [`not`].
+///
+/// A condition is evaluated at most once per workload run, clones share the result.\
+/// A single condition can gate many systems and nested workloads without borrowing its views again.
+///
+/// It can be used anywhere a `run_if`/`skip_if` function is accepted, for systems and workloads.
+///
+/// ### Example
+/// ```
+/// use shipyard::{not, Condition, IntoCondition, SystemModificator, Unique, UniqueView, World};
+///
+/// #[derive(Unique)]
+/// struct Paused(bool);
+///
+/// #[derive(Unique)]
+/// struct InMenu(bool);
+///
+/// fn is_paused(paused: UniqueView<Paused>) -> bool {
+///     paused.0
+/// }
+///
+/// fn in_menu(in_menu: UniqueView<InMenu>) -> bool {
+///     in_menu.0
+/// }
+///
+/// fn physics() {}
+/// fn menu() {}
+///
+/// let world = World::new();
+/// world.add_unique(Paused(false));
+/// world.add_unique(InMenu(true));
+///
+/// let paused = Condition::new(is_paused);
+///
+/// world.add_workload(move || {
+///     (
+///         physics.run_if(not(paused.clone()).and(not(in_menu))),
+///         menu.run_if(paused.clone().or(in_menu)),
+///     )
+/// });
+///
+/// world.run_default_workload().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Condition {
+    name: Cow<'static, str>,
+    condition: Arc<ConditionFn>,
+    /// Error of an invalid condition, reported when the condition is added to a system or workload
+    pub(super) invalid: Option<error::InvalidSystem>,
+    cache: Arc<ConditionCache>,
+}
+
+#[derive(Default)]
+struct ConditionCache {
+    /// Id of the workload run `result` was computed for, `0` if never evaluated
+    run: AtomicU32,
+    result: AtomicBool,
+}
+
+impl Condition {
+    /// Creates a new condition from a `run_if` function.
+    pub fn new<B, R: IntoRunIf<B>>(run_if: R) -> Condition {
+        let name = run_if.run_if_name();
+
+        match run_if.into_workload_run_if() {
+            Ok(run_if) => Condition::from_fn(name, Arc::from(run_if.system_fn), None),
+            Err(err) => Condition::from_fn(name, Arc::new(|_: &World| Ok(false)), Some(err)),
+        }
+    }
+    fn from_fn(
+        name: Cow<'static, str>,
+        condition: Arc<ConditionFn>,
+        invalid: Option<error::InvalidSystem>,
+    ) -> Condition {
+        Condition {
+            name,
+            condition,
+            invalid,
+            cache: Arc::default(),
+        }
+    }
+    /// Returns the name of the condition, `(is_paused && !in_menu)` for example.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Evaluates the condition, reusing the result if it was already evaluated during this workload run.
+    fn evaluate(&self, world: &World) -> Result<bool, error::Run> {
+        let run = world.workload_run.load(Ordering::Acquire);
+
+        if run != 0 && self.cache.run.load(Ordering::Acquire) == run {
+            return Ok(self.cache.result.load(Ordering::Acquire));
+        }
+
+        let result = (self.condition)(world)?;
+
+        self.cache.result.store(result, Ordering::Release);
+        self.cache.run.store(run, Ordering::Release);
+
+        Ok(result)
+    }
+    /// Combines two conditions, `rhs` is only evaluated if `lhs` doesn't decide the result.
+    fn combine(lhs: Condition, rhs: Condition, and: bool) -> Condition {
+        let name = format!(
+            "({} {} {})",
+            lhs.name,
+            if and { "&&" } else { "||" },
+            rhs.name
+        );
+        let invalid = lhs.invalid.or(rhs.invalid);
+
+        Condition::from_fn(
+            name.into(),
+            Arc::new(move |world: &World| {
+                if lhs.evaluate(world)? == and {
+                    rhs.evaluate(world)
+                } else {
+                    Ok(!and)
+                }
+            }),
+            invalid,
+        )
+    }
+}
+
+impl core::ops::Not for Condition {
+    type Output = Condition;
+
+    fn not(self) -> Condition {
+        let name = format!("!{}", self.name);
+        let invalid = self.invalid;
+
+        Condition::from_fn(
+            name.into(),
+            Arc::new(move |world: &World| Ok(!self.evaluate(world)?)),
+            invalid,
+        )
+    }
+}
+
+impl core::fmt::Debug for Condition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Condition").field(&self.name).finish()
+    }
+}
+
+impl IntoRunIf<Condition> for Condition {
+    fn into_workload_run_if(self) -> Result<RunIf, error::InvalidSystem> {
+        if let Some(err) = self.invalid {
+            return Err(err);
+        }
+
+        Ok(RunIf {
+            system_fn: Box::new(move |world: &World| self.evaluate(world)),
+        })
+    }
+    fn run_if_name(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+}
+
+impl IntoWorkloadRunIf<Condition> for Condition {
+    fn into_workload_run_if(self) -> Result<Box<dyn WorkloadRunIfFn>, error::InvalidSystem> {
+        if let Some(err) = self.invalid {
+            return Err(err);
+        }
+
+        Ok(Box::new(move |world: &World| self.evaluate(world)))
+    }
+    fn run_if_name(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+}
+
+/// Converts `run_if` functions into [`Condition`]s and composes them.
+pub trait IntoCondition<B>: Sized {
+    /// Converts `self` into a [`Condition`].
+    fn into_condition(self) -> Condition;
+    /// Returns a condition evaluating to `true` when both conditions are `true`.\
+    /// `other` is not evaluated if `self` is `false`.
+    fn and<OtherB, Other: IntoCondition<OtherB>>(self, other: Other) -> Condition {
+        Condition::combine(self.into_condition(), other.into_condition(), true)
+    }
+    /// Returns a condition evaluating to `true` when at least one of the conditions is `true`.\
+    /// `other` is not evaluated if `self` is `true`.
+    fn or<OtherB, Other: IntoCondition<OtherB>>(self, other: Other) -> Condition {
+        Condition::combine(self.into_condition(), other.into_condition(), false)
+    }
+}
+
+impl<B, R: IntoRunIf<B>> IntoCondition<B> for R {
+    fn into_condition(self) -> Condition {
+        Condition::new(self)
+    }
+}
+
+/// Returns a condition evaluating to `true` when `condition` is `false`.
+pub fn not<B, C: IntoCondition<B>>(condition: C) -> Condition {
+    !condition.into_condition()
+}
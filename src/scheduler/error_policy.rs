@@ -0,0 +1,20 @@
+use crate::error;
+use crate::scheduler::label::Label;
+
+/// Decides what a workload does when one of its systems returns an error.
+///
+/// Returned by the handler passed to [`Workload::on_error`](crate::Workload::on_error).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Ignores the error and continues running the workload.
+    Skip,
+    /// Runs the system again.\
+    /// There is no limit on the number of retries, the handler receives how many already happened to stop at some point.
+    Retry,
+    /// Stops the workload and returns the error.
+    Abort,
+}
+
+/// Handler called with the system's name, the error it returned and the number of times it was already retried.
+pub(crate) type ErrorHandler =
+    dyn Fn(&dyn Label, &error::Run, u32) -> ErrorPolicy + Send + Sync + 'static;
@@ -1,5 +1,34 @@
 //! Types for displaying workload information.
-
+//!
+//! [`TypeInfo`], [`Conflict`] and [`SystemId`] describe what systems borrow and why they can't run in parallel.\
+//! They are part of the stable API, external schedulers and static analyzers can rely on them.
+//!
+//! A system's borrows are listed in [`WorkloadSystem::borrow_constraints`](crate::WorkloadSystem::borrow_constraints).
+//!
+//! ### Example
+//! ```
+//! use shipyard::{Component, IntoWorkloadSystem, View, ViewMut};
+//!
+//! #[derive(Component)]
+//! struct Pos(f32);
+//!
+//! fn read(_: View<Pos>) {}
+//! fn write(_: ViewMut<Pos>) {}
+//!
+//! let read = read.into_workload_system().unwrap();
+//! let write = write.into_workload_system().unwrap();
+//!
+//! let conflict = read.borrow_constraints.iter().any(|type_info| {
+//!     write
+//!         .borrow_constraints
+//!         .iter()
+//!         .any(|other| type_info.conflicts_with(other))
+//! });
+//!
+//! assert!(conflict);
+//! ```
+
+use crate::all_storages::AllStorages;
 use crate::borrow::Mutability;
 use crate::scheduler::{AsLabel, Label};
 use crate::storage::StorageId;
@@ -9,19 +38,150 @@ use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::hash::BuildHasherDefault;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 /// Contains information related to a workload.
 ///
 /// A workload is a collection of systems with parallelism calculated based on the types borrow by the systems.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct WorkloadInfo {
     #[allow(missing_docs)]
     pub name: String,
     #[allow(missing_docs)]
     pub batch_info: Vec<BatchInfo>,
+    /// Conditions added to the workload itself with `run_if`/`skip_if`.\
+    /// Conditions of workloads merged into this one are listed on their systems.
+    #[cfg_attr(feature = "serde1", serde(default))]
+    pub run_if: Vec<RunIfInfo>,
+}
+
+impl WorkloadInfo {
+    /// Returns the index of the batches running `system`.
+    ///
+    /// `system` can be the system's full name or its name without path, `"my_crate::physics"` and `"physics"` both match the `physics` system.
+    pub fn batches_of(&self, system: &str) -> Vec<usize> {
+        let suffix = format!("::{}", system);
+
+        self.batch_info
+            .iter()
+            .enumerate()
+            .filter(|(_, batch)| {
+                batch
+                    .systems()
+                    .any(|info| info.name == system || info.name.ends_with(&suffix))
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+    #[track_caller]
+    fn expect_batches_of(&self, system: &str) -> Vec<usize> {
+        let batches = self.batches_of(system);
+
+        if batches.is_empty() {
+            panic!("System {} is not part of workload {}.", system, self.name);
+        }
+
+        batches
+    }
+    /// Asserts that all runs of `before` happen in an earlier batch than all runs of `after`.
+    ///
+    /// ### Panics
+    ///
+    /// - One of the systems isn't part of the workload.
+    /// - `before` doesn't run strictly before `after`.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Pos(f32);
+    ///
+    /// fn read(_: View<Pos>) {}
+    /// fn write(_: ViewMut<Pos>) {}
+    /// fn other_read(_: View<Pos>) {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Physics")
+    ///     .with_system(read)
+    ///     .with_system(write)
+    ///     .with_system(other_read)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// let workloads_info = world.workloads_info();
+    /// let info = &workloads_info.0["Physics"];
+    ///
+    /// info.assert_runs_before("read", "write");
+    /// info.assert_runs_before("write", "other_read");
+    /// info.assert_not_in_same_batch("read", "other_read");
+    /// ```
+    #[track_caller]
+    pub fn assert_runs_before(&self, before: &str, after: &str) {
+        let before_batches = self.expect_batches_of(before);
+        let after_batches = self.expect_batches_of(after);
+
+        let last_before = *before_batches.iter().max().unwrap();
+        let first_after = *after_batches.iter().min().unwrap();
+
+        assert!(
+            last_before < first_after,
+            "Expected {} to run before {} in workload {}, but they run in batches {:?} and {:?}.",
+            before,
+            after,
+            self.name,
+            before_batches,
+            after_batches
+        );
+    }
+    /// Asserts that `a` and `b` run in the same batch, they can run in parallel.
+    ///
+    /// ### Panics
+    ///
+    /// - One of the systems isn't part of the workload.
+    /// - The systems never run in the same batch.
+    #[track_caller]
+    pub fn assert_in_same_batch(&self, a: &str, b: &str) {
+        let a_batches = self.expect_batches_of(a);
+        let b_batches = self.expect_batches_of(b);
+
+        assert!(
+            a_batches.iter().any(|batch| b_batches.contains(batch)),
+            "Expected {} and {} to run in the same batch in workload {}, but they run in batches {:?} and {:?}.",
+            a,
+            b,
+            self.name,
+            a_batches,
+            b_batches
+        );
+    }
+    /// Asserts that `a` and `b` never run in the same batch.
+    ///
+    /// ### Panics
+    ///
+    /// - One of the systems isn't part of the workload.
+    /// - The systems run in the same batch.
+    #[track_caller]
+    pub fn assert_not_in_same_batch(&self, a: &str, b: &str) {
+        let a_batches = self.expect_batches_of(a);
+        let b_batches = self.expect_batches_of(b);
+
+        assert!(
+            !a_batches.iter().any(|batch| b_batches.contains(batch)),
+            "Expected {} and {} to run in different batches in workload {}, but they run in batches {:?} and {:?}.",
+            a,
+            b,
+            self.name,
+            a_batches,
+            b_batches
+        );
+    }
 }
 
 /// Contains information related to a batch.
@@ -44,6 +204,7 @@ impl BatchInfo {
 /// Contains information related to a system.
 #[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct SystemInfo {
     #[allow(missing_docs)]
     pub name: String,
@@ -57,6 +218,10 @@ pub struct SystemInfo {
     pub before: Vec<String>,
     #[allow(missing_docs)]
     pub after: Vec<String>,
+    /// Conditions that have to pass for the system to run, in evaluation order.\
+    /// Includes the conditions of the workloads the system was merged from.
+    #[cfg_attr(feature = "serde1", serde(default))]
+    pub run_if: Vec<RunIfInfo>,
 }
 
 impl core::fmt::Debug for SystemInfo {
@@ -69,37 +234,148 @@ impl core::fmt::Debug for SystemInfo {
     }
 }
 
+/// Condition added to a system or workload with `run_if` or `skip_if`.
+///
+/// The counters are shared with the scheduled workload, they keep updating after the info is retrieved.
+///
+/// ### Example
+/// ```
+/// use shipyard::{SystemModificator, Workload, World};
+///
+/// fn is_paused() -> bool {
+///     true
+/// }
+///
+/// fn physics() {}
+///
+/// let world = World::new();
+///
+/// Workload::new("Physics")
+///     .with_system(physics.skip_if(is_paused))
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Physics").unwrap();
+///
+/// let workloads_info = world.workloads_info();
+/// let workload_info = workloads_info.0.values().next().unwrap();
+/// let system_info = workload_info.batch_info[0].systems().next().unwrap();
+/// let run_if = &system_info.run_if[0];
+///
+/// assert!(run_if.name.ends_with("is_paused"));
+/// assert!(run_if.skip);
+/// assert_eq!(run_if.evaluated_true(), 1);
+/// assert_eq!(run_if.evaluated_false(), 0);
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunIfInfo {
+    /// Type name of the condition, for display only.
+    pub name: String,
+    /// `true` when the condition was added with `skip_if`, the system then runs when it evaluates to `false`.
+    pub skip: bool,
+    #[cfg_attr(feature = "serde1", serde(skip))]
+    pub(crate) counters: Arc<RunIfCounters>,
+}
+
+impl RunIfInfo {
+    pub(crate) fn new(name: &str, skip: bool) -> RunIfInfo {
+        RunIfInfo {
+            name: name.into(),
+            skip,
+            counters: Arc::default(),
+        }
+    }
+    /// Returns how many times the condition returned `true`.
+    pub fn evaluated_true(&self) -> usize {
+        self.counters.evaluated_true.load(Ordering::Relaxed)
+    }
+    /// Returns how many times the condition returned `false`.
+    pub fn evaluated_false(&self) -> usize {
+        self.counters.evaluated_false.load(Ordering::Relaxed)
+    }
+}
+
+impl PartialEq for RunIfInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.skip == other.skip
+    }
+}
+
+impl Eq for RunIfInfo {}
+
+impl core::fmt::Debug for RunIfInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RunIfInfo")
+            .field("name", &self.name)
+            .field("skip", &self.skip)
+            .field("evaluated_true", &self.evaluated_true())
+            .field("evaluated_false", &self.evaluated_false())
+            .finish()
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct RunIfCounters {
+    evaluated_true: AtomicUsize,
+    evaluated_false: AtomicUsize,
+}
+
+impl RunIfCounters {
+    pub(crate) fn count(&self, result: bool) {
+        if result {
+            self.evaluated_true.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.evaluated_false.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    /// Adds the evaluations counted by `other`.
+    pub(crate) fn add(&self, other: &RunIfCounters) {
+        self.evaluated_true.fetch_add(
+            other.evaluated_true.load(Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+        self.evaluated_false.fetch_add(
+            other.evaluated_false.load(Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+    }
+}
+
 /// Pinpoints the type and system that made a system unable to get into a batch.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
 pub enum Conflict {
     /// Rust rules do not allow the type described by `type_info` to be borrowed at the same time as `other_type_info`.
     Borrow {
-        #[allow(missing_docs)]
+        /// Borrow of the system that couldn't join the batch.\
+        /// `None` when the conflict comes from a before/after requirement.
         type_info: Option<TypeInfo>,
-        #[allow(missing_docs)]
+        /// System already in the batch.
         other_system: SystemId,
-        #[allow(missing_docs)]
+        /// Borrow of `other_system`.
         other_type_info: TypeInfo,
     },
     /// A `!Send` and/or `!Sync` type currently prevents any parrallelism.
     NotSendSync(TypeInfo),
     /// A `!Send` and/or `!Sync` type currently prevents any parrallelism.
     OtherNotSendSync {
-        #[allow(missing_docs)]
+        /// System already in the batch.
         system: SystemId,
-        #[allow(missing_docs)]
+        /// `!Send` and/or `!Sync` borrow of `system`.
         type_info: TypeInfo,
     },
 }
 
 /// Identify a system.
+///
+/// Two `SystemId` are equal when their `type_id` are.
 #[derive(Clone, Eq)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
 pub struct SystemId {
-    #[allow(missing_docs)]
+    /// Name of the system, for display only.
     pub name: String,
-    #[allow(missing_docs)]
+    /// Type of the system.
     pub type_id: TypeId,
 }
 
@@ -115,18 +391,43 @@ impl core::fmt::Debug for SystemId {
     }
 }
 
-/// Identify a type.
+/// Describes a storage borrowed by a system.
+///
+/// Two `TypeInfo` are equal when they borrow the same storage with the same mutability.
 #[derive(Clone, Eq)]
 #[cfg_attr(feature = "serde1", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeInfo {
-    #[allow(missing_docs)]
+    /// Name of the borrowed type, for display only.
     pub name: Cow<'static, str>,
-    #[allow(missing_docs)]
+    /// Whether the storage is borrowed shared or exclusively.
     pub mutability: Mutability,
-    #[allow(missing_docs)]
+    /// Identifies the storage.\
+    /// [`AllStorages`] is borrowed using `StorageId::of::<AllStorages>()`.
     pub storage_id: StorageId,
-    #[allow(missing_docs)]
+    /// `false` if the storage is `!Send` and/or `!Sync`.
     pub thread_safe: bool,
+    /// The storage doesn't have to be present for the borrow to succeed.
+    #[cfg_attr(feature = "serde1", serde(default))]
+    pub optional: bool,
+}
+
+impl TypeInfo {
+    /// Returns `true` if the two borrows can't happen at the same time.
+    ///
+    /// This is the rule workloads use to split systems into batches:
+    /// - both borrows are `!Send` and/or `!Sync`
+    /// - both borrow the same storage and at least one of them exclusively
+    /// - either one borrows [`AllStorages`]
+    pub fn conflicts_with(&self, other: &TypeInfo) -> bool {
+        let all_storages = StorageId::of::<AllStorages>();
+
+        (!self.thread_safe && !other.thread_safe)
+            || (self.storage_id == other.storage_id
+                && (self.mutability == Mutability::Exclusive
+                    || other.mutability == Mutability::Exclusive))
+            || self.storage_id == all_storages
+            || other.storage_id == all_storages
+    }
 }
 
 impl PartialEq for TypeInfo {
@@ -165,6 +466,7 @@ impl core::fmt::Debug for TypeInfo {
             .field("name", &self.name)
             .field("mutability", &self.mutability)
             .field("thread_safe", &self.thread_safe)
+            .field("optional", &self.optional)
             .finish()
     }
 }
@@ -184,12 +184,17 @@ where
                 tags: vec![name],
                 systems: vec![system],
                 run_if: None,
+                run_if_info: Vec::new(),
                 before_all: DedupedLabels::new(),
                 after_all: DedupedLabels::new(),
                 overwritten_name: false,
                 require_before: DedupedLabels::new(),
                 require_after: DedupedLabels::new(),
                 barriers: Vec::new(),
+                on_error: None,
+                max_threads: None,
+                clear_tracking: Vec::new(),
+                tagged_run_if: Vec::new(),
             }
         }
     }
@@ -226,12 +231,17 @@ macro_rules! impl_into_workload {
                     name,
                     systems: Vec::new(),
                     run_if: None,
+                    run_if_info: Vec::new(),
                     before_all: DedupedLabels::new(),
                     after_all: DedupedLabels::new(),
                     overwritten_name: false,
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
                     barriers: Vec::new(),
+                    on_error: None,
+                    max_threads: None,
+                    clear_tracking: Vec::new(),
+                    tagged_run_if: Vec::new(),
                 };
 
                 $(
@@ -256,12 +266,17 @@ macro_rules! impl_into_workload {
                     name,
                     systems: Vec::new(),
                     run_if: None,
+                    run_if_info: Vec::new(),
                     before_all: DedupedLabels::new(),
                     after_all: DedupedLabels::new(),
                     overwritten_name: false,
                     require_before: DedupedLabels::new(),
                     require_after: DedupedLabels::new(),
                     barriers: Vec::new(),
+                    on_error: None,
+                    max_threads: None,
+                    clear_tracking: Vec::new(),
+                    tagged_run_if: Vec::new(),
                 };
 
                 let mut sequential_tags = Vec::new();
@@ -0,0 +1,96 @@
+use crate::borrow::{BorrowInfo, WorldBorrow};
+use crate::info::DedupedLabels;
+use crate::scheduler::into_workload_system::Nothing;
+use crate::scheduler::label::SystemLabel;
+use crate::scheduler::WorkloadSystem;
+use crate::tracking::TrackingTimestamp;
+use crate::type_id::TypeId;
+use crate::World;
+use crate::{error, AsLabel};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Trait used to add systems returning an `Option` to a workload.
+pub trait IntoWorkloadOptionSystem<Views, T> {
+    /// Wraps a function returning an `Option` in a struct containing all information required by a workload.\
+    /// The workload will stop if `None` is returned, with a [`NoneReturned`](error::NoneReturned) error.
+    fn into_workload_option_system(self) -> Result<WorkloadSystem, error::InvalidSystem>;
+}
+
+/// Turns `None` into a [`NoneReturned`](error::NoneReturned) error.
+fn none_returned<T>(output: Option<T>, system: &'static str) -> Result<(), error::Run> {
+    match output {
+        Some(_) => Ok(()),
+        None => Err(error::Run::from_custom(error::NoneReturned { system })),
+    }
+}
+
+impl<T: 'static, F> IntoWorkloadOptionSystem<Nothing, T> for F
+where
+    F: 'static + Send + Sync + Fn() -> Option<T>,
+{
+    fn into_workload_option_system(self) -> Result<WorkloadSystem, error::InvalidSystem> {
+        let system_type_name = type_name::<F>();
+
+        Ok(WorkloadSystem {
+            borrow_constraints: Vec::new(),
+            tracking_to_enable: Vec::new(),
+            required_storages: Vec::new(),
+            prefetch: Vec::new(),
+            system_fn: Box::new(move |_: &World| none_returned((self)(), system_type_name)),
+            type_id: TypeId::of::<F>(),
+            display_name: Box::new(system_type_name),
+            generator: Box::new(|_| TypeId::of::<F>()),
+            before_all: DedupedLabels::new(),
+            after_all: DedupedLabels::new(),
+            tags: vec![Box::new(SystemLabel {
+                type_id: TypeId::of::<F>(),
+                name: system_type_name.as_label(),
+            })],
+            run_if: None,
+            run_if_info: Vec::new(),
+            require_in_workload: DedupedLabels::new(),
+            require_before: DedupedLabels::new(),
+            require_after: DedupedLabels::new(),
+        })
+    }
+}
+
+macro_rules! impl_into_workload_option_system {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: WorldBorrow + BorrowInfo,)+ T: 'static, Func> IntoWorkloadOptionSystem<($($type,)+), T> for Func
+        where
+            Func: 'static
+                + Send
+                + Sync,
+            for<'a, 'b> &'b Func:
+                Fn($($type),+) -> Option<T>
+                + Fn($($type::WorldView<'a>),+) -> Option<T>
+        {
+            fn into_workload_option_system(self) -> Result<WorkloadSystem, error::InvalidSystem> {
+                let last_run = AtomicU32::new(0);
+                WorkloadSystem::from_views::<($($type,)+), Func>(Box::new(move |world: &World| {
+                    let current = world.get_current();
+                    let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                    let views = world.profile_borrow(|| Ok(($($type::world_borrow(&world, Some(last_run), current)?,)+)))?;
+                    none_returned((&&self)($(views.$index),+), type_name::<Func>())
+                }))
+            }
+        }
+    }
+}
+
+macro_rules! into_workload_option_system {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_into_workload_option_system![$(($type, $index))*];
+        into_workload_option_system![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_into_workload_option_system![$(($type, $index))*];
+    }
+}
+
+into_workload_option_system![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
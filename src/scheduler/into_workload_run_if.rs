@@ -6,13 +6,19 @@ use crate::scheduler::TypeInfo;
 use crate::storage::StorageId;
 use crate::tracking::TrackingTimestamp;
 use crate::World;
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::any::type_name;
 use core::sync::atomic::{AtomicU32, Ordering};
 
 pub trait IntoRunIf<B> {
     fn into_workload_run_if(self) -> Result<RunIf, error::InvalidSystem>;
+    /// Name listed in [`RunIfInfo`](crate::info::RunIfInfo).
+    fn run_if_name(&self) -> Cow<'static, str> {
+        type_name::<Self>().into()
+    }
 }
 
 pub struct Nothing;
@@ -56,6 +62,7 @@ macro_rules! impl_into_workload_run_if {
                     storage_id: StorageId::of::<AllStorages>(),
                     mutability: Mutability::Exclusive,
                     thread_safe: true,
+                    optional: false,
                 }) && borrows.len() > 1
                 {
                     return Err(error::InvalidSystem::AllStorages);
@@ -107,6 +114,10 @@ into_workload_run_if![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (
 
 pub trait IntoWorkloadRunIf<B> {
     fn into_workload_run_if(self) -> Result<Box<dyn WorkloadRunIfFn>, error::InvalidSystem>;
+    /// Name listed in [`RunIfInfo`](crate::info::RunIfInfo).
+    fn run_if_name(&self) -> Cow<'static, str> {
+        type_name::<Self>().into()
+    }
 }
 
 impl<F> IntoWorkloadRunIf<Nothing> for F
@@ -141,6 +152,7 @@ macro_rules! impl_into_workload_run_if {
                     storage_id: StorageId::of::<AllStorages>(),
                     mutability: Mutability::Exclusive,
                     thread_safe: true,
+                    optional: false,
                 }) && borrows.len() > 1
                 {
                     return Err(error::InvalidSystem::AllStorages);
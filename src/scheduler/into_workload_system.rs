@@ -1,9 +1,7 @@
-use crate::all_storages::AllStorages;
-use crate::borrow::{BorrowInfo, Mutability, WorldBorrow};
+use crate::borrow::{BorrowInfo, WorldBorrow};
 use crate::info::DedupedLabels;
 use crate::scheduler::label::{SystemLabel, WorkloadLabel};
-use crate::scheduler::{TypeInfo, WorkloadSystem};
-use crate::storage::StorageId;
+use crate::scheduler::WorkloadSystem;
 use crate::tracking::TrackingTimestamp;
 use crate::type_id::TypeId;
 use crate::{error, AsLabel, Workload};
@@ -18,6 +16,25 @@ use core::sync::atomic::{AtomicU32, Ordering};
 ///
 /// Usually you don't have to use it directly except if you want to handle the error.\
 /// To modify the system execution see [SystemModificator](crate::SystemModificator).
+///
+/// A system can take up to 10 views.\
+/// Tuples of views are views too, grouping them lets a system borrow more storages without any additional cost.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, IntoWorkloadSystem, View, ViewMut};
+///
+/// #[derive(Component)]
+/// struct A;
+/// #[derive(Component)]
+/// struct B;
+/// #[derive(Component)]
+/// struct C;
+///
+/// fn sys((a, b): (View<A>, View<B>), c: ViewMut<C>) {}
+///
+/// sys.into_workload_system().unwrap();
+/// ```
 pub trait IntoWorkloadSystem<B, R> {
     /// Wraps a function in a struct containing all information required by a workload.
     fn into_workload_system(self) -> Result<WorkloadSystem, error::InvalidSystem>;
@@ -41,6 +58,8 @@ where
         Ok(WorkloadSystem {
             borrow_constraints: Vec::new(),
             tracking_to_enable: Vec::new(),
+            required_storages: Vec::new(),
+            prefetch: Vec::new(),
             system_fn: Box::new(move |_: &World| {
                 (self)();
                 Ok(())
@@ -55,6 +74,7 @@ where
                 name: type_name::<F>().as_label(),
             })],
             run_if: None,
+            run_if_info: Vec::new(),
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
@@ -106,74 +126,13 @@ macro_rules! impl_into_workload_system {
                 + Fn($($type::WorldView<'a>),+) -> R {
 
             fn into_workload_system(self) -> Result<WorkloadSystem, error::InvalidSystem> {
-                let mut borrows = Vec::new();
-                $(
-                    $type::borrow_info(&mut borrows);
-                )+
-
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                if borrows.len() > 1 {
-                    for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
-                        for b_type_info in &borrows[i + 1..] {
-                            if a_type_info.storage_id == b_type_info.storage_id {
-                                match (a_type_info.mutability, b_type_info.mutability) {
-                                    (Mutability::Exclusive, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViewsMut)
-                                    }
-                                    (Mutability::Exclusive, Mutability::Shared)
-                                    | (Mutability::Shared, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViews)
-                                    }
-                                    (Mutability::Shared, Mutability::Shared) => {}
-                                }
-                            }
-                        }
-                    }
-                }
-
-                let mut tracking_to_enable = Vec::new();
-                $(
-                    $type::enable_tracking(&mut tracking_to_enable);
-                )+
-
                 let last_run = AtomicU32::new(0);
-                Ok(WorkloadSystem {
-                    borrow_constraints: borrows,
-                    tracking_to_enable,
-                    system_fn: Box::new(move |world: &World| {
-                        let current = world.get_current();
-                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+)))
-                    }),
-                    type_id: TypeId::of::<Func>(),
-                    display_name: Box::new(type_name::<Func>()),
-                    before_all: DedupedLabels::new(),
-                    after_all: DedupedLabels::new(),
-                    tags: vec![Box::new(SystemLabel {
-                        type_id: TypeId::of::<Func>(),
-                        name: type_name::<Func>().as_label(),
-                    })],
-                    generator: Box::new(|constraints| {
-                        $(
-                            $type::borrow_info(constraints);
-                        )+
-
-                        TypeId::of::<Func>()
-                    }),
-                    run_if: None,
-                    require_in_workload: DedupedLabels::new(),
-                    require_before: DedupedLabels::new(),
-                    require_after: DedupedLabels::new(),
-                })
+                WorkloadSystem::from_views::<($($type,)+), Func>(Box::new(move |world: &World| {
+                    let current = world.get_current();
+                    let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                    let views = world.profile_borrow(|| Ok(($($type::world_borrow(&world, Some(last_run), current)?,)+)))?;
+                    Ok(drop((&&self)($(views.$index),+)))
+                }))
             }
             fn label(&self) -> Box<dyn Label> {
                 Box::new(SystemLabel {
@@ -1,10 +1,8 @@
-use crate::all_storages::AllStorages;
-use crate::borrow::{BorrowInfo, Mutability, WorldBorrow};
+use crate::borrow::{BorrowInfo, WorldBorrow};
 use crate::info::DedupedLabels;
 use crate::scheduler::into_workload_system::Nothing;
 use crate::scheduler::label::SystemLabel;
-use crate::scheduler::{TypeInfo, WorkloadSystem};
-use crate::storage::StorageId;
+use crate::scheduler::WorkloadSystem;
 use crate::tracking::TrackingTimestamp;
 use crate::type_id::TypeId;
 use crate::World;
@@ -55,6 +53,8 @@ where
         Ok(WorkloadSystem {
             borrow_constraints: Vec::new(),
             tracking_to_enable: Vec::new(),
+            required_storages: Vec::new(),
+            prefetch: Vec::new(),
             system_fn: Box::new(move |_: &World| {
                 (self)().into().map_err(error::Run::from_custom)?;
                 Ok(())
@@ -69,6 +69,7 @@ where
                 name: system_type_name.as_label(),
             })],
             run_if: None,
+            run_if_info: Vec::new(),
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
@@ -86,6 +87,8 @@ where
         Ok(WorkloadSystem {
             borrow_constraints: Vec::new(),
             tracking_to_enable: Vec::new(),
+            required_storages: Vec::new(),
+            prefetch: Vec::new(),
             system_fn: Box::new(move |_: &World| {
                 (self)().into().map_err(error::Run::from_custom)?;
                 Ok(())
@@ -100,6 +103,7 @@ where
                 name: system_type_name.as_label(),
             })],
             run_if: None,
+            run_if_info: Vec::new(),
             require_in_workload: DedupedLabels::new(),
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
@@ -140,145 +144,23 @@ macro_rules! impl_into_workload_try_system {
         {
             #[cfg(feature = "std")]
             fn into_workload_try_system<Ok, Err: Into<Box<dyn Error + Send + Sync>>>(self) -> Result<WorkloadSystem, error::InvalidSystem> where R: Into<Result<Ok, Err>> {
-                let mut borrows = Vec::new();
-                $(
-                    $type::borrow_info(&mut borrows);
-                )+
-
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                if borrows.len() > 1 {
-                    for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
-                        for b_type_info in &borrows[i + 1..] {
-                            if a_type_info.storage_id == b_type_info.storage_id {
-                                match (a_type_info.mutability, b_type_info.mutability) {
-                                    (Mutability::Exclusive, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViewsMut)
-                                    }
-                                    (Mutability::Exclusive, Mutability::Shared)
-                                    | (Mutability::Shared, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViews)
-                                    }
-                                    (Mutability::Shared, Mutability::Shared) => {}
-                                }
-                            }
-                        }
-                    }
-                }
-
-                let mut tracking_to_enable = Vec::new();
-                $(
-                    $type::enable_tracking(&mut tracking_to_enable);
-                )+
-
                 let last_run = AtomicU32::new(0);
-                Ok(WorkloadSystem {
-                    borrow_constraints: borrows,
-                    tracking_to_enable,
-                    system_fn: Box::new(move |world: &World| {
-                        let current = world.get_current();
-                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
-                    }),
-                    type_id: TypeId::of::<Func>(),
-                    display_name: Box::new(type_name::<Func>()),
-                    generator: Box::new(|constraints| {
-                        $(
-                            $type::borrow_info(constraints);
-                        )+
-
-                        TypeId::of::<Func>()
-                    }),
-                    before_all: DedupedLabels::new(),
-                    after_all: DedupedLabels::new(),
-                    tags: vec![Box::new(SystemLabel {
-                        type_id: TypeId::of::<Func>(),
-                        name: type_name::<Func>().as_label(),
-                    })],
-                    run_if: None,
-                    require_in_workload: DedupedLabels::new(),
-                    require_before: DedupedLabels::new(),
-                    require_after: DedupedLabels::new(),
-                })
+                WorkloadSystem::from_views::<($($type,)+), Func>(Box::new(move |world: &World| {
+                    let current = world.get_current();
+                    let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                    let views = world.profile_borrow(|| Ok(($($type::world_borrow(&world, Some(last_run), current)?,)+)))?;
+                    Ok(drop((&&self)($(views.$index),+).into().map_err(error::Run::from_custom)?))
+                }))
             }
             #[cfg(not(feature = "std"))]
             fn into_workload_try_system<Ok, Err: 'static + Send + Any>(self) -> Result<WorkloadSystem, error::InvalidSystem> where R: Into<Result<Ok, Err>> {
-                let mut borrows = Vec::new();
-                $(
-                    $type::borrow_info(&mut borrows);
-                )+
-
-                if borrows.contains(&TypeInfo {
-                    name: "".into(),
-                    storage_id: StorageId::of::<AllStorages>(),
-                    mutability: Mutability::Exclusive,
-                    thread_safe: true,
-                }) && borrows.len() > 1
-                {
-                    return Err(error::InvalidSystem::AllStorages);
-                }
-
-                if borrows.len() > 1 {
-                    for (i, a_type_info) in borrows[..borrows.len() - 1].iter().enumerate() {
-                        for b_type_info in &borrows[i + 1..] {
-                            if a_type_info.storage_id == b_type_info.storage_id {
-                                match (a_type_info.mutability, b_type_info.mutability) {
-                                    (Mutability::Exclusive, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViewsMut)
-                                    }
-                                    (Mutability::Exclusive, Mutability::Shared)
-                                    | (Mutability::Shared, Mutability::Exclusive) => {
-                                        return Err(error::InvalidSystem::MultipleViews)
-                                    }
-                                    (Mutability::Shared, Mutability::Shared) => {}
-                                }
-                            }
-                        }
-                    }
-                }
-
-                let mut tracking_to_enable = Vec::new();
-                $(
-                    $type::enable_tracking(&mut tracking_to_enable);
-                )+
-
                 let last_run = AtomicU32::new(0);
-                Ok(WorkloadSystem {
-                    borrow_constraints: borrows,
-                    tracking_to_enable,
-                    system_fn: Box::new(move |world: &World| {
-                        let current = world.get_current();
-                        let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
-                        Ok(drop((&&self)($($type::world_borrow(&world, Some(last_run), current)?),+).into().map_err(error::Run::from_custom)?))
-                    }),
-                    type_id: TypeId::of::<Func>(),
-                    display_name: Box::new(type_name::<Func>()),
-                    generator: Box::new(|constraints| {
-                        $(
-                            $type::borrow_info(constraints);
-                        )+
-
-                        TypeId::of::<Func>()
-                    }),
-                    before_all: DedupedLabels::new(),
-                    after_all: DedupedLabels::new(),
-                    tags: vec![Box::new(SystemLabel {
-                        type_id: TypeId::of::<Func>(),
-                        name: type_name::<Func>().as_label(),
-                    })],
-                    run_if: None,
-                    require_in_workload: DedupedLabels::new(),
-                    require_before: DedupedLabels::new(),
-                    require_after: DedupedLabels::new(),
-                })
+                WorkloadSystem::from_views::<($($type,)+), Func>(Box::new(move |world: &World| {
+                    let current = world.get_current();
+                    let last_run = TrackingTimestamp::new(last_run.swap(current.get(), Ordering::Acquire));
+                    let views = world.profile_borrow(|| Ok(($($type::world_borrow(&world, Some(last_run), current)?,)+)))?;
+                    Ok(drop((&&self)($(views.$index),+).into().map_err(error::Run::from_custom)?))
+                }))
             }
         }
     }
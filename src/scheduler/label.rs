@@ -1,4 +1,7 @@
+use crate::component::Unique;
+use crate::storage::StorageId;
 use crate::type_id::TypeId;
+use crate::unique::UniqueStorage;
 use crate::IntoWorkloadSystem;
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
@@ -49,28 +52,52 @@ macro_rules! impl_label {
     };
 }
 
-impl Label for TypeId {
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-    fn dyn_eq(&self, other: &dyn Label) -> bool {
-        if let Some(other) = other.as_any().downcast_ref::<Self>() {
-            self == other
-        } else {
-            false
-        }
-    }
-    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
-        Self::hash(self, &mut state);
-    }
-    fn dyn_clone(&self) -> Box<dyn Label> {
-        Box::new(*self)
-    }
-    fn dyn_debug(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
-        self.fmt(f)
-    }
+/// Implements `Label` using `Debug` to display the label.
+macro_rules! impl_debug_label {
+    ($($type: ty),+) => {
+        $(
+            impl Label for $type {
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+                fn dyn_eq(&self, other: &dyn Label) -> bool {
+                    if let Some(other) = other.as_any().downcast_ref::<Self>() {
+                        self == other
+                    } else {
+                        false
+                    }
+                }
+                fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+                    Self::hash(self, &mut state);
+                }
+                fn dyn_clone(&self) -> Box<dyn Label> {
+                    Box::new(*self)
+                }
+                fn dyn_debug(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+                    Debug::fmt(self, f)
+                }
+            }
+        )+
+    };
 }
 
+impl_debug_label![
+    TypeId,
+    core::any::TypeId,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize
+];
+
 impl_label![&'static str, String, Cow<'static, str>];
 
 impl Label for Box<dyn Label> {
@@ -113,6 +140,37 @@ impl PartialEq for dyn Label {
 
 impl Eq for dyn Label {}
 
+impl dyn Label {
+    /// Returns the text of `&'static str`, `String` and `Cow<'static, str>` labels.
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        let any = self.as_any();
+
+        if let Some(label) = any.downcast_ref::<&'static str>() {
+            Some(label)
+        } else if let Some(label) = any.downcast_ref::<String>() {
+            Some(label)
+        } else {
+            any.downcast_ref::<Cow<'static, str>>()
+                .map(|label| &**label)
+        }
+    }
+    /// Returns `true` if both labels are equal or are text labels with the same text, regardless of their type.
+    pub(crate) fn collides_with(&self, other: &dyn Label) -> bool {
+        self == other
+            || matches!((self.as_str(), other.as_str()), (Some(label), Some(other)) if label == other)
+    }
+    /// Returns `true` if this is a text label equal to `namespace` or starting with `namespace/`.
+    pub(crate) fn is_under(&self, namespace: &str) -> bool {
+        let namespace = namespace.trim_end_matches('/');
+
+        self.as_str().is_some_and(|label| {
+            label
+                .strip_prefix(namespace)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+}
+
 impl Debug for dyn Label {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         self.dyn_debug(f)
@@ -236,3 +294,45 @@ impl Label for WorkloadLabel {
         f.write_fmt(format_args!("Workload({:?})", self.name))
     }
 }
+
+/// Tag of the systems producing a unique.
+#[derive(Clone, Copy, Debug, Hash)]
+pub(crate) struct ProducedUniqueLabel {
+    pub(crate) storage_id: StorageId,
+    pub(crate) name: &'static str,
+}
+
+impl ProducedUniqueLabel {
+    pub(crate) fn of<T: Unique>() -> ProducedUniqueLabel {
+        ProducedUniqueLabel {
+            storage_id: StorageId::of::<UniqueStorage<T>>(),
+            name: core::any::type_name::<T>(),
+        }
+    }
+}
+
+impl Label for ProducedUniqueLabel {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn Label) -> bool {
+        if let Some(other) = other.as_any().downcast_ref::<ProducedUniqueLabel>() {
+            self.storage_id == other.storage_id
+        } else {
+            false
+        }
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        StorageId::hash(&self.storage_id, &mut state)
+    }
+
+    fn dyn_clone(&self) -> Box<dyn Label> {
+        Box::new(*self)
+    }
+
+    fn dyn_debug(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        f.write_fmt(format_args!("Produces({})", self.name))
+    }
+}
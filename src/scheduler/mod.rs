@@ -1,33 +1,54 @@
+mod clear_tracking;
+mod condition;
+mod error_policy;
 pub mod info;
 mod into_workload;
+mod into_workload_option_system;
 mod into_workload_run_if;
 mod into_workload_system;
 mod into_workload_try_system;
 mod label;
+mod prefetch;
+mod reactive;
 mod system;
 mod system_modificator;
 mod workload;
 mod workload_modificator;
 
+pub use clear_tracking::ClearTrackingStorages;
+pub use condition::{not, Condition, IntoCondition};
+pub use error_policy::ErrorPolicy;
 pub use into_workload::IntoWorkload;
+pub use into_workload_option_system::IntoWorkloadOptionSystem;
 pub use into_workload_system::IntoWorkloadSystem;
 pub use into_workload_try_system::IntoWorkloadTrySystem;
 pub use label::{AsLabel, Label};
+pub use prefetch::PrefetchStorages;
+pub use reactive::ReactiveStorages;
 pub use system::WorkloadSystem;
 pub use system_modificator::SystemModificator;
 pub use workload::{ScheduledWorkload, Workload};
 pub use workload_modificator::WorkloadModificator;
 
+pub(crate) use condition::next_workload_run;
+pub(crate) use error_policy::ErrorHandler;
 pub(crate) use info::TypeInfo;
 
+use crate::all_storages::AllStorages;
 use crate::info::WorkloadInfo;
+use crate::memory_usage::MemoryBytes;
 use crate::scheduler::system::WorkloadRunIfFn;
 use crate::type_id::TypeId;
 use crate::World;
 use crate::{error, ShipHashMap};
 use alloc::boxed::Box;
+use alloc::vec;
 use alloc::vec::Vec;
-use core::hash::BuildHasherDefault;
+use core::hash::{BuildHasherDefault, Hasher};
+use core::mem::{size_of, size_of_val};
+use core::num::NonZeroUsize;
+use core::sync::atomic::AtomicU32;
+use siphasher::sip::SipHasher13;
 
 /// List of indexes into both systems and system_names
 #[derive(Default)]
@@ -41,7 +62,36 @@ pub(super) struct Batches {
     pub(super) sequential: Vec<usize>,
     pub(super) sequential_run_if:
         Vec<Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>>,
+    /// Storages to prefetch before each system, same order as `sequential`, read by the thread running the system
+    pub(super) sequential_prefetch: Vec<Vec<fn(&AllStorages)>>,
     pub(super) run_if: Option<Box<dyn WorkloadRunIfFn>>,
+    pub(super) on_error: Option<Box<ErrorHandler>>,
+    #[cfg_attr(not(feature = "parallel"), allow(dead_code))]
+    pub(super) max_threads: Option<NonZeroUsize>,
+    pub(super) rng_streams: RngStreams,
+}
+
+/// Identifies the [`Rng`](crate::Rng) streams of a workload's systems.
+#[derive(Default)]
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+pub(crate) struct RngStreams {
+    /// Key of each system's stream, same order as `sequential`
+    pub(crate) keys: Vec<u64>,
+    /// Number of times the workload started running
+    pub(crate) runs: AtomicU32,
+}
+
+impl RngStreams {
+    /// Returns the key of the stream of the system added at `index` in `workload`.\
+    /// It only depends on the workload's name and the order systems were added in to stay the same across builds.
+    fn key(workload: &str, index: usize) -> u64 {
+        let mut hasher = SipHasher13::new();
+
+        hasher.write(workload.as_bytes());
+        hasher.write_u64(index as u64);
+
+        hasher.finish()
+    }
 }
 
 #[cfg(test)]
@@ -96,7 +146,88 @@ impl Default for Scheduler {
     }
 }
 
+impl Batches {
+    fn memory_usage(&self) -> MemoryBytes {
+        let used = self
+            .parallel
+            .iter()
+            .chain(&self.parallel_run_if)
+            .map(|(_, systems)| systems.len() * size_of::<usize>())
+            .sum::<usize>()
+            + (self.parallel.len() + self.parallel_run_if.len())
+                * size_of::<(Option<usize>, Vec<usize>)>()
+            + self.sequential.len() * size_of::<usize>()
+            + self.sequential_run_if.len()
+                * size_of::<Option<Box<dyn Fn(&World) -> Result<bool, error::Run>>>>()
+            + self
+                .sequential_prefetch
+                .iter()
+                .map(|prefetch| {
+                    size_of::<Vec<fn(&AllStorages)>>()
+                        + prefetch.len() * size_of::<fn(&AllStorages)>()
+                })
+                .sum::<usize>()
+            + self.rng_streams.keys.len() * size_of::<u64>();
+        let allocated = self
+            .parallel
+            .iter()
+            .chain(&self.parallel_run_if)
+            .map(|(_, systems)| systems.capacity() * size_of::<usize>())
+            .sum::<usize>()
+            + (self.parallel.capacity() + self.parallel_run_if.capacity())
+                * size_of::<(Option<usize>, Vec<usize>)>()
+            + self.sequential.capacity() * size_of::<usize>()
+            + self.sequential_run_if.capacity()
+                * size_of::<Option<Box<dyn Fn(&World) -> Result<bool, error::Run>>>>()
+            + self.sequential_prefetch.capacity() * size_of::<Vec<fn(&AllStorages)>>()
+            + self
+                .sequential_prefetch
+                .iter()
+                .map(|prefetch| prefetch.capacity() * size_of::<fn(&AllStorages)>())
+                .sum::<usize>()
+            + self.rng_streams.keys.capacity() * size_of::<u64>();
+
+        MemoryBytes { used, allocated }
+    }
+}
+
 impl Scheduler {
+    /// Approximates the memory used by the systems and workloads.\
+    /// Memory owned by the systems themselves is not included.
+    pub(crate) fn memory_usage(&self) -> MemoryBytes {
+        let systems_used = self
+            .systems
+            .iter()
+            .map(|system| size_of_val(&**system))
+            .sum::<usize>();
+        let system_entry_size = size_of::<Box<dyn Fn(&World) -> Result<(), error::Run>>>()
+            + size_of::<Box<dyn Label>>()
+            + size_of::<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId>>();
+
+        let mut memory = MemoryBytes {
+            used: systems_used
+                + self.systems.len() * system_entry_size
+                + self.lookup_table.len() * size_of::<(TypeId, usize)>()
+                + (self.workloads.len() + self.workloads_info.len()) * size_of::<Box<dyn Label>>()
+                + self.workloads.len() * size_of::<Batches>()
+                + self.workloads_info.len() * size_of::<WorkloadInfo>()
+                + size_of::<Self>(),
+            allocated: systems_used
+                + self.systems.capacity() * system_entry_size
+                + self.lookup_table.capacity() * size_of::<(TypeId, usize)>()
+                + (self.workloads.capacity() + self.workloads_info.capacity())
+                    * size_of::<Box<dyn Label>>()
+                + self.workloads.capacity() * size_of::<Batches>()
+                + self.workloads_info.capacity() * size_of::<WorkloadInfo>()
+                + size_of::<Self>(),
+        };
+
+        for batches in self.workloads.values() {
+            memory += batches.memory_usage();
+        }
+
+        memory
+    }
     pub(crate) fn set_default<L: Label>(
         &mut self,
         label: L,
@@ -116,6 +247,19 @@ impl Scheduler {
             Err(error::RunWorkload::MissingWorkload)
         }
     }
+    /// Returns the workloads under `namespace`, sorted by label.
+    pub(crate) fn workloads_under(&self, namespace: &str) -> Vec<(&dyn Label, &Batches)> {
+        let mut workloads: Vec<(&dyn Label, &Batches)> = self
+            .workloads
+            .iter()
+            .filter(|(label, _)| label.is_under(namespace))
+            .map(|(label, batches)| (&**label, batches))
+            .collect();
+
+        workloads.sort_unstable_by(|(label, _), (other, _)| label.as_str().cmp(&other.as_str()));
+
+        workloads
+    }
     pub(crate) fn default_workload(&self) -> &Batches {
         &self.workloads[&self.default]
     }
@@ -134,6 +278,56 @@ impl Scheduler {
             self.workloads.insert(new, batches);
         }
     }
+    /// Removes the systems no workload uses anymore and updates the indices of the other ones.
+    pub(crate) fn remove_unused_systems(&mut self) {
+        let mut used = vec![false; self.systems.len()];
+        for batches in self.workloads.values() {
+            for &index in &batches.sequential {
+                used[index] = true;
+            }
+        }
+
+        if used.iter().all(|&used| used) {
+            return;
+        }
+
+        let mut new_indices = Vec::with_capacity(used.len());
+        let mut new_index = 0;
+        for &used in &used {
+            new_indices.push(new_index);
+            new_index += usize::from(used);
+        }
+
+        let mut keep = used.iter();
+        self.systems.retain(|_| *keep.next().unwrap());
+        let mut keep = used.iter();
+        self.system_names.retain(|_| *keep.next().unwrap());
+        let mut keep = used.iter();
+        self.system_generators.retain(|_| *keep.next().unwrap());
+
+        self.lookup_table.retain(|_, index| {
+            let is_used = used[*index];
+            *index = new_indices[*index];
+
+            is_used
+        });
+
+        for batches in self.workloads.values_mut() {
+            for index in &mut batches.sequential {
+                *index = new_indices[*index];
+            }
+
+            for (single, parallel) in &mut batches.parallel {
+                if let Some(index) = single {
+                    *index = new_indices[*index];
+                }
+
+                for index in parallel {
+                    *index = new_indices[*index];
+                }
+            }
+        }
+    }
 }
 
 impl core::fmt::Debug for Scheduler {
@@ -0,0 +1,61 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::component::Component;
+use crate::sparse_set::SparseSet;
+use alloc::vec::Vec;
+use core::mem::{size_of_val, MaybeUninit};
+
+/// Distance between two reads when touching a storage.
+const CACHE_LINE: usize = 64;
+
+/// Tuple of component storages a system can [`prefetch`](crate::SystemModificator::prefetch).
+pub trait PrefetchStorages {
+    /// Adds a function reading the components of each storage.
+    fn prefetch(prefetch_fns: &mut Vec<fn(&AllStorages)>);
+}
+
+/// Reads the entities and components of `T`'s storage to bring them in cache.
+///
+/// Missing or exclusively borrowed storages are skipped.
+fn prefetch_storage<T: Component + Send + Sync>(all_storages: &AllStorages) {
+    if let Ok(sparse_set) = all_storages.custom_storage::<SparseSet<T>>() {
+        touch(&sparse_set.dense);
+        touch(&sparse_set.data);
+    }
+}
+
+/// Reads a byte of each cache line of `slice`.
+fn touch<T>(slice: &[T]) {
+    let ptr = slice.as_ptr().cast::<MaybeUninit<u8>>();
+
+    for offset in (0..size_of_val(slice)).step_by(CACHE_LINE) {
+        // SAFETY: offset is within the slice and bytes are read as `MaybeUninit` to include padding
+        // volatile prevents the read from being optimized out
+        unsafe {
+            ptr.add(offset).read_volatile();
+        }
+    }
+}
+
+macro_rules! impl_prefetch_storages {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: Component + Send + Sync,)+> PrefetchStorages for ($($type,)+) {
+            fn prefetch(prefetch_fns: &mut Vec<fn(&AllStorages)>) {
+                $(
+                    prefetch_fns.push(prefetch_storage::<$type>);
+                )+
+            }
+        }
+    }
+}
+
+macro_rules! prefetch_storages {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_prefetch_storages![$(($type, $index))*];
+        prefetch_storages![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_prefetch_storages![$(($type, $index))*];
+    }
+}
+
+prefetch_storages![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
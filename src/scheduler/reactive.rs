@@ -0,0 +1,56 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::component::Component;
+use crate::error;
+use crate::sparse_set::SparseSet;
+use crate::tracking::TrackingTimestamp;
+
+/// Tuple of component storages a system can be [`reactive_to`](crate::SystemModificator::reactive_to).
+pub trait ReactiveStorages {
+    /// Returns `true` if any of the storages changed within `last` and `current`.
+    ///
+    /// Missing storages are considered unchanged.\
+    /// If a storage is already borrowed, assume it changed.
+    fn changed_within(
+        all_storages: &AllStorages,
+        last: TrackingTimestamp,
+        current: TrackingTimestamp,
+    ) -> bool;
+}
+
+fn storage_changed_within<T: Component + Send + Sync>(
+    all_storages: &AllStorages,
+    last: TrackingTimestamp,
+    current: TrackingTimestamp,
+) -> bool {
+    match all_storages.custom_storage::<SparseSet<T>>() {
+        Ok(sparse_set) => sparse_set.last_change.is_within(last, current),
+        Err(error::GetStorage::MissingStorage { .. }) => false,
+        Err(_) => true,
+    }
+}
+
+macro_rules! impl_reactive_storages {
+    ($(($type: ident, $index: tt))+) => {
+        impl<$($type: Component + Send + Sync,)+> ReactiveStorages for ($($type,)+) {
+            fn changed_within(
+                all_storages: &AllStorages,
+                last: TrackingTimestamp,
+                current: TrackingTimestamp,
+            ) -> bool {
+                $(storage_changed_within::<$type>(all_storages, last, current))||+
+            }
+        }
+    }
+}
+
+macro_rules! reactive_storages {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_reactive_storages![$(($type, $index))*];
+        reactive_storages![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_reactive_storages![$(($type, $index))*];
+    }
+}
+
+reactive_storages![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
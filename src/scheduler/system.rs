@@ -1,13 +1,18 @@
 use super::TypeInfo;
 use crate::all_storages::AllStorages;
+use crate::borrow::{BorrowInfo, Mutability};
 use crate::error;
-use crate::info::DedupedLabels;
-use crate::scheduler::label::Label;
+use crate::info::{DedupedLabels, RunIfInfo};
+use crate::scheduler::label::{AsLabel, Label, SystemLabel};
 use crate::scheduler::workload::Workload;
+use crate::storage::StorageId;
 use crate::type_id::TypeId;
 use crate::world::World;
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
+use alloc::vec;
 use alloc::vec::Vec;
+use core::any::type_name;
 
 /// Self contained system that may be inserted into a [`Workload`].
 ///
@@ -34,6 +39,7 @@ use alloc::vec::Vec;
 ///
 /// [`Workload`]: crate::Workload
 #[allow(clippy::type_complexity)]
+#[non_exhaustive]
 pub struct WorkloadSystem {
     #[allow(missing_docs)]
     pub type_id: TypeId,
@@ -45,10 +51,16 @@ pub struct WorkloadSystem {
     pub borrow_constraints: Vec<TypeInfo>,
     /// Generates the tracking to enable for this system's views
     pub tracking_to_enable: Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    /// Storages that have to be present in the `World` for the system's views to be borrowed
+    pub required_storages: Vec<StorageId>,
+    /// Reads the storages listed with [`prefetch`](crate::SystemModificator::prefetch) on the thread running the system, before it runs
+    pub prefetch: Vec<fn(&AllStorages)>,
     /// Generates constraints and system type id
     pub generator: Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>,
     #[allow(missing_docs)]
     pub run_if: Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static>>,
+    /// Conditions added with `run_if`/`skip_if`, listed in [`SystemInfo::run_if`](crate::info::SystemInfo::run_if).
+    pub run_if_info: Vec<RunIfInfo>,
     #[allow(missing_docs)]
     pub tags: Vec<Box<dyn Label>>,
     #[allow(missing_docs)]
@@ -63,6 +75,200 @@ pub struct WorkloadSystem {
     pub require_after: DedupedLabels,
 }
 
+impl WorkloadSystem {
+    /// Creates a system from a function whose borrows are only known at runtime, a scripting function for example.
+    ///
+    /// The workload schedules the system using `borrows`, they have to list all storages `system` borrows.\
+    /// Borrowing a storage missing from the list can't lead to undefined behavior
+    /// but can make the system fail with a borrow error when it runs alongside a conflicting system.\
+    /// Tracking is not enabled by this system, storages have to track what `system` reads.
+    ///
+    /// `name` identifies the system, systems created with the same name are considered the same system.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::info::TypeInfo;
+    /// use shipyard::{
+    ///     Component, IntoIter, Mutability, SparseSet, StorageId, ViewMut, Workload, WorkloadSystem,
+    ///     World,
+    /// };
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.add_entity(Health(0));
+    ///
+    /// let heal = WorkloadSystem::from_fn_with_borrows(
+    ///     "heal",
+    ///     |world: &World| {
+    ///         let mut healths = world.borrow::<ViewMut<Health>>()?;
+    ///         for mut health in (&mut healths).iter() {
+    ///             health.0 += 1;
+    ///         }
+    ///
+    ///         Ok(())
+    ///     },
+    ///     vec![TypeInfo {
+    ///         name: "Health".into(),
+    ///         mutability: Mutability::Exclusive,
+    ///         storage_id: StorageId::of::<SparseSet<Health>>(),
+    ///         thread_safe: true,
+    ///         optional: false,
+    ///     }],
+    /// )
+    /// .unwrap();
+    ///
+    /// Workload::new("Update")
+    ///     .with_system(heal)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Update").unwrap();
+    ///
+    /// assert_eq!(world.get::<&Health>(entity).unwrap().0, 1);
+    /// ```
+    pub fn from_fn_with_borrows<F>(
+        name: impl Into<Cow<'static, str>>,
+        system: F,
+        borrows: Vec<TypeInfo>,
+    ) -> Result<WorkloadSystem, error::InvalidSystem>
+    where
+        F: Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static,
+    {
+        check_borrows(&borrows)?;
+
+        let name: Cow<'static, str> = name.into();
+        let type_id = TypeId::of_name(&name);
+        let generator_borrows = borrows.clone();
+
+        Ok(WorkloadSystem {
+            type_id,
+            display_name: Box::new(name.clone()),
+            system_fn: Box::new(system),
+            borrow_constraints: borrows,
+            tracking_to_enable: Vec::new(),
+            required_storages: Vec::new(),
+            prefetch: Vec::new(),
+            generator: Box::new(move |constraints| {
+                constraints.extend(generator_borrows.iter().cloned());
+
+                type_id
+            }),
+            run_if: None,
+            run_if_info: Vec::new(),
+            tags: vec![Box::new(SystemLabel {
+                type_id,
+                name: Box::new(name),
+            })],
+            before_all: DedupedLabels::new(),
+            after_all: DedupedLabels::new(),
+            require_in_workload: DedupedLabels::new(),
+            require_before: DedupedLabels::new(),
+            require_after: DedupedLabels::new(),
+        })
+    }
+    /// Creates the system of `Func`, a function borrowing `Views`.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn from_views<Views: BorrowInfo, Func: 'static>(
+        system_fn: Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>,
+    ) -> Result<WorkloadSystem, error::InvalidSystem> {
+        let mut borrows = Vec::new();
+        Views::borrow_info(&mut borrows);
+
+        check_borrows(&borrows)?;
+
+        let mut tracking_to_enable = Vec::new();
+        Views::enable_tracking(&mut tracking_to_enable);
+
+        let mut required_storages = Vec::new();
+        Views::required_storages(&mut required_storages);
+
+        Ok(WorkloadSystem {
+            borrow_constraints: borrows,
+            tracking_to_enable,
+            required_storages,
+            prefetch: Vec::new(),
+            system_fn,
+            type_id: TypeId::of::<Func>(),
+            display_name: Box::new(type_name::<Func>()),
+            generator: Box::new(|constraints| {
+                Views::borrow_info(constraints);
+
+                TypeId::of::<Func>()
+            }),
+            before_all: DedupedLabels::new(),
+            after_all: DedupedLabels::new(),
+            tags: vec![Box::new(SystemLabel {
+                type_id: TypeId::of::<Func>(),
+                name: type_name::<Func>().as_label(),
+            })],
+            run_if: None,
+            run_if_info: Vec::new(),
+            require_in_workload: DedupedLabels::new(),
+            require_before: DedupedLabels::new(),
+            require_after: DedupedLabels::new(),
+        })
+    }
+    /// Adds a condition evaluated after the ones already present.\
+    /// With `skip` the system runs when `run_if` evaluates to `false`.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn add_run_if(&mut self, run_if: RunIf, name: &str, skip: bool) {
+        let run_if_info = RunIfInfo::new(name, skip);
+        let counters = run_if_info.counters.clone();
+        let condition = run_if.system_fn;
+
+        let run_if: Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync + 'static> =
+            Box::new(move |world| {
+                let result = (condition)(world)?;
+                counters.count(result);
+
+                Ok(result != skip)
+            });
+
+        self.run_if = if let Some(prev_run_if) = self.run_if.take() {
+            Some(Box::new(move |world| {
+                Ok((prev_run_if)(world)? && (run_if)(world)?)
+            }))
+        } else {
+            Some(run_if)
+        };
+
+        self.run_if_info.push(run_if_info);
+    }
+}
+
+/// Checks that a system's borrows can be taken at the same time.
+fn check_borrows(borrows: &[TypeInfo]) -> Result<(), error::InvalidSystem> {
+    if borrows.len() > 1
+        && borrows.iter().any(|type_info| {
+            type_info.storage_id == StorageId::of::<AllStorages>()
+                && type_info.mutability == Mutability::Exclusive
+        })
+    {
+        return Err(error::InvalidSystem::AllStorages);
+    }
+
+    for (i, a_type_info) in borrows.iter().enumerate() {
+        for b_type_info in &borrows[i + 1..] {
+            if a_type_info.storage_id == b_type_info.storage_id {
+                match (a_type_info.mutability, b_type_info.mutability) {
+                    (Mutability::Exclusive, Mutability::Exclusive) => {
+                        return Err(error::InvalidSystem::MultipleViewsMut)
+                    }
+                    (Mutability::Exclusive, Mutability::Shared)
+                    | (Mutability::Shared, Mutability::Exclusive) => {
+                        return Err(error::InvalidSystem::MultipleViews)
+                    }
+                    (Mutability::Shared, Mutability::Shared) => {}
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 impl Extend<WorkloadSystem> for Workload {
     fn extend<T: IntoIterator<Item = WorkloadSystem>>(&mut self, iter: T) {
         self.systems.extend(iter);
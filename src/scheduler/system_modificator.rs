@@ -1,11 +1,19 @@
 use crate::borrow::{BorrowInfo, WorldBorrow};
 use crate::scheduler::into_workload_run_if::IntoRunIf;
+use crate::scheduler::label::ProducedUniqueLabel;
+use crate::scheduler::prefetch::PrefetchStorages;
+use crate::scheduler::reactive::ReactiveStorages;
+use crate::scheduler::system::RunIf;
 use crate::scheduler::{IntoWorkloadSystem, WorkloadSystem};
 use crate::storage::StorageId;
-use crate::{error, AllStoragesViewMut, AsLabel, Unique, UniqueStorage};
-use crate::{Component, SparseSet};
+use crate::tracking::{InsertionTracking, ModificationTracking, TrackingTimestamp};
+use crate::{error, AllStoragesViewMut, AsLabel, Unique, UniqueStorage, World};
+use crate::{Component, IntoIter, SparseSet, View};
 use alloc::boxed::Box;
-use core::ops::Not;
+use alloc::format;
+use alloc::sync::Arc;
+use core::any::type_name;
+use core::sync::atomic::{AtomicU32, Ordering};
 
 /// Modifies a system.
 pub trait SystemModificator<B, R> {
@@ -54,6 +62,7 @@ pub trait SystemModificator<B, R> {
     fn skip_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem;
     /// Do not run the system if the `T` storage is empty.
     ///
+    /// The check happens before any of the system's views are borrowed.\
     /// If the storage is not present it is considered empty.
     /// If the storage is already borrowed, assume it's not empty.
     fn skip_if_storage_empty<T: Component>(self) -> WorkloadSystem
@@ -91,6 +100,201 @@ pub trait SystemModificator<B, R> {
 
         self.skip_if(should_skip)
     }
+    /// Do not run the system if no `T` component was inserted since the last time this check ran.
+    ///
+    /// The check happens before any of the system's views are borrowed.
+    fn skip_if_no_inserted<T: Component + Send + Sync>(self) -> WorkloadSystem
+    where
+        Self: Sized,
+        T::Tracking: InsertionTracking,
+    {
+        let should_skip = |view: View<'_, T>| view.inserted().iter().next().is_none();
+
+        self.skip_if(should_skip)
+    }
+    /// Do not run the system if no `T` component was modified since the last time this check ran.
+    ///
+    /// The check happens before any of the system's views are borrowed.
+    fn skip_if_no_modified<T: Component + Send + Sync>(self) -> WorkloadSystem
+    where
+        Self: Sized,
+        T::Tracking: ModificationTracking,
+    {
+        let should_skip = |view: View<'_, T>| view.modified().iter().next().is_none();
+
+        self.skip_if(should_skip)
+    }
+    /// Only run the system if one of the `T` storages changed since the last time the system ran.
+    ///
+    /// `T` is a tuple of components, the system doesn't have to borrow them.\
+    /// A storage changes when a component is added, deleted or removed, or when it is borrowed exclusively, with [`ViewMut`](crate::ViewMut) for example.\
+    /// It doesn't require any tracking, each storage keeps the time of its last change.\
+    /// Changes made by the system itself do not make it run again.\
+    /// The check happens before any of the system's views are borrowed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, SystemModificator, View, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// fn update_bounds(positions: View<Position>) {
+    ///     for _ in positions.iter() {}
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// Workload::new("Bounds")
+    ///     .with_system(update_bounds.reactive_to::<(Position, Velocity)>())
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// // No change, the system is skipped
+    /// world.run_default_workload().unwrap();
+    ///
+    /// world.add_entity(Position(0.0));
+    ///
+    /// // A Position was inserted, the system runs
+    /// world.run_default_workload().unwrap();
+    /// ```
+    #[track_caller]
+    fn reactive_to<T: ReactiveStorages>(self) -> WorkloadSystem
+    where
+        Self: Sized,
+    {
+        let last_run = Arc::new(AtomicU32::new(0));
+
+        let check_last_run = last_run.clone();
+        let run_if = RunIf {
+            system_fn: Box::new(move |world: &World| {
+                let current = world.get_current();
+                let last_run = TrackingTimestamp::new(check_last_run.load(Ordering::Acquire));
+                let all_storages = world
+                    .all_storages
+                    .borrow()
+                    .map_err(error::GetStorage::AllStoragesBorrow)?;
+
+                Ok(T::changed_within(&all_storages, last_run, current))
+            }),
+        };
+
+        let mut system = self.run_if(run_if);
+        // `run_if` only sees the condition as a `RunIf`
+        if let Some(run_if_info) = system.run_if_info.last_mut() {
+            run_if_info.name = format!("reactive_to::<{}>", type_name::<T>());
+        }
+
+        let system_fn = system.system_fn;
+        system.system_fn = Box::new(move |world: &World| {
+            (system_fn)(world)?;
+
+            last_run.store(world.get_current().get(), Ordering::Release);
+
+            Ok(())
+        });
+
+        system
+    }
+    /// Declares that this system creates the `T` unique.
+    ///
+    /// When building a workload, the systems consuming `T` are placed after all systems producing it.\
+    /// Systems borrowing `T`, like with [`UniqueView`](crate::UniqueView), consume it implicitly.\
+    /// Producers depending on each other in a loop make the workload fail to build.
+    ///
+    /// This lets a world's initialization be split into small systems, run once in a setup workload.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AllStoragesViewMut, SystemModificator, Unique, UniqueView, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Device(u32);
+    ///
+    /// #[derive(Unique)]
+    /// struct Pipeline(u32);
+    ///
+    /// fn create_pipeline(all_storages: AllStoragesViewMut) {
+    ///     let device = all_storages.borrow::<UniqueView<Device>>().unwrap().0;
+    ///
+    ///     all_storages.add_unique(Pipeline(device + 1));
+    /// }
+    ///
+    /// fn create_device(all_storages: AllStoragesViewMut) {
+    ///     all_storages.add_unique(Device(1));
+    /// }
+    ///
+    /// fn check(pipeline: UniqueView<Pipeline>) {
+    ///     assert_eq!(pipeline.0, 2);
+    /// }
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Setup")
+    ///     .with_system(check)
+    ///     .with_system(
+    ///         create_pipeline
+    ///             .produces::<Pipeline>()
+    ///             .consumes::<Device>(),
+    ///     )
+    ///     .with_system(create_device.produces::<Device>())
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Setup").unwrap();
+    /// ```
+    fn produces<T: Unique>(self) -> WorkloadSystem
+    where
+        Self: Sized,
+    {
+        self.tag(ProducedUniqueLabel::of::<T>())
+    }
+    /// Declares that this system needs the `T` unique, it will be placed after all systems producing it.\
+    /// Only needed when the system doesn't borrow `T`, see [`produces`](SystemModificator::produces).
+    fn consumes<T: Unique>(self) -> WorkloadSystem
+    where
+        Self: Sized,
+    {
+        self.after_all(ProducedUniqueLabel::of::<T>())
+    }
+    /// Reads the components of the `T` storages right before this system runs, to bring them in cache.
+    ///
+    /// The storages are read on the thread that will run the system, just before it borrows its views.\
+    /// `T` is a tuple of components, usually the ones the system iterates.\
+    /// This is only a hint, missing storages or storages already borrowed exclusively are skipped.
+    /// It has no effect on the system's result.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, SystemModificator, View, ViewMut, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// fn movement(mut positions: ViewMut<Position>, velocities: View<Velocity>) {
+    ///     for (mut position, velocity) in (&mut positions, &velocities).iter() {
+    ///         position.0 += velocity.0;
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Position(0.0), Velocity(1.0)));
+    ///
+    /// Workload::new("Movement")
+    ///     .with_system(movement.prefetch::<(Position, Velocity)>())
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    /// ```
+    fn prefetch<T: PrefetchStorages>(self) -> WorkloadSystem;
     /// When building a workload, this system will be placed before all invocation of the other system or workload.
     fn before_all<T>(self, other: impl AsLabel<T>) -> WorkloadSystem;
     /// When building a workload, this system will be placed after all invocation of the other system or workload.
@@ -123,19 +327,30 @@ where
     #[track_caller]
     fn run_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem {
         let mut system = self.into_workload_system().unwrap();
+        let name = run_if.run_if_name();
         let run_if = run_if.into_workload_run_if().unwrap();
 
-        system.run_if = Some(run_if.system_fn);
+        system.add_run_if(run_if, &name, false);
 
         system
     }
     #[track_caller]
     fn skip_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem {
-        let mut run_if = run_if.into_workload_run_if().unwrap();
+        let mut system = self.into_workload_system().unwrap();
+        let name = run_if.run_if_name();
+        let run_if = run_if.into_workload_run_if().unwrap();
 
-        run_if.system_fn = Box::new(move |world| (run_if.system_fn)(world).map(Not::not));
+        system.add_run_if(run_if, &name, true);
 
-        self.run_if(run_if)
+        system
+    }
+    #[track_caller]
+    fn prefetch<T: PrefetchStorages>(self) -> WorkloadSystem {
+        let mut system = self.into_workload_system().unwrap();
+
+        T::prefetch(&mut system.prefetch);
+
+        system
     }
     #[track_caller]
     fn before_all<T>(self, other: impl AsLabel<T>) -> WorkloadSystem {
@@ -198,25 +413,26 @@ where
 impl SystemModificator<WorkloadSystem, ()> for WorkloadSystem {
     #[track_caller]
     fn run_if<RunB, Run: IntoRunIf<RunB>>(mut self, run_if: Run) -> WorkloadSystem {
+        let name = run_if.run_if_name();
         let run_if = run_if.into_workload_run_if().unwrap();
 
-        self.run_if = if let Some(prev_run_if) = self.run_if {
-            Some(Box::new(move |world| {
-                Ok((prev_run_if)(world)? && (run_if.system_fn)(world)?)
-            }))
-        } else {
-            Some(run_if.system_fn)
-        };
+        self.add_run_if(run_if, &name, false);
 
         self
     }
     #[track_caller]
-    fn skip_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem {
-        let mut run_if = run_if.into_workload_run_if().unwrap();
+    fn skip_if<RunB, Run: IntoRunIf<RunB>>(mut self, run_if: Run) -> WorkloadSystem {
+        let name = run_if.run_if_name();
+        let run_if = run_if.into_workload_run_if().unwrap();
 
-        run_if.system_fn = Box::new(move |world| (run_if.system_fn)(world).map(Not::not));
+        self.add_run_if(run_if, &name, true);
 
-        self.run_if(run_if)
+        self
+    }
+    fn prefetch<T: PrefetchStorages>(mut self) -> WorkloadSystem {
+        T::prefetch(&mut self.prefetch);
+
+        self
     }
     fn before_all<T>(mut self, other: impl AsLabel<T>) -> WorkloadSystem {
         self.before_all.add(other);
@@ -273,19 +489,30 @@ macro_rules! impl_into_workload_system {
             #[track_caller]
             fn run_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem {
                 let mut system = IntoWorkloadSystem::<($($type,)+), R>::into_workload_system(self).unwrap();
+                let name = run_if.run_if_name();
                 let run_if = run_if.into_workload_run_if().unwrap();
 
-                system.run_if = Some(run_if.system_fn);
+                system.add_run_if(run_if, &name, false);
 
                 system
             }
             #[track_caller]
             fn skip_if<RunB, Run: IntoRunIf<RunB>>(self, run_if: Run) -> WorkloadSystem {
-                let mut run_if = run_if.into_workload_run_if().unwrap();
+                let mut system = IntoWorkloadSystem::<($($type,)+), R>::into_workload_system(self).unwrap();
+                let name = run_if.run_if_name();
+                let run_if = run_if.into_workload_run_if().unwrap();
 
-                run_if.system_fn = Box::new(move |world| (run_if.system_fn)(world).map(Not::not));
+                system.add_run_if(run_if, &name, true);
 
-                SystemModificator::<($($type,)+), R>::run_if(self, run_if)
+                system
+            }
+            #[track_caller]
+            fn prefetch<T: PrefetchStorages>(self) -> WorkloadSystem {
+                let mut system = IntoWorkloadSystem::<($($type,)+), R>::into_workload_system(self).unwrap();
+
+                T::prefetch(&mut system.prefetch);
+
+                system
             }
             #[track_caller]
             fn before_all<T>(self, other: impl AsLabel<T>) -> WorkloadSystem {
@@ -1,16 +1,19 @@
 use crate::all_storages::AllStorages;
-use crate::borrow::Mutability;
 use crate::component::{Component, Unique};
 use crate::scheduler::info::{
-    BatchInfo, Conflict, DedupedLabels, SystemId, SystemInfo, TypeInfo, WorkloadInfo,
+    BatchInfo, Conflict, DedupedLabels, RunIfInfo, SystemId, SystemInfo, TypeInfo, WorkloadInfo,
 };
-use crate::scheduler::label::{SystemLabel, WorkloadLabel};
+use crate::scheduler::into_workload_run_if::IntoRunIf;
+use crate::scheduler::label::{ProducedUniqueLabel, SystemLabel, WorkloadLabel};
 use crate::scheduler::system::{ExtractWorkloadRunIf, WorkloadRunIfFn};
-use crate::scheduler::{AsLabel, Batches, IntoWorkloadTrySystem, Label, Scheduler, WorkloadSystem};
+use crate::scheduler::{
+    AsLabel, Batches, ClearTrackingStorages, Condition, ErrorHandler, ErrorPolicy, IntoCondition,
+    IntoWorkloadOptionSystem, IntoWorkloadTrySystem, Label, RngStreams, Scheduler, WorkloadSystem,
+};
 use crate::storage::StorageId;
 use crate::type_id::TypeId;
 use crate::unique::UniqueStorage;
-use crate::world::World;
+use crate::world::{Deadline, World};
 use crate::{error, IntoWorkload, IntoWorkloadSystem, ShipHashMap};
 use alloc::boxed::Box;
 use alloc::format;
@@ -21,6 +24,8 @@ use core::any::type_name;
 #[cfg(not(feature = "std"))]
 use core::any::Any;
 use core::hash::BuildHasherDefault;
+use core::num::NonZeroUsize;
+use core::sync::atomic::Ordering;
 #[cfg(feature = "std")]
 use std::error::Error;
 
@@ -36,7 +41,6 @@ pub struct ScheduledWorkload {
     #[allow(clippy::type_complexity)]
     systems: Vec<Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>>,
     system_names: Vec<Box<dyn Label>>,
-    #[allow(unused)]
     system_generators: Vec<Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>>,
     // system's `TypeId` to an index into both systems and system_names
     #[allow(unused)]
@@ -61,8 +65,10 @@ impl ScheduledWorkload {
         world.run_batches(
             &self.systems,
             &self.system_names,
+            &self.system_generators,
             &self.workloads[&self.name],
             &self.name,
+            Deadline::none(),
         )
     }
 
@@ -128,12 +134,18 @@ pub struct Workload {
     pub(super) tags: Vec<Box<dyn Label>>,
     pub(super) systems: Vec<WorkloadSystem>,
     pub(super) run_if: Option<Box<dyn WorkloadRunIfFn>>,
+    pub(super) run_if_info: Vec<RunIfInfo>,
     pub(super) before_all: DedupedLabels,
     pub(super) after_all: DedupedLabels,
     pub(super) overwritten_name: bool,
     pub(super) require_before: DedupedLabels,
     pub(super) require_after: DedupedLabels,
     pub(super) barriers: Vec<usize>,
+    pub(super) on_error: Option<Box<ErrorHandler>>,
+    pub(super) max_threads: Option<NonZeroUsize>,
+    pub(super) clear_tracking: Vec<WorkloadSystem>,
+    /// Conditions added with `run_if_tagged`/`skip_if_tagged`, applied when the workload is built or merged
+    pub(super) tagged_run_if: Vec<(Box<dyn Label>, Condition, bool)>,
 }
 
 impl Workload {
@@ -185,6 +197,7 @@ impl Workload {
             systems: Vec::new(),
             name: label.clone(),
             run_if: None,
+            run_if_info: Vec::new(),
             tags: vec![label],
             before_all: DedupedLabels::new(),
             after_all: DedupedLabels::new(),
@@ -192,12 +205,18 @@ impl Workload {
             require_before: DedupedLabels::new(),
             require_after: DedupedLabels::new(),
             barriers: Vec::new(),
+            on_error: None,
+            max_threads: None,
+            clear_tracking: Vec::new(),
+            tagged_run_if: Vec::new(),
         }
     }
     /// Moves all systems of `other` into `Self`, leaving `other` empty.  
     /// This allows us to collect systems in different builders before joining them together.
     pub fn append(mut self, other: &mut Self) -> Self {
         self.systems.append(&mut other.systems);
+        self.clear_tracking.append(&mut other.clear_tracking);
+        self.tagged_run_if.append(&mut other.tagged_run_if);
 
         self
     }
@@ -215,12 +234,45 @@ impl Workload {
                 .map(|barrier| barrier + systems_len),
         );
 
+        if self.on_error.is_none() {
+            self.on_error = other.on_error.take();
+        }
+
+        if self.max_threads.is_none() {
+            self.max_threads = other.max_threads.take();
+        }
+
         self.append(&mut other)
     }
+    /// Adds a condition evaluated after the ones already present.\
+    /// With `skip` the workload runs when `run_if` evaluates to `false`.
+    pub(super) fn add_run_if(&mut self, run_if: Box<dyn WorkloadRunIfFn>, name: &str, skip: bool) {
+        let run_if_info = RunIfInfo::new(name, skip);
+        let counters = run_if_info.counters.clone();
+
+        let run_if: Box<dyn WorkloadRunIfFn> = Box::new(move |world: &World| {
+            let result = run_if.run(world)?;
+            counters.count(result);
+
+            Ok(result != skip)
+        });
+
+        self.run_if = if let Some(prev_run_if) = self.run_if.take() {
+            Some(Box::new(move |world: &World| {
+                Ok(prev_run_if.run(world)? && run_if.run(world)?)
+            }))
+        } else {
+            Some(run_if)
+        };
+
+        self.run_if_info.push(run_if_info);
+    }
     /// Propagates all information into the systems.  
     /// This includes `run_if`/`skip_if`, `tags`, `before`/`after` requirements.
     fn propagate(&mut self) {
-        for system in &mut self.systems {
+        self.apply_run_if_tagged();
+
+        for system in self.systems.iter_mut().chain(&mut self.clear_tracking) {
             system.run_if = match (system.run_if.take(), self.run_if.clone()) {
                 (None, None) => None,
                 (None, Some(run_if)) => Some(run_if.to_non_clone()),
@@ -230,6 +282,11 @@ impl Workload {
                 })),
             };
 
+            // the workload's conditions are evaluated first
+            system
+                .run_if_info
+                .splice(0..0, self.run_if_info.iter().cloned());
+
             system.tags.extend(self.tags.iter().cloned());
 
             system.before_all.extend(self.before_all.iter().cloned());
@@ -243,6 +300,7 @@ impl Workload {
         }
 
         self.run_if = None;
+        self.run_if_info.clear();
         self.tags.clear();
         self.before_all.clear();
         self.after_all.clear();
@@ -254,6 +312,33 @@ impl Workload {
     pub fn with_workload(self, other: Workload) -> Workload {
         self.merge(other)
     }
+    /// Calls `f` with the workload if `condition` is `true`.\
+    /// Lets variants of a workload be built from the same chain of calls.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn physics() {}
+    /// fn render() {}
+    ///
+    /// let is_server = true;
+    ///
+    /// let (_, info) = Workload::new("Frame")
+    ///     .with_system(physics)
+    ///     .when(!is_server, |workload| workload.with_system(render))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(info.batch_info.len(), 1);
+    /// ```
+    pub fn when<F: FnOnce(Workload) -> Workload>(self, condition: bool, f: F) -> Workload {
+        if condition {
+            f(self)
+        } else {
+            self
+        }
+    }
     /// Adds a system to the workload being created.
     ///
     /// ### Example:
@@ -299,6 +384,225 @@ impl Workload {
 
         self
     }
+    /// Inserts a system at position `index` in the workload being created, shifting all systems after it.\
+    /// Inserting at the workload's length is equivalent to [`with_system`](Workload::with_system).
+    ///
+    /// ### Panics
+    ///
+    /// - `index` is greater than the number of systems.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Unique, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Log(Vec<&'static str>);
+    ///
+    /// fn input(mut log: UniqueViewMut<Log>) {
+    ///     log.0.push("input");
+    /// }
+    ///
+    /// fn physics(mut log: UniqueViewMut<Log>) {
+    ///     log.0.push("physics");
+    /// }
+    ///
+    /// fn render(mut log: UniqueViewMut<Log>) {
+    ///     log.0.push("render");
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Log(Vec::new()));
+    ///
+    /// Workload::new("Frame")
+    ///     .with_system(input)
+    ///     .with_system(render)
+    ///     .insert_system_at(1, physics)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    ///
+    /// assert_eq!(
+    ///     world.get_unique::<&Log>().unwrap().0,
+    ///     vec!["input", "physics", "render"]
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn insert_system_at<B, R, S: IntoWorkloadSystem<B, R>>(
+        mut self,
+        index: usize,
+        system: S,
+    ) -> Workload {
+        assert!(
+            index <= self.systems.len(),
+            "Insertion index ({}) should be <= the number of systems ({}).",
+            index,
+            self.systems.len()
+        );
+
+        self.systems
+            .insert(index, system.into_workload_system().unwrap());
+
+        for barrier in &mut self.barriers {
+            if *barrier > index {
+                *barrier += 1;
+            }
+        }
+
+        self
+    }
+    /// Removes all systems matching `label` from the workload being created.\
+    /// A system matches if `label` is its function or one of the tags added with [`tag`](crate::SystemModificator::tag).
+    ///
+    /// Workloads merged into this one have already propagated their tags to their systems, so their name can be used to remove all their systems.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Unique, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Log(Vec<&'static str>);
+    ///
+    /// fn input(mut log: UniqueViewMut<Log>) {
+    ///     log.0.push("input");
+    /// }
+    ///
+    /// fn debug_overlay(mut log: UniqueViewMut<Log>) {
+    ///     log.0.push("debug_overlay");
+    /// }
+    ///
+    /// fn render(mut log: UniqueViewMut<Log>) {
+    ///     log.0.push("render");
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Log(Vec::new()));
+    ///
+    /// Workload::new("Frame")
+    ///     .with_system(input)
+    ///     .with_system(debug_overlay)
+    ///     .with_system(render)
+    ///     .remove_system(debug_overlay)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    ///
+    /// assert_eq!(world.get_unique::<&Log>().unwrap().0, vec!["input", "render"]);
+    /// ```
+    pub fn remove_system<T>(mut self, label: impl AsLabel<T>) -> Workload {
+        let label = label.as_label();
+
+        // number of systems removed before each index, used to move barriers
+        let mut removed_before = Vec::with_capacity(self.systems.len() + 1);
+        let mut removed = 0;
+        self.systems.retain(|system| {
+            removed_before.push(removed);
+
+            if system.tags.iter().any(|tag| **tag == *label) {
+                removed += 1;
+
+                false
+            } else {
+                true
+            }
+        });
+        removed_before.push(removed);
+
+        for barrier in &mut self.barriers {
+            *barrier -= removed_before[*barrier];
+        }
+
+        self
+    }
+    /// Only run the systems matching `tag` if the condition evaluates to `true`.\
+    /// A system matches if `tag` is its function or one of its tags, workloads merged into this one can be targeted by name.
+    ///
+    /// The condition is applied when the workload is built or merged into another one, systems added after this call are affected.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Unique, UniqueView, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Paused(bool);
+    ///
+    /// #[derive(Unique)]
+    /// struct Log(Vec<&'static str>);
+    ///
+    /// fn is_paused(paused: UniqueView<Paused>) -> bool {
+    ///     paused.0
+    /// }
+    ///
+    /// fn physics(mut log: UniqueViewMut<Log>) {
+    ///     log.0.push("physics");
+    /// }
+    ///
+    /// fn render(mut log: UniqueViewMut<Log>) {
+    ///     log.0.push("render");
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Paused(true));
+    /// world.add_unique(Log(Vec::new()));
+    ///
+    /// Workload::new("Frame")
+    ///     .with_workload(Workload::new("Simulation").with_system(physics))
+    ///     .with_system(render)
+    ///     .skip_if_tagged("Simulation", is_paused)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    ///
+    /// assert_eq!(world.get_unique::<&Log>().unwrap().0, vec!["render"]);
+    /// ```
+    #[track_caller]
+    pub fn run_if_tagged<T, RunB, Run: IntoCondition<RunB>>(
+        mut self,
+        tag: impl AsLabel<T>,
+        run_if: Run,
+    ) -> Workload {
+        self.add_run_if_tagged(tag.as_label(), run_if.into_condition(), false);
+
+        self
+    }
+    /// Do not run the systems matching `tag` if the condition evaluates to `true`.\
+    /// A system matches if `tag` is its function or one of its tags, workloads merged into this one can be targeted by name.
+    ///
+    /// The condition is applied when the workload is built or merged into another one, systems added after this call are affected.
+    #[track_caller]
+    pub fn skip_if_tagged<T, RunB, Run: IntoCondition<RunB>>(
+        mut self,
+        tag: impl AsLabel<T>,
+        should_skip: Run,
+    ) -> Workload {
+        self.add_run_if_tagged(tag.as_label(), should_skip.into_condition(), true);
+
+        self
+    }
+    #[track_caller]
+    fn add_run_if_tagged(&mut self, tag: Box<dyn Label>, condition: Condition, skip: bool) {
+        if let Some(err) = &condition.invalid {
+            panic!("{:?}", err);
+        }
+
+        self.tagged_run_if.push((tag, condition, skip));
+    }
+    /// Adds the `run_if_tagged`/`skip_if_tagged` conditions to the systems matching their tag.
+    fn apply_run_if_tagged(&mut self) {
+        for (tag, condition, skip) in self.tagged_run_if.drain(..) {
+            for system in &mut self.systems {
+                if system.tags.iter().any(|system_tag| **system_tag == *tag) {
+                    let name = IntoRunIf::run_if_name(&condition);
+                    // invalid conditions are rejected by `add_run_if_tagged`
+                    let run_if = IntoRunIf::into_workload_run_if(condition.clone()).unwrap();
+
+                    system.add_run_if(run_if, &name, skip);
+                }
+            }
+        }
+    }
     /// Adds a fallible system to the workload being created.  
     /// The workload's execution will stop if any error is encountered.
     ///
@@ -411,6 +715,57 @@ impl Workload {
 
         self
     }
+    /// Adds a system returning an `Option` to the workload being created.\
+    /// `None` is handled like an error: the workload stops unless its [`on_error`](Workload::on_error) handler decides otherwise.
+    /// The error is a [`NoneReturned`](error::NoneReturned).
+    ///
+    /// To ignore `None` instead, add the system with [`with_system`](Workload::with_system).
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{ErrorPolicy, Unique, UniqueView, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Target(Option<u32>);
+    ///
+    /// #[derive(Unique)]
+    /// struct Aimed(u32);
+    ///
+    /// fn aim(target: UniqueView<Target>, mut aimed: UniqueViewMut<Aimed>) -> Option<()> {
+    ///     aimed.0 = target.0?;
+    ///
+    ///     Some(())
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Target(None));
+    /// world.add_unique(Aimed(0));
+    ///
+    /// Workload::new("Stop")
+    ///     .with_option_system(aim)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// assert!(world.run_workload("Stop").is_err());
+    ///
+    /// Workload::new("Skip")
+    ///     .with_option_system(aim)
+    ///     .on_error(|_system, _error, _retries| ErrorPolicy::Skip)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// assert!(world.run_workload("Skip").is_ok());
+    /// ```
+    #[track_caller]
+    pub fn with_option_system<B, T, S: IntoWorkloadOptionSystem<B, T>>(
+        mut self,
+        system: S,
+    ) -> Self {
+        self.systems
+            .push(system.into_workload_option_system().unwrap());
+
+        self
+    }
     /// Finishes the workload creation and stores it in the [`World`].  
     /// Returns a struct with describing how the workload has been split in batches.
     ///
@@ -429,6 +784,32 @@ impl Workload {
     /// - Storage borrow failed.
     #[allow(clippy::blocks_in_conditions)]
     pub fn add_to_world(self, world: &World) -> Result<(), error::AddWorkload> {
+        self.insert_in_world(world, false)
+    }
+    /// Replaces the systems of the workload with the same name in `world`.
+    ///
+    /// Unlike removing and adding the workload again, there is no point in time where it is missing from the `World`.\
+    /// The workload stays the default one if it was.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    /// - [`AllStorages`] (shared)
+    /// - Systems' storage (exclusive) to enable tracking
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed, this happens when a workload is running.
+    /// - No workload with this name is present.
+    /// - Same errors as [`Workload::add_to_world`], the previous workload is kept if the new one can't be built.
+    pub(crate) fn replace_in_world(self, world: &World) -> Result<(), error::AddWorkload> {
+        self.insert_in_world(world, true)
+    }
+    fn insert_in_world(self, world: &World, replace: bool) -> Result<(), error::AddWorkload> {
+        let mut scheduler = world
+            .scheduler
+            .borrow_mut()
+            .map_err(|_| error::AddWorkload::Borrow)?;
         let Scheduler {
             systems,
             system_names,
@@ -437,16 +818,23 @@ impl Workload {
             workloads,
             workloads_info,
             default,
-        } = &mut *world
-            .scheduler
-            .borrow_mut()
-            .map_err(|_| error::AddWorkload::Borrow)?;
+        } = &mut *scheduler;
 
         let mut tracking_to_enable = Vec::new();
 
         let name = self.name.dyn_clone();
 
-        let workload_info = create_workload(
+        let previous = if replace {
+            Some(
+                workloads
+                    .remove(&name)
+                    .ok_or(error::AddWorkload::MissingWorkload)?,
+            )
+        } else {
+            None
+        };
+
+        let workload_info = match create_workload(
             self,
             systems,
             system_names,
@@ -455,7 +843,35 @@ impl Workload {
             &mut tracking_to_enable,
             workloads,
             default,
-        )?;
+        ) {
+            Ok(workload_info) => workload_info,
+            Err(err) => {
+                if let Some(previous) = previous {
+                    workloads.insert(name, previous);
+                }
+
+                return Err(err);
+            }
+        };
+
+        if let Some(previous) = previous {
+            workloads[&name].rng_streams.runs.store(
+                previous.rng_streams.runs.load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+
+            if let Some(previous_info) = workloads_info.get(&name) {
+                carry_over_run_if_counters(previous_info, &workload_info);
+            }
+        }
+
+        workloads_info.insert(name, workload_info);
+
+        if replace {
+            scheduler.remove_unused_systems();
+        }
+
+        drop(scheduler);
 
         let all_storages = world
             .all_storages()
@@ -470,8 +886,6 @@ impl Workload {
             })?;
         }
 
-        workloads_info.insert(name, workload_info);
-
         Ok(())
     }
     /// Returns the first [`Unique`] storage borrowed by this workload that is not present in `world`.\
@@ -510,6 +924,74 @@ impl Workload {
 
         Ok(())
     }
+    /// Checks every system of this workload against `world` without running them or their `run_if`s.\
+    /// Returns all the problems found:
+    /// - a system borrows a [`Unique`] storage that is not present in `world`, optional borrows like `Option<UniqueView<T>>` are skipped
+    ///
+    /// `NonSend`, `NonSync` and `NonSendSync` views are checked like the others.\
+    /// Conflicting borrows within a single system are already rejected when the system is added to the workload.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, Unique, UniqueView, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Time(f32);
+    ///
+    /// #[derive(Unique)]
+    /// struct Paused(bool);
+    ///
+    /// let world = World::new();
+    ///
+    /// let errors = Workload::new("Physics")
+    ///     .with_system(|_: UniqueView<Time>| {})
+    ///     .with_system(|_: UniqueViewMut<Paused>| {})
+    ///     .validate(&world)
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(errors.len(), 2);
+    /// assert!(matches!(errors[0], error::Validation::MissingUnique { .. }));
+    /// ```
+    pub fn validate(&self, world: &World) -> Result<(), Vec<error::Validation>> {
+        let all_storages = world
+            .all_storages
+            .borrow()
+            .map_err(|_| vec![error::Validation::AllStorages])?;
+        let storages = all_storages.storages.read();
+
+        let mut errors = Vec::new();
+
+        for system in &self.systems {
+            for storage_id in &system.required_storages {
+                if storages.contains_key(storage_id) {
+                    continue;
+                }
+
+                // BorrowInfo has to list every storage it borrows, required ones included
+                if let Some(type_info) = system
+                    .borrow_constraints
+                    .iter()
+                    .find(|type_info| type_info.storage_id == *storage_id)
+                {
+                    errors.push(error::Validation::MissingUnique {
+                        system: system.display_name.clone(),
+                        type_info: type_info.clone(),
+                    });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
     /// Build the [`Workload`](super::Workload) from the [`Workload`].
     pub fn build(self) -> Result<(ScheduledWorkload, WorkloadInfo), error::AddWorkload> {
         let mut workload = ScheduledWorkload {
@@ -543,6 +1025,172 @@ impl Workload {
 
         self
     }
+    /// Sets the function called when a system of this workload returns an error.\
+    /// The handler receives the system's name, the error and how many times the system was already retried.
+    /// It then decides if the workload should skip the system, run it again or abort.
+    ///
+    /// Without handler the workload aborts on the first error.\
+    /// A workload only has a single handler, when merging workloads the first one set is kept.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{ErrorPolicy, Unique, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Attempts(u32);
+    ///
+    /// #[derive(Debug)]
+    /// struct Transient;
+    ///
+    /// impl std::fmt::Display for Transient {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         f.write_str("transient failure")
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for Transient {}
+    ///
+    /// fn stream_assets(mut attempts: UniqueViewMut<Attempts>) -> Result<(), Transient> {
+    ///     attempts.0 += 1;
+    ///
+    ///     if attempts.0 < 3 {
+    ///         Err(Transient)
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Attempts(0));
+    ///
+    /// Workload::new("Frame")
+    ///     .with_try_system(stream_assets)
+    ///     .on_error(|_system, _error, retries| {
+    ///         if retries < 5 {
+    ///             ErrorPolicy::Retry
+    ///         } else {
+    ///             ErrorPolicy::Skip
+    ///         }
+    ///     })
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    ///
+    /// assert_eq!(world.get_unique::<&Attempts>().unwrap().0, 3);
+    /// ```
+    pub fn on_error<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&dyn Label, &error::Run, u32) -> ErrorPolicy + Send + Sync + 'static,
+    {
+        self.on_error = Some(Box::new(handler));
+
+        self
+    }
+    /// Limits the number of threads the systems of a batch can run on.\
+    /// Systems are still scheduled in the same batches, they're split between at most `max_threads` threads.
+    ///
+    /// This doesn't limit the threads used by parallel iterators inside the systems.\
+    /// Without the `parallel` feature, workloads always run on a single thread.
+    ///
+    /// ### Panics
+    ///
+    /// - `max_threads` is 0.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Workload, World};
+    ///
+    /// fn sys1() {}
+    /// fn sys2() {}
+    /// fn sys3() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Background")
+    ///     .with_system(sys1)
+    ///     .with_system(sys2)
+    ///     .with_system(sys3)
+    ///     .with_max_threads(2)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workload("Background").unwrap();
+    /// ```
+    #[track_caller]
+    pub fn with_max_threads(mut self, max_threads: usize) -> Self {
+        self.max_threads = Some(NonZeroUsize::new(max_threads).expect("max_threads can't be 0."));
+
+        self
+    }
+    /// Clears the *inserted* and *modified* flags of `T`'s storages once all other systems of the workload ran.\
+    /// `T` can be a single component or a tuple of components.
+    ///
+    /// This is the same as calling [`ViewMut::clear_all_inserted_and_modified`](crate::ViewMut::clear_all_inserted_and_modified) in a system added last, after a barrier.\
+    /// Each storage is cleared by its own system, they only borrow the storage they clear.\
+    /// When merging workloads, the clearing systems of both are kept and still run last, under the `run_if` of the workload they come from.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{track, Component, IntoIter, View, Workload, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Insertion, Modification)]
+    /// struct Health(u32);
+    ///
+    /// fn update_health_bars(healths: View<Health>) {
+    ///     for _health in healths.inserted_or_modified().iter() {
+    ///         // update UI
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((Health(10),));
+    ///
+    /// Workload::new("Frame")
+    ///     .with_system(update_health_bars)
+    ///     .clear_tracking_after::<Health>()
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    ///
+    /// assert!(!world.borrow::<View<Health>>().unwrap().is_inserted(entity));
+    /// ```
+    pub fn clear_tracking_after<T: ClearTrackingStorages>(mut self) -> Self {
+        T::clear_tracking_systems(&mut self.clear_tracking);
+
+        self
+    }
+}
+
+/// Same as [`Workload::merge`], the resulting workload keeps the name of the left one.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Workload, World};
+///
+/// fn physics() {}
+/// fn net_sync() {}
+/// fn render() {}
+///
+/// let shared = || Workload::new("Frame").with_system(physics);
+///
+/// let server = shared() + Workload::new("Server").with_system(net_sync);
+/// let client = shared() + Workload::new("Client").with_system(render);
+///
+/// let world = World::new();
+/// server.add_to_world(&world).unwrap();
+///
+/// assert!(world.contains_workload("Frame"));
+/// ```
+impl core::ops::Add for Workload {
+    type Output = Workload;
+
+    fn add(self, other: Workload) -> Workload {
+        self.merge(other)
+    }
 }
 
 fn check_uniques_in_systems(
@@ -555,7 +1203,9 @@ fn check_uniques_in_systems(
     } = system;
 
     for type_info in borrow_constraints {
-        if type_info.name.starts_with(unique_name) && !storages.contains_key(&type_info.storage_id)
+        if !type_info.optional
+            && type_info.name.starts_with(unique_name)
+            && !storages.contains_key(&type_info.storage_id)
         {
             return Some(Err(error::UniquePresence::Unique(type_info.clone())));
         }
@@ -564,6 +1214,31 @@ fn check_uniques_in_systems(
     None
 }
 
+/// Adds the evaluations of the conditions of `previous` to the matching conditions of `workload`.\
+/// Systems are matched by type and their conditions by position, name and kind.
+fn carry_over_run_if_counters(previous: &WorkloadInfo, workload: &WorkloadInfo) {
+    fn carry_over(previous: &[RunIfInfo], run_if: &[RunIfInfo]) {
+        for (previous, run_if) in previous.iter().zip(run_if) {
+            if previous == run_if {
+                run_if.counters.add(&previous.counters);
+            }
+        }
+    }
+
+    carry_over(&previous.run_if, &workload.run_if);
+
+    for system in workload.batch_info.iter().flat_map(BatchInfo::systems) {
+        if let Some(previous) = previous
+            .batch_info
+            .iter()
+            .flat_map(BatchInfo::systems)
+            .find(|previous| previous.type_id == system.type_id)
+        {
+            carry_over(&previous.run_if, &system.run_if);
+        }
+    }
+}
+
 #[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn create_workload(
     mut builder: Workload,
@@ -575,10 +1250,20 @@ fn create_workload(
     workloads: &mut ShipHashMap<Box<dyn Label>, Batches>,
     default: &mut Box<dyn Label>,
 ) -> Result<WorkloadInfo, error::AddWorkload> {
-    if workloads.contains_key(&*builder.name) {
+    if workloads
+        .keys()
+        .any(|label| label.collides_with(&*builder.name))
+    {
         return Err(error::AddWorkload::AlreadyExists);
     }
 
+    builder.apply_run_if_tagged();
+
+    if !builder.clear_tracking.is_empty() {
+        builder.barriers.push(builder.systems.len());
+        builder.systems.append(&mut builder.clear_tracking);
+    }
+
     for index in builder.barriers.drain(..) {
         let tag = format!("__barrier__{}", index);
 
@@ -609,6 +1294,29 @@ fn create_workload(
         );
     }
 
+    // Systems borrowing a unique are placed after the systems producing it
+    let produced_uniques = collected_systems
+        .iter()
+        .flat_map(|(_, system)| &system.tags)
+        .filter_map(|tag| tag.as_any().downcast_ref::<ProducedUniqueLabel>())
+        .copied()
+        .collect::<Vec<_>>();
+
+    if !produced_uniques.is_empty() {
+        for (_, system) in &mut collected_systems {
+            for type_info in &system.borrow_constraints {
+                if let Some(produced) = produced_uniques
+                    .iter()
+                    .find(|produced| produced.storage_id == type_info.storage_id)
+                {
+                    if !system.tags.iter().any(|tag| produced.dyn_eq(&**tag)) {
+                        system.after_all.add(*produced);
+                    }
+                }
+            }
+        }
+    }
+
     if workloads.is_empty() {
         *default = builder.name.clone();
     }
@@ -616,6 +1324,8 @@ fn create_workload(
     let batches = workloads.entry(builder.name.clone()).or_default();
 
     batches.run_if = builder.run_if;
+    batches.on_error = builder.on_error;
+    batches.max_threads = builder.max_threads;
 
     if collected_systems.len() == 1 {
         let (
@@ -625,6 +1335,8 @@ fn create_workload(
                 display_name,
                 borrow_constraints,
                 run_if,
+                run_if_info,
+                prefetch,
                 ..
             },
         ) = collected_systems.pop().unwrap();
@@ -652,6 +1364,11 @@ fn create_workload(
 
         batches.sequential.push(system_index);
         batches.sequential_run_if.push(run_if);
+        batches.sequential_prefetch.push(prefetch);
+        batches
+            .rng_streams
+            .keys
+            .push(RngStreams::key(&format!("{:?}", builder.name), 0));
 
         let batch_info = BatchInfo {
             systems: (
@@ -662,6 +1379,7 @@ fn create_workload(
                     conflict: None,
                     before: Vec::new(),
                     after: Vec::new(),
+                    run_if: run_if_info,
                 }),
                 Vec::new(),
             ),
@@ -670,12 +1388,14 @@ fn create_workload(
         return Ok(WorkloadInfo {
             name: format!("{:?}", builder.name),
             batch_info: vec![batch_info],
+            run_if: builder.run_if_info,
         });
     }
 
     let mut workload_info = WorkloadInfo {
         name: format!("{:?}", builder.name),
         batch_info: vec![],
+        run_if: core::mem::take(&mut builder.run_if_info),
     };
 
     // // Extract systems that have before/after requirements as they are not scheduled the same way
@@ -714,6 +1434,7 @@ fn create_workload(
     let mut collected_before = Vec::new();
     let mut collected_after = Vec::new();
     let mut collected_names = Vec::new();
+    let mut collected_prefetch = Vec::new();
 
     for (
         index,
@@ -727,6 +1448,7 @@ fn create_workload(
                 require_before,
                 require_after,
                 display_name,
+                prefetch,
                 ..
             },
         ),
@@ -739,6 +1461,7 @@ fn create_workload(
         collected_before.push(core::mem::take(require_before));
         collected_after.push(core::mem::take(require_after));
         collected_names.push(display_name.clone());
+        collected_prefetch.push(core::mem::take(prefetch));
     }
 
     // Remove before/after that are not present in the workload
@@ -863,6 +1586,7 @@ fn create_workload(
                 display_name,
                 borrow_constraints,
                 run_if,
+                run_if_info,
                 tags: _,
                 ..
             },
@@ -878,6 +1602,7 @@ fn create_workload(
             display_name,
             borrow_constraints,
             run_if,
+            run_if_info,
             &mut seq_system_index_map,
             &mut par_system_index_map,
         );
@@ -892,6 +1617,7 @@ fn create_workload(
                 display_name,
                 borrow_constraints,
                 run_if,
+                run_if_info,
                 tags: _,
                 ..
             },
@@ -908,6 +1634,7 @@ fn create_workload(
             &display_name,
             borrow_constraints,
             run_if,
+            run_if_info,
             &memoize_before,
             &memoize_after,
             &mut seq_system_index_map,
@@ -915,6 +1642,15 @@ fn create_workload(
         )?;
     }
 
+    batches.sequential_prefetch = seq_system_index_map
+        .iter()
+        .map(|&index| core::mem::take(&mut collected_prefetch[index]))
+        .collect();
+    batches.rng_streams.keys = seq_system_index_map
+        .iter()
+        .map(|&index| RngStreams::key(&workload_info.name, index))
+        .collect();
+
     for (i, &index) in seq_system_index_map.iter().enumerate() {
         let mut require_in_workload = collected_require_in_workload[index].to_vec();
         let mut require_before = collected_before[index].to_vec();
@@ -1052,6 +1788,7 @@ fn insert_system(
     display_name: Box<dyn Label>,
     borrow_constraints: Vec<TypeInfo>,
     run_if: Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>,
+    run_if_info: Vec<RunIfInfo>,
     seq_system_index_map: &mut Vec<usize>,
     par_system_index_map: &mut Vec<(Option<usize>, Vec<usize>)>,
 ) {
@@ -1103,6 +1840,7 @@ fn insert_system(
                         }),
                         before: Vec::new(),
                         after: Vec::new(),
+                        run_if: run_if_info,
                     };
 
                     if valid < batches.parallel.len() {
@@ -1134,6 +1872,7 @@ fn insert_system(
             conflict: None,
             before: Vec::new(),
             after: Vec::new(),
+            run_if: run_if_info,
         };
 
         if valid < batches.parallel.len() {
@@ -1172,6 +1911,7 @@ fn insert_system(
                     }),
                     before: Vec::new(),
                     after: Vec::new(),
+                    run_if: run_if_info,
                 };
 
                 if valid < batches.parallel.len() {
@@ -1216,6 +1956,7 @@ fn insert_system(
             conflict,
             before: Vec::new(),
             after: Vec::new(),
+            run_if: run_if_info,
         };
 
         if valid < batches.parallel.len() {
@@ -1261,67 +2002,29 @@ fn check_conflict(
 ) {
     for other_type_info in &other_system.borrow {
         for type_info in borrow_constraints {
-            match type_info.mutability {
-                Mutability::Exclusive => {
-                    if !type_info.thread_safe && !other_type_info.thread_safe {
-                        *conflict = Some(Conflict::OtherNotSendSync {
-                            system: SystemId {
-                                name: other_system.name.clone(),
-                                type_id: other_system.type_id,
-                            },
-                            type_info: other_type_info.clone(),
-                        });
-
-                        return;
-                    }
-
-                    if type_info.storage_id == other_type_info.storage_id
-                        || type_info.storage_id == TypeId::of::<AllStorages>()
-                        || other_type_info.storage_id == TypeId::of::<AllStorages>()
-                    {
-                        *conflict = Some(Conflict::Borrow {
-                            type_info: Some(type_info.clone()),
-                            other_system: SystemId {
-                                name: other_system.name.clone(),
-                                type_id: other_system.type_id,
-                            },
-                            other_type_info: other_type_info.clone(),
-                        });
-
-                        return;
-                    }
-                }
-                Mutability::Shared => {
-                    if !type_info.thread_safe && !other_type_info.thread_safe {
-                        *conflict = Some(Conflict::OtherNotSendSync {
-                            system: SystemId {
-                                name: other_system.name.clone(),
-                                type_id: other_system.type_id,
-                            },
-                            type_info: other_type_info.clone(),
-                        });
+            if !type_info.conflicts_with(other_type_info) {
+                continue;
+            }
 
-                        return;
-                    }
+            let other_system_id = SystemId {
+                name: other_system.name.clone(),
+                type_id: other_system.type_id,
+            };
 
-                    if (type_info.storage_id == other_type_info.storage_id
-                        && other_type_info.mutability == Mutability::Exclusive)
-                        || type_info.storage_id == TypeId::of::<AllStorages>()
-                        || other_type_info.storage_id == TypeId::of::<AllStorages>()
-                    {
-                        *conflict = Some(Conflict::Borrow {
-                            type_info: Some(type_info.clone()),
-                            other_system: SystemId {
-                                name: other_system.name.clone(),
-                                type_id: other_system.type_id,
-                            },
-                            other_type_info: other_type_info.clone(),
-                        });
+            *conflict = if !type_info.thread_safe && !other_type_info.thread_safe {
+                Some(Conflict::OtherNotSendSync {
+                    system: other_system_id,
+                    type_info: other_type_info.clone(),
+                })
+            } else {
+                Some(Conflict::Borrow {
+                    type_info: Some(type_info.clone()),
+                    other_system: other_system_id,
+                    other_type_info: other_type_info.clone(),
+                })
+            };
 
-                        return;
-                    }
-                }
-            }
+            return;
         }
     }
 }
@@ -1337,6 +2040,7 @@ fn insert_before_after_system(
     display_name: &dyn Label,
     borrow_constraints: Vec<TypeInfo>,
     run_if: Option<Box<dyn Fn(&World) -> Result<bool, error::Run> + Send + Sync>>,
+    run_if_info: Vec<RunIfInfo>,
     memoize_before: &ShipHashMap<usize, DedupedLabels>,
     memoize_after: &ShipHashMap<usize, DedupedLabels>,
     seq_system_index_map: &mut Vec<usize>,
@@ -1432,6 +2136,7 @@ fn insert_before_after_system(
         conflict,
         before: memoize_before[&index].to_string_vec(),
         after: memoize_after[&index].to_string_vec(),
+        run_if: run_if_info,
     };
 
     if !can_go_in || system_info.conflict.is_some() {
@@ -1691,7 +2396,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1721,7 +2430,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1753,7 +2466,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1785,7 +2502,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1817,7 +2538,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1841,7 +2566,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1880,7 +2609,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1911,7 +2644,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1934,7 +2671,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1958,7 +2699,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -1981,7 +2726,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2023,7 +2772,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 0],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2049,7 +2802,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2072,7 +2829,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2095,7 +2856,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2121,7 +2886,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2153,7 +2922,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2178,7 +2951,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2219,7 +2996,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2, 3],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             })
         );
         assert_eq!(&scheduler.default, &label);
@@ -2515,7 +3296,11 @@ mod tests {
                 parallel_run_if: Vec::new(),
                 sequential: vec![0, 1, 2],
                 sequential_run_if: Vec::new(),
+                sequential_prefetch: Vec::new(),
                 run_if: None,
+                on_error: None,
+                max_threads: None,
+                rng_streams: RngStreams::default(),
             }
         );
     }
@@ -2642,4 +3427,50 @@ mod tests {
             .custom_error()
             .is_some());
     }
+
+    #[test]
+    fn replace_removes_unused_systems() {
+        fn sys1() {}
+        fn sys2() {}
+        fn sys3() {}
+
+        let world = World::new();
+
+        Workload::new("Default")
+            .with_system(sys1)
+            .with_system(sys2)
+            .add_to_world(&world)
+            .unwrap();
+        Workload::new("Other")
+            .with_system(sys2)
+            .add_to_world(&world)
+            .unwrap();
+
+        world
+            .replace_workload("Default", Workload::new("").with_system(sys3))
+            .unwrap();
+
+        let scheduler = world.scheduler.borrow().unwrap();
+        assert_eq!(scheduler.systems.len(), 2);
+        assert_eq!(scheduler.system_names.len(), 2);
+        assert_eq!(scheduler.system_generators.len(), 2);
+        assert_eq!(scheduler.lookup_table.len(), 2);
+
+        let sys2_index = scheduler.lookup_table[&sys2.into_workload_system().unwrap().type_id];
+        let sys3_index = scheduler.lookup_table[&sys3.into_workload_system().unwrap().type_id];
+        assert_eq!(
+            scheduler.workloads[&"Other".as_label()].sequential,
+            vec![sys2_index]
+        );
+        assert_eq!(
+            scheduler.workloads[&"Default".as_label()].sequential,
+            vec![sys3_index]
+        );
+        assert!(format!("{:?}", scheduler.system_names[sys2_index]).ends_with("sys2"));
+        assert!(format!("{:?}", scheduler.system_names[sys3_index]).ends_with("sys3"));
+        drop(scheduler);
+
+        world.run_workload("Other").unwrap();
+        world.run_default_workload().unwrap();
+    }
 }
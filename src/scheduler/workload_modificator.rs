@@ -10,10 +10,8 @@ use crate::Component;
 use crate::SparseSet;
 use crate::Unique;
 use crate::UniqueStorage;
-use crate::World;
 use alloc::boxed::Box;
 use core::any::type_name;
-use core::ops::Not;
 
 /// Modifies a workload.
 pub trait WorkloadModificator {
@@ -112,15 +110,10 @@ pub trait WorkloadModificator {
 impl WorkloadModificator for Workload {
     #[track_caller]
     fn run_if<RunB, Run: IntoWorkloadRunIf<RunB>>(mut self, run_if: Run) -> Workload {
+        let name = run_if.run_if_name();
         let run_if = run_if.into_workload_run_if().unwrap();
 
-        self.run_if = if let Some(prev_run_if) = self.run_if.take() {
-            Some(Box::new(move |world: &World| {
-                Ok(prev_run_if.run(world)? && run_if.run(world)?)
-            }))
-        } else {
-            Some(run_if)
-        };
+        self.add_run_if(run_if, &name, false);
 
         self
     }
@@ -146,17 +139,10 @@ impl WorkloadModificator for Workload {
         self.run_if(run_if)
     }
     fn skip_if<RunB, Run: IntoWorkloadRunIf<RunB>>(mut self, should_skip: Run) -> Self {
-        let mut should_skip = should_skip.into_workload_run_if().unwrap();
-
-        should_skip = Box::new(move |world: &World| should_skip.run(world).map(Not::not));
+        let name = should_skip.run_if_name();
+        let should_skip = should_skip.into_workload_run_if().unwrap();
 
-        self.run_if = if let Some(prev_run_if) = self.run_if.take() {
-            Some(Box::new(move |world: &World| {
-                Ok(prev_run_if.run(world)? && should_skip.run(world)?)
-            }))
-        } else {
-            Some(should_skip)
-        };
+        self.add_run_if(should_skip, &name, true);
 
         self
     }
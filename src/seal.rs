@@ -1 +1,5 @@
 pub trait Sealed {}
+
+/// Token making trait methods impossible to call or implement outside of the crate.
+#[derive(Clone, Copy)]
+pub struct Private;
@@ -0,0 +1,294 @@
+use crate::entity_id::EntityId;
+use crate::seal::Private;
+use crate::sparse_set::SecondaryMap;
+use crate::storage::Storage;
+use crate::tracking::TrackingTimestamp;
+use crate::ShipHashMap;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+
+/// Storage where equal components are stored once and shared by all entities owning them.
+///
+/// Useful for components with few distinct values owned by many entities, like configurations or enum-like metadata.\
+/// Values can't be modified in place, [`update`](SharedComponentStorage::update) makes a copy and deduplicates it again.
+///
+/// The storage is borrowed with [`SharedComponentView`](crate::SharedComponentView) and [`SharedComponentViewMut`](crate::SharedComponentViewMut).\
+/// Deleting an entity or clearing the `World` removes its value.
+///
+/// ### Example
+/// ```
+/// use shipyard::{SharedComponentView, SharedComponentViewMut, World};
+///
+/// #[derive(Clone, Hash, PartialEq, Eq, Debug)]
+/// enum Terrain {
+///     Grass,
+///     Water,
+/// }
+///
+/// let mut world = World::new();
+///
+/// let tiles: Vec<_> = (0..100).map(|_| world.add_entity(())).collect();
+///
+/// world.run(|mut terrains: SharedComponentViewMut<Terrain>| {
+///     for &tile in &tiles {
+///         terrains.insert(tile, Terrain::Grass);
+///     }
+///
+///     terrains.update(tiles[0], |terrain| *terrain = Terrain::Water);
+/// });
+///
+/// world.run(|terrains: SharedComponentView<Terrain>| {
+///     assert_eq!(terrains.len(), 100);
+///     assert_eq!(terrains.distinct_len(), 2);
+///     assert_eq!(terrains.get(tiles[0]), Some(&Terrain::Water));
+///     assert_eq!(terrains.get(tiles[1]), Some(&Terrain::Grass));
+/// });
+/// ```
+pub struct SharedComponentStorage<T> {
+    entities: SecondaryMap<u32>,
+    slots: Vec<Option<Slot<T>>>,
+    free_slots: Vec<u32>,
+    /// Maps a value's hash to the slots of all values with this hash.
+    lookup: ShipHashMap<u64, Vec<u32>>,
+}
+
+struct Slot<T> {
+    value: T,
+    hash: u64,
+    count: usize,
+}
+
+impl<T: Hash + Eq> SharedComponentStorage<T> {
+    /// Creates an empty storage.
+    pub fn new() -> Self {
+        SharedComponentStorage {
+            entities: SecondaryMap::new(),
+            slots: Vec::new(),
+            free_slots: Vec::new(),
+            lookup: ShipHashMap::default(),
+        }
+    }
+    /// Returns the number of entities owning a value.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+    /// Returns `true` if no entity owns a value.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+    /// Returns the number of distinct values stored.
+    #[inline]
+    pub fn distinct_len(&self) -> usize {
+        self.slots.len() - self.free_slots.len()
+    }
+    /// Returns `true` if `entity` owns a value.
+    #[inline]
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.entities.contains(entity)
+    }
+    /// Returns `entity`'s value.
+    #[inline]
+    pub fn get(&self, entity: EntityId) -> Option<&T> {
+        let slot = *self.entities.get(entity)?;
+
+        self.slots[slot as usize].as_ref().map(|slot| &slot.value)
+    }
+    /// Returns the number of entities sharing `entity`'s value.
+    #[inline]
+    pub fn share_count(&self, entity: EntityId) -> Option<usize> {
+        let slot = *self.entities.get(entity)?;
+
+        self.slots[slot as usize].as_ref().map(|slot| slot.count)
+    }
+    /// Sets `entity`'s value.\
+    /// If an equal value is already stored, `value` is dropped and the stored one is shared.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is dead.
+    #[track_caller]
+    pub fn insert(&mut self, entity: EntityId, value: T) {
+        self.remove(entity);
+
+        let slot = self.intern(value);
+
+        match self.entities.replace(entity, slot) {
+            // the value of an entity with the same index and a smaller generation was overwritten
+            Ok(Some(overwritten)) => self.release(overwritten),
+            Ok(None) => {}
+            // an entity with the same index and a larger generation owns a value
+            Err(slot) => self.release(slot),
+        }
+    }
+    /// Removes `entity`'s value.\
+    /// Returns `true` if `entity` owned a value.
+    pub fn remove(&mut self, entity: EntityId) -> bool {
+        match self.entities.remove(entity) {
+            Some(slot) => {
+                self.release(slot);
+
+                true
+            }
+            None => false,
+        }
+    }
+    /// Modifies `entity`'s value without affecting the other entities sharing it.\
+    /// The value is copied if it's shared, then deduplicated again after `f`.\
+    /// Returns `false` if `entity` doesn't own a value.
+    pub fn update<F: FnOnce(&mut T)>(&mut self, entity: EntityId, f: F) -> bool
+    where
+        T: Clone,
+    {
+        let slot = match self.entities.remove(entity) {
+            Some(slot) => slot,
+            None => return false,
+        };
+
+        let mut value = self.take_or_clone(slot);
+        f(&mut value);
+
+        let slot = self.intern(value);
+        // `entity`'s entry was just removed, the insertion can't be rejected
+        let _ = self.entities.insert(entity, slot);
+
+        true
+    }
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.entities.clear();
+        self.slots.clear();
+        self.free_slots.clear();
+        self.lookup.clear();
+    }
+    /// Returns an iterator over all entities and their value.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &T)> {
+        self.entities.iter().map(move |(entity, &slot)| {
+            // slots owned by an entity are never empty
+            let value = &self.slots[slot as usize].as_ref().unwrap().value;
+
+            (entity, value)
+        })
+    }
+    /// Returns an iterator over all distinct values and how many entities share them.
+    pub fn distinct(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.slots
+            .iter()
+            .flatten()
+            .map(|slot| (&slot.value, slot.count))
+    }
+    fn hash(&self, value: &T) -> u64 {
+        self.lookup.hasher().hash_one(value)
+    }
+    /// Returns the slot holding `value`, creating it if needed, and increments its count.
+    fn intern(&mut self, value: T) -> u32 {
+        let hash = self.hash(&value);
+        let slots = &mut self.slots;
+        let candidates = self.lookup.entry(hash).or_default();
+
+        for &candidate in candidates.iter() {
+            if let Some(slot) = &mut slots[candidate as usize] {
+                if slot.value == value {
+                    slot.count += 1;
+
+                    return candidate;
+                }
+            }
+        }
+
+        let slot = Slot {
+            value,
+            hash,
+            count: 1,
+        };
+
+        let index = match self.free_slots.pop() {
+            Some(index) => {
+                slots[index as usize] = Some(slot);
+                index
+            }
+            None => {
+                slots.push(Some(slot));
+                (slots.len() - 1) as u32
+            }
+        };
+
+        candidates.push(index);
+
+        index
+    }
+    /// Decrements `index`'s count and frees the slot when no entity owns it anymore.
+    fn release(&mut self, index: u32) {
+        self.take_unshared(index);
+    }
+    fn take_or_clone(&mut self, index: u32) -> T
+    where
+        T: Clone,
+    {
+        match self.take_unshared(index) {
+            Some(slot) => slot.value,
+            // the slot is still owned by other entities
+            None => self.slots[index as usize].as_ref().unwrap().value.clone(),
+        }
+    }
+    /// Decrements `index`'s count, if no entity owns it anymore it is removed and returned.
+    fn take_unshared(&mut self, index: u32) -> Option<Slot<T>> {
+        let slot = self.slots[index as usize].as_mut()?;
+        slot.count -= 1;
+
+        if slot.count > 0 {
+            return None;
+        }
+
+        let hash = slot.hash;
+        let slot = self.slots[index as usize].take();
+        self.free_slots.push(index);
+
+        if let Some(candidates) = self.lookup.get_mut(&hash) {
+            candidates.retain(|&candidate| candidate != index);
+
+            if candidates.is_empty() {
+                self.lookup.remove(&hash);
+            }
+        }
+
+        slot
+    }
+}
+
+impl<T: Hash + Eq> Default for SharedComponentStorage<T> {
+    fn default() -> Self {
+        SharedComponentStorage::new()
+    }
+}
+
+impl<T: Hash + Eq + 'static> Storage for SharedComponentStorage<T> {
+    #[inline]
+    fn delete(&mut self, entity: EntityId, _current: TrackingTimestamp) {
+        self.remove(entity);
+    }
+    #[inline]
+    fn clear(&mut self, _current: TrackingTimestamp) {
+        SharedComponentStorage::clear(self);
+    }
+    fn bump_generation(&mut self, entity: EntityId, new_entity: EntityId, _: Private) {
+        if let Some(slot) = self.entities.remove(entity) {
+            // `new_entity` has a larger generation, the insertion can't be rejected
+            let _ = self.entities.insert(new_entity, slot);
+        }
+    }
+    fn is_empty(&self) -> bool {
+        SharedComponentStorage::is_empty(self)
+    }
+    fn can_archive(&self, entity: EntityId, _: Private) -> bool {
+        !self.contains(entity)
+    }
+}
+
+impl<T: Hash + Eq + fmt::Debug> fmt::Debug for SharedComponentStorage<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -34,6 +34,7 @@ impl<T: Send + Sync + Component> TupleAddComponent for T {
     ) {
         all_storages
             .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
+            .unwrap()
             .insert(entity, self, current)
             .assert_inserted();
     }
@@ -51,6 +52,7 @@ impl<T: Send + Sync + Component> TupleAddComponent for Option<T> {
         if let Some(component) = self {
             all_storages
                 .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
+                .unwrap()
                 .insert(entity, component, current)
                 .assert_inserted();
         }
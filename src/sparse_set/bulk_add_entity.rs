@@ -1,9 +1,10 @@
-use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::all_storages::AllStorages;
 use crate::component::Component;
 use crate::entities::Entities;
 use crate::entity_id::EntityId;
 use crate::reserve::BulkEntityIter;
 use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
 use crate::tracking::TrackingTimestamp;
 #[cfg(doc)]
 use crate::world::World;
@@ -45,6 +46,8 @@ impl BulkInsert for () {
         let len = iter.size_hint().0;
 
         let entities = all_storages.exclusive_storage_mut::<Entities>().unwrap();
+        // reserved entities are added before the new ones
+        entities.flush_reserved();
         let entities_len = entities.data.len();
 
         entities.bulk_generate(len);
@@ -67,13 +70,15 @@ impl<T: Send + Sync + Component> BulkInsert for T {
         let current = all_storages.get_current();
         let mut entities = all_storages.entities_mut().unwrap();
         let mut sparse_set = all_storages
-            .custom_storage_or_insert_mut(SparseSet::<T>::new)
+            .storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new, false)
             .unwrap();
 
         // add components to the storage
         sparse_set.data.extend(iter);
 
         // generate new EntityId for the entities created
+        // reserved entities are added before the new ones
+        entities.flush_reserved();
         let entities_len = entities.data.len();
         let old_len = sparse_set.dense.len();
         let new_entities_count = sparse_set.data.len() - old_len;
@@ -82,6 +87,8 @@ impl<T: Send + Sync + Component> BulkInsert for T {
         // add new EntityId to the storage for the components we added above
         sparse_set.dense.extend_from_slice(new_entities);
 
+        sparse_set.last_change = current;
+
         // add tracking info if needed
         if sparse_set.is_tracking_insertion() {
             sparse_set
@@ -131,10 +138,15 @@ macro_rules! impl_bulk_insert {
             fn bulk_insert<Source: IntoIterator<Item = Self>>(all_storages: &mut AllStorages, iter: Source) -> BulkEntityIter<'_> {
                 let iter = iter.into_iter();
                 let size_hint = iter.size_hint().0;
+                let current = all_storages.get_current();
                 let mut entities = all_storages.entities_mut().unwrap();
-                let mut $sparse_set1 = all_storages.custom_storage_or_insert_mut(SparseSet::<$type1>::new).unwrap();
+                let mut $sparse_set1 = all_storages
+                    .storage_or_insert_mut(StorageId::of::<SparseSet<$type1>>(), SparseSet::<$type1>::new, false)
+                    .unwrap();
                 $(
-                    let mut $sparse_set = all_storages.custom_storage_or_insert_mut(SparseSet::<$type>::new).unwrap();
+                    let mut $sparse_set = all_storages
+                        .storage_or_insert_mut(StorageId::of::<SparseSet<$type>>(), SparseSet::<$type>::new, false)
+                        .unwrap();
                 )*
 
                 $sparse_set1.reserve(size_hint);
@@ -149,13 +161,17 @@ macro_rules! impl_bulk_insert {
                     )*
                 }
 
+                // reserved entities are added before the new ones
+                entities.flush_reserved();
                 let entities_len = entities.data.len();
                 let new_entities_count = $sparse_set1.data.len() - $sparse_set1.dense.len();
                 let new_entities = entities.bulk_generate(new_entities_count);
 
                 $sparse_set1.dense.extend_from_slice(new_entities);
+                $sparse_set1.last_change = current;
                 $(
                     $sparse_set.dense.extend_from_slice(new_entities);
+                    $sparse_set.last_change = current;
                 )*
 
                 if $sparse_set1.is_tracking_insertion() {
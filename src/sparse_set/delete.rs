@@ -19,6 +19,7 @@ impl<T: Send + Sync + Component> TupleDelete for T {
 
         all_storages
             .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .unwrap()
             .dyn_delete(entity, current)
     }
 }
@@ -32,6 +33,7 @@ macro_rules! impl_delete_component {
                 $(
                     all_storages
                         .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<$type>>(), SparseSet::<$type>::new)
+                        .unwrap()
                         .dyn_delete(entity, current)
                 )||+
             }
@@ -2,7 +2,10 @@ mod add_component;
 mod bulk_add_entity;
 mod delete;
 mod drain;
+mod partition;
+mod raw_window;
 mod remove;
+mod secondary_map;
 mod sparse_array;
 mod window;
 
@@ -10,24 +13,35 @@ pub use add_component::TupleAddComponent;
 pub use bulk_add_entity::BulkAddEntity;
 pub use delete::TupleDelete;
 pub use drain::SparseSetDrain;
+pub use partition::{Partition, PartitionMut};
+pub use raw_window::{RawWindow, RawWindowMut};
 pub use remove::TupleRemove;
-pub use sparse_array::SparseArray;
+pub use secondary_map::SecondaryMap;
+pub use sparse_array::{SparseArray, SparseArrayStats};
 
 pub(crate) use window::{FullRawWindow, FullRawWindowMut};
 
 use crate::all_storages::AllStorages;
+use crate::archive::ArchivedEntity;
 #[cfg(feature = "thread_local")]
 use crate::borrow::{NonSend, NonSendSync, NonSync};
 use crate::component::Component;
+use crate::drop_queue::DropQueue;
+#[cfg(feature = "thread_local")]
+use crate::drop_queue::LocalDropQueue;
+use crate::entities::Entities;
 use crate::entity_id::EntityId;
 use crate::error;
-use crate::memory_usage::StorageMemoryUsage;
+use crate::memory_usage::{StorageCategory, StorageMemoryUsage};
 use crate::r#mut::Mut;
+use crate::seal::Private;
 use crate::storage::{Storage, StorageId};
 use crate::tracking::{Tracking, TrackingTimestamp};
+use crate::ShipHashMap;
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 use core::any::type_name;
+use core::hash::BuildHasherDefault;
 use core::mem::size_of;
 use core::{
     cmp::{Ord, Ordering},
@@ -35,6 +49,9 @@ use core::{
 };
 
 pub(crate) const BUCKET_SIZE: usize = 256 / size_of::<EntityId>();
+/// Minimum amount of component bytes a parallel iterator task processes by default.
+#[cfg(feature = "parallel")]
+const PAR_MIN_CHUNK_BYTES: usize = 1024;
 
 /// Default component storage.
 // A sparse array is a data structure with 2 vectors: one sparse, the other dense.
@@ -46,11 +63,13 @@ pub(crate) const BUCKET_SIZE: usize = 256 / size_of::<EntityId>();
 // We can't be limited to store solely integers, this is why there is a third vector.
 // It mimics the dense vector in regard to insertion/deletion.
 pub struct SparseSet<T: Component> {
-    pub(crate) sparse: SparseArray<EntityId, BUCKET_SIZE>,
+    pub(crate) sparse: SparseArray<EntityId>,
     pub(crate) dense: Vec<EntityId>,
     pub(crate) data: Vec<T>,
     pub(crate) last_insert: TrackingTimestamp,
     pub(crate) last_modified: TrackingTimestamp,
+    /// Last time a component was inserted, deleted or removed, or the storage was borrowed exclusively
+    pub(crate) last_change: TrackingTimestamp,
     pub(crate) insertion_data: Vec<TrackingTimestamp>,
     pub(crate) modification_data: Vec<TrackingTimestamp>,
     pub(crate) deletion_data: Vec<(EntityId, TrackingTimestamp, T)>,
@@ -59,10 +78,17 @@ pub struct SparseSet<T: Component> {
     pub(crate) is_tracking_modification: bool,
     pub(crate) is_tracking_deletion: bool,
     pub(crate) is_tracking_removal: bool,
+    /// Components of disabled entities with their insertion and modification timestamps
+    pub(crate) disabled: ShipHashMap<EntityId, (T, TrackingTimestamp, TrackingTimestamp)>,
+    pub(crate) is_deferring_drops: bool,
+    /// Deleted components waiting to be collected in a `DropQueue`
+    pub(crate) deferred_drops: Vec<T>,
     #[allow(clippy::type_complexity)]
     on_insertion: Option<Box<dyn FnMut(EntityId, &T) + Send + Sync>>,
     #[allow(clippy::type_complexity)]
     on_removal: Option<Box<dyn FnMut(EntityId, &T) + Send + Sync>>,
+    #[cfg(feature = "parallel")]
+    par_min_len: Option<usize>,
 }
 
 impl<T: fmt::Debug + Component> fmt::Debug for SparseSet<T> {
@@ -82,6 +108,7 @@ impl<T: Component> SparseSet<T> {
             data: Vec::new(),
             last_insert: TrackingTimestamp::new(0),
             last_modified: TrackingTimestamp::new(0),
+            last_change: TrackingTimestamp::new(0),
             insertion_data: Vec::new(),
             modification_data: Vec::new(),
             deletion_data: Vec::new(),
@@ -90,8 +117,13 @@ impl<T: Component> SparseSet<T> {
             is_tracking_modification: T::Tracking::track_modification(),
             is_tracking_deletion: T::Tracking::track_deletion(),
             is_tracking_removal: T::Tracking::track_removal(),
+            disabled: ShipHashMap::with_hasher(BuildHasherDefault::default()),
+            is_deferring_drops: false,
+            deferred_drops: Vec::new(),
             on_insertion: None,
             on_removal: None,
+            #[cfg(feature = "parallel")]
+            par_min_len: None,
         }
     }
     /// Returns a new [`SparseSet`] to be used in custom storage.
@@ -104,6 +136,50 @@ impl<T: Component> SparseSet<T> {
     pub fn as_slice(&self) -> &[T] {
         &self.data
     }
+    /// Returns all the components in this storage as bytes, in the same order as [`as_slice`].
+    ///
+    /// [`as_slice`]: Self::as_slice
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: bytemuck::Pod,
+    {
+        bytemuck::cast_slice(&self.data)
+    }
+    /// Returns a slice of all the `EntityId`s owning a component in this storage.\
+    /// The slice is aligned with [`as_slice`], the component at index `i` belongs to the entity at index `i`.\
+    /// [`index_of`] returns the position of an entity in both slices.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(U32(0));
+    /// let entity1 = world.add_entity(U32(1));
+    ///
+    /// world.run(|u32s: View<U32>| {
+    ///     assert_eq!(u32s.ids(), &[entity0, entity1]);
+    ///
+    ///     let index = u32s.index_of(entity1).unwrap();
+    ///     assert_eq!(u32s.ids()[index], entity1);
+    ///     assert_eq!(u32s.as_slice()[index], U32(1));
+    /// });
+    /// ```
+    ///
+    /// [`as_slice`]: Self::as_slice
+    /// [`index_of`]: Self::index_of
+    #[inline]
+    pub fn ids(&self) -> &[EntityId] {
+        &self.dense
+    }
 }
 
 impl<T: Component> SparseSet<T> {
@@ -148,11 +224,202 @@ impl<T: Component> SparseSet<T> {
     pub unsafe fn index_of_unchecked(&self, entity: EntityId) -> usize {
         self.sparse.get_unchecked(entity).uindex()
     }
+    pub(crate) fn private_validate_integrity(
+        &self,
+        storage_name: &'static str,
+        entities: &Entities,
+        current: TrackingTimestamp,
+        errors: &mut Vec<error::IntegrityError>,
+    ) {
+        let dense_len = self.dense.len();
+
+        let lengths = [
+            (true, self.data.len()),
+            (self.is_tracking_insertion, self.insertion_data.len()),
+            (self.is_tracking_modification, self.modification_data.len()),
+        ];
+        for (is_used, len) in lengths {
+            if is_used && len != dense_len {
+                errors.push(error::IntegrityError::LengthMismatch {
+                    storage_name: storage_name.into(),
+                    dense_len,
+                    len,
+                });
+            }
+        }
+
+        // timestamps handed out so far are all older than `current`
+        let is_future = |timestamp: TrackingTimestamp| !timestamp.is_older_than(current);
+
+        for (index, &entity) in self.dense.iter().enumerate() {
+            if !entities.is_alive(entity) {
+                errors.push(error::IntegrityError::DeadEntity {
+                    storage_name: storage_name.into(),
+                    entity,
+                });
+            }
+
+            if self.index_of(entity) != Some(index) {
+                errors.push(error::IntegrityError::SparseDenseMismatch {
+                    storage_name: storage_name.into(),
+                    entity,
+                    index,
+                });
+            }
+
+            let insertion = self
+                .insertion_data
+                .get(index)
+                .copied()
+                .filter(|_| self.is_tracking_insertion);
+            // components that were never modified hold a timestamp far from their insertion
+            let modification = self
+                .modification_data
+                .get(index)
+                .copied()
+                .filter(|_| self.is_tracking_modification)
+                .filter(|modification| {
+                    insertion.is_some_and(|insertion| {
+                        modification.get() != insertion.furthest_from().get()
+                    })
+                });
+
+            for timestamp in insertion.into_iter().chain(modification) {
+                if is_future(timestamp) {
+                    errors.push(error::IntegrityError::FutureTimestamp {
+                        storage_name: storage_name.into(),
+                        entity,
+                        timestamp,
+                    });
+                }
+            }
+        }
+
+        for (entity_index, sparse_entity) in self.sparse.occupied() {
+            let index = sparse_entity.uindex();
+            let entity = EntityId::new_from_index_and_gen(entity_index as u64, sparse_entity.gen());
+
+            if self.dense.get(index) != Some(&entity) {
+                errors.push(error::IntegrityError::DanglingSparseEntry {
+                    storage_name: storage_name.into(),
+                    entity,
+                    index,
+                });
+            }
+        }
+
+        let deleted = self
+            .deletion_data
+            .iter()
+            .map(|(entity, timestamp, _)| (*entity, *timestamp));
+        let removed = self.removal_data.iter().copied();
+        for (entity, timestamp) in deleted.chain(removed) {
+            if is_future(timestamp) {
+                errors.push(error::IntegrityError::FutureTimestamp {
+                    storage_name: storage_name.into(),
+                    entity,
+                    timestamp,
+                });
+            }
+        }
+    }
+    pub(crate) fn private_memory_usage(&self, storage_name: &'static str) -> StorageMemoryUsage {
+        let allocated_tracking_memory_bytes = (self.insertion_data.capacity()
+            * size_of::<TrackingTimestamp>())
+            + (self.modification_data.capacity() * size_of::<TrackingTimestamp>())
+            + (self.deletion_data.capacity() * size_of::<(EntityId, TrackingTimestamp, T)>())
+            + (self.removal_data.capacity() * size_of::<(EntityId, TrackingTimestamp)>());
+        let used_tracking_memory_bytes = (self.insertion_data.len()
+            * size_of::<TrackingTimestamp>())
+            + (self.modification_data.len() * size_of::<TrackingTimestamp>())
+            + (self.deletion_data.len() * size_of::<(EntityId, TrackingTimestamp, T)>())
+            + (self.removal_data.len() * size_of::<(EntityId, TrackingTimestamp)>());
+        let disabled_entry_size =
+            size_of::<(EntityId, (T, TrackingTimestamp, TrackingTimestamp))>();
+
+        StorageMemoryUsage {
+            storage_name: storage_name.into(),
+            allocated_memory_bytes: self.sparse.reserved_memory()
+                + (self.dense.capacity() * size_of::<EntityId>())
+                + (self.data.capacity() * size_of::<T>())
+                + (self.disabled.capacity() * disabled_entry_size)
+                + allocated_tracking_memory_bytes
+                + size_of::<Self>(),
+            used_memory_bytes: self.sparse.used_memory()
+                + (self.dense.len() * size_of::<EntityId>())
+                + (self.data.len() * size_of::<T>())
+                + (self.disabled.len() * disabled_entry_size)
+                + used_tracking_memory_bytes
+                + size_of::<Self>(),
+            used_tracking_memory_bytes,
+            allocated_tracking_memory_bytes,
+            component_count: self.len(),
+            category: StorageCategory::Components,
+        }
+    }
+    /// Returns the minimum number of components a parallel iterator task will process.
+    ///
+    /// Unless set with [`set_par_min_len`](SparseSet::set_par_min_len), it is derived from the size of `T`,
+    /// so that each task processes at least 1KiB of components.
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    #[inline]
+    pub fn par_min_len(&self) -> usize {
+        self.par_min_len
+            .unwrap_or_else(|| (PAR_MIN_CHUNK_BYTES / size_of::<T>().max(1)).max(1))
+    }
+    /// Sets the minimum number of components a parallel iterator task will process.\
+    /// `None` restores the default based on the size of `T`.
+    ///
+    /// When iterating multiple storages, the smallest minimum is used.
+    ///
+    /// ### Example
+    /// ```
+    /// use rayon::prelude::ParallelIterator;
+    /// use shipyard::{Component, IntoIter, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Path(Vec<u32>);
+    ///
+    /// let world = World::new();
+    ///
+    /// let mut paths = world.borrow::<ViewMut<Path>>().unwrap();
+    ///
+    /// // each component is expensive to process, split as much as possible
+    /// paths.set_par_min_len(Some(1));
+    ///
+    /// assert_eq!(paths.par_iter().min_len(), 1);
+    /// ```
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    #[inline]
+    pub fn set_par_min_len(&mut self, min_len: Option<usize>) {
+        self.par_min_len = min_len.map(|min_len| min_len.max(1));
+    }
     /// Returns the `EntityId` at a given `index`.
     #[inline]
     pub fn id_at(&self, index: usize) -> Option<EntityId> {
         self.dense.get(index).copied()
     }
+    #[inline]
+    pub(crate) fn private_insertion_timestamp(
+        &self,
+        entity: EntityId,
+    ) -> Option<TrackingTimestamp> {
+        self.index_of(entity)
+            .and_then(|index| self.insertion_data.get(index).copied())
+    }
+    /// Components that were never modified hold a timestamp far in the future compared to `current`.
+    #[inline]
+    pub(crate) fn private_modification_timestamp(
+        &self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+    ) -> Option<TrackingTimestamp> {
+        self.index_of(entity)
+            .and_then(|index| self.modification_data.get(index).copied())
+            .filter(|timestamp| current.get().wrapping_sub(timestamp.get()) < u32::MAX / 2)
+    }
 
     /// Sets the on insertion callback.
     pub fn on_insertion(&mut self, f: impl FnMut(EntityId, &T) + Send + Sync + 'static) {
@@ -185,6 +452,30 @@ impl<T: Component> SparseSet<T> {
         self.index_of(entity)
             .map(|index| unsafe { self.data.get_unchecked(index) })
     }
+    /// Pushes the entities deleted after `timestamp` to `entities`.
+    pub(crate) fn private_deleted_since(
+        &self,
+        timestamp: TrackingTimestamp,
+        current: TrackingTimestamp,
+        entities: &mut Vec<EntityId>,
+    ) {
+        if self.is_tracking_deletion {
+            entities.extend(
+                self.deletion_data
+                    .iter()
+                    .filter(|(_, t, _)| t.is_within(timestamp, current))
+                    .map(|(entity, _, _)| *entity),
+            );
+        }
+    }
+    /// Builds the error returned when `entity` doesn't have a component in this storage.
+    #[cold]
+    pub(crate) fn missing_component(&self, entity: EntityId) -> error::MissingComponent {
+        error::MissingComponent {
+            id: entity,
+            name: type_name::<T>(),
+        }
+    }
 }
 
 #[must_use]
@@ -230,6 +521,7 @@ impl<T: Component> SparseSet<T> {
         value: T,
         current: TrackingTimestamp,
     ) -> InsertionResult<T> {
+        self.last_change = current;
         self.sparse.allocate_at(entity);
 
         // at this point there can't be nothing at the sparse index
@@ -316,24 +608,40 @@ impl<T: Component> SparseSet<T> {
     /// Same as `delete` but checks tracking at runtime.
     #[inline]
     pub(crate) fn dyn_delete(&mut self, entity: EntityId, current: TrackingTimestamp) -> bool {
-        if let Some(component) = self.actual_remove(entity) {
-            if self.is_tracking_deletion() {
-                self.deletion_data.push((entity, current, component));
-            }
-
-            true
+        let component = if let Some(component) = self.actual_remove(entity) {
+            component
+        } else if let Some((component, _, _)) = self.disabled.remove(&entity) {
+            component
         } else {
-            false
+            return false;
+        };
+
+        self.last_change = current;
+
+        if self.is_tracking_deletion() {
+            self.deletion_data.push((entity, current, component));
+        } else if self.is_deferring_drops {
+            self.deferred_drops.push(component);
         }
+
+        true
     }
 
     /// Same as `remove` but checks tracking at runtime.
     #[inline]
     pub(crate) fn dyn_remove(&mut self, entity: EntityId, current: TrackingTimestamp) -> Option<T> {
-        let component = self.actual_remove(entity);
+        let component = self.actual_remove(entity).or_else(|| {
+            self.disabled
+                .remove(&entity)
+                .map(|(component, _, _)| component)
+        });
 
-        if component.is_some() && self.is_tracking_removal() {
-            self.removal_data.push((entity, current));
+        if component.is_some() {
+            self.last_change = current;
+
+            if self.is_tracking_removal() {
+                self.removal_data.push((entity, current));
+            }
         }
 
         component
@@ -382,6 +690,132 @@ impl<T: Component> SparseSet<T> {
     }
 }
 
+impl<T: Component> SparseSet<T> {
+    /// Hides `entity`'s component from this storage without dropping it.\
+    /// No callback is called and no tracking information is recorded.
+    ///
+    /// Returns `true` if `entity` had a component in this storage.
+    pub(crate) fn private_disable(&mut self, entity: EntityId) -> bool {
+        let index = if let Some(index) = self.index_of(entity) {
+            index
+        } else {
+            return false;
+        };
+
+        unsafe {
+            *self.sparse.get_mut_unchecked(entity) = EntityId::dead();
+        }
+
+        self.dense.swap_remove(index);
+        let insertion = if self.is_tracking_insertion() {
+            self.insertion_data.swap_remove(index)
+        } else {
+            TrackingTimestamp::new(0)
+        };
+        let modification = if self.is_tracking_modification() {
+            self.modification_data.swap_remove(index)
+        } else {
+            TrackingTimestamp::new(0)
+        };
+        let component = self.data.swap_remove(index);
+
+        if index < self.dense.len() {
+            unsafe {
+                let last = *self.dense.get_unchecked(index);
+                self.sparse.get_mut_unchecked(last).set_index(index as u64);
+            }
+        }
+
+        self.disabled
+            .insert(entity, (component, insertion, modification));
+
+        true
+    }
+    /// Moves `entity`'s component, enabled or disabled, to `new_entity` which has the same index.
+    pub(crate) fn private_bump_generation(&mut self, entity: EntityId, new_entity: EntityId) {
+        if let Some(index) = self.index_of(entity) {
+            // SAFE index_of checked that entity is in the sparse and dense arrays
+            unsafe {
+                self.sparse.get_mut_unchecked(entity).copy_gen(new_entity);
+                *self.dense.get_unchecked_mut(index) = new_entity;
+            }
+        }
+
+        if let Some(disabled) = self.disabled.remove(&entity) {
+            self.disabled.insert(new_entity, disabled);
+        }
+    }
+    /// Makes a component hidden by `private_disable` visible again.\
+    /// The component keeps the tracking information it had when it was disabled.\
+    /// If a component was added to `entity` while it was disabled, the disabled component is dropped.
+    ///
+    /// Returns `true` if `entity` had a disabled component in this storage.
+    pub(crate) fn private_enable(&mut self, entity: EntityId) -> bool {
+        let (component, insertion, modification) =
+            if let Some(disabled) = self.disabled.remove(&entity) {
+                disabled
+            } else {
+                return false;
+            };
+
+        if self.index_of(entity).is_some() {
+            // the component added while the entity was disabled overwrites the disabled one
+            return true;
+        }
+
+        // removes the component of a previous generation still using this index
+        self.actual_remove(entity);
+
+        self.sparse.allocate_at(entity);
+
+        unsafe {
+            *self.sparse.get_mut_unchecked(entity) =
+                EntityId::new_from_index_and_gen(self.dense.len() as u64, entity.gen());
+        }
+
+        if self.is_tracking_insertion() {
+            self.insertion_data.push(insertion);
+        }
+        if self.is_tracking_modification() {
+            self.modification_data.push(modification);
+        }
+
+        self.dense.push(entity);
+        self.data.push(component);
+
+        true
+    }
+    /// Returns `true` if `entity`'s component in this storage is disabled.
+    #[inline]
+    pub fn is_disabled(&self, entity: EntityId) -> bool {
+        self.disabled.contains_key(&entity)
+    }
+    /// Returns a reference to the component of a disabled entity.
+    ///
+    /// Disabled components are not visible to iteration nor [`Get`](crate::Get).
+    #[inline]
+    pub fn get_disabled(&self, entity: EntityId) -> Option<&T> {
+        self.disabled
+            .get(&entity)
+            .map(|(component, _, _)| component)
+    }
+    /// Returns a mutable reference to the component of a disabled entity.\
+    /// Modification tracking is not affected.
+    ///
+    /// Disabled components are not visible to iteration nor [`Get`](crate::Get).
+    #[inline]
+    pub fn get_disabled_mut(&mut self, entity: EntityId) -> Option<&mut T> {
+        self.disabled
+            .get_mut(&entity)
+            .map(|(component, _, _)| component)
+    }
+    /// Returns the number of disabled components in this storage.
+    #[inline]
+    pub fn disabled_len(&self) -> usize {
+        self.disabled.len()
+    }
+}
+
 impl<T: Component> SparseSet<T> {
     /// Removes the *inserted* flag on all components of this storage.
     pub(crate) fn private_clear_all_inserted(&mut self, current: TrackingTimestamp) {
@@ -396,14 +830,42 @@ impl<T: Component> SparseSet<T> {
         self.last_insert = current;
         self.last_modified = current;
     }
+    /// Drops the components kept by deletion tracking, or defers their drop if the storage [defers drops](SparseSet::defer_drops).
+    pub(crate) fn private_clear_deleted(&mut self) {
+        if self.is_deferring_drops {
+            self.deferred_drops.extend(
+                self.deletion_data
+                    .drain(..)
+                    .map(|(_, _, component)| component),
+            );
+        } else {
+            self.deletion_data.clear();
+        }
+    }
+    /// Same as [`SparseSet::private_clear_deleted`] but only for components deleted before `timestamp`.
+    pub(crate) fn private_clear_deleted_older_than(&mut self, timestamp: TrackingTimestamp) {
+        if self.is_deferring_drops {
+            let deletion_data = core::mem::take(&mut self.deletion_data);
+
+            for (entity, t, component) in deletion_data {
+                if timestamp.is_older_than(t) {
+                    self.deletion_data.push((entity, t, component));
+                } else {
+                    self.deferred_drops.push(component);
+                }
+            }
+        } else {
+            self.deletion_data
+                .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        }
+    }
     /// Clear all deletion tracking data.
     pub fn clear_all_deleted(&mut self) {
-        self.deletion_data.clear();
+        self.private_clear_deleted();
     }
     /// Clear all deletion tracking data older than some timestamp.
     pub fn clear_all_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
-        self.deletion_data
-            .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        self.private_clear_deleted_older_than(timestamp);
     }
     /// Clear all removal tracking data.
     pub fn clear_all_removed(&mut self) {
@@ -423,8 +885,7 @@ impl<T: Component> SparseSet<T> {
         &mut self,
         timestamp: TrackingTimestamp,
     ) {
-        self.deletion_data
-            .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        self.private_clear_deleted_older_than(timestamp);
         self.removal_data
             .retain(|(_, t)| timestamp.is_older_than(*t));
     }
@@ -490,6 +951,18 @@ impl<T: Component> SparseSet<T> {
     pub fn is_tracking_removal(&self) -> bool {
         self.is_tracking_removal
     }
+    /// Makes this storage keep the components it deletes instead of dropping them.\
+    /// They can then be dropped later using [`World::take_deferred_drops`](crate::World::take_deferred_drops).
+    ///
+    /// Components kept by deletion tracking are deferred once the tracking data is cleared.
+    pub fn defer_drops(&mut self) -> &mut SparseSet<T> {
+        self.is_deferring_drops = true;
+        self
+    }
+    /// Returns `true` if the storage keeps the components it deletes.
+    pub fn is_deferring_drops(&self) -> bool {
+        self.is_deferring_drops
+    }
     /// Returns `true` if the storage tracks insertion, deletion or removal.
     pub fn is_tracking_any(&self) -> bool {
         self.is_tracking_insertion()
@@ -529,6 +1002,77 @@ impl<T: Component> SparseSet<T> {
 }
 
 impl<T: Component> SparseSet<T> {
+    /// Returns the occupancy of the sparse array.\
+    /// Useful to see how fragmented entity ids left it.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(U32(0));
+    ///
+    /// let stats = world.borrow::<View<U32>>().unwrap().sparse_stats();
+    ///
+    /// assert_eq!(stats.allocated_pages, 1);
+    /// assert_eq!(stats.occupied, 1);
+    /// ```
+    pub fn sparse_stats(&self) -> SparseArrayStats {
+        self.sparse.stats()
+    }
+    /// Frees the sparse array's pages without any entity.
+    pub fn compact_sparse(&mut self) {
+        self.sparse.compact();
+    }
+    /// Returns the number of entries in a page of the sparse array.
+    #[inline]
+    pub fn sparse_page_size(&self) -> usize {
+        self.sparse.page_size()
+    }
+    /// Sets the number of entries in a page of the sparse array and moves the existing entries to the new pages.\
+    /// `None` restores the default page size.
+    ///
+    /// Smaller pages waste less memory for storages with few, scattered entities.
+    /// Larger pages allocate less often for storages used by most entities.\
+    /// Pages before the first one used by an entity are never allocated, regardless of the page size.\
+    /// Entities are always placed at their index, there is no custom index mapping.
+    ///
+    /// ### Panics
+    ///
+    /// - `page_size` is not a power of two.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Rare(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// for _ in 0..1000 {
+    ///     world.add_entity(());
+    /// }
+    /// let entity = world.add_entity(Rare(0));
+    ///
+    /// world.run(|mut rares: ViewMut<Rare>| {
+    ///     rares.set_sparse_page_size(Some(4));
+    ///
+    ///     let stats = rares.sparse_stats();
+    ///     assert_eq!(stats.page_size, 4);
+    ///     assert_eq!(stats.pages, 1);
+    ///
+    ///     assert_eq!(rares[entity].0, 0);
+    /// });
+    /// ```
+    #[track_caller]
+    pub fn set_sparse_page_size(&mut self, page_size: Option<usize>) {
+        self.sparse.set_page_size(page_size.unwrap_or(BUCKET_SIZE));
+    }
     /// Reserves memory for at least `additional` components. Adding components can still allocate though.
     #[inline]
     pub fn reserve(&mut self, additional: usize) {
@@ -650,8 +1194,32 @@ impl<T: Component> SparseSet<T> {
         }
     }
 
+    /// Replaces `entity`'s component and returns the old one.\
+    /// Does nothing if `entity` doesn't have a component in this storage.
+    pub(crate) fn private_replace(
+        &mut self,
+        entity: EntityId,
+        component: T,
+        current: TrackingTimestamp,
+    ) -> Option<T> {
+        let index = self.index_of(entity)?;
+
+        if self.is_tracking_modification {
+            unsafe {
+                *self.modification_data.get_unchecked_mut(index) = current;
+            }
+        }
+
+        Some(core::mem::replace(
+            unsafe { self.data.get_unchecked_mut(index) },
+            component,
+        ))
+    }
+
     /// Deletes all components in this storage.
     pub(crate) fn private_clear(&mut self, current: TrackingTimestamp) {
+        self.last_change = current;
+
         for &id in &self.dense {
             unsafe {
                 *self.sparse.get_mut_unchecked(id) = EntityId::dead();
@@ -662,22 +1230,62 @@ impl<T: Component> SparseSet<T> {
 
         let is_tracking_deletion = self.is_tracking_deletion();
 
-        let iter = self
-            .dense
-            .drain(..)
-            .zip(self.data.drain(..))
-            .map(|(entity, component)| (entity, current, component));
+        if is_tracking_deletion {
+            self.deletion_data.extend(
+                self.disabled
+                    .drain()
+                    .map(|(entity, (component, _, _))| (entity, current, component)),
+            );
+        } else if self.is_deferring_drops {
+            self.deferred_drops.extend(
+                self.disabled
+                    .drain()
+                    .map(|(_, (component, _, _))| component),
+            );
+        } else {
+            self.disabled.clear();
+        }
 
         if is_tracking_deletion {
+            let iter = self
+                .dense
+                .drain(..)
+                .zip(self.data.drain(..))
+                .map(|(entity, component)| (entity, current, component));
+
             self.deletion_data.extend(iter);
+        } else if self.is_deferring_drops {
+            self.dense.clear();
+            self.deferred_drops.append(&mut self.data);
+        } else {
+            self.dense.clear();
+            self.data.clear();
         }
     }
 
     /// Creates a draining iterator that empties the storage and yields the removed items.
     pub(crate) fn private_drain(&mut self, current: TrackingTimestamp) -> SparseSetDrain<'_, T> {
+        self.last_change = current;
+
         if self.is_tracking_removal {
             self.removal_data
                 .extend(self.dense.iter().map(|&entity| (entity, current)));
+            self.removal_data
+                .extend(self.disabled.keys().map(|&entity| (entity, current)));
+        }
+
+        self.insertion_data.clear();
+        self.modification_data.clear();
+
+        // disabled components are not part of the drain, they are dropped
+        if self.is_deferring_drops {
+            self.deferred_drops.extend(
+                self.disabled
+                    .drain()
+                    .map(|(_, (component, _, _))| component),
+            );
+        } else {
+            self.disabled.clear();
         }
 
         for id in &self.dense {
@@ -760,41 +1368,71 @@ impl<T: 'static + Component + Send + Sync> Storage for SparseSet<T> {
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
+    #[inline]
+    fn disable(&mut self, entity: EntityId, _: Private) -> bool {
+        self.private_disable(entity)
+    }
+    #[inline]
+    fn enable(&mut self, entity: EntityId, _: Private) -> bool {
+        self.private_enable(entity)
+    }
+    #[inline]
+    fn bump_generation(&mut self, entity: EntityId, new_entity: EntityId, _: Private) {
+        self.private_bump_generation(entity, new_entity);
+    }
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
-        Some(StorageMemoryUsage {
-            storage_name: type_name::<Self>().into(),
-            allocated_memory_bytes: self.sparse.reserved_memory()
-                + (self.dense.capacity() * size_of::<EntityId>())
-                + (self.data.capacity() * size_of::<T>())
-                + (self.insertion_data.capacity() * size_of::<TrackingTimestamp>())
-                + (self.modification_data.capacity() * size_of::<TrackingTimestamp>())
-                + (self.deletion_data.capacity() * size_of::<(T, EntityId)>())
-                + (self.removal_data.capacity() * size_of::<EntityId>())
-                + size_of::<Self>(),
-            used_memory_bytes: self.sparse.used_memory()
-                + (self.dense.len() * size_of::<EntityId>())
-                + (self.data.len() * size_of::<T>())
-                + (self.insertion_data.len() * size_of::<TrackingTimestamp>())
-                + (self.modification_data.len() * size_of::<TrackingTimestamp>())
-                + (self.deletion_data.len() * size_of::<(EntityId, T)>())
-                + (self.removal_data.len() * size_of::<EntityId>())
-                + size_of::<Self>(),
-            component_count: self.len(),
-        })
+        Some(self.private_memory_usage(type_name::<Self>()))
     }
-    fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
+    fn sparse_array(&self) -> Option<&SparseArray<EntityId>> {
         Some(&self.sparse)
     }
+    fn entity_ids(&self, _: Private) -> Option<&[EntityId]> {
+        Some(&self.dense)
+    }
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+    fn validate_integrity(
+        &self,
+        entities: &Entities,
+        current: TrackingTimestamp,
+        errors: &mut Vec<error::IntegrityError>,
+        _: Private,
+    ) {
+        self.private_validate_integrity(type_name::<Self>(), entities, current, errors);
+    }
+    fn deleted_since(
+        &self,
+        timestamp: TrackingTimestamp,
+        current: TrackingTimestamp,
+        entities: &mut Vec<EntityId>,
+        _: Private,
+    ) {
+        self.private_deleted_since(timestamp, current, entities);
+    }
+    fn take_deferred_drops(&mut self, drop_queue: &mut DropQueue, _: Private) {
+        drop_queue.push(core::mem::take(&mut self.deferred_drops));
+    }
+    fn can_archive(&self, _entity: EntityId, _: Private) -> bool {
+        true
+    }
+    fn archive(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+        archived: &mut ArchivedEntity,
+        _: Private,
+    ) {
+        if let Some(component) = self.dyn_remove(entity, current) {
+            archived.push(component);
+        }
+    }
     fn clear_all_removed_and_deleted(&mut self) {
-        self.deletion_data.clear();
+        self.private_clear_deleted();
         self.removal_data.clear();
     }
     fn clear_all_removed_and_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
-        self.deletion_data
-            .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        self.private_clear_deleted_older_than(timestamp);
 
         self.removal_data
             .retain(|(_, t)| timestamp.is_older_than(*t));
@@ -810,10 +1448,12 @@ impl<T: 'static + Component + Send + Sync> Storage for SparseSet<T> {
         other_current: TrackingTimestamp,
     ) {
         if let Some(component) = self.dyn_remove(from, current) {
-            let other_sparse_set = other_all_storages.exclusive_storage_or_insert_mut(
-                StorageId::of::<SparseSet<T>>(),
-                SparseSet::<T>::new,
-            );
+            let mut other_sparse_set = other_all_storages
+                .exclusive_storage_or_insert_mut(
+                    StorageId::of::<SparseSet<T>>(),
+                    SparseSet::<T>::new,
+                )
+                .unwrap();
 
             let _ = other_sparse_set.insert(to, component, other_current);
         }
@@ -830,39 +1470,60 @@ impl<T: 'static + Component + Sync> Storage for NonSend<SparseSet<T>> {
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
+    #[inline]
+    fn disable(&mut self, entity: EntityId, _: Private) -> bool {
+        self.private_disable(entity)
+    }
+    #[inline]
+    fn enable(&mut self, entity: EntityId, _: Private) -> bool {
+        self.private_enable(entity)
+    }
+    #[inline]
+    fn bump_generation(&mut self, entity: EntityId, new_entity: EntityId, _: Private) {
+        self.private_bump_generation(entity, new_entity);
+    }
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
-        Some(StorageMemoryUsage {
-            storage_name: type_name::<Self>().into(),
-            allocated_memory_bytes: self.sparse.reserved_memory()
-                + (self.dense.capacity() * size_of::<EntityId>())
-                + (self.data.capacity() * size_of::<T>())
-                + (self.insertion_data.capacity() * size_of::<u32>())
-                + (self.deletion_data.capacity() * size_of::<(T, EntityId)>())
-                + (self.removal_data.capacity() * size_of::<EntityId>())
-                + size_of::<Self>(),
-            used_memory_bytes: self.sparse.used_memory()
-                + (self.dense.len() * size_of::<EntityId>())
-                + (self.data.len() * size_of::<T>())
-                + (self.insertion_data.len() * size_of::<u32>())
-                + (self.deletion_data.len() * size_of::<(EntityId, T)>())
-                + (self.removal_data.len() * size_of::<EntityId>())
-                + size_of::<Self>(),
-            component_count: self.len(),
-        })
+        Some(self.private_memory_usage(type_name::<Self>()))
     }
-    fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
+    fn sparse_array(&self) -> Option<&SparseArray<EntityId>> {
         Some(&self.sparse)
     }
+    fn entity_ids(&self, _: Private) -> Option<&[EntityId]> {
+        Some(&self.dense)
+    }
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    fn validate_integrity(
+        &self,
+        entities: &Entities,
+        current: TrackingTimestamp,
+        errors: &mut Vec<error::IntegrityError>,
+        _: Private,
+    ) {
+        self.private_validate_integrity(type_name::<Self>(), entities, current, errors);
+    }
+    fn deleted_since(
+        &self,
+        timestamp: TrackingTimestamp,
+        current: TrackingTimestamp,
+        entities: &mut Vec<EntityId>,
+        _: Private,
+    ) {
+        self.private_deleted_since(timestamp, current, entities);
+    }
+    fn take_local_deferred_drops(&mut self, drop_queue: &mut LocalDropQueue, _: Private) {
+        drop_queue.push(core::mem::take(&mut self.deferred_drops));
+    }
+    fn can_archive(&self, entity: EntityId, _: Private) -> bool {
+        !self.sparse.contains(entity) && !self.disabled.contains_key(&entity)
+    }
     fn clear_all_removed_and_deleted(&mut self) {
-        self.deletion_data.clear();
+        self.private_clear_deleted();
         self.removal_data.clear();
     }
     fn clear_all_removed_and_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
-        self.deletion_data
-            .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        self.private_clear_deleted_older_than(timestamp);
 
         self.removal_data
             .retain(|(_, t)| timestamp.is_older_than(*t));
@@ -878,10 +1539,12 @@ impl<T: 'static + Component + Sync> Storage for NonSend<SparseSet<T>> {
         other_current: TrackingTimestamp,
     ) {
         if let Some(component) = self.dyn_remove(from, current) {
-            let other_sparse_set = other_all_storages.exclusive_storage_or_insert_non_send_mut(
-                StorageId::of::<NonSend<SparseSet<T>>>(),
-                || NonSend(SparseSet::<T>::new()),
-            );
+            let mut other_sparse_set = other_all_storages
+                .exclusive_storage_or_insert_non_send_mut(
+                    StorageId::of::<NonSend<SparseSet<T>>>(),
+                    || NonSend(SparseSet::<T>::new()),
+                )
+                .unwrap();
 
             let _ = other_sparse_set.insert(to, component, other_current);
         }
@@ -898,39 +1561,60 @@ impl<T: 'static + Component + Send> Storage for NonSync<SparseSet<T>> {
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
+    #[inline]
+    fn disable(&mut self, entity: EntityId, _: Private) -> bool {
+        self.private_disable(entity)
+    }
+    #[inline]
+    fn enable(&mut self, entity: EntityId, _: Private) -> bool {
+        self.private_enable(entity)
+    }
+    #[inline]
+    fn bump_generation(&mut self, entity: EntityId, new_entity: EntityId, _: Private) {
+        self.private_bump_generation(entity, new_entity);
+    }
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
-        Some(StorageMemoryUsage {
-            storage_name: type_name::<Self>().into(),
-            allocated_memory_bytes: self.sparse.reserved_memory()
-                + (self.dense.capacity() * size_of::<EntityId>())
-                + (self.data.capacity() * size_of::<T>())
-                + (self.insertion_data.capacity() * size_of::<u32>())
-                + (self.deletion_data.capacity() * size_of::<(T, EntityId)>())
-                + (self.removal_data.capacity() * size_of::<EntityId>())
-                + size_of::<Self>(),
-            used_memory_bytes: self.sparse.used_memory()
-                + (self.dense.len() * size_of::<EntityId>())
-                + (self.data.len() * size_of::<T>())
-                + (self.insertion_data.len() * size_of::<u32>())
-                + (self.deletion_data.len() * size_of::<(EntityId, T)>())
-                + (self.removal_data.len() * size_of::<EntityId>())
-                + size_of::<Self>(),
-            component_count: self.len(),
-        })
+        Some(self.private_memory_usage(type_name::<Self>()))
     }
-    fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
+    fn sparse_array(&self) -> Option<&SparseArray<EntityId>> {
         Some(&self.sparse)
     }
+    fn entity_ids(&self, _: Private) -> Option<&[EntityId]> {
+        Some(&self.dense)
+    }
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    fn validate_integrity(
+        &self,
+        entities: &Entities,
+        current: TrackingTimestamp,
+        errors: &mut Vec<error::IntegrityError>,
+        _: Private,
+    ) {
+        self.private_validate_integrity(type_name::<Self>(), entities, current, errors);
+    }
+    fn deleted_since(
+        &self,
+        timestamp: TrackingTimestamp,
+        current: TrackingTimestamp,
+        entities: &mut Vec<EntityId>,
+        _: Private,
+    ) {
+        self.private_deleted_since(timestamp, current, entities);
+    }
+    fn take_deferred_drops(&mut self, drop_queue: &mut DropQueue, _: Private) {
+        drop_queue.push_non_sync(core::mem::take(&mut self.deferred_drops));
+    }
+    fn can_archive(&self, entity: EntityId, _: Private) -> bool {
+        !self.sparse.contains(entity) && !self.disabled.contains_key(&entity)
+    }
     fn clear_all_removed_and_deleted(&mut self) {
-        self.deletion_data.clear();
+        self.private_clear_deleted();
         self.removal_data.clear();
     }
     fn clear_all_removed_and_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
-        self.deletion_data
-            .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        self.private_clear_deleted_older_than(timestamp);
 
         self.removal_data
             .retain(|(_, t)| timestamp.is_older_than(*t));
@@ -946,10 +1630,12 @@ impl<T: 'static + Component + Send> Storage for NonSync<SparseSet<T>> {
         other_current: TrackingTimestamp,
     ) {
         if let Some(component) = self.dyn_remove(from, current) {
-            let other_sparse_set = other_all_storages.exclusive_storage_or_insert_non_sync_mut(
-                StorageId::of::<NonSync<SparseSet<T>>>(),
-                || NonSync(SparseSet::<T>::new()),
-            );
+            let mut other_sparse_set = other_all_storages
+                .exclusive_storage_or_insert_non_sync_mut(
+                    StorageId::of::<NonSync<SparseSet<T>>>(),
+                    || NonSync(SparseSet::<T>::new()),
+                )
+                .unwrap();
 
             let _ = other_sparse_set.insert(to, component, other_current);
         }
@@ -966,39 +1652,60 @@ impl<T: 'static + Component> Storage for NonSendSync<SparseSet<T>> {
     fn clear(&mut self, current: TrackingTimestamp) {
         self.private_clear(current);
     }
+    #[inline]
+    fn disable(&mut self, entity: EntityId, _: Private) -> bool {
+        self.private_disable(entity)
+    }
+    #[inline]
+    fn enable(&mut self, entity: EntityId, _: Private) -> bool {
+        self.private_enable(entity)
+    }
+    #[inline]
+    fn bump_generation(&mut self, entity: EntityId, new_entity: EntityId, _: Private) {
+        self.private_bump_generation(entity, new_entity);
+    }
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
-        Some(StorageMemoryUsage {
-            storage_name: type_name::<Self>().into(),
-            allocated_memory_bytes: self.sparse.reserved_memory()
-                + (self.dense.capacity() * size_of::<EntityId>())
-                + (self.data.capacity() * size_of::<T>())
-                + (self.insertion_data.capacity() * size_of::<u32>())
-                + (self.deletion_data.capacity() * size_of::<(T, EntityId)>())
-                + (self.removal_data.capacity() * size_of::<EntityId>())
-                + size_of::<Self>(),
-            used_memory_bytes: self.sparse.used_memory()
-                + (self.dense.len() * size_of::<EntityId>())
-                + (self.data.len() * size_of::<T>())
-                + (self.insertion_data.len() * size_of::<u32>())
-                + (self.deletion_data.len() * size_of::<(EntityId, T)>())
-                + (self.removal_data.len() * size_of::<EntityId>())
-                + size_of::<Self>(),
-            component_count: self.len(),
-        })
+        Some(self.private_memory_usage(type_name::<Self>()))
     }
-    fn sparse_array(&self) -> Option<&SparseArray<EntityId, BUCKET_SIZE>> {
+    fn sparse_array(&self) -> Option<&SparseArray<EntityId>> {
         Some(&self.sparse)
     }
+    fn entity_ids(&self, _: Private) -> Option<&[EntityId]> {
+        Some(&self.dense)
+    }
     fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+    fn validate_integrity(
+        &self,
+        entities: &Entities,
+        current: TrackingTimestamp,
+        errors: &mut Vec<error::IntegrityError>,
+        _: Private,
+    ) {
+        self.private_validate_integrity(type_name::<Self>(), entities, current, errors);
+    }
+    fn deleted_since(
+        &self,
+        timestamp: TrackingTimestamp,
+        current: TrackingTimestamp,
+        entities: &mut Vec<EntityId>,
+        _: Private,
+    ) {
+        self.private_deleted_since(timestamp, current, entities);
+    }
+    fn take_local_deferred_drops(&mut self, drop_queue: &mut LocalDropQueue, _: Private) {
+        drop_queue.push(core::mem::take(&mut self.deferred_drops));
+    }
+    fn can_archive(&self, entity: EntityId, _: Private) -> bool {
+        !self.sparse.contains(entity) && !self.disabled.contains_key(&entity)
+    }
     fn clear_all_removed_and_deleted(&mut self) {
-        self.deletion_data.clear();
+        self.private_clear_deleted();
         self.removal_data.clear();
     }
     fn clear_all_removed_and_deleted_older_than_timestamp(&mut self, timestamp: TrackingTimestamp) {
-        self.deletion_data
-            .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        self.private_clear_deleted_older_than(timestamp);
 
         self.removal_data
             .retain(|(_, t)| timestamp.is_older_than(*t));
@@ -1014,11 +1721,12 @@ impl<T: 'static + Component> Storage for NonSendSync<SparseSet<T>> {
         other_current: TrackingTimestamp,
     ) {
         if let Some(component) = self.dyn_remove(from, current) {
-            let other_sparse_set = other_all_storages
+            let mut other_sparse_set = other_all_storages
                 .exclusive_storage_or_insert_non_send_sync_mut(
                     StorageId::of::<NonSendSync<SparseSet<T>>>(),
                     || NonSendSync(SparseSet::<T>::new()),
-                );
+                )
+                .unwrap();
 
             let _ = other_sparse_set.insert(to, component, other_current);
         }
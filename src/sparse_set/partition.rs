@@ -0,0 +1,239 @@
+use crate::entity_id::EntityId;
+use crate::r#mut::Mut;
+use crate::tracking::TrackingTimestamp;
+
+/// Shared access to a contiguous range of a storage's components.
+///
+/// Partitions can be split further and sent to other threads, making it possible to process a storage with any executor.
+///
+/// Obtained with [`View::partition`](crate::View::partition).
+pub struct Partition<'a, T> {
+    ids: &'a [EntityId],
+    data: &'a [T],
+}
+
+impl<'a, T> Partition<'a, T> {
+    #[inline]
+    pub(crate) fn new(ids: &'a [EntityId], data: &'a [T]) -> Self {
+        Partition { ids, data }
+    }
+    /// Returns the number of components in the partition.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+    /// Returns `true` if the partition doesn't contain any component.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+    /// Returns the entities owning a component, aligned with the components.
+    #[inline]
+    pub fn ids(&self) -> &'a [EntityId] {
+        self.ids
+    }
+    /// Returns the components.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T] {
+        self.data
+    }
+    /// Returns the component at `index` in the partition.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        self.data.get(index)
+    }
+    /// Iterates the components and the entities owning them.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &'a T)> {
+        self.ids.iter().copied().zip(self.data)
+    }
+    /// Divides the partition in two at `mid`.\
+    /// The first partition contains the components in `[0, mid)`, the second `[mid, len)`.
+    ///
+    /// ### Panics
+    ///
+    /// - `mid > len`.
+    #[inline]
+    #[track_caller]
+    pub fn split_at(&self, mid: usize) -> (Partition<'a, T>, Partition<'a, T>) {
+        let (ids_left, ids_right) = self.ids.split_at(mid);
+        let (data_left, data_right) = self.data.split_at(mid);
+
+        (
+            Partition::new(ids_left, data_left),
+            Partition::new(ids_right, data_right),
+        )
+    }
+    /// Divides the partition in partitions of `chunk_size` components.\
+    /// The last partition can be smaller.
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    #[track_caller]
+    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = Partition<'a, T>> {
+        assert!(chunk_size != 0, "chunk_size must not be 0.");
+
+        self.ids
+            .chunks(chunk_size)
+            .zip(self.data.chunks(chunk_size))
+            .map(|(ids, data)| Partition::new(ids, data))
+    }
+}
+
+impl<T> Clone for Partition<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Partition<'_, T> {}
+
+/// Exclusive access to a contiguous range of a storage's components.
+///
+/// Partitions can be split further and sent to other threads, making it possible to process a storage with any executor.\
+/// Components accessed mutably are flagged as modified if the storage tracks modification.\
+/// Entities can't be added nor removed.
+///
+/// Obtained with [`ViewMut::partition_mut`](crate::ViewMut::partition_mut).
+pub struct PartitionMut<'a, T> {
+    ids: &'a [EntityId],
+    data: &'a mut [T],
+    modification_data: Option<&'a mut [TrackingTimestamp]>,
+    current: TrackingTimestamp,
+}
+
+impl<'a, T> PartitionMut<'a, T> {
+    #[inline]
+    pub(crate) fn new(
+        ids: &'a [EntityId],
+        data: &'a mut [T],
+        modification_data: Option<&'a mut [TrackingTimestamp]>,
+        current: TrackingTimestamp,
+    ) -> Self {
+        PartitionMut {
+            ids,
+            data,
+            modification_data,
+            current,
+        }
+    }
+    /// Returns the number of components in the partition.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+    /// Returns `true` if the partition doesn't contain any component.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+    /// Returns the entities owning a component, aligned with the components.
+    #[inline]
+    pub fn ids(&self) -> &'a [EntityId] {
+        self.ids
+    }
+    /// Returns the components.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.data
+    }
+    /// Returns the component at `index` in the partition.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.data.get(index)
+    }
+    /// Returns the component at `index` in the partition.\
+    /// The component is flagged as modified when accessed mutably.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<Mut<'_, T>> {
+        let data = self.data.get_mut(index)?;
+
+        Some(Mut {
+            flag: self
+                .modification_data
+                .as_deref_mut()
+                .map(|modification_data| &mut modification_data[index]),
+            current: self.current,
+            data,
+        })
+    }
+    /// Iterates the components and the entities owning them.\
+    /// Components are flagged as modified when accessed mutably.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (EntityId, Mut<'_, T>)> {
+        let current = self.current;
+        let mut modification_data = self
+            .modification_data
+            .as_deref_mut()
+            .map(|modification_data| modification_data.iter_mut());
+
+        self.ids
+            .iter()
+            .copied()
+            .zip(self.data.iter_mut())
+            .map(move |(id, data)| {
+                let flag = modification_data
+                    .as_mut()
+                    .and_then(|modification_data| modification_data.next());
+
+                (
+                    id,
+                    Mut {
+                        flag,
+                        current,
+                        data,
+                    },
+                )
+            })
+    }
+    /// Divides the partition in two at `mid`.\
+    /// The first partition contains the components in `[0, mid)`, the second `[mid, len)`.
+    ///
+    /// ### Panics
+    ///
+    /// - `mid > len`.
+    #[track_caller]
+    pub fn split_at_mut(self, mid: usize) -> (PartitionMut<'a, T>, PartitionMut<'a, T>) {
+        let (ids_left, ids_right) = self.ids.split_at(mid);
+        let (data_left, data_right) = self.data.split_at_mut(mid);
+        let (modification_left, modification_right) = match self.modification_data {
+            Some(modification_data) => {
+                let (left, right) = modification_data.split_at_mut(mid);
+                (Some(left), Some(right))
+            }
+            None => (None, None),
+        };
+
+        (
+            PartitionMut::new(ids_left, data_left, modification_left, self.current),
+            PartitionMut::new(ids_right, data_right, modification_right, self.current),
+        )
+    }
+    /// Divides the partition in partitions of `chunk_size` components.\
+    /// The last partition can be smaller.
+    ///
+    /// ### Panics
+    ///
+    /// - `chunk_size` is 0.
+    #[track_caller]
+    pub fn chunks_mut(self, chunk_size: usize) -> impl Iterator<Item = PartitionMut<'a, T>> {
+        assert!(chunk_size != 0, "chunk_size must not be 0.");
+
+        let mut rest = Some(self);
+
+        core::iter::from_fn(move || {
+            let partition = rest.take()?;
+
+            if partition.is_empty() {
+                return None;
+            }
+
+            let mid = chunk_size.min(partition.len());
+            let (chunk, tail) = partition.split_at_mut(mid);
+            rest = Some(tail);
+
+            Some(chunk)
+        })
+    }
+}
@@ -0,0 +1,213 @@
+use crate::entity_id::EntityId;
+use core::marker::PhantomData;
+
+/// Raw access to the components of a storage and the entities owning them.
+///
+/// Components are stored contiguously, the component at index `i` belongs to the entity at index `i` of [`ids`](RawWindow::ids).\
+/// The window borrows the view it was created from, the pointers are valid as long as the window is alive.
+///
+/// Obtained with [`View::raw_window`](crate::View::raw_window).
+pub struct RawWindow<'a, T> {
+    ids: &'a [EntityId],
+    data: &'a [T],
+}
+
+impl<'a, T> RawWindow<'a, T> {
+    #[inline]
+    pub(crate) fn new(ids: &'a [EntityId], data: &'a [T]) -> Self {
+        RawWindow { ids, data }
+    }
+    /// Creates a window from raw pointers, for example when it was handed over through FFI.
+    ///
+    /// ### Safety
+    ///
+    /// - `ids` and `data` must be non-null, even when `len` is 0, valid for reads of `len` elements and properly aligned.
+    /// - The memory must not be mutated for the lifetime `'a`.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, RawWindow, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(7));
+    ///
+    /// let u32s = world.borrow::<View<U32>>().unwrap();
+    /// let window = u32s.raw_window();
+    ///
+    /// let window = unsafe {
+    ///     RawWindow::<U32>::from_raw_parts(window.ids_ptr(), window.data_ptr(), window.len())
+    /// };
+    ///
+    /// assert_eq!(window.get(entity).unwrap().0, 7);
+    /// ```
+    #[inline]
+    pub unsafe fn from_raw_parts(ids: *const EntityId, data: *const T, len: usize) -> Self {
+        RawWindow {
+            ids: core::slice::from_raw_parts(ids, len),
+            data: core::slice::from_raw_parts(data, len),
+        }
+    }
+    /// Returns the number of components in the window.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+    /// Returns `true` if the window doesn't contain any component.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+    /// Returns the entities owning a component, aligned with the components.
+    #[inline]
+    pub fn ids(&self) -> &'a [EntityId] {
+        self.ids
+    }
+    /// Returns the components.
+    #[inline]
+    pub fn data(&self) -> &'a [T] {
+        self.data
+    }
+    /// Returns `entity`'s component if it's part of the window.\
+    /// This is a linear search over [`ids`](RawWindow::ids).
+    #[inline]
+    pub fn get(&self, entity: EntityId) -> Option<&'a T> {
+        let index = self.ids.iter().position(|&id| id == entity)?;
+
+        self.data.get(index)
+    }
+    /// Returns a pointer to the first entity.\
+    /// [`len`](RawWindow::len) entities can be read from it.
+    #[inline]
+    pub fn ids_ptr(&self) -> *const EntityId {
+        self.ids.as_ptr()
+    }
+    /// Returns a pointer to the first component.\
+    /// [`len`](RawWindow::len) components can be read from it.
+    #[inline]
+    pub fn data_ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+}
+
+impl<T> Clone for RawWindow<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RawWindow<'_, T> {}
+
+/// Raw mutable access to the components of a storage and the entities owning them.
+///
+/// Components are stored contiguously, the component at index `i` belongs to the entity at index `i` of [`ids`](RawWindowMut::ids).\
+/// The window exclusively borrows the view it was created from, the pointers are valid as long as the window is alive.
+///
+/// Modifications made through this window are not tracked.\
+/// Entities can't be added nor removed, only components can be modified in place.
+///
+/// Obtained with [`ViewMut::raw_window_mut`](crate::ViewMut::raw_window_mut).
+pub struct RawWindowMut<'a, T> {
+    ids: &'a [EntityId],
+    data: *mut T,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+unsafe impl<T: Send> Send for RawWindowMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for RawWindowMut<'_, T> {}
+
+impl<'a, T> RawWindowMut<'a, T> {
+    #[inline]
+    pub(crate) fn new(ids: &'a [EntityId], data: &'a mut [T]) -> Self {
+        RawWindowMut {
+            ids,
+            data: data.as_mut_ptr(),
+            _phantom: PhantomData,
+        }
+    }
+    /// Creates a window from raw pointers, for example when it was handed over through FFI.
+    ///
+    /// ### Safety
+    ///
+    /// - `ids` and `data` must be non-null, even when `len` is 0, and properly aligned.
+    /// - `ids` must be valid for reads of `len` elements.
+    /// - `data` must be valid for reads and writes of `len` elements.
+    /// - No other access to the memory must happen for the lifetime `'a`.
+    #[inline]
+    pub unsafe fn from_raw_parts(ids: *const EntityId, data: *mut T, len: usize) -> Self {
+        RawWindowMut {
+            ids: core::slice::from_raw_parts(ids, len),
+            data,
+            _phantom: PhantomData,
+        }
+    }
+    /// Returns the number of components in the window.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+    /// Returns `true` if the window doesn't contain any component.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+    /// Returns the entities owning a component, aligned with the components.
+    #[inline]
+    pub fn ids(&self) -> &'a [EntityId] {
+        self.ids
+    }
+    /// Returns the components.
+    #[inline]
+    pub fn data(&self) -> &[T] {
+        // SAFE the window has exclusive access to `len` components
+        unsafe { core::slice::from_raw_parts(self.data, self.ids.len()) }
+    }
+    /// Returns the components.\
+    /// Modifications are not tracked.
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [T] {
+        // SAFE the window has exclusive access to `len` components
+        unsafe { core::slice::from_raw_parts_mut(self.data, self.ids.len()) }
+    }
+    /// Returns `entity`'s component if it's part of the window.\
+    /// This is a linear search over [`ids`](RawWindowMut::ids).
+    #[inline]
+    pub fn get(&self, entity: EntityId) -> Option<&T> {
+        let index = self.ids.iter().position(|&id| id == entity)?;
+
+        self.data().get(index)
+    }
+    /// Returns `entity`'s component if it's part of the window.\
+    /// This is a linear search over [`ids`](RawWindowMut::ids).\
+    /// Modifications are not tracked.
+    #[inline]
+    pub fn get_mut(&mut self, entity: EntityId) -> Option<&mut T> {
+        let index = self.ids.iter().position(|&id| id == entity)?;
+
+        self.data_mut().get_mut(index)
+    }
+    /// Returns a pointer to the first entity.\
+    /// [`len`](RawWindowMut::len) entities can be read from it.
+    #[inline]
+    pub fn ids_ptr(&self) -> *const EntityId {
+        self.ids.as_ptr()
+    }
+    /// Returns a pointer to the first component.\
+    /// [`len`](RawWindowMut::len) components can be read from it.
+    #[inline]
+    pub fn data_ptr(&self) -> *const T {
+        self.data
+    }
+    /// Returns a pointer to the first component.\
+    /// [`len`](RawWindowMut::len) components can be read from and written to it.\
+    /// Modifications are not tracked.
+    #[inline]
+    pub fn data_mut_ptr(&mut self) -> *mut T {
+        self.data
+    }
+}
@@ -3,6 +3,7 @@ use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::sparse_set::SparseSet;
 use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
 #[cfg(doc)]
 use crate::world::World;
 
@@ -21,12 +22,22 @@ impl<T: Send + Sync + Component> TupleRemove for T {
     fn remove(all_storages: &mut AllStorages, entity: EntityId) -> Self::Out {
         let current = all_storages.get_current();
 
-        all_storages
-            .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
-            .dyn_remove(entity, current)
+        remove_component(all_storages, entity, current)
     }
 }
 
+#[track_caller]
+fn remove_component<T: Send + Sync + Component>(
+    all_storages: &mut AllStorages,
+    entity: EntityId,
+    current: TrackingTimestamp,
+) -> Option<T> {
+    all_storages
+        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::new)
+        .unwrap()
+        .dyn_remove(entity, current)
+}
+
 macro_rules! impl_remove_component {
     ($(($type: ident, $index: tt))+) => {
         impl<$($type: Send + Sync + Component,)+> TupleRemove for ($($type,)+) {
@@ -36,9 +47,7 @@ macro_rules! impl_remove_component {
                 let current = all_storages.get_current();
 
                 ($(
-                    all_storages
-                        .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<$type>>(), SparseSet::new)
-                        .dyn_remove(entity, current),
+                    remove_component::<$type>(all_storages, entity, current),
                 )+)
             }
         }
@@ -0,0 +1,227 @@
+use super::SparseArray;
+use crate::entities::Entities;
+use crate::entity_id::EntityId;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Container associating data to entities outside of the `World`.
+///
+/// Unlike components, the data is not visible to views nor workloads.\
+/// Each entry remembers its entity's generation, an entry is only accessible with the `EntityId` it was inserted with.\
+/// Entries are not removed when their entity is deleted, they can be removed with [`retain_alive`](SecondaryMap::retain_alive).
+///
+/// ### Example
+///
+/// ```
+/// use shipyard::{EntitiesView, SecondaryMap, World};
+///
+/// let mut world = World::new();
+/// let mut names = SecondaryMap::new();
+///
+/// let entity = world.add_entity(());
+/// names.insert(entity, "player").unwrap();
+///
+/// assert_eq!(names.get(entity), Some(&"player"));
+///
+/// world.delete_entity(entity);
+/// let new_entity = world.add_entity(());
+///
+/// // `new_entity` reuses `entity`'s index but has a different generation
+/// assert_eq!(names.get(new_entity), None);
+///
+/// names.retain_alive(&world.borrow::<EntitiesView>().unwrap());
+/// assert!(names.is_empty());
+/// ```
+pub struct SecondaryMap<T> {
+    sparse: SparseArray<EntityId>,
+    dense: Vec<EntityId>,
+    data: Vec<T>,
+}
+
+impl<T> SecondaryMap<T> {
+    /// Creates an empty `SecondaryMap`.
+    #[inline]
+    pub fn new() -> Self {
+        SecondaryMap {
+            sparse: SparseArray::new(),
+            dense: Vec::new(),
+            data: Vec::new(),
+        }
+    }
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.dense.len()
+    }
+    /// Returns `true` if the map doesn't contain any entry.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+    /// Returns `true` if `entity` has an entry.
+    #[inline]
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.index_of(entity).is_some()
+    }
+    #[inline]
+    fn index_of(&self, entity: EntityId) -> Option<usize> {
+        self.sparse.get(entity).and_then(|sparse_entity| {
+            if entity.gen() == sparse_entity.gen() {
+                Some(sparse_entity.uindex())
+            } else {
+                None
+            }
+        })
+    }
+    /// Inserts `value` for `entity`.\
+    /// Returns the previous value if `entity` already had an entry.
+    ///
+    /// An entry of an entity with the same index but a smaller generation is overwritten.\
+    /// If the entry belongs to an entity with a larger generation, nothing is inserted and `value` is returned as an error.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is dead.
+    #[track_caller]
+    pub fn insert(&mut self, entity: EntityId, value: T) -> Result<Option<T>, T> {
+        let same_entity = self.contains(entity);
+
+        self.replace(entity, value)
+            .map(|old_value| old_value.filter(|_| same_entity))
+    }
+    /// Inserts `value` for `entity` and returns the value previously stored at `entity`'s index, whatever its generation.\
+    /// If the entry belongs to an entity with a larger generation, `value` is returned as an error and nothing is inserted.
+    ///
+    /// ### Panics
+    ///
+    /// - `entity` is dead.
+    #[track_caller]
+    pub(crate) fn replace(&mut self, entity: EntityId, value: T) -> Result<Option<T>, T> {
+        self.sparse.allocate_at(entity);
+
+        // SAFE we just allocated the bucket
+        let sparse_entity = unsafe { self.sparse.get_mut_unchecked(entity) };
+
+        if sparse_entity.is_dead() {
+            *sparse_entity =
+                EntityId::new_from_index_and_gen(self.dense.len() as u64, entity.gen());
+
+            self.dense.push(entity);
+            self.data.push(value);
+
+            Ok(None)
+        } else if entity.gen() >= sparse_entity.gen() {
+            let index = sparse_entity.uindex();
+
+            sparse_entity.copy_gen(entity);
+            self.dense[index] = entity;
+
+            Ok(Some(core::mem::replace(&mut self.data[index], value)))
+        } else {
+            Err(value)
+        }
+    }
+    /// Returns a reference to `entity`'s value.
+    #[inline]
+    pub fn get(&self, entity: EntityId) -> Option<&T> {
+        self.index_of(entity).map(|index| &self.data[index])
+    }
+    /// Returns a mutable reference to `entity`'s value.
+    #[inline]
+    pub fn get_mut(&mut self, entity: EntityId) -> Option<&mut T> {
+        self.index_of(entity)
+            .map(move |index| &mut self.data[index])
+    }
+    /// Removes `entity`'s entry and returns its value.
+    pub fn remove(&mut self, entity: EntityId) -> Option<T> {
+        let index = self.index_of(entity)?;
+
+        unsafe {
+            *self.sparse.get_mut_unchecked(entity) = EntityId::dead();
+        }
+
+        self.dense.swap_remove(index);
+        let value = self.data.swap_remove(index);
+
+        if index < self.dense.len() {
+            unsafe {
+                let last = *self.dense.get_unchecked(index);
+                self.sparse.get_mut_unchecked(last).set_index(index as u64);
+            }
+        }
+
+        Some(value)
+    }
+    /// Removes the entries of all entities that are not alive in `entities`.
+    ///
+    /// This can be called from a system taking an [`EntitiesView`](crate::EntitiesView).
+    pub fn retain_alive(&mut self, entities: &Entities) {
+        self.retain(|entity, _| entities.is_alive(entity));
+    }
+    /// Removes all entries for which `f(entity, &value)` returns `false`.
+    pub fn retain(&mut self, mut f: impl FnMut(EntityId, &T) -> bool) {
+        let mut index = 0;
+
+        while index < self.dense.len() {
+            let entity = self.dense[index];
+
+            if f(entity, &self.data[index]) {
+                index += 1;
+            } else {
+                self.remove(entity);
+            }
+        }
+    }
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        for &entity in &self.dense {
+            unsafe {
+                *self.sparse.get_mut_unchecked(entity) = EntityId::dead();
+            }
+        }
+
+        self.dense.clear();
+        self.data.clear();
+    }
+    /// Returns an iterator over all entities and their value.
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &T)> {
+        self.dense.iter().copied().zip(&self.data)
+    }
+    /// Returns an iterator over all entities and a mutable reference to their value.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (EntityId, &mut T)> {
+        self.dense.iter().copied().zip(&mut self.data)
+    }
+}
+
+impl<T> Default for SecondaryMap<T> {
+    #[inline]
+    fn default() -> Self {
+        SecondaryMap::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SecondaryMap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T> core::ops::Index<EntityId> for SecondaryMap<T> {
+    type Output = T;
+
+    #[track_caller]
+    #[inline]
+    fn index(&self, entity: EntityId) -> &Self::Output {
+        self.get(entity)
+            .unwrap_or_else(|| panic!("{:?} does not have an entry in this SecondaryMap.", entity))
+    }
+}
+
+impl<T> core::ops::IndexMut<EntityId> for SecondaryMap<T> {
+    #[track_caller]
+    #[inline]
+    fn index_mut(&mut self, entity: EntityId) -> &mut Self::Output {
+        self.get_mut(entity)
+            .unwrap_or_else(|| panic!("{:?} does not have an entry in this SecondaryMap.", entity))
+    }
+}
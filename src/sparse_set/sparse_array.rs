@@ -1,3 +1,4 @@
+use super::BUCKET_SIZE;
 use crate::entity_id::EntityId;
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
@@ -8,101 +9,304 @@ use core::mem::size_of;
 
 /// Internal part of a [`SparseSet`].
 ///
+/// Pages before the first used one are not stored, storages only used by entities with high indices don't pay for the lower ones.
+/// The page size is chosen at runtime with [`SparseSet::set_sparse_page_size`].
+///
+/// [`SparseSet`]: crate::sparse_set::SparseSet
+/// [`SparseSet::set_sparse_page_size`]: crate::sparse_set::SparseSet::set_sparse_page_size
+pub struct SparseArray<T> {
+    pages: Vec<Option<Box<[T]>>>,
+    /// Page index of `pages[0]`.
+    first_page: usize,
+    /// `log2` of the number of entries in a page.
+    page_shift: u32,
+}
+
+/// Returns the position of `index` in `pages` and its position in the page.\
+/// Indices before `first_page` return a page position out of bounds.
+#[inline]
+pub(super) fn locate(index: usize, first_page: usize, page_shift: u32) -> (usize, usize) {
+    (
+        (index >> page_shift).wrapping_sub(first_page),
+        index & ((1 << page_shift) - 1),
+    )
+}
+
+/// Occupancy of a [`SparseSet`]'s sparse array.
+///
+/// The sparse array is split in pages of [`page_size`] entries, pages are only allocated once an entity lands in them.\
+/// Returned by [`SparseSet::sparse_stats`].
+///
 /// [`SparseSet`]: crate::sparse_set::SparseSet
-pub struct SparseArray<T, const N: usize>(Vec<Option<Box<[T; N]>>>);
+/// [`SparseSet::sparse_stats`]: crate::sparse_set::SparseSet::sparse_stats
+/// [`page_size`]: SparseArrayStats::page_size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SparseArrayStats {
+    /// Number of entries in a page.
+    pub page_size: usize,
+    /// Number of pages, allocated or not.
+    pub pages: usize,
+    /// Number of allocated pages.
+    pub allocated_pages: usize,
+    /// Number of allocated pages without any entity.\
+    /// These are freed by [`SparseSet::compact_sparse`].
+    ///
+    /// [`SparseSet::compact_sparse`]: crate::sparse_set::SparseSet::compact_sparse
+    pub holes: usize,
+    /// Number of entities across all pages.
+    pub occupied: usize,
+    /// Number of entities in the fullest page.
+    pub densest_page_occupancy: usize,
+}
 
-impl<T, const N: usize> SparseArray<T, N> {
+impl SparseArrayStats {
+    /// Ratio of allocated entries used by an entity, between 0 and 1.\
+    /// Returns 1 when no page is allocated.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn load_factor(&self) -> f32 {
+        if self.allocated_pages == 0 {
+            1.0
+        } else {
+            self.occupied as f32 / (self.allocated_pages * self.page_size) as f32
+        }
+    }
+}
+
+impl<T> SparseArray<T> {
     #[inline]
     pub(super) fn new() -> Self {
-        SparseArray(Vec::new())
+        SparseArray {
+            pages: Vec::new(),
+            first_page: 0,
+            page_shift: BUCKET_SIZE.trailing_zeros(),
+        }
     }
     #[inline]
     pub(crate) fn len(&self) -> usize {
-        self.0.len()
+        self.pages.len()
+    }
+    #[inline]
+    pub(super) fn first_page(&self) -> usize {
+        self.first_page
+    }
+    #[inline]
+    pub(super) fn page_shift(&self) -> u32 {
+        self.page_shift
     }
+    /// Returns the number of entries in a page.
     #[inline]
-    pub(super) fn as_ptr(&self) -> *const Option<Box<[T; N]>> {
-        self.0.as_ptr()
+    pub(crate) fn page_size(&self) -> usize {
+        1 << self.page_shift
     }
     #[inline]
-    pub(super) fn as_mut_ptr(&mut self) -> *mut Option<Box<[T; N]>> {
-        self.0.as_mut_ptr()
+    pub(super) fn as_ptr(&self) -> *const Option<Box<[T]>> {
+        self.pages.as_ptr()
+    }
+    #[inline]
+    pub(super) fn as_mut_ptr(&mut self) -> *mut Option<Box<[T]>> {
+        self.pages.as_mut_ptr()
     }
     pub(super) fn used_memory(&self) -> usize {
-        self.0.len() * size_of::<Option<Box<T>>>()
-            + self.0.iter().fold(0, |count, array| {
-                if array.is_some() {
-                    count + size_of::<[T; N]>()
-                } else {
-                    count
-                }
-            })
+        self.pages.len() * size_of::<Option<Box<[T]>>>() + self.allocated_pages_memory()
     }
     pub(super) fn reserved_memory(&self) -> usize {
-        self.0.capacity() * size_of::<Option<Box<T>>>()
-            + self.0.iter().fold(0, |count, array| {
-                if array.is_some() {
-                    count + size_of::<[T; N]>()
-                } else {
-                    count
-                }
-            })
+        self.pages.capacity() * size_of::<Option<Box<[T]>>>() + self.allocated_pages_memory()
+    }
+    fn allocated_pages_memory(&self) -> usize {
+        self.pages.iter().flatten().count() * self.page_size() * size_of::<T>()
+    }
+    /// Makes sure `pages` covers all pages from `start` to `end` included.
+    fn cover_pages(&mut self, start: usize, end: usize) {
+        if self.pages.is_empty() {
+            self.first_page = start;
+        }
+
+        if start < self.first_page {
+            let missing = self.first_page - start;
+            self.pages
+                .splice(0..0, core::iter::repeat_with(|| None).take(missing));
+            self.first_page = start;
+        }
+
+        if end - self.first_page >= self.pages.len() {
+            self.pages.resize_with(end - self.first_page + 1, || None);
+        }
     }
 }
 
-impl<const N: usize> SparseArray<EntityId, N> {
+impl SparseArray<EntityId> {
     #[inline]
     #[track_caller]
-    pub(super) fn allocate_at(&mut self, entity: EntityId) {
+    pub(crate) fn allocate_at(&mut self, entity: EntityId) {
         if entity.is_dead() {
             panic!("Tried to add a component with a dead entity.");
         }
 
-        if entity.bucket() >= self.0.len() {
-            self.0.resize(entity.bucket() + 1, None);
-        }
-        unsafe {
-            // SAFE we just allocated at least entity.bucket()
-            let bucket = self.0.get_unchecked_mut(entity.bucket());
+        self.allocate_index(entity.uindex());
+    }
+    fn allocate_index(&mut self, index: usize) {
+        let page = index >> self.page_shift;
+        let page_size = self.page_size();
 
-            if bucket.is_none() {
-                *bucket = Some(Box::new([EntityId::dead(); N]));
-            }
+        self.cover_pages(page, page);
+
+        let page = &mut self.pages[page - self.first_page];
+
+        if page.is_none() {
+            *page = Some(new_page(page_size));
         }
     }
     pub(crate) fn bulk_allocate(&mut self, start: EntityId, end: EntityId) {
-        if end.bucket() >= self.0.len() {
-            self.0.resize(end.bucket() + 1, None);
-        }
-        for bucket_index in start.bucket()..end.bucket() + 1 {
-            let bucket = unsafe { self.0.get_unchecked_mut(bucket_index) };
+        let start_page = start.uindex() >> self.page_shift;
+        let end_page = end.uindex() >> self.page_shift;
+        let page_size = self.page_size();
 
-            if bucket.is_none() {
-                *bucket = Some(Box::new([EntityId::dead(); N]));
+        self.cover_pages(start_page, end_page);
+
+        for page in &mut self.pages[start_page - self.first_page..=end_page - self.first_page] {
+            if page.is_none() {
+                *page = Some(new_page(page_size));
             }
         }
     }
     #[inline]
     pub(crate) fn get(&self, entity: EntityId) -> Option<EntityId> {
-        self.0
-            .get(entity.bucket())?
+        let (page, offset) = locate(entity.uindex(), self.first_page, self.page_shift);
+
+        self.pages
+            .get(page)?
             .as_ref()
-            .map(|bucket| unsafe { *bucket.get_unchecked(entity.bucket_index()) })
+            .map(|page| unsafe { *page.get_unchecked(offset) })
     }
     #[inline]
     pub(super) unsafe fn get_unchecked(&self, entity: EntityId) -> EntityId {
-        match self.0.get_unchecked(entity.bucket()) {
-            Some(bucket) => *bucket.get_unchecked(entity.bucket_index()),
+        let (page, offset) = locate(entity.uindex(), self.first_page, self.page_shift);
+
+        match self.pages.get_unchecked(page) {
+            Some(page) => *page.get_unchecked(offset),
             None => unreachable_unchecked(),
         }
     }
     #[inline]
     pub(crate) unsafe fn get_mut_unchecked(&mut self, entity: EntityId) -> &mut EntityId {
-        match self.0.get_unchecked_mut(entity.bucket()) {
-            Some(bucket) => bucket.get_unchecked_mut(entity.bucket_index()),
+        self.index_mut_unchecked(entity.uindex())
+    }
+    #[inline]
+    unsafe fn index_mut_unchecked(&mut self, index: usize) -> &mut EntityId {
+        let (page, offset) = locate(index, self.first_page, self.page_shift);
+
+        match self.pages.get_unchecked_mut(page) {
+            Some(page) => page.get_unchecked_mut(offset),
             None => unreachable_unchecked(),
         }
     }
+    /// Moves all entries to pages of `page_size` entries.
+    #[track_caller]
+    pub(super) fn set_page_size(&mut self, page_size: usize) {
+        assert!(
+            page_size.is_power_of_two(),
+            "Sparse page size has to be a power of two, got {}.",
+            page_size
+        );
+
+        if page_size == self.page_size() {
+            return;
+        }
+
+        let old_pages = core::mem::take(&mut self.pages);
+        let old_first_page = self.first_page;
+        let old_page_shift = self.page_shift;
+
+        self.first_page = 0;
+        self.page_shift = page_size.trailing_zeros();
+
+        for (page_index, page) in old_pages.into_iter().enumerate() {
+            let page = match page {
+                Some(page) => page,
+                None => continue,
+            };
+
+            for (offset, &sparse_entity) in page.iter().enumerate() {
+                if !sparse_entity.is_dead() {
+                    let index = ((old_first_page + page_index) << old_page_shift) | offset;
+
+                    self.allocate_index(index);
+                    unsafe {
+                        *self.index_mut_unchecked(index) = sparse_entity;
+                    }
+                }
+            }
+        }
+    }
+    /// Returns the entity index and entry of all occupied entries.
+    pub(super) fn occupied(&self) -> impl Iterator<Item = (usize, EntityId)> + '_ {
+        let first_page = self.first_page;
+        let page_shift = self.page_shift;
+
+        self.pages
+            .iter()
+            .enumerate()
+            .filter_map(|(page_index, page)| Some((page_index, page.as_ref()?)))
+            .flat_map(move |(page_index, page)| {
+                page.iter()
+                    .enumerate()
+                    .filter(|(_, sparse_entity)| !sparse_entity.is_dead())
+                    .map(move |(offset, &sparse_entity)| {
+                        (
+                            ((first_page + page_index) << page_shift) | offset,
+                            sparse_entity,
+                        )
+                    })
+            })
+    }
+    pub(super) fn stats(&self) -> SparseArrayStats {
+        let mut stats = SparseArrayStats {
+            page_size: self.page_size(),
+            pages: self.pages.len(),
+            allocated_pages: 0,
+            holes: 0,
+            occupied: 0,
+            densest_page_occupancy: 0,
+        };
+
+        for page in self.pages.iter().flatten() {
+            let occupancy = page.iter().filter(|entity| !entity.is_dead()).count();
+
+            stats.allocated_pages += 1;
+            if occupancy == 0 {
+                stats.holes += 1;
+            }
+            stats.occupied += occupancy;
+            stats.densest_page_occupancy = stats.densest_page_occupancy.max(occupancy);
+        }
+
+        stats
+    }
+    /// Frees the pages without any entity and shrinks the page list.
+    pub(super) fn compact(&mut self) {
+        for page in &mut self.pages {
+            if page
+                .as_ref()
+                .is_some_and(|page| page.iter().all(|entity| entity.is_dead()))
+            {
+                *page = None;
+            }
+        }
+
+        while let Some(None) = self.pages.last() {
+            self.pages.pop();
+        }
+
+        let leading = self.pages.iter().take_while(|page| page.is_none()).count();
+        self.pages.drain(..leading);
+        self.first_page = if self.pages.is_empty() {
+            0
+        } else {
+            self.first_page + leading
+        };
+
+        self.pages.shrink_to_fit();
+    }
     #[inline]
     #[allow(missing_docs)]
     pub fn contains(&self, entity: EntityId) -> bool {
@@ -113,3 +317,7 @@ impl<const N: usize> SparseArray<EntityId, N> {
         }
     }
 }
+
+fn new_page(page_size: usize) -> Box<[EntityId]> {
+    alloc::vec![EntityId::dead(); page_size].into_boxed_slice()
+}
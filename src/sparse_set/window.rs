@@ -1,3 +1,4 @@
+use super::sparse_array::locate;
 use crate::atomic_refcell::{ExclusiveBorrow, SharedBorrow};
 use crate::component::Component;
 use crate::entity_id::EntityId;
@@ -9,8 +10,10 @@ use core::marker::PhantomData;
 use core::ptr;
 
 pub struct FullRawWindow<'a, T> {
-    sparse: *const *const EntityId,
+    sparse: *const Option<Box<[EntityId]>>,
     sparse_len: usize,
+    first_page: usize,
+    page_shift: u32,
     pub(crate) dense: *const EntityId,
     pub(crate) dense_len: usize,
     pub(crate) data: *const T,
@@ -27,13 +30,11 @@ unsafe impl<T: Send + Component> Send for FullRawWindow<'_, T> {}
 impl<'w, T: Component> FullRawWindow<'w, T> {
     #[inline]
     pub(crate) fn from_view<Track: Tracking>(view: &View<'_, T, Track>) -> Self {
-        let sparse_len = view.sparse.len();
-        let sparse: *const Option<Box<[EntityId; super::BUCKET_SIZE]>> = view.sparse.as_ptr();
-        let sparse = sparse as *const *const EntityId;
-
         FullRawWindow {
-            sparse,
-            sparse_len,
+            sparse: view.sparse.as_ptr(),
+            sparse_len: view.sparse.len(),
+            first_page: view.sparse.first_page(),
+            page_shift: view.sparse.page_shift(),
             dense: view.dense.as_ptr(),
             dense_len: view.dense.len(),
             data: view.data.as_ptr(),
@@ -45,10 +46,29 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
             _phantom: PhantomData,
         }
     }
+    /// Returns a window over no component.
+    #[inline]
+    pub(crate) fn empty() -> Self {
+        FullRawWindow {
+            sparse: ptr::NonNull::dangling().as_ptr(),
+            sparse_len: 0,
+            first_page: 0,
+            page_shift: 0,
+            dense: ptr::NonNull::dangling().as_ptr(),
+            dense_len: 0,
+            data: ptr::NonNull::dangling().as_ptr(),
+            insertion_data: ptr::NonNull::dangling().as_ptr(),
+            modification_data: ptr::NonNull::dangling().as_ptr(),
+            last_insertion: TrackingTimestamp::new(0),
+            last_modification: TrackingTimestamp::new(0),
+            current: TrackingTimestamp::new(0),
+            _phantom: PhantomData,
+        }
+    }
     #[inline]
     pub(crate) fn from_owned_view<Track: Tracking>(
         view: View<'_, T, Track>,
-    ) -> (Self, Option<SharedBorrow<'_>>, SharedBorrow<'_>) {
+    ) -> (Self, Option<SharedBorrow<'_>>, Option<SharedBorrow<'_>>) {
         let View {
             sparse_set,
             all_borrow,
@@ -59,14 +79,12 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
             ..
         } = view;
 
-        let sparse_len = sparse_set.len();
-        let sparse: *const Option<Box<[EntityId; super::BUCKET_SIZE]>> = sparse_set.sparse.as_ptr();
-        let sparse = sparse as *const *const EntityId;
-
         (
             FullRawWindow {
-                sparse,
-                sparse_len,
+                sparse: sparse_set.sparse.as_ptr(),
+                sparse_len: sparse_set.sparse.len(),
+                first_page: sparse_set.sparse.first_page(),
+                page_shift: sparse_set.sparse.page_shift(),
                 dense: sparse_set.dense.as_ptr(),
                 dense_len: sparse_set.dense.len(),
                 data: sparse_set.data.as_ptr(),
@@ -83,13 +101,11 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
     }
     #[inline]
     pub(crate) fn from_view_mut<Track: Tracking>(view: &ViewMut<'_, T, Track>) -> Self {
-        let sparse_len = view.sparse.len();
-        let sparse: *const Option<Box<[EntityId; super::BUCKET_SIZE]>> = view.sparse.as_ptr();
-        let sparse = sparse as *const *const EntityId;
-
         FullRawWindow {
-            sparse,
-            sparse_len,
+            sparse: view.sparse.as_ptr(),
+            sparse_len: view.sparse.len(),
+            first_page: view.sparse.first_page(),
+            page_shift: view.sparse.page_shift(),
             dense: view.dense.as_ptr(),
             dense_len: view.dense.len(),
             data: view.data.as_ptr(),
@@ -127,14 +143,12 @@ impl<'w, T: Component> FullRawWindow<'w, T> {
     }
     #[inline]
     fn sparse_index(&self, entity: EntityId) -> Option<EntityId> {
-        if entity.bucket() < self.sparse_len {
-            let bucket = unsafe { ptr::read(self.sparse.add(entity.bucket())) };
+        let (page, offset) = locate(entity.uindex(), self.first_page, self.page_shift);
 
-            if !bucket.is_null() {
-                Some(unsafe { ptr::read(bucket.add(entity.bucket_index())) })
-            } else {
-                None
-            }
+        if page < self.sparse_len {
+            unsafe { &*self.sparse.add(page) }
+                .as_ref()
+                .map(|page| unsafe { *page.get_unchecked(offset) })
         } else {
             None
         }
@@ -147,6 +161,8 @@ impl<T: Component> Clone for FullRawWindow<'_, T> {
         FullRawWindow {
             sparse: self.sparse,
             sparse_len: self.sparse_len,
+            first_page: self.first_page,
+            page_shift: self.page_shift,
             dense: self.dense,
             dense_len: self.dense_len,
             data: self.data,
@@ -161,8 +177,10 @@ impl<T: Component> Clone for FullRawWindow<'_, T> {
 }
 
 pub struct FullRawWindowMut<'a, T, Track> {
-    sparse: *mut *mut EntityId,
+    sparse: *mut Option<Box<[EntityId]>>,
     sparse_len: usize,
+    first_page: usize,
+    page_shift: u32,
     pub(crate) dense: *mut EntityId,
     pub(crate) dense_len: usize,
     pub(crate) data: *mut T,
@@ -180,13 +198,11 @@ unsafe impl<T: Send + Component, Track> Send for FullRawWindowMut<'_, T, Track>
 impl<'w, T: Component, Track> FullRawWindowMut<'w, T, Track> {
     #[inline]
     pub(crate) fn new(view: &mut ViewMut<'_, T, Track>) -> Self {
-        let sparse_len = view.sparse.len();
-        let sparse: *mut Option<Box<[EntityId; super::BUCKET_SIZE]>> = view.sparse.as_mut_ptr();
-        let sparse = sparse as *mut *mut EntityId;
-
         FullRawWindowMut {
-            sparse,
-            sparse_len,
+            sparse_len: view.sparse.len(),
+            first_page: view.sparse.first_page(),
+            page_shift: view.sparse.page_shift(),
+            sparse: view.sparse.as_mut_ptr(),
             dense: view.dense.as_mut_ptr(),
             dense_len: view.dense.len(),
             data: view.data.as_mut_ptr(),
@@ -213,15 +229,12 @@ impl<'w, T: Component, Track> FullRawWindowMut<'w, T, Track> {
             ..
         } = view;
 
-        let sparse_len = sparse_set.len();
-        let sparse: *mut Option<Box<[EntityId; super::BUCKET_SIZE]>> =
-            sparse_set.sparse.as_mut_ptr();
-        let sparse = sparse as *mut *mut EntityId;
-
         (
             FullRawWindowMut {
-                sparse,
-                sparse_len,
+                sparse_len: sparse_set.sparse.len(),
+                first_page: sparse_set.sparse.first_page(),
+                page_shift: sparse_set.sparse.page_shift(),
+                sparse: sparse_set.sparse.as_mut_ptr(),
                 dense: sparse_set.dense.as_mut_ptr(),
                 dense_len: sparse_set.dense.len(),
                 data: sparse_set.data.as_mut_ptr(),
@@ -263,14 +276,12 @@ impl<'w, T: Component, Track> FullRawWindowMut<'w, T, Track> {
     }
     #[inline]
     fn sparse_index(&self, entity: EntityId) -> Option<EntityId> {
-        if entity.bucket() < self.sparse_len {
-            let bucket = unsafe { ptr::read(self.sparse.add(entity.bucket())) };
+        let (page, offset) = locate(entity.uindex(), self.first_page, self.page_shift);
 
-            if !bucket.is_null() {
-                Some(unsafe { ptr::read(bucket.add(entity.bucket_index())) })
-            } else {
-                None
-            }
+        if page < self.sparse_len {
+            unsafe { &*self.sparse.add(page) }
+                .as_ref()
+                .map(|page| unsafe { *page.get_unchecked(offset) })
         } else {
             None
         }
@@ -283,6 +294,8 @@ impl<T: Component, Track> Clone for FullRawWindowMut<'_, T, Track> {
         FullRawWindowMut {
             sparse: self.sparse,
             sparse_len: self.sparse_len,
+            first_page: self.first_page,
+            page_shift: self.page_shift,
             dense: self.dense,
             dense_len: self.dense_len,
             data: self.data,
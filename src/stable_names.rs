@@ -0,0 +1,224 @@
+use crate::component::{Component, Unique};
+use crate::info::{BatchInfo, Conflict, SystemId, SystemInfo, TypeInfo, WorkloadsInfo};
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::type_id::TypeId;
+use crate::unique::UniqueStorage;
+use crate::ShipHashMap;
+use alloc::borrow::Cow;
+use core::hash::BuildHasherDefault;
+
+/// Explicit names for storages and systems, stable across builds.
+///
+/// `TypeId`s are not guaranteed to be the same between two builds, even for the same code.\
+/// When a type has a registered name, exported information uses the name and an id derived from it instead.\
+/// This includes [`World::workloads_info`](crate::World::workloads_info) and what is serialized with `serde1`.
+///
+/// String labels are already stable and don't need to be registered.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, SparseSet, StorageId, View, World};
+///
+/// #[derive(Component)]
+/// struct Position;
+///
+/// fn sys(_: View<Position>) {}
+///
+/// let mut world = World::new();
+///
+/// world
+///     .stable_names_mut()
+///     .register_component::<Position>("Position")
+///     .register_system(sys, "sys");
+///
+/// let storage_id = StorageId::of::<SparseSet<Position>>();
+///
+/// assert_eq!(world.stable_names().name(storage_id), Some("Position"));
+/// assert_eq!(world.stable_names().storage_id("Position"), Some(storage_id));
+/// assert_eq!(
+///     world.stable_names().stable_storage_id(storage_id),
+///     StorageId::of_name("Position")
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StableNames {
+    names: ShipHashMap<TypeId, Cow<'static, str>>,
+    type_ids: ShipHashMap<Cow<'static, str>, TypeId>,
+}
+
+impl StableNames {
+    /// Creates an empty `StableNames`.
+    pub fn new() -> StableNames {
+        StableNames {
+            names: ShipHashMap::with_hasher(BuildHasherDefault::default()),
+            type_ids: ShipHashMap::with_hasher(BuildHasherDefault::default()),
+        }
+    }
+    /// Registers `name` as the stable name of `T`'s storage.
+    ///
+    /// ### Panics
+    ///
+    /// - `name` is already registered for another type.
+    /// - `T` is already registered with another name.
+    #[track_caller]
+    pub fn register_component<T: Component>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> &mut StableNames {
+        self.register_storage::<SparseSet<T>>(name)
+    }
+    /// Registers `name` as the stable name of `T`'s unique storage.
+    ///
+    /// ### Panics
+    ///
+    /// - `name` is already registered for another type.
+    /// - `T` is already registered with another name.
+    #[track_caller]
+    pub fn register_unique<T: Unique>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> &mut StableNames {
+        self.register_storage::<UniqueStorage<T>>(name)
+    }
+    /// Registers `name` as the stable name of the storage `S`.\
+    /// Can also be used for types used as labels.
+    ///
+    /// ### Panics
+    ///
+    /// - `name` is already registered for another type.
+    /// - `S` is already registered with another name.
+    #[track_caller]
+    pub fn register_storage<S: ?Sized + 'static>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> &mut StableNames {
+        self.register_type_id(TypeId::of::<S>(), name.into())
+    }
+    /// Registers `name` as the stable name of `system`.\
+    /// Only functions and closures can be registered, not [`WorkloadSystem`](crate::WorkloadSystem).
+    ///
+    /// ### Panics
+    ///
+    /// - `name` is already registered for another type.
+    /// - `system` is already registered with another name.
+    #[track_caller]
+    pub fn register_system<S: 'static>(
+        &mut self,
+        _system: S,
+        name: impl Into<Cow<'static, str>>,
+    ) -> &mut StableNames {
+        self.register_type_id(TypeId::of::<S>(), name.into())
+    }
+    #[track_caller]
+    fn register_type_id(&mut self, type_id: TypeId, name: Cow<'static, str>) -> &mut StableNames {
+        if let Some(other_type_id) = self.type_ids.get(&name) {
+            assert!(
+                *other_type_id == type_id,
+                "{:?} is already the stable name of another type.",
+                name
+            );
+        }
+
+        if let Some(other_name) = self.names.get(&type_id) {
+            assert!(
+                *other_name == name,
+                "This type is already registered as {:?}.",
+                other_name
+            );
+        }
+
+        self.names.insert(type_id, name.clone());
+        self.type_ids.insert(name, type_id);
+
+        self
+    }
+    /// Returns the stable name registered for `storage_id`.
+    pub fn name(&self, storage_id: StorageId) -> Option<&str> {
+        match storage_id {
+            StorageId::TypeId(type_id) => self.names.get(&type_id).map(|name| &**name),
+            StorageId::Custom(_) => None,
+        }
+    }
+    /// Returns the `StorageId`, valid for this build, of the storage registered as `name`.
+    pub fn storage_id(&self, name: &str) -> Option<StorageId> {
+        self.type_id(name).map(StorageId::TypeId)
+    }
+    /// Returns the `TypeId`, valid for this build, of the type registered as `name`.
+    pub fn type_id(&self, name: &str) -> Option<TypeId> {
+        self.type_ids.get(name).copied()
+    }
+    /// Returns the stable `StorageId` of `storage_id`.\
+    /// If no name is registered, `storage_id` is returned as is.
+    pub fn stable_storage_id(&self, storage_id: StorageId) -> StorageId {
+        match self.name(storage_id) {
+            Some(name) => StorageId::of_name(name),
+            None => storage_id,
+        }
+    }
+    /// Returns `true` if no name is registered.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+    /// Replaces `TypeId`s and names by their stable version in `workloads_info`.
+    pub(crate) fn apply(&self, workloads_info: &mut WorkloadsInfo) {
+        if self.is_empty() {
+            return;
+        }
+
+        for workload_info in workloads_info.0.values_mut() {
+            for batch_info in &mut workload_info.batch_info {
+                self.apply_batch(batch_info);
+            }
+        }
+    }
+    fn apply_batch(&self, batch_info: &mut BatchInfo) {
+        let (single_system, systems) = &mut batch_info.systems;
+
+        for system_info in single_system.iter_mut().chain(systems) {
+            self.apply_system(system_info);
+        }
+    }
+    fn apply_system(&self, system_info: &mut SystemInfo) {
+        if let Some(name) = self.names.get(&system_info.type_id) {
+            system_info.name = name.clone().into_owned();
+            system_info.type_id = TypeId::of_name(name);
+        }
+
+        for type_info in &mut system_info.borrow {
+            self.apply_type_info(type_info);
+        }
+
+        match &mut system_info.conflict {
+            Some(Conflict::Borrow {
+                type_info,
+                other_system,
+                other_type_info,
+            }) => {
+                if let Some(type_info) = type_info {
+                    self.apply_type_info(type_info);
+                }
+                self.apply_system_id(other_system);
+                self.apply_type_info(other_type_info);
+            }
+            Some(Conflict::NotSendSync(type_info)) => self.apply_type_info(type_info),
+            Some(Conflict::OtherNotSendSync { system, type_info }) => {
+                self.apply_system_id(system);
+                self.apply_type_info(type_info);
+            }
+            None => {}
+        }
+    }
+    fn apply_system_id(&self, system_id: &mut SystemId) {
+        if let Some(name) = self.names.get(&system_id.type_id) {
+            system_id.name = name.clone().into_owned();
+            system_id.type_id = TypeId::of_name(name);
+        }
+    }
+    fn apply_type_info(&self, type_info: &mut TypeInfo) {
+        if let Some(name) = self.name(type_info.storage_id) {
+            type_info.storage_id = StorageId::of_name(name);
+            type_info.name = Cow::Owned(name.into());
+        }
+    }
+}
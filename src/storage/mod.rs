@@ -1,16 +1,27 @@
 mod sbox;
 mod storage_id;
+mod storage_info;
 
 pub use storage_id::StorageId;
+pub use storage_info::StorageInfo;
 
-pub(crate) use sbox::SBox;
+pub(crate) use sbox::{ExclusiveStorage, KeepAlive, SBox};
 
 use crate::all_storages::AllStorages;
+use crate::archive::ArchivedEntity;
+use crate::drop_queue::DropQueue;
+#[cfg(feature = "thread_local")]
+use crate::drop_queue::LocalDropQueue;
+use crate::entities::Entities;
 use crate::entity_id::EntityId;
+use crate::error::IntegrityError;
 use crate::memory_usage::StorageMemoryUsage;
+use crate::seal::Private;
 use crate::sparse_set::SparseArray;
 use crate::tracking::TrackingTimestamp;
+use crate::unique::UniqueInfo;
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 use core::any::Any;
 
 pub trait SizedAny {
@@ -30,6 +41,8 @@ impl<T: 'static> SizedAny for T {
 }
 
 /// Defines common storage operations.
+///
+/// Some methods are only used by shipyard's own storages, they can't be called nor implemented outside of the crate.
 pub trait Storage: SizedAny {
     /// Casts to `&dyn Any`.
     fn any(&self) -> &dyn Any {
@@ -47,6 +60,30 @@ pub trait Storage: SizedAny {
     #[inline]
     #[allow(unused_variables)]
     fn clear(&mut self, current: TrackingTimestamp) {}
+    /// Hides `entity`'s component from this storage without deleting it.\
+    /// Returns `true` if the storage had a component for `entity`.
+    #[inline]
+    #[allow(unused_variables)]
+    #[doc(hidden)]
+    fn disable(&mut self, entity: EntityId, _: Private) -> bool {
+        false
+    }
+    /// Makes `entity`'s component visible again after a call to [`Storage::disable`].\
+    /// Returns `true` if the storage had a disabled component for `entity`.
+    #[inline]
+    #[allow(unused_variables)]
+    #[doc(hidden)]
+    fn enable(&mut self, entity: EntityId, _: Private) -> bool {
+        false
+    }
+    /// Replaces `entity` by `new_entity`, the same entity with a greater generation.\
+    /// Called by [`AllStorages::bump_generation`] on all storages not shared with another `World`.
+    ///
+    /// Storages holding components have to implement it, otherwise `entity`'s component stays under its previous id.
+    #[inline]
+    #[allow(unused_variables)]
+    #[doc(hidden)]
+    fn bump_generation(&mut self, entity: EntityId, new_entity: EntityId, _: Private) {}
     /// Returns how much memory this storage uses.
     fn memory_usage(&self) -> Option<StorageMemoryUsage> {
         None
@@ -59,13 +96,101 @@ pub trait Storage: SizedAny {
     ///
     /// [`SparseSet`]: crate::sparse_set::SparseSet
     /// [`SparseArray`]: crate::sparse_set::SparseArray
-    fn sparse_array(&self) -> Option<&SparseArray<EntityId, 32>> {
+    fn sparse_array(&self) -> Option<&SparseArray<EntityId>> {
+        None
+    }
+    /// Returns the entities owning a component in this storage.\
+    /// Storages returning `None` are ignored by [`AllStorages::delete_any_by_id`].
+    #[doc(hidden)]
+    fn entity_ids(&self, _: Private) -> Option<&[EntityId]> {
         None
     }
     /// Returns `true` if the storage is empty.
     fn is_empty(&self) -> bool {
         false
     }
+    /// Returns the value of a unique storage.\
+    /// Other storages return `None`.
+    #[doc(hidden)]
+    fn unique_value(&self, _: Private) -> Option<&dyn Any> {
+        None
+    }
+    /// Returns the value of a unique storage and flags it modified at `current`.\
+    /// Other storages return `None`.
+    #[allow(unused_variables)]
+    #[doc(hidden)]
+    fn unique_value_mut(
+        &mut self,
+        current: TrackingTimestamp,
+        _: Private,
+    ) -> Option<&mut (dyn Any + 'static)> {
+        None
+    }
+    /// Returns information about a unique storage, `current` is the `World`'s current tracking timestamp.\
+    /// Other storages return `None`.\
+    /// `is_send` and `is_sync` are set by the `World` based on how the storage was added.
+    #[allow(unused_variables)]
+    #[doc(hidden)]
+    fn unique_info(&self, current: TrackingTimestamp, _: Private) -> Option<UniqueInfo> {
+        None
+    }
+    /// Pushes all inconsistencies found in the storage to `errors`.\
+    /// `current` is the `World`'s current tracking timestamp.
+    #[allow(unused_variables)]
+    #[doc(hidden)]
+    fn validate_integrity(
+        &self,
+        entities: &Entities,
+        current: TrackingTimestamp,
+        errors: &mut Vec<IntegrityError>,
+        _: Private,
+    ) {
+    }
+    /// Pushes the entities deleted from this storage after `timestamp` to `entities`.\
+    /// Storages not tracking deletion don't push anything.
+    #[allow(unused_variables)]
+    #[doc(hidden)]
+    fn deleted_since(
+        &self,
+        timestamp: TrackingTimestamp,
+        current: TrackingTimestamp,
+        entities: &mut Vec<EntityId>,
+        _: Private,
+    ) {
+    }
+    /// Moves the components kept by [`SparseSet::defer_drops`] to `drop_queue`.
+    ///
+    /// [`SparseSet::defer_drops`]: crate::sparse_set::SparseSet::defer_drops
+    #[allow(unused_variables)]
+    #[doc(hidden)]
+    fn take_deferred_drops(&mut self, drop_queue: &mut DropQueue, _: Private) {}
+    /// Moves the `!Send` components kept by [`SparseSet::defer_drops`] to `drop_queue`.
+    ///
+    /// [`SparseSet::defer_drops`]: crate::sparse_set::SparseSet::defer_drops
+    #[cfg(feature = "thread_local")]
+    #[allow(unused_variables)]
+    #[doc(hidden)]
+    fn take_local_deferred_drops(&mut self, drop_queue: &mut LocalDropQueue, _: Private) {}
+    /// Returns `false` if `entity` has a component in this storage that [`Storage::archive`] can't move to an archive.\
+    /// By default, storages can't archive the components listed in their [`SparseArray`].
+    #[doc(hidden)]
+    fn can_archive(&self, entity: EntityId, _: Private) -> bool {
+        !self
+            .sparse_array()
+            .is_some_and(|sparse_array| sparse_array.contains(entity))
+    }
+    /// Removes `entity`'s component from this storage and pushes it to `archived`.\
+    /// Only called once [`Storage::can_archive`] returned `true` for all storages, [`Storage::delete`] is called right after.
+    #[allow(unused_variables)]
+    #[doc(hidden)]
+    fn archive(
+        &mut self,
+        entity: EntityId,
+        current: TrackingTimestamp,
+        archived: &mut ArchivedEntity,
+        _: Private,
+    ) {
+    }
     /// Clear all deletion and removal tracking data.
     fn clear_all_removed_and_deleted(&mut self) {}
     /// Clear all deletion and removal tracking data older than some timestamp.
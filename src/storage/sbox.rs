@@ -1,32 +1,94 @@
-use crate::atomic_refcell::AtomicRefCell;
+use crate::atomic_refcell::{ARefMut, AtomicRefCell};
+#[cfg(feature = "thread_local")]
+use crate::error::ThreadSafety;
 use crate::storage::Storage;
 use alloc::boxed::Box;
-#[cfg(feature = "thread_local")]
 use alloc::sync::Arc;
+use core::ops::{Deref, DerefMut};
+use core::panic::Location;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 /// Abstract away `T` from `AtomicRefCell<T>` to be able to store
 /// different types in a `HashMap<TypeId, Storage>`.
 /// and box the `AtomicRefCell` so it doesn't move when the `HashMap` reallocates
-pub(crate) struct SBox(pub(crate) *mut AtomicRefCell<dyn Storage>);
+pub(crate) struct SBox {
+    pub(crate) ptr: *mut AtomicRefCell<dyn Storage>,
+    /// Owner of the allocation when the storage is shared between multiple `World`s or kept alive
+    owner: Option<Arc<Owner>>,
+    /// `true` when the storage is immutable, a shared borrow is then leaked for as long as it stays immutable
+    immutable: bool,
+    /// Location where the storage was added, when known
+    location: Option<&'static Location<'static>>,
+}
 
 #[cfg(not(feature = "thread_local"))]
 unsafe impl Send for SBox {}
 
 unsafe impl Sync for SBox {}
 
-impl Drop for SBox {
+/// Owns a storage shared by multiple `SBox`s.
+struct Owner {
+    ptr: *mut AtomicRefCell<dyn Storage>,
+    /// Number of [`KeepAlive`] handles, they don't count as sharing the storage
+    keep_alive: AtomicUsize,
+}
+
+#[cfg(not(feature = "thread_local"))]
+unsafe impl Send for Owner {}
+
+unsafe impl Sync for Owner {}
+
+impl Drop for Owner {
     fn drop(&mut self) {
         // SAFE the pointer came from a `Box` of the same type
         unsafe {
-            let _ = Box::from_raw(self.0);
+            let _ = Box::from_raw(self.ptr);
+        }
+    }
+}
+
+/// Keeps a storage alive without sharing it with another `World`.
+pub(crate) struct KeepAlive(Arc<Owner>);
+
+impl KeepAlive {
+    /// Returns `true` if `sbox` points to the storage kept alive.
+    #[inline]
+    pub(crate) fn keeps_alive(&self, sbox: &SBox) -> bool {
+        self.0.ptr.cast::<()>() == sbox.ptr.cast::<()>()
+    }
+}
+
+impl Drop for KeepAlive {
+    fn drop(&mut self) {
+        self.0.keep_alive.fetch_sub(1, Ordering::Release);
+    }
+}
+
+impl Drop for SBox {
+    fn drop(&mut self) {
+        // Shared storages are dropped with their owner
+        if self.owner.is_none() {
+            // SAFE the pointer came from a `Box` of the same type
+            unsafe {
+                let _ = Box::from_raw(self.ptr);
+            }
         }
     }
 }
 
 impl SBox {
+    #[inline]
+    fn from_ptr(ptr: *mut AtomicRefCell<dyn Storage>) -> Self {
+        SBox {
+            ptr,
+            owner: None,
+            immutable: false,
+            location: None,
+        }
+    }
     #[inline]
     pub(crate) fn new<T: Storage + Send + Sync + 'static>(value: T) -> Self {
-        SBox(Box::into_raw(Box::new(AtomicRefCell::new(value))))
+        SBox::from_ptr(Box::into_raw(Box::new(AtomicRefCell::new(value))))
     }
     #[cfg(feature = "thread_local")]
     #[inline]
@@ -34,14 +96,14 @@ impl SBox {
         value: T,
         thread_id: Arc<dyn Fn() -> u64 + Send + Sync>,
     ) -> Self {
-        SBox(Box::into_raw(Box::new(AtomicRefCell::new_non_send(
+        SBox::from_ptr(Box::into_raw(Box::new(AtomicRefCell::new_non_send(
             value, thread_id,
         ))))
     }
     #[cfg(feature = "thread_local")]
     #[inline]
     pub(crate) fn new_non_sync<T: Storage + Send + 'static>(value: T) -> Self {
-        SBox(Box::into_raw(Box::new(AtomicRefCell::new_non_sync(value))))
+        SBox::from_ptr(Box::into_raw(Box::new(AtomicRefCell::new_non_sync(value))))
     }
     #[cfg(feature = "thread_local")]
     #[inline]
@@ -49,15 +111,230 @@ impl SBox {
         value: T,
         thread_id: Arc<dyn Fn() -> u64 + Send + Sync>,
     ) -> Self {
-        SBox(Box::into_raw(Box::new(AtomicRefCell::new_non_send_sync(
+        SBox::from_ptr(Box::into_raw(Box::new(AtomicRefCell::new_non_send_sync(
             value, thread_id,
         ))))
     }
+    // With thread_local, SBox is !Send but so is AllStorages
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn owner(&mut self) -> &Arc<Owner> {
+        let ptr = self.ptr;
+
+        self.owner.get_or_insert_with(|| {
+            Arc::new(Owner {
+                ptr,
+                keep_alive: AtomicUsize::new(0),
+            })
+        })
+    }
+    /// Returns a new `SBox` pointing to the same storage.
+    ///
+    /// The storage is only dropped once all `SBox`s sharing it are.
+    pub(crate) fn share(&mut self) -> SBox {
+        let ptr = self.ptr;
+        let location = self.location;
+
+        SBox {
+            ptr,
+            owner: Some(self.owner().clone()),
+            immutable: false,
+            location,
+        }
+    }
+    /// Returns a handle keeping the storage alive even if this `SBox` is dropped.\
+    /// Unlike [`SBox::share`], the storage isn't considered shared.
+    pub(crate) fn keep_alive(&mut self) -> KeepAlive {
+        // the `Arc` is cloned first, incrementing `keep_alive` before would make
+        // a shared storage look unshared to `is_shared` until it's cloned
+        let owner = self.owner().clone();
+        owner.keep_alive.fetch_add(1, Ordering::AcqRel);
+
+        KeepAlive(owner)
+    }
+    /// Returns a new `SBox` pointing to the same storage as this shared `SBox`.
+    ///
+    /// ### Panics
+    ///
+    /// - The storage was never shared.
+    pub(crate) fn clone_shared(&self) -> SBox {
+        SBox {
+            ptr: self.ptr,
+            owner: Some(self.owner.clone().unwrap()),
+            immutable: false,
+            location: self.location,
+        }
+    }
+    /// Records where the storage was added.
+    #[inline]
+    pub(crate) fn with_location(mut self, location: &'static Location<'static>) -> Self {
+        self.location = Some(location);
+        self
+    }
+    /// Returns where the storage was added, when known.
+    #[cfg(any(feature = "thread_local", feature = "storage_location"))]
+    #[inline]
+    pub(crate) fn location(&self) -> Option<&'static Location<'static>> {
+        self.location
+    }
+    /// Returns the thread safety of the storage.
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    pub(crate) fn thread_safety(&self) -> ThreadSafety {
+        // SAFE only reads fields that can't be modified while the storage is borrowed
+        let refcell = unsafe { &*self.ptr };
+
+        match (refcell.bound_thread().is_some(), refcell.is_sync()) {
+            (false, true) => ThreadSafety::SendSync,
+            (true, true) => ThreadSafety::NonSend,
+            (false, false) => ThreadSafety::NonSync,
+            (true, false) => ThreadSafety::NonSendSync,
+        }
+    }
+    /// Returns `true` if the storage is shared with another `World`.
+    #[inline]
+    pub(crate) fn is_shared(&self) -> bool {
+        matches!(
+            &self.owner,
+            Some(owner) if Arc::strong_count(owner) - owner.keep_alive.load(Ordering::Acquire) > 1
+        )
+    }
+    /// Makes the storage immutable by leaking a shared borrow.\
+    /// Exclusive borrows will fail and [`SBox::get_mut`] will return `None` until [`SBox::make_mutable`] is called.
+    pub(crate) fn make_immutable(&mut self) -> Result<(), crate::error::Borrow> {
+        if !self.immutable {
+            unsafe { &*self.ptr }.leak_borrow()?;
+            self.immutable = true;
+        }
+
+        Ok(())
+    }
+    /// Makes the storage mutable again.\
+    /// Returns `false` if the storage wasn't immutable.
+    ///
+    /// # Safety
+    ///
+    /// No reference obtained with [`SBox::get_immutable`] can be alive.
+    pub(crate) unsafe fn make_mutable(&mut self) -> bool {
+        if self.immutable {
+            (*self.ptr).release_leaked_borrow();
+            self.immutable = false;
+
+            true
+        } else {
+            false
+        }
+    }
+    /// Returns the storage without borrowing it.
+    ///
+    /// # Safety
+    ///
+    /// The storage has to stay immutable while the reference is alive.
+    #[inline]
+    pub(crate) unsafe fn get_immutable(&self) -> &dyn Storage {
+        debug_assert!(self.immutable);
+
+        (*self.ptr).get_unchecked()
+    }
+    /// Returns the storage if it's immutable.
+    #[inline]
+    pub(crate) fn immutable(&self) -> Option<&dyn Storage> {
+        // SAFE the storage can only be made mutable again with an exclusive access to `self`
+        self.immutable
+            .then(|| unsafe { (*self.ptr).get_unchecked() })
+    }
+    /// Returns the id of the thread a `!Send` storage is bound to.
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    pub(crate) fn bound_thread(&self) -> Option<u64> {
+        // SAFE only reads a field that can't be modified while the storage is borrowed
+        unsafe { &*self.ptr }.bound_thread()
+    }
+    /// Binds a `!Send` storage to the current thread.\
+    /// Does nothing if the storage is shared with another `World`.
+    #[cfg(feature = "thread_local")]
+    #[inline]
+    pub(crate) fn rebind_thread(&mut self) {
+        if !self.is_shared() {
+            // SAFE the storage is owned by this `SBox` and we have exclusive access to it
+            unsafe { &mut *self.ptr }.rebind_thread();
+        }
+    }
+    /// Returns the storage without borrowing it.\
+    /// Returns `None` if the storage is shared with another `World` or immutable, it has to be borrowed in this case.
+    #[inline]
+    pub(crate) fn get_mut(&mut self) -> Option<&mut dyn Storage> {
+        if self.is_shared() || self.immutable {
+            None
+        } else {
+            // SAFE the storage is owned by this `SBox` and we have exclusive access to it
+            Some(unsafe { &mut *self.ptr }.get_mut())
+        }
+    }
+    /// Returns the storage, borrowing it if it's shared with another `World`.\
+    /// Fails if the storage is immutable or borrowed by another `World`.
+    #[inline]
+    pub(crate) fn get_or_borrow_mut(
+        &mut self,
+    ) -> Result<ExclusiveStorage<'_, dyn Storage>, crate::error::Borrow> {
+        if self.is_shared() || self.immutable {
+            // SAFE the storage is kept alive by this `SBox`
+            unsafe { &*self.ptr }
+                .borrow_mut()
+                .map(ExclusiveStorage::Borrowed)
+        } else {
+            // SAFE the storage is owned by this `SBox` and we have exclusive access to it
+            Ok(ExclusiveStorage::Owned(unsafe { &mut *self.ptr }.get_mut()))
+        }
+    }
+}
+
+/// Exclusive access to a storage.\
+/// Storages shared with other `World`s can't be accessed through `&mut AllStorages` alone and are borrowed.
+pub(crate) enum ExclusiveStorage<'a, T: ?Sized> {
+    Owned(&'a mut T),
+    Borrowed(ARefMut<'a, &'a mut T>),
+}
+
+impl<'a, T: ?Sized> ExclusiveStorage<'a, T> {
+    #[inline]
+    pub(crate) fn map<U: ?Sized, F: FnOnce(&mut T) -> &mut U>(
+        self,
+        f: F,
+    ) -> ExclusiveStorage<'a, U> {
+        match self {
+            ExclusiveStorage::Owned(storage) => ExclusiveStorage::Owned(f(storage)),
+            ExclusiveStorage::Borrowed(storage) => {
+                ExclusiveStorage::Borrowed(ARefMut::map(storage, f))
+            }
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for ExclusiveStorage<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        match self {
+            ExclusiveStorage::Owned(storage) => storage,
+            ExclusiveStorage::Borrowed(storage) => storage,
+        }
+    }
+}
+
+impl<T: ?Sized> DerefMut for ExclusiveStorage<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        match self {
+            ExclusiveStorage::Owned(storage) => storage,
+            ExclusiveStorage::Borrowed(storage) => storage,
+        }
+    }
 }
 
 impl core::fmt::Debug for SBox {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if let Ok(storage) = unsafe { &*self.0 }.borrow() {
+        if let Ok(storage) = unsafe { &*self.ptr }.borrow() {
             f.write_str(&storage.name())
         } else {
             f.write_str("Could not borrow storage")
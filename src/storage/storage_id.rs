@@ -1,5 +1,7 @@
 use crate::type_id::TypeId;
 use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use siphasher::sip::SipHasher13;
 
 /// Id of a storage, can be a `TypeId` or `u64`.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -16,6 +18,21 @@ impl StorageId {
     pub fn of<T: 'static>() -> Self {
         TypeId::of::<T>().into()
     }
+    /// Returns a `StorageId` derived from `name`.\
+    /// Unlike [`StorageId::of`], the result is the same across builds, compiler versions and platforms.
+    pub fn of_name(name: &str) -> Self {
+        TypeId::of_name(name).into()
+    }
+    /// Returns the `StorageId` of the `T` storage identified by `label`.\
+    /// Multiple storages of the same type can coexist as long as their labels differ.
+    pub fn of_labeled<T: 'static>(label: impl Hash) -> Self {
+        let mut hasher = SipHasher13::new_with_keys(0, 0);
+
+        TypeId::of::<T>().hash(&mut hasher);
+        label.hash(&mut hasher);
+
+        StorageId::Custom(hasher.finish())
+    }
 }
 
 impl From<TypeId> for StorageId {
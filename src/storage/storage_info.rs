@@ -0,0 +1,21 @@
+use crate::storage::StorageId;
+use alloc::borrow::Cow;
+#[cfg(feature = "storage_location")]
+use core::panic::Location;
+
+/// Information about a storage of a `World`.
+#[derive(Clone, Debug)]
+pub struct StorageInfo {
+    #[allow(missing_docs)]
+    pub id: StorageId,
+    /// Name of the storage, `None` if it was borrowed exclusively when the information was gathered.
+    pub name: Option<Cow<'static, str>>,
+    /// Location of the call that created the storage.\
+    /// `None` for storages created by the `World` itself, like `Entities`.
+    ///
+    /// Storages created implicitly, by borrowing a view or adding a component for example,
+    /// point to the outermost call to shipyard.
+    #[cfg(feature = "storage_location")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage_location")))]
+    pub created_at: Option<&'static Location<'static>>,
+}
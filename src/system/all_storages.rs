@@ -13,7 +13,8 @@ pub trait AllSystem<Data, Borrow> {
     /// The system return type
     type Return;
 
-    #[allow(missing_docs)]
+    /// Borrows the views and calls the system.\
+    /// Fails if any view can't be borrowed.
     fn run(self, data: Data, all_storages: &AllStorages)
         -> Result<Self::Return, error::GetStorage>;
 }
@@ -51,6 +52,7 @@ macro_rules! impl_all_system {
         {
             type Return = R;
 
+            #[cfg_attr(feature = "storage_location", track_caller)]
             fn run(
                 self,
                 _: (),
@@ -68,6 +70,7 @@ macro_rules! impl_all_system {
         {
             type Return = R;
 
+            #[cfg_attr(feature = "storage_location", track_caller)]
             fn run(
                 self,
                 (data,): (Data,),
@@ -0,0 +1,56 @@
+use crate::borrow::WorldBorrow;
+use crate::error;
+use crate::world::World;
+
+/// Trait bound encompassing all functions that can be run for each element of an iterator.
+///
+/// `Data` is the element passed to the system by `for_each_system`.
+/// `Borrow` are the storages borrowed, they are passed to the system by mutable reference.
+pub trait ForEachSystem<Data, Borrow> {
+    #[allow(missing_docs)]
+    fn run_for_each<Iter: IntoIterator<Item = Data>>(
+        self,
+        data: Iter,
+        world: &World,
+    ) -> Result<(), error::GetStorage>;
+}
+
+macro_rules! impl_for_each_system {
+    ($(($type: ident, $index: tt))+) => {
+        impl<Data, $($type: WorldBorrow,)+ Func> ForEachSystem<Data, ($($type,)+)> for Func
+        where
+            Func: FnMut(Data, $(&mut $type),+)
+                + FnMut(Data, $(&mut $type::WorldView<'_>),+)
+        {
+            #[allow(non_snake_case)]
+            fn run_for_each<Iter: IntoIterator<Item = Data>>(
+                mut self,
+                data: Iter,
+                world: &World,
+            ) -> Result<(), error::GetStorage> {
+                let current = world.get_current();
+                $(
+                    let mut $type = $type::world_borrow(world, None, current)?;
+                )+
+
+                for data in data {
+                    (self)(data, $(&mut $type),+);
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! for_each_system {
+    ($(($type: ident, $index: tt))*;($type1: ident, $index1: tt) $(($queue_type: ident, $queue_index: tt))*) => {
+        impl_for_each_system![$(($type, $index))*];
+        for_each_system![$(($type, $index))* ($type1, $index1); $(($queue_type, $queue_index))*];
+    };
+    ($(($type: ident, $index: tt))*;) => {
+        impl_for_each_system![$(($type, $index))*];
+    }
+}
+
+for_each_system![(A, 0); (B, 1) (C, 2) (D, 3) (E, 4) (F, 5) (G, 6) (H, 7) (I, 8) (J, 9)];
@@ -1,6 +1,12 @@
 mod all_storages;
+mod for_each;
+mod parallel;
 
 pub use all_storages::AllSystem;
+pub use for_each::ForEachSystem;
+#[cfg(feature = "parallel")]
+pub(crate) use parallel::are_compatible;
+pub use parallel::ParallelSystems;
 
 use crate::borrow::WorldBorrow;
 use crate::error;
@@ -14,11 +20,17 @@ pub struct Nothing;
 /// `Data` is the external data passed to the system through `run_with_data`.
 /// `Borrow` are the storages borrowed.
 /// `Return` is the type returned by the system.
+///
+/// It is implemented for functions taking up to 10 views, their borrows are known at compile time.\
+/// Systems borrowing storages only known at runtime can be added to workloads with [`WorkloadSystem::from_fn_with_borrows`].
+///
+/// [`WorkloadSystem::from_fn_with_borrows`]: crate::WorkloadSystem::from_fn_with_borrows
 pub trait System<Data, Borrow> {
     /// The system return type
     type Return;
 
-    #[allow(missing_docs)]
+    /// Borrows the views and calls the system.\
+    /// Fails if any view can't be borrowed.
     fn run(self, data: Data, world: &World) -> Result<Self::Return, error::GetStorage>;
 }
 
@@ -55,6 +67,7 @@ macro_rules! impl_system {
         {
             type Return = R;
 
+            #[cfg_attr(feature = "storage_location", track_caller)]
             fn run(self, _: (), world: &World) -> Result<R, error::GetStorage> {
                 let current = world.get_current();
                 Ok((self)($($type::world_borrow(world, None, current)?,)+))
@@ -68,6 +81,7 @@ macro_rules! impl_system {
         {
             type Return = R;
 
+            #[cfg_attr(feature = "storage_location", track_caller)]
             fn run(self, (data,): (Data,), world: &World) -> Result<R, error::GetStorage> {
                 let current = world.get_current();
                 Ok((self)(data, $($type::world_borrow(world, None, current)?,)+))
@@ -0,0 +1,137 @@
+use super::System;
+#[cfg(feature = "parallel")]
+use crate::all_storages::AllStorages;
+use crate::borrow::BorrowInfo;
+#[cfg(feature = "parallel")]
+use crate::borrow::Mutability;
+use crate::error;
+use crate::scheduler::info::TypeInfo;
+use crate::seal::Sealed;
+#[cfg(feature = "parallel")]
+use crate::storage::StorageId;
+use crate::world::World;
+use alloc::vec::Vec;
+
+/// Tuple of systems that can be run with [`World::run_parallel`].
+///
+/// This trait is sealed, it is implemented for tuples of 1 to 10 systems that could each be run with [`World::run`].\
+/// With the `parallel` feature, the systems and their return types also have to be `Send`.
+///
+/// [`World::run_parallel`]: crate::World::run_parallel()
+/// [`World::run`]: crate::World::run()
+pub trait ParallelSystems<B>: Sealed {
+    /// The systems return types
+    type Return;
+
+    /// Returns the borrows of each system.
+    fn borrow_infos() -> Vec<Vec<TypeInfo>>;
+    /// Runs the systems one after the other, in tuple order.
+    fn run_sequential(self, world: &World) -> Result<Self::Return, error::GetStorage>;
+    /// Runs the systems in parallel, on the `World`'s thread pool if it has one, rayon's global pool otherwise.
+    #[cfg(feature = "parallel")]
+    fn run_parallel(self, world: &World) -> Result<Self::Return, error::GetStorage>;
+}
+
+/// Returns `true` if no system borrows a storage another system borrows exclusively.\
+/// Systems borrowing `!Send` or `!Sync` storages or `AllStorages` are never considered compatible.
+#[cfg(feature = "parallel")]
+pub(crate) fn are_compatible(borrow_infos: &[Vec<TypeInfo>]) -> bool {
+    for (index, borrow) in borrow_infos.iter().enumerate() {
+        for type_info in borrow {
+            if !type_info.thread_safe || type_info.storage_id == StorageId::of::<AllStorages>() {
+                return false;
+            }
+
+            for other_borrow in &borrow_infos[index + 1..] {
+                for other_type_info in other_borrow {
+                    if type_info.storage_id == other_type_info.storage_id
+                        && (type_info.mutability == Mutability::Exclusive
+                            || other_type_info.mutability == Mutability::Exclusive)
+                    {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+
+    true
+}
+
+macro_rules! impl_parallel_systems {
+    ($(($system: ident, $borrow: ident, $index: tt))+) => {
+        impl<$($system,)+> Sealed for ($($system,)+) {}
+
+        #[cfg(not(feature = "parallel"))]
+        impl<$($borrow: BorrowInfo, $system: System<(), $borrow>,)+> ParallelSystems<($($borrow,)+)> for ($($system,)+) {
+            type Return = ($($system::Return,)+);
+
+            fn borrow_infos() -> Vec<Vec<TypeInfo>> {
+                let mut borrow_infos = Vec::new();
+                $(
+                    let mut borrow = Vec::new();
+                    $borrow::borrow_info(&mut borrow);
+                    borrow_infos.push(borrow);
+                )+
+                borrow_infos
+            }
+            fn run_sequential(self, world: &World) -> Result<Self::Return, error::GetStorage> {
+                Ok(($(self.$index.run((), world)?,)+))
+            }
+        }
+
+        #[cfg(feature = "parallel")]
+        impl<$($borrow: BorrowInfo, $system: System<(), $borrow> + Send,)+> ParallelSystems<($($borrow,)+)> for ($($system,)+)
+        where
+            $($system::Return: Send,)+
+        {
+            type Return = ($($system::Return,)+);
+
+            fn borrow_infos() -> Vec<Vec<TypeInfo>> {
+                let mut borrow_infos = Vec::new();
+                $(
+                    let mut borrow = Vec::new();
+                    $borrow::borrow_info(&mut borrow);
+                    borrow_infos.push(borrow);
+                )+
+                borrow_infos
+            }
+            fn run_sequential(self, world: &World) -> Result<Self::Return, error::GetStorage> {
+                Ok(($(self.$index.run((), world)?,)+))
+            }
+            fn run_parallel(self, world: &World) -> Result<Self::Return, error::GetStorage> {
+                let mut results = ($(Option::<Result<$system::Return, error::GetStorage>>::None,)+);
+
+                if let Some(thread_pool) = &world.thread_pool {
+                    thread_pool.scope(|scope| {
+                        $(
+                            let result = &mut results.$index;
+                            let system = self.$index;
+                            scope.spawn(move |_| *result = Some(system.run((), world)));
+                        )+
+                    });
+                } else {
+                    rayon::in_place_scope(|scope| {
+                        $(
+                            let result = &mut results.$index;
+                            let system = self.$index;
+                            scope.spawn(move |_| *result = Some(system.run((), world)));
+                        )+
+                    });
+                }
+
+                Ok(($(results.$index.unwrap()?,)+))
+            }
+        }
+    }
+}
+
+macro_rules! parallel_systems {
+    ($(($system: ident, $borrow: ident, $index: tt))*;($system1: ident, $borrow1: ident, $index1: tt) $(($queue_system: ident, $queue_borrow: ident, $queue_index: tt))*) => {
+        impl_parallel_systems![$(($system, $borrow, $index))* ($system1, $borrow1, $index1)];
+        parallel_systems![$(($system, $borrow, $index))* ($system1, $borrow1, $index1); $(($queue_system, $queue_borrow, $queue_index))*];
+    };
+    ($(($system: ident, $borrow: ident, $index: tt))*;) => {}
+}
+
+parallel_systems![(A, BA, 0); (B, BB, 1) (C, BC, 2) (D, BD, 3) (E, BE, 4) (F, BF, 5) (G, BG, 6) (H, BH, 7) (I, BI, 8) (J, BJ, 9)];
@@ -93,7 +93,7 @@ impl RemovalOrDeletionTracking for All {
     }
 
     fn clear_all_removed_and_deleted<T: Component>(sparse_set: &mut SparseSet<T>) {
-        sparse_set.deletion_data.clear();
+        sparse_set.private_clear_deleted();
         sparse_set.removal_data.clear();
     }
 
@@ -101,9 +101,7 @@ impl RemovalOrDeletionTracking for All {
         sparse_set: &mut SparseSet<T>,
         timestamp: TrackingTimestamp,
     ) {
-        sparse_set
-            .deletion_data
-            .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        sparse_set.private_clear_deleted_older_than(timestamp);
 
         sparse_set
             .removal_data
@@ -63,7 +63,7 @@ impl RemovalOrDeletionTracking for DeletionAndRemoval {
     }
 
     fn clear_all_removed_and_deleted<T: Component>(sparse_set: &mut SparseSet<T>) {
-        sparse_set.deletion_data.clear();
+        sparse_set.private_clear_deleted();
         sparse_set.removal_data.clear();
     }
 
@@ -71,9 +71,7 @@ impl RemovalOrDeletionTracking for DeletionAndRemoval {
         sparse_set: &mut SparseSet<T>,
         timestamp: TrackingTimestamp,
     ) {
-        sparse_set
-            .deletion_data
-            .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        sparse_set.private_clear_deleted_older_than(timestamp);
 
         sparse_set
             .removal_data
@@ -63,15 +63,13 @@ impl RemovalOrDeletionTracking for ModificationAndDeletion {
             .chain([].iter().copied())
     }
     fn clear_all_removed_and_deleted<T: Component>(sparse_set: &mut SparseSet<T>) {
-        sparse_set.deletion_data.clear();
+        sparse_set.private_clear_deleted();
     }
 
     fn clear_all_removed_and_deleted_older_than_timestamp<T: Component>(
         sparse_set: &mut SparseSet<T>,
         timestamp: TrackingTimestamp,
     ) {
-        sparse_set
-            .deletion_data
-            .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        sparse_set.private_clear_deleted_older_than(timestamp);
     }
 }
@@ -78,7 +78,7 @@ impl RemovalOrDeletionTracking for ModificationAndDeletionAndRemoval {
     }
 
     fn clear_all_removed_and_deleted<T: Component>(sparse_set: &mut SparseSet<T>) {
-        sparse_set.deletion_data.clear();
+        sparse_set.private_clear_deleted();
         sparse_set.removal_data.clear();
     }
 
@@ -86,9 +86,7 @@ impl RemovalOrDeletionTracking for ModificationAndDeletionAndRemoval {
         sparse_set: &mut SparseSet<T>,
         timestamp: TrackingTimestamp,
     ) {
-        sparse_set
-            .deletion_data
-            .retain(|(_, t, _)| timestamp.is_older_than(*t));
+        sparse_set.private_clear_deleted_older_than(timestamp);
 
         sparse_set
             .removal_data
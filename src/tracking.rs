@@ -181,7 +181,20 @@ pub(crate) fn map_deletion_data<T>(
 }
 
 /// Timestamp used to clear tracking information.
-#[derive(Clone, Copy, Debug)]
+///
+/// ### Semantics
+///
+/// The `World` holds a single counter shared by all its storages.\
+/// Each borrow of a view and each [`World::tick`] advances it by one, so timestamps taken later are always newer.
+///
+/// Clearing tracking information (`clear_all_inserted`, `clear_all_modified`,...) never rewinds the counter,
+/// it only moves the start of the tracking window forward.
+///
+/// The counter wraps around after `u32::MAX` cycles.
+/// Comparisons are meaningful as long as both timestamps are less than `u32::MAX / 2` cycles apart.
+///
+/// [`World::tick`]: crate::World::tick
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct TrackingTimestamp(u32);
 
 impl TrackingTimestamp {
@@ -191,8 +204,10 @@ impl TrackingTimestamp {
         TrackingTimestamp(now)
     }
 
+    /// Returns the tracking cycle of this timestamp.\
+    /// The value wraps around after `u32::MAX` cycles.
     #[inline]
-    pub(crate) fn get(self) -> u32 {
+    pub fn get(self) -> u32 {
         self.0
     }
 
@@ -215,6 +230,25 @@ impl TrackingTimestamp {
         other.0.wrapping_sub(1).wrapping_sub(self.0) < u32::MAX / 2
     }
 
+    /// Returns the number of cycles between `earlier` and this timestamp.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let world = World::new();
+    ///
+    /// let start = world.tick();
+    /// world.tick();
+    /// let end = world.tick();
+    ///
+    /// assert_eq!(end.elapsed_since(start), 2);
+    /// ```
+    #[inline]
+    pub fn elapsed_since(self, earlier: TrackingTimestamp) -> u32 {
+        self.0.wrapping_sub(earlier.0)
+    }
+
     /// Returns the timesptamp the furthest from the given one.
     #[inline]
     pub fn furthest_from(self) -> TrackingTimestamp {
@@ -255,6 +289,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn elapsed() {
+        let tests = [(0, 0, 0), (10, 5, 5), (2, u32::MAX, 3)];
+
+        for (timestamp, earlier, expected) in tests {
+            assert_eq!(
+                TrackingTimestamp::new(timestamp).elapsed_since(TrackingTimestamp::new(earlier)),
+                expected,
+                "t: {timestamp}, e: {earlier}"
+            );
+        }
+    }
+
     #[test]
     fn is_older() {
         let tests = [
@@ -24,6 +24,7 @@ impl<T: Send + Sync + Component> TupleTrack for T {
     fn track_insertion(all_storages: &mut AllStorages) {
         all_storages
             .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .unwrap()
             .track_insertion();
     }
 
@@ -31,6 +32,7 @@ impl<T: Send + Sync + Component> TupleTrack for T {
     fn track_modification(all_storages: &mut AllStorages) {
         all_storages
             .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .unwrap()
             .track_modification();
     }
 
@@ -38,6 +40,7 @@ impl<T: Send + Sync + Component> TupleTrack for T {
     fn track_deletion(all_storages: &mut AllStorages) {
         all_storages
             .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .unwrap()
             .track_deletion();
     }
 
@@ -45,6 +48,7 @@ impl<T: Send + Sync + Component> TupleTrack for T {
     fn track_removal(all_storages: &mut AllStorages) {
         all_storages
             .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .unwrap()
             .track_removal();
     }
 
@@ -52,6 +56,7 @@ impl<T: Send + Sync + Component> TupleTrack for T {
     fn track_all(all_storages: &mut AllStorages) {
         all_storages
             .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<T>>(), SparseSet::<T>::new)
+            .unwrap()
             .track_all();
     }
 }
@@ -64,6 +69,7 @@ macro_rules! impl_track {
                 $(
                     all_storages
                         .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<$type>>(), SparseSet::<$type>::new)
+                        .unwrap()
                         .track_insertion();
                 )+
             }
@@ -72,6 +78,7 @@ macro_rules! impl_track {
                 $(
                     all_storages
                         .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<$type>>(), SparseSet::<$type>::new)
+                        .unwrap()
                         .track_modification();
                 )+
             }
@@ -80,6 +87,7 @@ macro_rules! impl_track {
                 $(
                     all_storages
                         .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<$type>>(), SparseSet::<$type>::new)
+                        .unwrap()
                         .track_deletion();
                 )+
             }
@@ -88,6 +96,7 @@ macro_rules! impl_track {
                 $(
                     all_storages
                         .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<$type>>(), SparseSet::<$type>::new)
+                        .unwrap()
                         .track_removal();
                 )+
             }
@@ -96,6 +105,7 @@ macro_rules! impl_track {
                 $(
                     all_storages
                         .exclusive_storage_or_insert_mut(StorageId::of::<SparseSet<$type>>(), SparseSet::<$type>::new)
+                        .unwrap()
                         .track_all();
                 )+
             }
@@ -2,6 +2,8 @@ mod hasher;
 
 pub(crate) use hasher::TypeIdHasher;
 
+use siphasher::sip128::{Hasher128, SipHasher13};
+
 use core::{
     hash::{Hash, Hasher},
     mem::{align_of, size_of},
@@ -18,6 +20,15 @@ impl TypeId {
     pub fn of<T: ?Sized + 'static>() -> Self {
         core::any::TypeId::of::<T>().into()
     }
+    /// Returns a `TypeId` derived from `name`.\
+    /// Unlike [`TypeId::of`], the result is the same across builds, compiler versions and platforms.
+    pub fn of_name(name: &str) -> Self {
+        let mut hasher = SipHasher13::new_with_keys(0, 0);
+
+        hasher.write(name.as_bytes());
+
+        TypeId(hasher.finish128().into())
+    }
     #[cfg(test)]
     pub(crate) fn of_val<T: ?Sized + 'static>(_: &T) -> TypeId {
         core::any::TypeId::of::<T>().into()
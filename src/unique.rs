@@ -1,10 +1,27 @@
 use crate::component::Unique;
-use crate::memory_usage::StorageMemoryUsage;
+use crate::memory_usage::{StorageCategory, StorageMemoryUsage};
+use crate::seal::Private;
 use crate::storage::Storage;
 use crate::tracking::TrackingTimestamp;
-use core::any::type_name;
+use alloc::borrow::Cow;
+use core::any::{type_name, Any};
 use core::mem::size_of;
 
+/// Information about a unique, see [`World::uniques_info`](crate::World::uniques_info).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UniqueInfo {
+    /// Type name of the unique.
+    pub name: Cow<'static, str>,
+    /// Size of the unique in bytes, memory it owns on the heap is not included.
+    pub size_bytes: usize,
+    /// `false` for uniques added with `add_unique_non_send` or `add_unique_non_send_sync`.
+    pub is_send: bool,
+    /// `false` for uniques added with `add_unique_non_sync` or `add_unique_non_send_sync`.
+    pub is_sync: bool,
+    /// Last time the unique was accessed mutably, `None` if it wasn't since it was added.
+    pub last_modified: Option<TrackingTimestamp>,
+}
+
 /// Unique storage.
 pub struct UniqueStorage<T: Unique> {
     pub(crate) value: T,
@@ -20,12 +37,38 @@ impl<T: Unique> Storage for UniqueStorage<T> {
             storage_name: type_name::<Self>().into(),
             allocated_memory_bytes: size_of::<Self>(),
             used_memory_bytes: size_of::<Self>(),
+            used_tracking_memory_bytes: 0,
+            allocated_tracking_memory_bytes: 0,
             component_count: 1,
+            category: StorageCategory::Unique,
         })
     }
     fn is_empty(&self) -> bool {
         false
     }
+    fn unique_value(&self, _: Private) -> Option<&dyn Any> {
+        Some(&self.value)
+    }
+    fn unique_value_mut(
+        &mut self,
+        current: TrackingTimestamp,
+        _: Private,
+    ) -> Option<&mut (dyn Any + 'static)> {
+        self.modification = current;
+
+        Some(&mut self.value)
+    }
+    fn unique_info(&self, current: TrackingTimestamp, _: Private) -> Option<UniqueInfo> {
+        Some(UniqueInfo {
+            name: type_name::<T>().into(),
+            size_bytes: size_of::<T>(),
+            is_send: true,
+            is_sync: true,
+            // uniques that were never modified hold a timestamp ahead of `current`
+            last_modified: Some(self.modification)
+                .filter(|&modification| !current.is_older_than(modification)),
+        })
+    }
 }
 
 impl<T: Unique> UniqueStorage<T> {
@@ -39,3 +82,36 @@ impl<T: Unique> UniqueStorage<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter;
+    impl Unique for Counter {}
+
+    #[test]
+    fn last_modified_across_wraparound() {
+        let insert = TrackingTimestamp::new(u32::MAX - 1);
+        let mut unique = UniqueStorage::new(Counter, insert);
+
+        assert_eq!(
+            unique
+                .unique_info(TrackingTimestamp::new(3), Private)
+                .unwrap()
+                .last_modified,
+            None
+        );
+
+        let modification = TrackingTimestamp::new(1);
+        unique.unique_value_mut(modification, Private);
+
+        assert_eq!(
+            unique
+                .unique_info(TrackingTimestamp::new(3), Private)
+                .unwrap()
+                .last_modified,
+            Some(modification)
+        );
+    }
+}
@@ -1,5 +1,13 @@
 mod all_storages;
+mod atomic_view_mut;
 mod entities;
+mod maybe_view;
+#[cfg(feature = "std")]
+mod rng;
+mod shared_component;
+#[cfg(feature = "std")]
+mod system_context;
+mod unique_atomic_view;
 mod unique_or_default;
 mod unique_or_default_mut;
 mod unique_or_init;
@@ -7,10 +15,22 @@ mod unique_or_init_mut;
 mod unique_view;
 mod unique_view_mut;
 mod view;
+mod view_labeled;
 mod view_mut;
+mod workload_data;
 
 pub use all_storages::{AllStoragesView, AllStoragesViewMut};
+pub use atomic_view_mut::AtomicViewMut;
 pub use entities::{EntitiesView, EntitiesViewMut};
+pub use maybe_view::MaybeView;
+#[cfg(feature = "std")]
+pub use rng::{Rng, RngUnique};
+pub use shared_component::{SharedComponentView, SharedComponentViewMut};
+#[cfg(feature = "std")]
+pub use system_context::SystemContext;
+#[cfg(feature = "std")]
+pub(crate) use system_context::{next_rng_stream, CurrentSystemGuard, NestedRunGuard, RngStream};
+pub use unique_atomic_view::UniqueAtomicView;
 pub use unique_or_default::UniqueOrDefaultView;
 pub use unique_or_default_mut::UniqueOrDefaultViewMut;
 pub use unique_or_init::UniqueOrInitView;
@@ -18,4 +38,7 @@ pub use unique_or_init_mut::UniqueOrInitViewMut;
 pub use unique_view::UniqueView;
 pub use unique_view_mut::UniqueViewMut;
 pub use view::View;
+pub use view_labeled::{ViewLabeled, ViewLabeledMut};
 pub use view_mut::ViewMut;
+pub use workload_data::WorkloadData;
+pub(crate) use workload_data::WorkloadDataGuard;
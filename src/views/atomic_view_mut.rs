@@ -0,0 +1,160 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::SharedBorrow;
+use crate::borrow::{Borrow, BorrowInfo};
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::get::Get;
+use crate::info::TypeInfo;
+use crate::tracking::{Tracking, TrackingTimestamp};
+use crate::views::View;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+/// Shared view over a component storage, for components updated through a shared reference, like atomics.
+///
+/// The storage is borrowed exactly like a [`View`], the scheduler sees a shared borrow and
+/// systems accumulating into the same storage can run in the same batch.\
+/// It doesn't add any synchronization, the component has to provide it, with atomics or locks for example.
+/// The view only states the intent in the system's signature and provides [`update`](AtomicViewMut::update)
+/// and [`par_update`](AtomicViewMut::par_update) on top of the `View` it derefs to.\
+/// Components updated through this view are not flagged as modified.
+///
+/// ### Example
+/// ```
+/// use shipyard::{AtomicViewMut, Component, IntoIter, View, Workload, World};
+/// use std::sync::atomic::{AtomicU32, Ordering};
+///
+/// #[derive(Component, Default)]
+/// struct Damage(AtomicU32);
+///
+/// fn fire(damage: AtomicViewMut<Damage>) {
+///     for damage in damage.iter() {
+///         damage.0.fetch_add(2, Ordering::Relaxed);
+///     }
+/// }
+///
+/// fn poison(damage: AtomicViewMut<Damage>) {
+///     for damage in damage.iter() {
+///         damage.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// let entity = world.add_entity((Damage::default(),));
+///
+/// Workload::new("Damage")
+///     .with_system(fire)
+///     .with_system(poison)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Damage").unwrap();
+///
+/// world.run(|damage: View<Damage>| {
+///     assert_eq!(damage[entity].0.load(Ordering::Relaxed), 3);
+/// });
+/// ```
+pub struct AtomicViewMut<'v, T: Component, Track: Tracking = <T as Component>::Tracking>(
+    View<'v, T, Track>,
+);
+
+impl<'v, T: Component, Track: Tracking> AtomicViewMut<'v, T, Track> {
+    /// Calls `f` with `entity`'s component.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` doesn't have a component in this storage.
+    pub fn update<R, F: FnOnce(&T) -> R>(
+        &self,
+        entity: EntityId,
+        f: F,
+    ) -> Result<R, error::MissingComponent> {
+        (&self.0).get(entity).map(f)
+    }
+    /// Calls `f` on all components of the storage in parallel.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AtomicViewMut, Component, World};
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    ///
+    /// #[derive(Component, Default)]
+    /// struct Damage(AtomicU32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.add_entity((Damage::default(),));
+    ///
+    /// world.run(|damage: AtomicViewMut<Damage>| {
+    ///     damage.par_update(|_, damage| {
+    ///         damage.0.fetch_add(2, Ordering::Relaxed);
+    ///     });
+    ///
+    ///     assert_eq!(damage[entity].0.load(Ordering::Relaxed), 2);
+    /// });
+    /// ```
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    pub fn par_update<F: Fn(EntityId, &T) + Send + Sync>(&self, f: F)
+    where
+        T: Sync,
+    {
+        use rayon::prelude::*;
+
+        let sparse_set = self.0.sparse_set;
+
+        sparse_set
+            .dense
+            .par_iter()
+            .zip(&sparse_set.data)
+            .for_each(|(&id, component)| f(id, component));
+    }
+}
+
+impl<'v, T: Component, Track: Tracking> Deref for AtomicViewMut<'v, T, Track> {
+    type Target = View<'v, T, Track>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Send + Sync + Component, Track> Borrow for AtomicViewMut<'_, T, Track>
+where
+    Track: Tracking,
+{
+    type View<'a> = AtomicViewMut<'a, T, Track>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        View::<T, Track>::borrow(all_storages, all_borrow, last_run, current).map(AtomicViewMut)
+    }
+}
+
+unsafe impl<T: Send + Sync + Component, Track> BorrowInfo for AtomicViewMut<'_, T, Track>
+where
+    Track: Tracking,
+{
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        View::<T, Track>::borrow_info(info);
+    }
+    fn enable_tracking(
+        enable_tracking_fn: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    ) {
+        View::<T, Track>::enable_tracking(enable_tracking_fn);
+    }
+}
+
+impl<T: core::fmt::Debug + Component, Track: Tracking> core::fmt::Debug
+    for AtomicViewMut<'_, T, Track>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,209 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::atomic_refcell::{ARef, SharedBorrow};
+use crate::borrow::{Borrow, BorrowInfo, Mutability};
+use crate::component::Component;
+use crate::contains::Contains;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::get::Get;
+use crate::info::TypeInfo;
+use crate::iter::IntoAbstract;
+use crate::sparse_set::{FullRawWindow, SparseSet};
+use crate::storage::StorageId;
+use crate::tracking::{Tracking, TrackingTimestamp};
+use crate::type_id::TypeId;
+use crate::views::View;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::fmt;
+
+/// Shared view over a component storage that might not exist.
+///
+/// Unlike [`View`], borrowing a `MaybeView` never creates the storage.\
+/// When the storage is missing the view behaves like an empty storage: iteration yields nothing and [`get`](Get::get) always fails.\
+/// Useful for generic systems that should degrade gracefully in worlds that don't use some components.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, IntoIter, MaybeView, World};
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// let mut world = World::new();
+///
+/// world.run(|healths: MaybeView<Health>| {
+///     assert!(!healths.is_present());
+///     assert_eq!(healths.iter().count(), 0);
+/// });
+///
+/// // the storage wasn't created by the previous borrow
+/// assert!(!world.borrow::<MaybeView<Health>>().unwrap().is_present());
+///
+/// world.add_entity(Health(10));
+///
+/// world.run(|healths: MaybeView<Health>| {
+///     assert!(healths.is_present());
+///     assert_eq!(healths.iter().map(|health| health.0).sum::<u32>(), 10);
+/// });
+/// ```
+pub struct MaybeView<'a, T: Component, Track: Tracking = <T as Component>::Tracking> {
+    view: Option<View<'a, T, Track>>,
+}
+
+impl<'a, T: Component, Track: Tracking> MaybeView<'a, T, Track> {
+    /// Returns `true` if the storage exists.
+    #[inline]
+    pub fn is_present(&self) -> bool {
+        self.view.is_some()
+    }
+    /// Returns the inner [`View`] if the storage exists.
+    #[inline]
+    pub fn as_view(&self) -> Option<&View<'a, T, Track>> {
+        self.view.as_ref()
+    }
+    /// Returns the inner [`View`] if the storage exists.
+    #[inline]
+    pub fn into_view(self) -> Option<View<'a, T, Track>> {
+        self.view
+    }
+    /// Returns the number of components in the storage, `0` if it doesn't exist.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.view.as_ref().map_or(0, |view| SparseSet::len(view))
+    }
+    /// Returns `true` if the storage doesn't exist or contains no component.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: Send + Sync + Component, Track> Borrow for MaybeView<'_, T, Track>
+where
+    Track: Tracking,
+{
+    type View<'a> = MaybeView<'a, T, Track>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let view = match all_storages.custom_storage::<SparseSet<T>>() {
+            Ok(view) => view,
+            Err(error::GetStorage::MissingStorage { .. }) => return Ok(MaybeView { view: None }),
+            Err(err) => return Err(err),
+        };
+
+        let (sparse_set, borrow) = unsafe { ARef::destructure(view) };
+
+        sparse_set.check_tracking::<Track>()?;
+
+        Ok(MaybeView {
+            view: Some(View::new(
+                sparse_set,
+                borrow,
+                all_borrow,
+                last_run,
+                current,
+                all_storages.entity_names(),
+            )),
+        })
+    }
+}
+
+unsafe impl<'a, T: Send + Sync + Component, Track> BorrowInfo for MaybeView<'a, T, Track>
+where
+    Track: Tracking,
+{
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<SparseSet<T>>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of::<SparseSet<T>>(),
+            thread_safe: true,
+            optional: true,
+        });
+    }
+    fn enable_tracking(
+        enable_tracking_fn: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    ) {
+        enable_tracking_fn.push(|all_storages| {
+            match all_storages.custom_storage_mut::<SparseSet<T>>() {
+                Ok(mut sparse_set) => sparse_set.enable_tracking::<Track>(),
+                Err(error::GetStorage::MissingStorage { .. }) => {}
+                Err(err) => return Err(err),
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<'a: 'b, 'b, T: Component, Track: Tracking> IntoAbstract for &'b MaybeView<'a, T, Track> {
+    type AbsView = FullRawWindow<'b, T>;
+
+    #[inline]
+    fn into_abstract(self) -> Self::AbsView {
+        match &self.view {
+            Some(view) => FullRawWindow::from_view(view),
+            None => FullRawWindow::empty(),
+        }
+    }
+    #[inline]
+    fn len(&self) -> Option<usize> {
+        Some(MaybeView::len(self))
+    }
+    #[inline]
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<SparseSet<T>>()
+    }
+    #[inline]
+    fn inner_type_id(&self) -> TypeId {
+        TypeId::of::<T>()
+    }
+    #[inline]
+    fn dense(&self) -> *const EntityId {
+        match &self.view {
+            Some(view) => view.dense.as_ptr(),
+            None => core::ptr::NonNull::dangling().as_ptr(),
+        }
+    }
+    #[cfg(feature = "parallel")]
+    #[inline]
+    fn par_min_len(&self) -> Option<usize> {
+        self.view.as_ref().map(|view| SparseSet::par_min_len(view))
+    }
+}
+
+impl<'a, 'b, T: Component, Track: Tracking> Get for &'b MaybeView<'a, T, Track> {
+    type Out = &'b T;
+
+    #[inline]
+    fn get(self, entity: EntityId) -> Result<Self::Out, error::MissingComponent> {
+        match &self.view {
+            Some(view) => Get::get(view, entity),
+            None => Err(error::MissingComponent {
+                id: entity,
+                name: type_name::<T>(),
+            }),
+        }
+    }
+}
+
+impl<'a: 'b, 'b, T: Component, Track: Tracking> Contains for &'b MaybeView<'a, T, Track> {
+    fn contains(&self, entity: EntityId) -> bool {
+        self.view
+            .as_ref()
+            .is_some_and(|view| SparseSet::contains(view, entity))
+    }
+}
+
+impl<T: fmt::Debug + Component, Track: Tracking> fmt::Debug for MaybeView<'_, T, Track> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MaybeView").field(&self.view).finish()
+    }
+}
@@ -0,0 +1,188 @@
+use crate::component::Unique;
+use crate::views::{next_rng_stream, UniqueView};
+use core::hash::Hasher;
+use core::ops::Range;
+use core::sync::atomic::{AtomicU64, Ordering};
+use siphasher::sip::SipHasher13;
+
+/// Seed of the random number streams handed out by [`Rng`].
+///
+/// Each system of a workload gets its own stream, derived from the seed, the workload's name and the order systems were added in.\
+/// Systems can then use [`Rng`] in parallel and still get the same numbers from one run to the next.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Rng, RngUnique, World};
+///
+/// let world = World::new();
+/// world.add_unique(RngUnique::new(42));
+///
+/// let roll = world.run(|mut rng: Rng| rng.range(1..7));
+///
+/// assert!((1..7).contains(&roll));
+/// ```
+pub struct RngUnique {
+    seed: u64,
+    /// Number of [`Rng`] borrowed outside of workloads
+    outside_borrows: AtomicU64,
+}
+
+impl Unique for RngUnique {}
+
+impl RngUnique {
+    /// Creates the streams' shared seed.
+    pub fn new(seed: u64) -> RngUnique {
+        RngUnique {
+            seed,
+            outside_borrows: AtomicU64::new(0),
+        }
+    }
+    /// Returns the seed the streams are derived from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+    /// Derives the following numbers from `seed`.\
+    /// Systems run outside of a workload restart their stream, systems in a workload continue from their workload's number of runs.
+    pub fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        *self.outside_borrows.get_mut() = 0;
+    }
+    /// Returns the initial state of a `Rng`.
+    fn state(&self, stream: Option<u64>, run: u64, borrows: u64) -> u64 {
+        let mut hasher = SipHasher13::new_with_keys(self.seed, 0);
+
+        match stream {
+            Some(key) => {
+                hasher.write_u8(1);
+                hasher.write_u64(key);
+            }
+            None => hasher.write_u8(0),
+        }
+        hasher.write_u64(run);
+        hasher.write_u64(borrows);
+
+        hasher.finish()
+    }
+}
+
+/// Random number generator of the running system, requires a [`RngUnique`].
+///
+/// Each system of a workload has its own stream, a system added twice gets two streams.\
+/// The numbers of a run only depend on the seed, the workload's name, the order systems were added in and the number of times the workload ran.
+/// They don't depend on the order systems run in.\
+/// Systems run outside of a workload all share a single stream.
+///
+/// Only [`RngUnique`] is borrowed, and only shared, so systems using `Rng` can run in parallel.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Rng, RngUnique, Workload, World};
+///
+/// fn spawn_enemies(mut rng: Rng) {
+///     let _count = rng.range(0..5);
+/// }
+///
+/// fn spawn_loot(mut rng: Rng) {
+///     let _drop_rate = rng.next_f64();
+/// }
+///
+/// let world = World::new();
+/// world.add_unique(RngUnique::new(42));
+///
+/// Workload::new("Spawn")
+///     .with_system(spawn_enemies)
+///     .with_system(spawn_loot)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Spawn").unwrap();
+/// ```
+pub struct Rng<'v> {
+    rng: UniqueView<'v, RngUnique>,
+    state: u64,
+}
+
+impl<'v> Rng<'v> {
+    /// Picks the stream of the running system.
+    pub(crate) fn new(rng: UniqueView<'v, RngUnique>) -> Rng<'v> {
+        let state = match next_rng_stream() {
+            Some((stream, borrows)) => {
+                rng.state(Some(stream.key), stream.run.into(), borrows.into())
+            }
+            None => {
+                let borrows = rng.outside_borrows.fetch_add(1, Ordering::Relaxed);
+
+                rng.state(None, 0, borrows)
+            }
+        };
+
+        Rng { rng, state }
+    }
+    /// Returns a random `u64`.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    /// Returns a random `u32`.
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+    /// Returns a random `f64` in `[0, 1)`.
+    // 53 bits fit in `f64`'s mantissa, no precision is lost
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+    /// Returns a random `f32` in `[0, 1)`.
+    // 24 bits fit in `f32`'s mantissa, no precision is lost
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+    /// Returns a random `bool`.
+    #[inline]
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+    /// Returns a random `u64` in `range`.
+    ///
+    /// ### Panics
+    ///
+    /// - `range` is empty.
+    #[track_caller]
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(
+            range.start < range.end,
+            "Cannot pick a number in an empty range."
+        );
+
+        let len = range.end - range.start;
+        // rejects the values that would favor the lower numbers
+        let zone = u64::MAX - u64::MAX % len;
+
+        loop {
+            let value = self.next_u64();
+
+            if value < zone {
+                return range.start + value % len;
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for Rng<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Rng")
+            .field("seed", &self.rng.seed)
+            .finish_non_exhaustive()
+    }
+}
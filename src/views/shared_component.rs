@@ -0,0 +1,140 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::atomic_refcell::{ARef, ARefMut, ExclusiveBorrow, SharedBorrow};
+use crate::borrow::{Borrow, BorrowInfo, Mutability};
+use crate::error;
+use crate::info::TypeInfo;
+use crate::shared_component::SharedComponentStorage;
+use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::fmt;
+use core::hash::Hash;
+use core::ops::{Deref, DerefMut};
+
+/// Shared view over a [`SharedComponentStorage`].
+///
+/// The storage is created if it doesn't exist.
+pub struct SharedComponentView<'v, T: Hash + Eq + Send + Sync + 'static> {
+    storage: &'v SharedComponentStorage<T>,
+    _borrow: SharedBorrow<'v>,
+    _all_borrow: Option<SharedBorrow<'v>>,
+}
+
+impl<T: Hash + Eq + Send + Sync + 'static> Deref for SharedComponentView<'_, T> {
+    type Target = SharedComponentStorage<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.storage
+    }
+}
+
+impl<T: Hash + Eq + Send + Sync + 'static> Borrow for SharedComponentView<'_, T> {
+    type View<'a> = SharedComponentView<'a, T>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let view = all_storages.custom_storage_or_insert(SharedComponentStorage::<T>::new)?;
+
+        let (storage, borrow) = unsafe { ARef::destructure(view) };
+
+        Ok(SharedComponentView {
+            storage,
+            _borrow: borrow,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+unsafe impl<T: Hash + Eq + Send + Sync + 'static> BorrowInfo for SharedComponentView<'_, T> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<SharedComponentStorage<T>>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of::<SharedComponentStorage<T>>(),
+            thread_safe: true,
+            optional: false,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+impl<T: Hash + Eq + fmt::Debug + Send + Sync + 'static> fmt::Debug for SharedComponentView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.storage.fmt(f)
+    }
+}
+
+/// Exclusive view over a [`SharedComponentStorage`].
+///
+/// The storage is created if it doesn't exist.
+pub struct SharedComponentViewMut<'v, T: Hash + Eq + Send + Sync + 'static> {
+    storage: &'v mut SharedComponentStorage<T>,
+    _borrow: ExclusiveBorrow<'v>,
+    _all_borrow: Option<SharedBorrow<'v>>,
+}
+
+impl<T: Hash + Eq + Send + Sync + 'static> Deref for SharedComponentViewMut<'_, T> {
+    type Target = SharedComponentStorage<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.storage
+    }
+}
+
+impl<T: Hash + Eq + Send + Sync + 'static> DerefMut for SharedComponentViewMut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.storage
+    }
+}
+
+impl<T: Hash + Eq + Send + Sync + 'static> Borrow for SharedComponentViewMut<'_, T> {
+    type View<'a> = SharedComponentViewMut<'a, T>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let view = all_storages.custom_storage_or_insert_mut(SharedComponentStorage::<T>::new)?;
+
+        let (storage, borrow) = unsafe { ARefMut::destructure(view) };
+
+        Ok(SharedComponentViewMut {
+            storage,
+            _borrow: borrow,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+unsafe impl<T: Hash + Eq + Send + Sync + 'static> BorrowInfo for SharedComponentViewMut<'_, T> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<SharedComponentStorage<T>>().into(),
+            mutability: Mutability::Exclusive,
+            storage_id: StorageId::of::<SharedComponentStorage<T>>(),
+            thread_safe: true,
+            optional: false,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+impl<T: Hash + Eq + fmt::Debug + Send + Sync + 'static> fmt::Debug
+    for SharedComponentViewMut<'_, T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.storage.fmt(f)
+    }
+}
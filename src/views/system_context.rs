@@ -0,0 +1,249 @@
+use crate::atomic_refcell::{ARef, SharedBorrow};
+use crate::scheduler::{Batches, Label};
+use crate::tracking::TrackingTimestamp;
+use crate::World;
+use alloc::boxed::Box;
+use core::cell::Cell;
+
+std::thread_local! {
+    static CURRENT_SYSTEM: Cell<Option<CurrentSystem>> = const { Cell::new(None) };
+}
+
+/// System running on this thread.
+#[derive(Clone, Copy)]
+struct CurrentSystem {
+    /// `None` during a nested [`World::run`](crate::World::run)
+    position: Option<SystemPosition>,
+    rng_stream: RngStream,
+    /// Number of [`Rng`](crate::Rng) borrowed during this run
+    rng_borrows: u32,
+}
+
+/// Where the running system is in its workload.
+#[derive(Clone, Copy)]
+struct SystemPosition {
+    /// Index into the system names
+    system: usize,
+    batch: usize,
+    /// Only compared to the `World`'s scheduler, never dereferenced
+    system_names: *const Box<dyn Label>,
+    /// Only compared to the `World`'s workloads, never dereferenced
+    batches: *const Batches,
+}
+
+/// Identifies the [`Rng`](crate::Rng) numbers of a system run.
+#[derive(Clone, Copy)]
+pub(crate) struct RngStream {
+    /// Key of the system's stream, computed when the workload is built
+    pub(crate) key: u64,
+    /// Run of the workload the system is part of
+    pub(crate) run: u32,
+}
+
+/// Sets the system running on this thread until dropped.
+pub(crate) struct CurrentSystemGuard {
+    previous: Option<CurrentSystem>,
+}
+
+impl CurrentSystemGuard {
+    #[inline]
+    pub(crate) fn new(
+        system_names: &[Box<dyn Label>],
+        system: usize,
+        batches: &Batches,
+        batch: usize,
+        rng_stream: RngStream,
+    ) -> CurrentSystemGuard {
+        let previous = CURRENT_SYSTEM.with(|current| {
+            current.replace(Some(CurrentSystem {
+                position: Some(SystemPosition {
+                    system,
+                    batch,
+                    system_names: system_names.as_ptr(),
+                    batches,
+                }),
+                rng_stream,
+                rng_borrows: 0,
+            }))
+        });
+
+        CurrentSystemGuard { previous }
+    }
+}
+
+impl Drop for CurrentSystemGuard {
+    #[inline]
+    fn drop(&mut self) {
+        CURRENT_SYSTEM.with(|current| current.set(self.previous));
+    }
+}
+
+/// Hides the running system from the views borrowed by a nested [`World::run`](crate::World::run) until dropped.\
+/// The [`Rng`](crate::Rng) stream is kept, nested runs draw from the system's stream.
+pub(crate) struct NestedRunGuard {
+    position: Option<SystemPosition>,
+}
+
+impl NestedRunGuard {
+    #[inline]
+    pub(crate) fn new() -> NestedRunGuard {
+        let position = CURRENT_SYSTEM.with(|current| {
+            let mut current_system = current.get()?;
+            let position = current_system.position.take();
+            current.set(Some(current_system));
+
+            position
+        });
+
+        NestedRunGuard { position }
+    }
+}
+
+impl Drop for NestedRunGuard {
+    #[inline]
+    fn drop(&mut self) {
+        CURRENT_SYSTEM.with(|current| {
+            if let Some(mut current_system) = current.get() {
+                current_system.position = self.position;
+                current.set(Some(current_system));
+            }
+        });
+    }
+}
+
+/// Returns the stream of the system running on this thread and how many [`Rng`](crate::Rng) it borrowed before.
+pub(crate) fn next_rng_stream() -> Option<(RngStream, u32)> {
+    CURRENT_SYSTEM.with(|current| {
+        let mut current_system = current.get()?;
+        let borrows = current_system.rng_borrows;
+
+        current_system.rng_borrows += 1;
+        current.set(Some(current_system));
+
+        Some((current_system.rng_stream, borrows))
+    })
+}
+
+/// Information about the system currently running.
+///
+/// Systems run outside of a workload, with [`World::run`](crate::World::run) for example, don't have a system, workload nor batch.\
+/// This includes `World::run` called from inside a workload's system.\
+/// Systems run by a [`ScheduledWorkload`](crate::ScheduledWorkload) have a batch but no system nor workload label.
+///
+/// ### Borrows
+///
+/// - Scheduler (shared) when run by a workload
+///
+/// ### Example
+/// ```
+/// use shipyard::{SystemContext, Workload, World};
+///
+/// fn log(context: SystemContext) {
+///     let workload = context.workload().unwrap();
+///
+///     assert_eq!(format!("{:?}", workload), "Update");
+///     assert_eq!(context.batch(), Some(0));
+/// }
+///
+/// let world = World::new();
+///
+/// Workload::new("Update")
+///     .with_system(log)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload("Update").unwrap();
+/// ```
+pub struct SystemContext<'a> {
+    system: Option<&'a dyn Label>,
+    workload: Option<&'a dyn Label>,
+    batch: Option<usize>,
+    last_run: Option<TrackingTimestamp>,
+    current: TrackingTimestamp,
+    scheduler_borrow: Option<SharedBorrow<'a>>,
+}
+
+impl<'a> SystemContext<'a> {
+    /// Captures the system running on this thread.
+    pub(crate) fn new(
+        world: &'a World,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Self {
+        let mut context = SystemContext {
+            system: None,
+            workload: None,
+            batch: None,
+            last_run,
+            current,
+            scheduler_borrow: None,
+        };
+
+        let Some(position) = CURRENT_SYSTEM
+            .with(Cell::get)
+            .and_then(|current| current.position)
+        else {
+            return context;
+        };
+
+        context.batch = Some(position.batch);
+
+        let Ok(scheduler) = world.scheduler.borrow() else {
+            return context;
+        };
+
+        // SAFE the borrow is stored next to the references
+        let (scheduler, scheduler_borrow) = unsafe { ARef::destructure(scheduler) };
+
+        // A `ScheduledWorkload` runs systems that are not in the `World`'s scheduler
+        if scheduler.system_names.as_ptr() == position.system_names {
+            context.system = Some(&*scheduler.system_names[position.system]);
+            context.workload = scheduler
+                .workloads
+                .iter()
+                .find(|(_, batches)| core::ptr::eq(*batches, position.batches))
+                .map(|(label, _)| &**label);
+            context.scheduler_borrow = Some(scheduler_borrow);
+        }
+
+        context
+    }
+    /// Returns the label of the running system.
+    #[inline]
+    pub fn system(&self) -> Option<&dyn Label> {
+        self.system
+    }
+    /// Returns the label of the workload the system is part of.
+    #[inline]
+    pub fn workload(&self) -> Option<&dyn Label> {
+        self.workload
+    }
+    /// Returns the index of the batch the system runs in.\
+    /// Without the `parallel` feature, each system is its own batch.
+    #[inline]
+    pub fn batch(&self) -> Option<usize> {
+        self.batch
+    }
+    /// Returns the timestamp of the system's previous run.
+    #[inline]
+    pub fn last_run(&self) -> Option<TrackingTimestamp> {
+        self.last_run
+    }
+    /// Returns the current `World` tick.
+    #[inline]
+    pub fn tick(&self) -> TrackingTimestamp {
+        self.current
+    }
+}
+
+impl core::fmt::Debug for SystemContext<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SystemContext")
+            .field("system", &self.system)
+            .field("workload", &self.workload)
+            .field("batch", &self.batch)
+            .field("last_run", &self.last_run)
+            .field("tick", &self.current)
+            .finish()
+    }
+}
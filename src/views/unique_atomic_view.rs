@@ -0,0 +1,101 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::SharedBorrow;
+use crate::borrow::{Borrow, BorrowInfo};
+use crate::component::AtomicUnique;
+use crate::error;
+use crate::info::TypeInfo;
+use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
+use crate::views::UniqueView;
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+/// Shared view over a unique storage, for uniques updated through a shared reference, like atomics.
+///
+/// The unique is borrowed exactly like a [`UniqueView`], the scheduler sees a shared borrow and
+/// systems updating it can run in the same batch.\
+/// It doesn't add any synchronization, the unique has to provide it, with atomics or locks for example.
+/// The difference with `UniqueView` is the [`AtomicUnique`] bound: only uniques that opted in can be borrowed
+/// with this view, so a system signature can't use it by mistake for a unique meant to be modified through [`UniqueViewMut`](crate::UniqueViewMut).\
+/// The unique is not flagged as modified.
+///
+/// ### Example
+/// ```
+/// use shipyard::{AtomicUnique, Unique, UniqueAtomicView, Workload, World};
+/// use std::sync::atomic::{AtomicU32, Ordering};
+///
+/// #[derive(Unique, Default)]
+/// struct FrameStats {
+///     spawned: AtomicU32,
+/// }
+///
+/// impl AtomicUnique for FrameStats {}
+///
+/// fn spawn_enemies(stats: UniqueAtomicView<FrameStats>) {
+///     stats.spawned.fetch_add(2, Ordering::Relaxed);
+/// }
+///
+/// fn spawn_items(stats: UniqueAtomicView<FrameStats>) {
+///     stats.spawned.fetch_add(1, Ordering::Relaxed);
+/// }
+///
+/// let world = World::new();
+/// world.add_unique(FrameStats::default());
+///
+/// let (workload, info) = Workload::new("Spawn")
+///     .with_system(spawn_enemies)
+///     .with_system(spawn_items)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(info.batch_info.len(), 1);
+///
+/// workload.run_with_world(&world).unwrap();
+///
+/// let stats = world.get_unique::<&FrameStats>().unwrap();
+/// assert_eq!(stats.spawned.load(Ordering::Relaxed), 3);
+/// ```
+pub struct UniqueAtomicView<'v, T: AtomicUnique>(UniqueView<'v, T>);
+
+impl<'v, T: AtomicUnique> Deref for UniqueAtomicView<'v, T> {
+    type Target = UniqueView<'v, T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Send + Sync + AtomicUnique> Borrow for UniqueAtomicView<'_, T> {
+    type View<'a> = UniqueAtomicView<'a, T>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        UniqueView::<T>::borrow(all_storages, all_borrow, last_run, current).map(UniqueAtomicView)
+    }
+}
+
+unsafe impl<T: Send + Sync + AtomicUnique> BorrowInfo for UniqueAtomicView<'_, T> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        UniqueView::<T>::borrow_info(info);
+    }
+    fn enable_tracking(
+        enable_tracking_fn: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    ) {
+        UniqueView::<T>::enable_tracking(enable_tracking_fn);
+    }
+    fn required_storages(required: &mut Vec<StorageId>) {
+        UniqueView::<T>::required_storages(required);
+    }
+}
+
+impl<T: core::fmt::Debug + AtomicUnique> core::fmt::Debug for UniqueAtomicView<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
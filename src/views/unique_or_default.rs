@@ -50,7 +50,7 @@ impl<'v, T: Unique + Default + Send + Sync> WorldBorrow for UniqueOrDefaultView<
 
 unsafe impl<'v, T: Unique + Default + Send + Sync> BorrowInfo for UniqueOrDefaultView<'v, T> {
     fn borrow_info(info: &mut Vec<TypeInfo>) {
-        UniqueView::<T>::borrow_info(info);
+        Option::<UniqueView<'_, T>>::borrow_info(info);
     }
 
     fn enable_tracking(
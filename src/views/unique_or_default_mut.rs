@@ -56,7 +56,7 @@ impl<'v, T: Unique + Default + Send + Sync> WorldBorrow for UniqueOrDefaultViewM
 
 unsafe impl<'v, T: Unique + Default + Send + Sync> BorrowInfo for UniqueOrDefaultViewMut<'v, T> {
     fn borrow_info(info: &mut Vec<TypeInfo>) {
-        UniqueViewMut::<T>::borrow_info(info);
+        Option::<UniqueViewMut<'_, T>>::borrow_info(info);
     }
 
     fn enable_tracking(
@@ -170,7 +170,7 @@ impl<'v, T: Unique + Default + Send + Sync> WorldBorrow for UniqueOrInitView<'v,
 
 unsafe impl<'v, T: Unique + Default + Send + Sync> BorrowInfo for UniqueOrInitView<'v, T> {
     fn borrow_info(info: &mut Vec<TypeInfo>) {
-        UniqueView::<T>::borrow_info(info);
+        Option::<UniqueView<'_, T>>::borrow_info(info);
     }
 
     fn enable_tracking(
@@ -185,7 +185,7 @@ impl<'v, T: Unique + Default + Send + Sync> WorldBorrow for UniqueOrInitViewMut<
 
 unsafe impl<'v, T: Unique + Default + Send + Sync> BorrowInfo for UniqueOrInitViewMut<'v, T> {
     fn borrow_info(info: &mut Vec<TypeInfo>) {
-        UniqueViewMut::<T>::borrow_info(info);
+        Option::<UniqueViewMut<'_, T>>::borrow_info(info);
     }
 
     fn enable_tracking(
@@ -3,7 +3,8 @@ use crate::atomic_refcell::{ARef, SharedBorrow};
 use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::get::Get;
-use crate::sparse_set::SparseSet;
+use crate::name::missing_component_panic;
+use crate::sparse_set::{Partition, RawWindow, SparseSet};
 use crate::storage::StorageId;
 use crate::track;
 use crate::tracking::{
@@ -13,17 +14,20 @@ use crate::tracking::{
 use crate::{error, TrackingTimestamp};
 use core::fmt;
 use core::marker::PhantomData;
-use core::ops::Deref;
+use core::ops::{Deref, RangeBounds};
 
 /// Shared view over a component storage.
 pub struct View<'a, T: Component, Track: Tracking = <T as Component>::Tracking> {
     pub(crate) sparse_set: &'a SparseSet<T>,
     pub(crate) all_borrow: Option<SharedBorrow<'a>>,
-    pub(crate) borrow: SharedBorrow<'a>,
+    /// `None` when the view was created with [`View::from_raw_parts`]
+    pub(crate) borrow: Option<SharedBorrow<'a>>,
     pub(crate) last_insertion: TrackingTimestamp,
     pub(crate) last_modification: TrackingTimestamp,
     pub(crate) last_removal_or_deletion: TrackingTimestamp,
     pub(crate) current: TrackingTimestamp,
+    /// Only present when entity names are shown in errors.
+    pub(crate) entity_names: Option<&'a AllStorages>,
     pub(crate) phantom: PhantomData<Track>,
 }
 
@@ -55,6 +59,7 @@ impl<'a, T: Component, Track: Tracking> View<'a, T, Track> {
         all_borrow: Option<SharedBorrow<'a>>,
         last_run: Option<TrackingTimestamp>,
         current: TrackingTimestamp,
+        entity_names: Option<&'a AllStorages>,
     ) -> Self {
         let _: () = Self::ASSERT_VIEW_TRACKING_INSERTION;
         let _: () = Self::ASSERT_VIEW_TRACKING_MODIFICATION;
@@ -67,8 +72,9 @@ impl<'a, T: Component, Track: Tracking> View<'a, T, Track> {
             last_removal_or_deletion: last_run.unwrap_or(current),
             current,
             sparse_set,
-            borrow,
+            borrow: Some(borrow),
             all_borrow,
+            entity_names,
             phantom: PhantomData,
         }
     }
@@ -137,6 +143,164 @@ where
     ) -> TrackingTimestamp {
         core::mem::replace(&mut self.last_removal_or_deletion, new_timestamp)
     }
+    /// Returns raw access to the components and the entities owning them.\
+    /// Useful to hand the storage to SIMD code or over FFI.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// #[repr(transparent)]
+    /// struct Speed(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity(Speed(1.0));
+    /// let entity1 = world.add_entity(Speed(2.0));
+    ///
+    /// world.run(|speeds: View<Speed>| {
+    ///     let window = speeds.raw_window();
+    ///
+    ///     assert_eq!(window.ids(), &[entity0, entity1]);
+    ///
+    ///     // SAFE `Speed` is `repr(transparent)` and the window holds `len` components
+    ///     let raw = unsafe { std::slice::from_raw_parts(window.data_ptr() as *const f32, window.len()) };
+    ///     assert_eq!(raw, &[1.0, 2.0]);
+    /// });
+    /// ```
+    #[inline]
+    pub fn raw_window(&self) -> RawWindow<'_, T> {
+        RawWindow::new(&self.sparse_set.dense, &self.sparse_set.data)
+    }
+    /// Returns a [`Partition`] over the components at `range` in the storage.\
+    /// Partitions can be split further and sent to other threads.
+    ///
+    /// ### Panics
+    ///
+    /// - `range` is out of bounds.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.bulk_add_entity((0..10).map(U32));
+    ///
+    /// world.run(|u32s: View<U32>| {
+    ///     let (left, right) = u32s.partition(..).split_at(5);
+    ///
+    ///     std::thread::scope(|scope| {
+    ///         let left = scope.spawn(move || left.as_slice().iter().map(|u32| u32.0).sum::<u32>());
+    ///         let right = scope.spawn(move || right.as_slice().iter().map(|u32| u32.0).sum::<u32>());
+    ///
+    ///         assert_eq!(left.join().unwrap() + right.join().unwrap(), 45);
+    ///     });
+    /// });
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn partition(&self, range: impl RangeBounds<usize>) -> Partition<'_, T> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+
+        Partition::new(&self.sparse_set.dense[range], &self.sparse_set.data[range])
+    }
+    /// Creates a view over a storage handed over as a raw pointer, for example through FFI.\
+    /// The view doesn't borrow the storage, the view the pointer was obtained from has to stay alive.
+    ///
+    /// The timestamps are the tracking time windows of the original view,
+    /// they can be obtained with [`last_insertion`](View::last_insertion), [`last_modification`](View::last_modification),
+    /// [`last_removal_or_deletion`](View::last_removal_or_deletion) and [`current`](View::current).
+    ///
+    /// ### Safety
+    ///
+    /// - `sparse_set` must be non-null, properly aligned and point to an initialized `SparseSet<T>`,
+    ///   for example a pointer returned by [`View::as_ptr`].
+    /// - The storage must not be mutated nor dropped for the lifetime `'a`.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{track, Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Insertion)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(7));
+    ///
+    /// let u32s = world.borrow::<View<U32, track::Insertion>>().unwrap();
+    ///
+    /// let ffi_u32s = unsafe {
+    ///     View::<U32, track::Insertion>::from_raw_parts(
+    ///         u32s.as_ptr(),
+    ///         u32s.last_insertion(),
+    ///         u32s.last_modification(),
+    ///         u32s.last_removal_or_deletion(),
+    ///         u32s.current(),
+    ///     )
+    /// };
+    ///
+    /// assert_eq!(ffi_u32s[entity].0, 7);
+    /// assert!(ffi_u32s.is_inserted(entity));
+    /// ```
+    #[inline]
+    pub unsafe fn from_raw_parts(
+        sparse_set: *const SparseSet<T>,
+        last_insertion: TrackingTimestamp,
+        last_modification: TrackingTimestamp,
+        last_removal_or_deletion: TrackingTimestamp,
+        current: TrackingTimestamp,
+    ) -> Self {
+        let _: () = Self::ASSERT_VIEW_TRACKING_INSERTION;
+        let _: () = Self::ASSERT_VIEW_TRACKING_MODIFICATION;
+        let _: () = Self::ASSERT_VIEW_TRACKING_DELETION;
+        let _: () = Self::ASSERT_VIEW_TRACKING_REMOVAL;
+
+        View {
+            // SAFE the caller guarantees the pointer is valid and the storage isn't mutated for `'a`
+            sparse_set: &*sparse_set,
+            all_borrow: None,
+            borrow: None,
+            last_insertion,
+            last_modification,
+            last_removal_or_deletion,
+            current,
+            entity_names: None,
+            phantom: PhantomData,
+        }
+    }
+    /// Returns a pointer to the storage.\
+    /// It's valid as long as the view is alive.
+    #[inline]
+    pub fn as_ptr(&self) -> *const SparseSet<T> {
+        self.sparse_set
+    }
+    /// Returns the timestamp starting the tracking time window for insertions.
+    #[inline]
+    pub fn last_insertion(&self) -> TrackingTimestamp {
+        self.last_insertion
+    }
+    /// Returns the timestamp starting the tracking time window for modifications.
+    #[inline]
+    pub fn last_modification(&self) -> TrackingTimestamp {
+        self.last_modification
+    }
+    /// Returns the timestamp starting the tracking time window for removals and deletions.
+    #[inline]
+    pub fn last_removal_or_deletion(&self) -> TrackingTimestamp {
+        self.last_removal_or_deletion
+    }
+    /// Returns the timestamp ending the tracking time windows.
+    #[inline]
+    pub fn current(&self) -> TrackingTimestamp {
+        self.current
+    }
 }
 
 impl<'a, T: Component> View<'a, T, track::Untracked> {
@@ -182,11 +346,12 @@ impl<'a, T: Component> View<'a, T, track::Untracked> {
             Ok(View {
                 sparse_set,
                 all_borrow: Some(all_borrow),
-                borrow,
+                borrow: Some(borrow),
                 last_insertion: TrackingTimestamp::new(0),
                 last_modification: TrackingTimestamp::new(0),
                 last_removal_or_deletion: TrackingTimestamp::new(0),
                 current: TrackingTimestamp::new(0),
+                entity_names: all_storages.entity_names(),
                 phantom: PhantomData,
             })
         } else {
@@ -212,6 +377,14 @@ where
     pub fn is_inserted(&self, entity: EntityId) -> bool {
         Track::is_inserted(self.sparse_set, entity, self.last_insertion, self.current)
     }
+    /// Returns the timestamp at which `entity`'s component was inserted.\
+    /// Returns `None` if `entity` does not have a component in this storage.
+    ///
+    /// Can be compared with [`World::get_tracking_timestamp`](crate::World::get_tracking_timestamp) to know how long ago the insertion happened.
+    #[inline]
+    pub fn insertion_timestamp(&self, entity: EntityId) -> Option<TrackingTimestamp> {
+        self.sparse_set.private_insertion_timestamp(entity)
+    }
 }
 
 impl<Track, T: Component> View<'_, T, Track>
@@ -236,6 +409,15 @@ where
             self.current,
         )
     }
+    /// Returns the timestamp at which `entity`'s component was last modified.\
+    /// Returns `None` if `entity` does not have a component in this storage or if the component was never modified.
+    ///
+    /// Can be compared with [`World::get_tracking_timestamp`](crate::World::get_tracking_timestamp) to know how long ago the modification happened.
+    #[inline]
+    pub fn modification_timestamp(&self, entity: EntityId) -> Option<TrackingTimestamp> {
+        self.sparse_set
+            .private_modification_timestamp(entity, self.current)
+    }
 }
 
 impl<Track, T: Component> View<'_, T, Track>
@@ -358,6 +540,13 @@ impl<'a, T: Component, Track: Tracking> AsRef<SparseSet<T>> for View<'a, T, Trac
     }
 }
 
+impl<'a, T: Component, Track: Tracking> AsRef<[T]> for View<'a, T, Track> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.sparse_set.as_slice()
+    }
+}
+
 impl<'a, T: Component, Track: Tracking> Clone for View<'a, T, Track> {
     #[inline]
     fn clone(&self) -> Self {
@@ -369,6 +558,7 @@ impl<'a, T: Component, Track: Tracking> Clone for View<'a, T, Track> {
             last_modification: self.last_modification,
             last_removal_or_deletion: self.last_removal_or_deletion,
             current: self.current,
+            entity_names: self.entity_names,
             phantom: PhantomData,
         }
     }
@@ -385,6 +575,7 @@ impl<T: Component, Track: Tracking> core::ops::Index<EntityId> for View<'_, T, T
     #[track_caller]
     #[inline]
     fn index(&self, entity: EntityId) -> &Self::Output {
-        self.get(entity).unwrap()
+        self.get(entity)
+            .unwrap_or_else(|err| missing_component_panic(self.entity_names, err))
     }
 }
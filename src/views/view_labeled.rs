@@ -0,0 +1,217 @@
+use crate::all_storages::AllStorages;
+use crate::atomic_refcell::{ARef, ARefMut, SharedBorrow};
+use crate::borrow::{Borrow, BorrowInfo, Mutability};
+use crate::component::Component;
+use crate::error;
+use crate::info::TypeInfo;
+use crate::sparse_set::SparseSet;
+use crate::storage::StorageId;
+use crate::tracking::TrackingTimestamp;
+use crate::views::{View, ViewMut};
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+/// Shared view over the `T` storage labeled `LABEL`.
+///
+/// The label is part of the type so systems using it can be scheduled.\
+/// [`World::view_labeled`](crate::World::view_labeled) with the same `u64` label borrows the same storage.
+///
+/// ### Example
+/// ```
+/// use shipyard::{AddComponent, Component, IntoIter, ViewLabeled, ViewLabeledMut, World};
+///
+/// #[derive(Component)]
+/// struct Input(u32);
+///
+/// const PLAYER_1: u64 = 1;
+/// const PLAYER_2: u64 = 2;
+///
+/// fn read_inputs(player1: ViewLabeled<Input, PLAYER_1>, player2: ViewLabeled<Input, PLAYER_2>) {
+///     assert_eq!(player1.iter().map(|input| input.0).sum::<u32>(), 1);
+///     assert!(player2.is_empty());
+/// }
+///
+/// let mut world = World::new();
+///
+/// let entity = world.add_entity(());
+/// world.run(|mut player1: ViewLabeledMut<Input, PLAYER_1>| {
+///     player1.add_component_unchecked(entity, Input(1));
+/// });
+///
+/// world.run(read_inputs);
+/// assert!(world.view_labeled::<Input>(PLAYER_1).unwrap().contains(entity));
+/// ```
+pub struct ViewLabeled<'a, T: Component, const LABEL: u64> {
+    view: View<'a, T>,
+}
+
+impl<'a, T: Component, const LABEL: u64> Deref for ViewLabeled<'a, T, LABEL> {
+    type Target = View<'a, T>;
+
+    #[inline]
+    fn deref(&self) -> &View<'a, T> {
+        &self.view
+    }
+}
+
+impl<T: Send + Sync + Component, const LABEL: u64> Borrow for ViewLabeled<'_, T, LABEL> {
+    type View<'a> = ViewLabeled<'a, T, LABEL>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        // labeled storages can't be added beforehand, strict mode doesn't apply to them
+        let view = all_storages.storage_or_insert(
+            StorageId::of_labeled::<T>(LABEL),
+            SparseSet::new,
+            false,
+        )?;
+
+        let (sparse_set, borrow) = unsafe { ARef::destructure(view) };
+
+        sparse_set.check_tracking::<T::Tracking>()?;
+
+        Ok(ViewLabeled {
+            view: View::new(
+                sparse_set,
+                borrow,
+                all_borrow,
+                last_run,
+                current,
+                all_storages.entity_names(),
+            ),
+        })
+    }
+}
+
+unsafe impl<T: Send + Sync + Component, const LABEL: u64> BorrowInfo for ViewLabeled<'_, T, LABEL> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<SparseSet<T>>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of_labeled::<T>(LABEL),
+            thread_safe: true,
+            optional: false,
+        });
+    }
+    fn enable_tracking(
+        enable_tracking_fn: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    ) {
+        enable_tracking_fn.push(enable_labeled_tracking::<T, LABEL>);
+    }
+}
+
+impl<T: Component + fmt::Debug, const LABEL: u64> fmt::Debug for ViewLabeled<'_, T, LABEL> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.view.fmt(f)
+    }
+}
+
+/// Exclusive view over the `T` storage labeled `LABEL`.
+///
+/// The label is part of the type so systems using it can be scheduled.\
+/// [`World::view_labeled_mut`](crate::World::view_labeled_mut) with the same `u64` label borrows the same storage.
+pub struct ViewLabeledMut<'a, T: Component, const LABEL: u64> {
+    view: ViewMut<'a, T>,
+}
+
+impl<'a, T: Component, const LABEL: u64> Deref for ViewLabeledMut<'a, T, LABEL> {
+    type Target = ViewMut<'a, T>;
+
+    #[inline]
+    fn deref(&self) -> &ViewMut<'a, T> {
+        &self.view
+    }
+}
+
+impl<'a, T: Component, const LABEL: u64> DerefMut for ViewLabeledMut<'a, T, LABEL> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut ViewMut<'a, T> {
+        &mut self.view
+    }
+}
+
+impl<T: Send + Sync + Component, const LABEL: u64> Borrow for ViewLabeledMut<'_, T, LABEL> {
+    type View<'a> = ViewLabeledMut<'a, T, LABEL>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        last_run: Option<TrackingTimestamp>,
+        current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        // labeled storages can't be added beforehand, strict mode doesn't apply to them
+        let view = all_storages.storage_or_insert_mut(
+            StorageId::of_labeled::<T>(LABEL),
+            SparseSet::new,
+            false,
+        )?;
+
+        let (sparse_set, borrow) = unsafe { ARefMut::destructure(view) };
+
+        sparse_set.check_tracking::<T::Tracking>()?;
+        sparse_set.last_change = current;
+
+        Ok(ViewLabeledMut {
+            view: ViewMut {
+                last_insertion: last_run.unwrap_or(sparse_set.last_insert),
+                last_modification: last_run.unwrap_or(sparse_set.last_modified),
+                last_removal_or_deletion: last_run.unwrap_or(current),
+                current,
+                sparse_set,
+                borrow,
+                all_borrow,
+                entity_names: all_storages.entity_names(),
+                phantom: PhantomData,
+            },
+        })
+    }
+}
+
+unsafe impl<T: Send + Sync + Component, const LABEL: u64> BorrowInfo
+    for ViewLabeledMut<'_, T, LABEL>
+{
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<SparseSet<T>>().into(),
+            mutability: Mutability::Exclusive,
+            storage_id: StorageId::of_labeled::<T>(LABEL),
+            thread_safe: true,
+            optional: false,
+        });
+    }
+    fn enable_tracking(
+        enable_tracking_fn: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>,
+    ) {
+        enable_tracking_fn.push(enable_labeled_tracking::<T, LABEL>);
+    }
+}
+
+impl<T: Component + fmt::Debug, const LABEL: u64> fmt::Debug for ViewLabeledMut<'_, T, LABEL> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.view.fmt(f)
+    }
+}
+
+/// Enables `T`'s tracking on the `T` storage labeled `LABEL`.
+fn enable_labeled_tracking<T: Send + Sync + Component, const LABEL: u64>(
+    all_storages: &AllStorages,
+) -> Result<(), error::GetStorage> {
+    all_storages
+        .storage_or_insert_mut(
+            StorageId::of_labeled::<T>(LABEL),
+            SparseSet::<T>::new,
+            false,
+        )?
+        .enable_tracking::<T::Tracking>();
+
+    Ok(())
+}
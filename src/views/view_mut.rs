@@ -3,8 +3,9 @@ use crate::atomic_refcell::{ARef, ARefMut, ExclusiveBorrow, SharedBorrow};
 use crate::component::Component;
 use crate::entity_id::EntityId;
 use crate::get::Get;
+use crate::name::missing_component_panic;
 use crate::r#mut::Mut;
-use crate::sparse_set::{SparseSet, SparseSetDrain};
+use crate::sparse_set::{PartitionMut, RawWindowMut, SparseSet, SparseSetDrain};
 use crate::storage::StorageId;
 use crate::track;
 use crate::tracking::{
@@ -15,7 +16,7 @@ use crate::views::view::View;
 use crate::{error, TrackingTimestamp};
 use core::fmt;
 use core::marker::PhantomData;
-use core::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut, RangeBounds};
 
 /// Exclusive view over a component storage.
 pub struct ViewMut<'a, T: Component, Track = <T as Component>::Tracking> {
@@ -26,6 +27,8 @@ pub struct ViewMut<'a, T: Component, Track = <T as Component>::Tracking> {
     pub(crate) last_modification: TrackingTimestamp,
     pub(crate) last_removal_or_deletion: TrackingTimestamp,
     pub(crate) current: TrackingTimestamp,
+    /// Only present when entity names are shown in errors.
+    pub(crate) entity_names: Option<&'a AllStorages>,
     pub(crate) phantom: PhantomData<Track>,
 }
 
@@ -61,11 +64,12 @@ where
         View {
             sparse_set: self.sparse_set,
             all_borrow: self.all_borrow.as_ref().cloned(),
-            borrow: self.borrow.shared_reborrow(),
+            borrow: Some(self.borrow.shared_reborrow()),
             last_insertion: self.last_insertion,
             last_modification: self.last_modification,
             last_removal_or_deletion: self.last_removal_or_deletion,
             current: self.current,
+            entity_names: self.entity_names,
             phantom: PhantomData,
         }
     }
@@ -167,7 +171,9 @@ impl<'a, T: Component> ViewMut<'a, T, track::Untracked> {
 
         let name = storage.name();
 
-        if let Some(sparse_set) = storage.any_mut().downcast_mut() {
+        if let Some(sparse_set) = storage.any_mut().downcast_mut::<SparseSet<T>>() {
+            sparse_set.last_change = all_storages.get_current();
+
             Ok(ViewMut {
                 sparse_set,
                 all_borrow: Some(all_borrow),
@@ -176,6 +182,7 @@ impl<'a, T: Component> ViewMut<'a, T, track::Untracked> {
                 last_modification: TrackingTimestamp::new(0),
                 last_removal_or_deletion: TrackingTimestamp::new(0),
                 current: TrackingTimestamp::new(0),
+                entity_names: all_storages.entity_names(),
                 phantom: PhantomData,
             })
         } else {
@@ -223,6 +230,40 @@ where
     ) -> R {
         self.sparse_set.private_apply_mut(a, b, f, self.current)
     }
+    /// Replaces `entity`'s component with `component` and returns the old one.\
+    /// The component is flagged as modified, insertion tracking and the insertion callback are not triggered.
+    ///
+    /// Returns `None` and drops `component` if `entity` doesn't have a component in this storage.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// #[track(Modification)]
+    /// struct Name(String);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(Name("Alice".to_string()));
+    ///
+    /// world.run(|names: ViewMut<Name>| {
+    ///     names.clear_all_modified();
+    /// });
+    ///
+    /// world.run(|mut names: ViewMut<Name>| {
+    ///     assert_eq!(
+    ///         names.replace(entity, Name("Bob".to_string())),
+    ///         Some(Name("Alice".to_string()))
+    ///     );
+    ///     assert!(names.is_modified(entity));
+    /// });
+    /// ```
+    #[inline]
+    pub fn replace(&mut self, entity: EntityId, component: T) -> Option<T> {
+        self.sparse_set
+            .private_replace(entity, component, self.current)
+    }
 
     /// Deletes all components for which `f(id, &component)` returns `false`.
     pub fn retain<F: FnMut(EntityId, &T) -> bool>(&mut self, f: F) {
@@ -233,6 +274,200 @@ where
     pub fn retain_mut<F: FnMut(EntityId, Mut<'_, T>) -> bool>(&mut self, f: F) {
         self.sparse_set.private_retain_mut(self.current, f);
     }
+    /// Returns raw mutable access to the components and the entities owning them.\
+    /// Useful to hand the storage to SIMD code or over FFI.
+    ///
+    /// Modifications made through the window are not tracked.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// #[repr(transparent)]
+    /// struct Speed(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(Speed(1.0));
+    ///
+    /// world.run(|mut speeds: ViewMut<Speed>| {
+    ///     let mut window = speeds.raw_window_mut();
+    ///     let len = window.len();
+    ///
+    ///     // SAFE `Speed` is `repr(transparent)` and the window holds `len` components
+    ///     let raw = unsafe { std::slice::from_raw_parts_mut(window.data_mut_ptr() as *mut f32, len) };
+    ///     for speed in raw {
+    ///         *speed *= 2.0;
+    ///     }
+    /// });
+    ///
+    /// world.run(|speeds: View<Speed>| assert_eq!(speeds[entity], Speed(2.0)));
+    /// ```
+    #[inline]
+    pub fn raw_window_mut(&mut self) -> RawWindowMut<'_, T> {
+        RawWindowMut::new(&self.sparse_set.dense, &mut self.sparse_set.data)
+    }
+    /// Returns a [`PartitionMut`] over the components at `range` in the storage.\
+    /// Partitions can be split further and sent to other threads, components accessed mutably are flagged as modified.
+    ///
+    /// ### Panics
+    ///
+    /// - `range` is out of bounds.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.bulk_add_entity((0..10).map(U32));
+    ///
+    /// world.run(|mut u32s: ViewMut<U32>| {
+    ///     std::thread::scope(|scope| {
+    ///         for mut partition in u32s.partition_mut(..).chunks_mut(4) {
+    ///             scope.spawn(move || {
+    ///                 for (_, mut u32) in partition.iter_mut() {
+    ///                     u32.0 *= 2;
+    ///                 }
+    ///             });
+    ///         }
+    ///     });
+    /// });
+    ///
+    /// world.run(|u32s: View<U32>| {
+    ///     assert_eq!(u32s.as_slice().iter().map(|u32| u32.0).sum::<u32>(), 90);
+    /// });
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn partition_mut(&mut self, range: impl RangeBounds<usize>) -> PartitionMut<'_, T> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        let sparse_set = &mut *self.sparse_set;
+
+        let modification_data = if sparse_set.is_tracking_modification {
+            Some(&mut sparse_set.modification_data[range])
+        } else {
+            None
+        };
+
+        PartitionMut::new(
+            &sparse_set.dense[range],
+            &mut sparse_set.data[range],
+            modification_data,
+            self.current,
+        )
+    }
+    /// Returns all the components in this storage as mutable bytes, in the same order as [`as_slice`].\
+    /// All components are flagged as modified.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, World};
+    ///
+    /// #[derive(Component, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable, Debug, PartialEq)]
+    /// #[repr(transparent)]
+    /// struct Speed(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(Speed(1.0));
+    ///
+    /// world.run(|mut speeds: ViewMut<Speed>| {
+    ///     speeds.as_bytes_mut().copy_from_slice(&2.0f32.to_ne_bytes());
+    /// });
+    ///
+    /// world.run(|speeds: View<Speed>| {
+    ///     assert_eq!(speeds[entity], Speed(2.0));
+    ///     assert_eq!(speeds.as_bytes(), &2.0f32.to_ne_bytes());
+    /// });
+    /// ```
+    ///
+    /// [`as_slice`]: SparseSet::as_slice
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        T: bytemuck::Pod,
+    {
+        let current = self.current;
+        let sparse_set = &mut *self.sparse_set;
+
+        if sparse_set.is_tracking_modification {
+            sparse_set.modification_data.fill(current);
+        }
+
+        bytemuck::cast_slice_mut(&mut sparse_set.data)
+    }
+    /// Calls `f` on all components of the storage in parallel.\
+    /// Components are flagged as modified when accessed mutably.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.bulk_add_entity((0..10).map(U32));
+    ///
+    /// world.run(|mut u32s: ViewMut<U32>| {
+    ///     u32s.par_update(|_, mut u32| u32.0 *= 2);
+    /// });
+    ///
+    /// world.run(|u32s: View<U32>| {
+    ///     assert_eq!(u32s.as_slice().iter().map(|u32| u32.0).sum::<u32>(), 90);
+    /// });
+    /// ```
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+    pub fn par_update<F: Fn(EntityId, Mut<'_, T>) + Send + Sync>(&mut self, f: F)
+    where
+        T: Send,
+    {
+        use rayon::prelude::*;
+
+        let current = self.current;
+        let sparse_set = &mut *self.sparse_set;
+
+        if sparse_set.is_tracking_modification {
+            sparse_set
+                .dense
+                .par_iter()
+                .zip(&mut sparse_set.data)
+                .zip(&mut sparse_set.modification_data)
+                .for_each(|((&id, data), modification)| {
+                    f(
+                        id,
+                        Mut {
+                            flag: Some(modification),
+                            current,
+                            data,
+                        },
+                    )
+                });
+        } else {
+            sparse_set
+                .dense
+                .par_iter()
+                .zip(&mut sparse_set.data)
+                .for_each(|(&id, data)| {
+                    f(
+                        id,
+                        Mut {
+                            flag: None,
+                            current,
+                            data,
+                        },
+                    )
+                });
+        }
+    }
 }
 
 impl<'v, Track, T: Component + Default> ViewMut<'v, T, Track>
@@ -316,6 +551,14 @@ where
     pub fn is_inserted(&self, entity: EntityId) -> bool {
         Track::is_inserted(self.sparse_set, entity, self.last_insertion, self.current)
     }
+    /// Returns the timestamp at which `entity`'s component was inserted.\
+    /// Returns `None` if `entity` does not have a component in this storage.
+    ///
+    /// Can be compared with [`World::get_tracking_timestamp`](crate::World::get_tracking_timestamp) to know how long ago the insertion happened.
+    #[inline]
+    pub fn insertion_timestamp(&self, entity: EntityId) -> Option<TrackingTimestamp> {
+        self.sparse_set.private_insertion_timestamp(entity)
+    }
     /// Wraps this view to be able to iterate *inserted* components.
     #[inline]
     pub fn inserted(&self) -> Inserted<&Self> {
@@ -349,6 +592,15 @@ where
             self.current,
         )
     }
+    /// Returns the timestamp at which `entity`'s component was last modified.\
+    /// Returns `None` if `entity` does not have a component in this storage or if the component was never modified.
+    ///
+    /// Can be compared with [`World::get_tracking_timestamp`](crate::World::get_tracking_timestamp) to know how long ago the modification happened.
+    #[inline]
+    pub fn modification_timestamp(&self, entity: EntityId) -> Option<TrackingTimestamp> {
+        self.sparse_set
+            .private_modification_timestamp(entity, self.current)
+    }
     /// Wraps this view to be able to iterate *modified* components.
     #[inline]
     pub fn modified(&self) -> Modified<&Self> {
@@ -494,6 +746,13 @@ impl<'a, T: Component, Track> AsRef<SparseSet<T>> for ViewMut<'a, T, Track> {
     }
 }
 
+impl<'a, T: Component, Track> AsRef<[T]> for ViewMut<'a, T, Track> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.sparse_set.as_slice()
+    }
+}
+
 impl<'a, T: Component, Track> AsMut<SparseSet<T>> for ViewMut<'a, T, Track> {
     #[inline]
     fn as_mut(&mut self) -> &mut SparseSet<T> {
@@ -516,22 +775,21 @@ impl<T: fmt::Debug + Component, Track> fmt::Debug for ViewMut<'_, T, Track> {
 
 impl<'a, T: Component, Track> core::ops::Index<EntityId> for ViewMut<'a, T, Track> {
     type Output = T;
+    #[track_caller]
     #[inline]
     fn index(&self, entity: EntityId) -> &Self::Output {
-        self.get(entity).unwrap()
+        self.get(entity)
+            .unwrap_or_else(|err| missing_component_panic(self.entity_names, err))
     }
 }
 
 impl<'a, T: Component, Track> core::ops::IndexMut<EntityId> for ViewMut<'a, T, Track> {
+    #[track_caller]
     #[inline]
     fn index_mut(&mut self, entity: EntityId) -> &mut Self::Output {
-        let index = self
-            .index_of(entity)
-            .ok_or_else(|| error::MissingComponent {
-                id: entity,
-                name: core::any::type_name::<T>(),
-            })
-            .unwrap();
+        let index = self.index_of(entity).unwrap_or_else(|| {
+            missing_component_panic(self.entity_names, self.missing_component(entity))
+        });
 
         let SparseSet {
             data,
@@ -0,0 +1,172 @@
+use crate::all_storages::{AllStorages, CustomStorageAccess};
+use crate::atomic_refcell::{ARef, AtomicRefCell, SharedBorrow};
+use crate::borrow::{Borrow, BorrowInfo, Mutability};
+use crate::error;
+use crate::info::TypeInfo;
+use crate::storage::{SBox, Storage, StorageId};
+use crate::tracking::TrackingTimestamp;
+use alloc::vec::Vec;
+use core::any::type_name;
+use core::ops::Deref;
+
+/// Holds the data passed to [`World::run_workload_with_data`] for the duration of the run.
+///
+/// [`World::run_workload_with_data`]: crate::World::run_workload_with_data
+pub(crate) struct WorkloadDataStorage<T>(pub(crate) T);
+
+impl<T: 'static> Storage for WorkloadDataStorage<T> {}
+
+/// Removes the data passed to [`World::run_workload_with_data`] when dropped, even if a system panics.
+///
+/// [`World::run_workload_with_data`]: crate::World::run_workload_with_data
+pub(crate) struct WorkloadDataGuard<'w> {
+    all_storages: &'w AtomicRefCell<AllStorages>,
+    storage_id: StorageId,
+}
+
+impl<'w> WorkloadDataGuard<'w> {
+    /// Adds `data` to `all_storages` until the guard is dropped.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` borrow failed.
+    /// - Data of the same type is already passed to a running workload.
+    pub(crate) fn new<T: Send + Sync + 'static>(
+        all_storages: &'w AtomicRefCell<AllStorages>,
+        data: T,
+    ) -> Result<Self, error::GetStorage> {
+        let storage_id = StorageId::of::<WorkloadDataStorage<T>>();
+
+        let all_storages_ref = all_storages
+            .borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?;
+        let mut storages = all_storages_ref.storages.write();
+
+        if storages.contains_key(&storage_id) {
+            return Err(error::GetStorage::StorageBorrow {
+                name: Some(type_name::<WorkloadDataStorage<T>>()),
+                id: storage_id,
+                borrow: error::Borrow::Unique,
+            });
+        }
+
+        storages.insert(storage_id, SBox::new(WorkloadDataStorage(data)));
+
+        Ok(WorkloadDataGuard {
+            all_storages,
+            storage_id,
+        })
+    }
+}
+
+impl Drop for WorkloadDataGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(all_storages) = self.all_storages.borrow() {
+            let mut storages = all_storages.storages.write();
+
+            // new borrows need the lock, the data can only be dropped if nothing borrows it anymore
+            if let Some(sbox) = storages.get(&self.storage_id) {
+                // SAFE the storage is kept alive by the lock
+                if unsafe { &*sbox.ptr }.borrow_mut().is_ok() {
+                    storages.remove(&self.storage_id);
+                }
+            }
+        }
+    }
+}
+
+/// Shared view over the data passed to [`World::run_workload_with_data`].
+///
+/// The data is only present while the workload runs, borrowing it anywhere else fails with a missing storage error.\
+/// The data can't be modified, all systems borrowing it can run in the same batch.
+///
+/// ### Example
+/// ```
+/// use shipyard::{Component, IntoIter, ViewMut, Workload, WorkloadData, World};
+///
+/// #[derive(Component)]
+/// struct Position(f32);
+///
+/// fn move_forward(dt: WorkloadData<f32>, mut positions: ViewMut<Position>) {
+///     for mut position in (&mut positions).iter() {
+///         position.0 += *dt;
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// let entity = world.add_entity(Position(0.0));
+///
+/// Workload::new("Update")
+///     .with_system(move_forward)
+///     .add_to_world(&world)
+///     .unwrap();
+///
+/// world.run_workload_with_data("Update", 0.5f32).unwrap();
+/// world.run_workload_with_data("Update", 0.25f32).unwrap();
+///
+/// assert_eq!(world.get::<&Position>(entity).unwrap().0, 0.75);
+/// ```
+///
+/// [`World::run_workload_with_data`]: crate::World::run_workload_with_data
+pub struct WorkloadData<'v, T: Send + Sync + 'static> {
+    data: &'v T,
+    _borrow: SharedBorrow<'v>,
+    _all_borrow: Option<SharedBorrow<'v>>,
+}
+
+impl<T: Send + Sync + 'static> Deref for WorkloadData<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.data
+    }
+}
+
+impl<T: Send + Sync + 'static> AsRef<T> for WorkloadData<'_, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self.data
+    }
+}
+
+impl<T: Send + Sync + 'static> Borrow for WorkloadData<'_, T> {
+    type View<'a> = WorkloadData<'a, T>;
+
+    #[inline]
+    fn borrow<'a>(
+        all_storages: &'a AllStorages,
+        all_borrow: Option<SharedBorrow<'a>>,
+        _last_run: Option<TrackingTimestamp>,
+        _current: TrackingTimestamp,
+    ) -> Result<Self::View<'a>, error::GetStorage> {
+        let view = all_storages.custom_storage::<WorkloadDataStorage<T>>()?;
+
+        let (storage, borrow) = unsafe { ARef::destructure(view) };
+
+        Ok(WorkloadData {
+            data: &storage.0,
+            _borrow: borrow,
+            _all_borrow: all_borrow,
+        })
+    }
+}
+
+unsafe impl<T: Send + Sync + 'static> BorrowInfo for WorkloadData<'_, T> {
+    fn borrow_info(info: &mut Vec<TypeInfo>) {
+        info.push(TypeInfo {
+            name: type_name::<WorkloadDataStorage<T>>().into(),
+            mutability: Mutability::Shared,
+            storage_id: StorageId::of::<WorkloadDataStorage<T>>(),
+            thread_safe: true,
+            optional: false,
+        });
+    }
+    fn enable_tracking(_: &mut Vec<fn(&AllStorages) -> Result<(), error::GetStorage>>) {}
+}
+
+impl<T: core::fmt::Debug + Send + Sync + 'static> core::fmt::Debug for WorkloadData<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.data.fmt(f)
+    }
+}
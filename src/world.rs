@@ -1,33 +1,66 @@
 mod builder;
+mod immutable;
 mod run_batches;
 
 pub use builder::WorldBuilder;
 
-use crate::all_storages::{AllStorages, CustomStorageAccess, TupleDeleteAny, TupleRetainStorage};
+pub(crate) use run_batches::Deadline;
+
+use immutable::ImmutableStorage;
+
+#[cfg(feature = "access_stats")]
+use crate::access_stats::{AccessRecorder, AccessStats};
+use crate::all_storages::{
+    AllStorages, CustomStorageAccess, PartialSnapshot, TupleDeleteAny, TupleRetainStorage,
+    TupleSnapshot,
+};
+#[cfg(feature = "serde1")]
+use crate::all_storages::{TupleFilter, TupleSerialize};
+use crate::archive::ArchivedEntity;
 use crate::atomic_refcell::{ARef, ARefMut, AtomicRefCell};
 use crate::borrow::WorldBorrow;
 use crate::component::{Component, Unique};
-use crate::entities::Entities;
+use crate::drop_queue::DropQueue;
+#[cfg(feature = "thread_local")]
+use crate::drop_queue::LocalDropQueue;
+use crate::entities::{AddComponentPolicy, Entities, ReservationTicket};
 use crate::entity_id::EntityId;
+use crate::entity_mut::EntityMut;
 use crate::error;
+#[cfg(feature = "std")]
+use crate::frame_arena::FrameArena;
 use crate::get_component::GetComponent;
 use crate::get_unique::GetUnique;
-use crate::info::WorkloadsInfo;
+use crate::info::{TypeInfo, WorkloadsInfo};
 use crate::iter_component::{IntoIterRef, IterComponent};
 use crate::memory_usage::WorldMemoryUsage;
+#[cfg(feature = "std")]
+use crate::profiler::{Profile, ProfileEventKind, Profiler};
 use crate::r#mut::Mut;
 use crate::reserve::BulkEntityIter;
 use crate::scheduler::Label;
-use crate::scheduler::{AsLabel, Batches, Scheduler};
-use crate::sparse_set::{BulkAddEntity, TupleAddComponent, TupleDelete, TupleRemove};
-use crate::storage::{Storage, StorageId};
-use crate::system::System;
+use crate::scheduler::{
+    next_workload_run, AsLabel, Batches, Scheduler, Workload, WorkloadModificator,
+};
+use crate::sparse_set::{BulkAddEntity, SparseSet, TupleAddComponent, TupleDelete, TupleRemove};
+use crate::stable_names::StableNames;
+use crate::storage::{SBox, Storage, StorageId, StorageInfo};
+use crate::system::{ForEachSystem, ParallelSystems, System};
 use crate::tracking::{TrackingTimestamp, TupleTrack};
-use crate::views::EntitiesViewMut;
+use crate::type_id::TypeId;
+use crate::unique::UniqueInfo;
+#[cfg(feature = "std")]
+use crate::unique::UniqueStorage;
+#[cfg(feature = "std")]
+use crate::views::NestedRunGuard;
+use crate::views::{EntitiesView, EntitiesViewMut, View, ViewMut, WorkloadDataGuard};
+use crate::ShipHashMap;
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::sync::Arc;
-use core::sync::atomic::AtomicU32;
+use alloc::vec::Vec;
+use core::panic::Location;
+use core::sync::atomic::{AtomicPtr, AtomicU32, Ordering};
 
 /// `World` contains all data this library will manipulate.
 pub struct World {
@@ -35,7 +68,40 @@ pub struct World {
     pub(crate) scheduler: AtomicRefCell<Scheduler>,
     counter: Arc<AtomicU32>,
     #[cfg(feature = "parallel")]
-    thread_pool: Option<rayon::ThreadPool>,
+    pub(crate) thread_pool: Option<rayon::ThreadPool>,
+    stable_names: StableNames,
+    #[cfg(feature = "std")]
+    pub(crate) profiler: Option<Profiler>,
+    #[cfg(feature = "access_stats")]
+    pub(crate) access_recorder: Option<AccessRecorder>,
+    pub(crate) immutable_storages: ShipHashMap<StorageId, ImmutableStorage>,
+    /// Location of the `apply` call currently holding `AllStorages`, null otherwise.
+    apply_location: AtomicPtr<Location<'static>>,
+    /// Id of the last workload run started, used to evaluate each [`Condition`](crate::Condition) once per run.
+    pub(crate) workload_run: AtomicU32,
+}
+
+/// Records the location of an `apply` call until dropped.
+struct ApplyLocationGuard<'a> {
+    apply_location: &'a AtomicPtr<Location<'static>>,
+}
+
+impl<'a> ApplyLocationGuard<'a> {
+    fn new(
+        apply_location: &'a AtomicPtr<Location<'static>>,
+        location: &'static Location<'static>,
+    ) -> ApplyLocationGuard<'a> {
+        apply_location.store(core::ptr::from_ref(location).cast_mut(), Ordering::Release);
+
+        ApplyLocationGuard { apply_location }
+    }
+}
+
+impl Drop for ApplyLocationGuard<'_> {
+    fn drop(&mut self) {
+        self.apply_location
+            .store(core::ptr::null_mut(), Ordering::Release);
+    }
 }
 
 #[cfg(feature = "std")]
@@ -55,6 +121,14 @@ impl Default for World {
             counter,
             #[cfg(feature = "parallel")]
             thread_pool: None,
+            stable_names: StableNames::new(),
+            #[cfg(feature = "std")]
+            profiler: None,
+            #[cfg(feature = "access_stats")]
+            access_recorder: None,
+            immutable_storages: ShipHashMap::default(),
+            apply_location: AtomicPtr::new(core::ptr::null_mut()),
+            workload_run: AtomicU32::new(0),
         }
     }
 }
@@ -80,6 +154,7 @@ impl World {
     /// ### Panics
     ///
     /// - [`AllStorages`] borrow failed.
+    /// - A `T` unique was added with a different thread safety, with `add_unique_non_send` for example.
     ///
     /// ### Example
     ///
@@ -104,6 +179,30 @@ impl World {
     pub fn add_unique<T: Send + Sync + Unique>(&self, component: T) {
         self.all_storages.borrow().unwrap().add_unique(component);
     }
+    /// Adds a new unique storage, unique storages store a single value.\
+    /// Returns an error if a `T` unique was added with a different thread safety, with `add_unique_non_send` for example.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
+    #[track_caller]
+    pub fn try_add_unique<T: Send + Sync + Unique>(
+        &self,
+        component: T,
+    ) -> Result<(), error::DuplicateUnique> {
+        self.all_storages
+            .borrow()
+            .unwrap()
+            .try_add_unique(component)
+    }
     /// Adds a new unique storage, unique storages store a single value.
     /// To access a `!Send` unique storage value, use [`NonSend`] with [`UniqueView`] or [`UniqueViewMut`].
     /// Does nothing if the storage already exists.
@@ -115,6 +214,7 @@ impl World {
     /// ### Panics
     ///
     /// - [`AllStorages`] borrow failed.
+    /// - A `T` unique was added with a different thread safety, with `add_unique` for example.
     ///
     /// ### Example
     ///
@@ -146,6 +246,30 @@ impl World {
             .unwrap()
             .add_unique_non_send(component);
     }
+    /// Adds a new unique storage, unique storages store a single value.\
+    /// Returns an error if a `T` unique was added with a different thread safety, with `add_unique` for example.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
+    #[track_caller]
+    pub fn try_add_unique_non_send<T: Sync + Unique>(
+        &self,
+        component: T,
+    ) -> Result<(), error::DuplicateUnique> {
+        self.all_storages
+            .borrow()
+            .unwrap()
+            .try_add_unique_non_send(component)
+    }
     /// Adds a new unique storage, unique storages store a single value.
     /// To access a `!Sync` unique storage value, use [`NonSync`] with [`UniqueView`] or [`UniqueViewMut`].
     /// Does nothing if the storage already exists.
@@ -157,6 +281,7 @@ impl World {
     /// ### Panics
     ///
     /// - [`AllStorages`] borrow failed.
+    /// - A `T` unique was added with a different thread safety, with `add_unique` for example.
     ///
     /// ### Example
     ///
@@ -188,6 +313,30 @@ impl World {
             .unwrap()
             .add_unique_non_sync(component);
     }
+    /// Adds a new unique storage, unique storages store a single value.\
+    /// Returns an error if a `T` unique was added with a different thread safety, with `add_unique` for example.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
+    #[track_caller]
+    pub fn try_add_unique_non_sync<T: Send + Unique>(
+        &self,
+        component: T,
+    ) -> Result<(), error::DuplicateUnique> {
+        self.all_storages
+            .borrow()
+            .unwrap()
+            .try_add_unique_non_sync(component)
+    }
     /// Adds a new unique storage, unique storages store a single value.
     /// To access a `!Send + !Sync` unique storage value, use [`NonSendSync`] with [`UniqueView`] or [`UniqueViewMut`].
     /// Does nothing if the storage already exists.
@@ -199,6 +348,7 @@ impl World {
     /// ### Panics
     ///
     /// - [`AllStorages`] borrow failed.
+    /// - A `T` unique was added with a different thread safety, with `add_unique` for example.
     ///
     /// ### Example
     ///
@@ -230,6 +380,95 @@ impl World {
             .unwrap()
             .add_unique_non_send_sync(component);
     }
+    /// Adds a new unique storage, unique storages store a single value.\
+    /// Returns an error if a `T` unique was added with a different thread safety, with `add_unique` for example.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
+    #[track_caller]
+    pub fn try_add_unique_non_send_sync<T: Unique>(
+        &self,
+        component: T,
+    ) -> Result<(), error::DuplicateUnique> {
+        self.all_storages
+            .borrow()
+            .unwrap()
+            .try_add_unique_non_send_sync(component)
+    }
+    /// Returns the id of the thread `!Send` storages are bound to when created.\
+    /// This is the thread the `World` was created in unless [`World::rebind_main_thread`] was called.
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
+    #[inline]
+    pub fn main_thread_id(&self) -> u64 {
+        // `AllStorages` is bound to the main thread
+        self.all_storages.bound_thread().unwrap()
+    }
+    /// Returns the `!Send` storages and the id of the thread they are bound to.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
+    #[track_caller]
+    pub fn non_send_storages(&self) -> Vec<(StorageId, u64)> {
+        self.all_storages.borrow().unwrap().non_send_storages()
+    }
+    /// Binds the `World` and all its `!Send` storages to the current thread.\
+    /// Use it when the thread running the `World` changes, `!Send` storages will then only be accessible from this thread.\
+    /// Storages shared with another `World` stay bound to their thread.
+    ///
+    /// The `World` being `!Send` with the *thread_local* feature, this is mostly useful with a custom thread id.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{NonSend, Unique, UniqueViewMut, World};
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Unique)]
+    /// struct U32(u32);
+    ///
+    /// let thread = Arc::new(AtomicU64::new(0));
+    /// let thread_id = thread.clone();
+    /// let mut world = World::builder()
+    ///     .with_custom_thread_id(move || thread_id.load(Ordering::Relaxed))
+    ///     .build();
+    ///
+    /// // I'm using `u32` here but imagine it's a `!Send` type
+    /// world.add_unique_non_send(U32(0));
+    ///
+    /// // the update loop moves to another thread
+    /// thread.store(1, Ordering::Relaxed);
+    /// assert!(world.borrow::<NonSend<UniqueViewMut<U32>>>().is_err());
+    ///
+    /// world.rebind_main_thread();
+    ///
+    /// assert_eq!(world.main_thread_id(), 1);
+    /// assert!(world.borrow::<NonSend<UniqueViewMut<U32>>>().is_ok());
+    /// ```
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
+    pub fn rebind_main_thread(&mut self) {
+        self.all_storages.rebind_thread();
+        self.all_storages.get_mut().rebind_main_thread();
+    }
     /// Removes a unique storage.
     ///
     /// ### Borrows
@@ -371,11 +610,230 @@ let (entities, mut usizes) = world
     #[cfg_attr(feature = "thread_local", doc = "[NonSend]: crate::NonSend")]
     #[cfg_attr(feature = "thread_local", doc = "[NonSync]: crate::NonSync")]
     #[cfg_attr(feature = "thread_local", doc = "[NonSendSync]: crate::NonSendSync")]
+    #[cfg_attr(feature = "storage_location", track_caller)]
     pub fn borrow<V: WorldBorrow>(&self) -> Result<V::WorldView<'_>, error::GetStorage> {
         let current = self.get_current();
 
         V::world_borrow(self, None, current)
     }
+    /// Borrows the `T` storage identified by `label`, creating it if it doesn't exist.\
+    /// Labeled storages are independent from `T`'s regular storage and from each other.
+    /// Systems can borrow labeled storages with [`ViewLabeled`](crate::ViewLabeled).
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - `T` storage labeled `label` (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AddComponent, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Input(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    /// world
+    ///     .view_labeled_mut::<Input>(1u32)
+    ///     .unwrap()
+    ///     .add_component_unchecked(entity, Input(1));
+    ///
+    /// assert!(world.view_labeled::<Input>(1u32).unwrap().contains(entity));
+    /// assert!(!world.view_labeled::<Input>(2u32).unwrap().contains(entity));
+    /// ```
+    pub fn view_labeled<T: Send + Sync + Component>(
+        &self,
+        label: impl core::hash::Hash,
+    ) -> Result<View<'_, T>, error::GetStorage> {
+        let (all_storages, all_borrow) = unsafe {
+            ARef::destructure(
+                self.all_storages
+                    .borrow()
+                    .map_err(error::GetStorage::AllStoragesBorrow)?,
+            )
+        };
+
+        let mut view = all_storages.view_labeled(label)?;
+        view.all_borrow = Some(all_borrow);
+
+        Ok(view)
+    }
+    /// Exclusively borrows the `T` storage identified by `label`, creating it if it doesn't exist.\
+    /// Labeled storages are independent from `T`'s regular storage and from each other.
+    /// Systems can borrow labeled storages with [`ViewLabeledMut`](crate::ViewLabeledMut).
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - `T` storage labeled `label` (exclusive)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    pub fn view_labeled_mut<T: Send + Sync + Component>(
+        &self,
+        label: impl core::hash::Hash,
+    ) -> Result<ViewMut<'_, T>, error::GetStorage> {
+        let (all_storages, all_borrow) = unsafe {
+            ARef::destructure(
+                self.all_storages
+                    .borrow()
+                    .map_err(error::GetStorage::AllStoragesBorrow)?,
+            )
+        };
+
+        let mut view = all_storages.view_labeled_mut(label)?;
+        view.all_borrow = Some(all_borrow);
+
+        Ok(view)
+    }
+    /// Copies the storages of `T` and which entities are alive.\
+    /// `T` has to be a single component or a tuple of components implementing `Clone`.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - `Entities` (shared)
+    /// - `T` storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct Pos(f32);
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct Vel(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((Pos(0.0), Vel(1.0)));
+    ///
+    /// let snapshot = world.snapshot::<(Pos, Vel)>().unwrap();
+    ///
+    /// world.get::<&mut Pos>(entity).unwrap().0 = 10.0;
+    ///
+    /// world.restore(&snapshot);
+    ///
+    /// assert_eq!(world.get::<&Pos>(entity).unwrap().0, 0.0);
+    /// ```
+    pub fn snapshot<T: TupleSnapshot>(&self) -> Result<PartialSnapshot, error::GetStorage> {
+        self.all_storages
+            .borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .snapshot::<T>()
+    }
+    /// Brings back the storages and entities' liveness copied in `snapshot`.
+    ///
+    /// Entities created after the snapshot was taken are deleted, their `EntityId` can be handed out again.\
+    /// Entities deleted after the snapshot was taken are alive again but only have the components present in `snapshot`.\
+    /// The other storages are otherwise left untouched.
+    pub fn restore(&mut self, snapshot: &PartialSnapshot) {
+        self.thawed_all_storages().restore(snapshot);
+    }
+    /// Returns the entities deleted after `timestamp`, each entity is only returned once.\
+    /// Only storages tracking deletion are taken into account.\
+    /// Storages that can't be borrowed, for example because they are borrowed exclusively, are skipped.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - `Entities` (shared)
+    /// - All storages that aren't borrowed exclusively (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - `Entities` borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Deletion)]
+    /// struct Pos(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(Pos(0.0));
+    ///
+    /// let last_frame = world.tick();
+    /// world.delete_entity(entity);
+    ///
+    /// let deleted: Vec<_> = world.entities_deleted_since(last_frame).unwrap().collect();
+    /// assert_eq!(deleted, [entity]);
+    /// ```
+    pub fn entities_deleted_since(
+        &self,
+        timestamp: TrackingTimestamp,
+    ) -> Result<impl Iterator<Item = EntityId>, error::GetStorage> {
+        self.all_storages
+            .borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .entities_deleted_since(timestamp)
+    }
+    /// Returns the entities owning a component in all `include` storages and in none of the `exclude` storages.\
+    /// With an empty `include`, all alive entities are considered.
+    ///
+    /// Unlike typed iteration the storages are chosen at runtime, for example by a scripting layer.\
+    /// Missing storages and storages without entities, like uniques, are considered empty.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - Entities (shared)
+    /// - `include` and `exclude` storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Entities borrow failed.
+    /// - Storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Pos(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Vel(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Pos(0.0),));
+    /// let entity = world.add_entity((Pos(0.0), Vel(1.0)));
+    ///
+    /// let query = [StorageId::of::<SparseSet<Pos>>(), StorageId::of::<SparseSet<Vel>>()];
+    ///
+    /// assert_eq!(world.dyn_query(&query, &[]).unwrap(), [entity]);
+    /// ```
+    pub fn dyn_query(
+        &self,
+        include: &[StorageId],
+        exclude: &[StorageId],
+    ) -> Result<Vec<EntityId>, error::GetStorage> {
+        self.all_storages
+            .borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .dyn_query(include, exclude)
+    }
     #[doc = "Borrows the requested storages, runs the function and evaluates to the function's return value.
 Data can be passed to the function, this always has to be a single type but you can use a tuple if needed.
 
@@ -468,7 +926,7 @@ fn sys1((entity, [x, y]): (EntityId, [f32; 2]), mut positions: ViewMut<Position>
 
 let world = World::new();
 
-world.run_with_data(sys1, (EntityId::dead(), [0., 0.]));
+world.run_with_data(sys1, (EntityId::placeholder(), [0., 0.]));
 ```
 [AllStorages]: crate::AllStorages
 [EntitiesView]: crate::Entities
@@ -493,11 +951,75 @@ world.run_with_data(sys1, (EntityId::dead(), [0., 0.]));
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
 
+        #[cfg(feature = "std")]
+        let _nested_run = NestedRunGuard::new();
+
         system
             .run((data,), self)
             .map_err(error::Run::GetStorage)
             .unwrap()
     }
+    /// Runs `system` once for each element of `data`, passing the element as first argument.\
+    /// Storages are borrowed once for all elements, the system receives a mutable reference to each view.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (exclusive) when requesting [`AllStoragesViewMut`]
+    /// - [`AllStorages`] (shared) + storage (exclusive or shared) for all other views
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - Storage borrow failed.
+    /// - Unique storage did not exist.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, EntityId, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// struct Position([f32; 2]);
+    ///
+    /// fn move_entity((entity, position): (EntityId, [f32; 2]), positions: &mut ViewMut<Position>) {
+    ///     positions[entity].0 = position;
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity1 = world.add_entity(Position([0., 0.]));
+    /// let entity2 = world.add_entity(Position([0., 0.]));
+    ///
+    /// let messages = vec![(entity1, [1., 2.]), (entity2, [3., 4.])];
+    ///
+    /// world.for_each_system(messages, move_entity);
+    ///
+    /// world.run(|positions: ViewMut<Position>| {
+    ///     assert_eq!(positions[entity1], Position([1., 2.]));
+    ///     assert_eq!(positions[entity2], Position([3., 4.]));
+    /// });
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    /// [`AllStoragesViewMut`]: crate::AllStoragesViewMut
+    #[track_caller]
+    pub fn for_each_system<Data, B, S: ForEachSystem<Data, B>>(
+        &self,
+        data: impl IntoIterator<Item = Data>,
+        system: S,
+    ) {
+        #[cfg(feature = "tracing")]
+        let system_span = tracing::info_span!("system", name = ?core::any::type_name::<S>());
+        #[cfg(feature = "tracing")]
+        let _system_span = system_span.enter();
+
+        #[cfg(feature = "std")]
+        let _nested_run = NestedRunGuard::new();
+
+        system
+            .run_for_each(data, self)
+            .map_err(error::Run::GetStorage)
+            .unwrap()
+    }
     #[doc = "Borrows the requested storages, runs the function and evaluates to the function's return value.
 
 You can use:
@@ -619,11 +1141,78 @@ let i = world.run(sys1);
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
 
+        #[cfg(feature = "std")]
+        let _nested_run = NestedRunGuard::new();
+
         system
             .run((), self)
             .map_err(error::Run::GetStorage)
             .unwrap()
     }
+    /// Runs multiple systems at the same time if their borrows allow it.\
+    /// `systems` is a tuple of 2 to 10 systems, the same functions [`run`](World::run) accepts without data.
+    ///
+    /// The borrows are checked before running any system.
+    /// When two systems borrow the same storage and one of them borrows it exclusively,
+    /// or a system borrows `AllStorages` or a `!Send`/`!Sync` storage, all systems run sequentially in order.\
+    /// Without the *parallel* feature the systems always run sequentially.
+    ///
+    /// Returns a tuple of all systems return values.
+    ///
+    /// ### Borrows
+    ///
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Panics
+    ///
+    /// - Storage borrow failed.
+    /// - Unique storage did not exist.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, IntoIter, View, ViewMut, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Velocity(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Position(0.0), Velocity(1.0), Health(10)));
+    ///
+    /// let (_, total_health) = world.run_parallel((
+    ///     |mut positions: ViewMut<Position>, velocities: View<Velocity>| {
+    ///         for (mut position, velocity) in (&mut positions, &velocities).iter() {
+    ///             position.0 += velocity.0;
+    ///         }
+    ///     },
+    ///     |healths: View<Health>| healths.iter().map(|health| health.0).sum::<u32>(),
+    /// ));
+    ///
+    /// assert_eq!(total_health, 10);
+    /// ```
+    #[track_caller]
+    pub fn run_parallel<B, S: ParallelSystems<B>>(&self, systems: S) -> S::Return {
+        #[cfg(feature = "std")]
+        let _nested_run = NestedRunGuard::new();
+
+        #[cfg(feature = "parallel")]
+        let result = if crate::system::are_compatible(&S::borrow_infos()) {
+            systems.run_parallel(self)
+        } else {
+            systems.run_sequential(self)
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let result = systems.run_sequential(self);
+
+        result.map_err(error::Run::GetStorage).unwrap()
+    }
     /// Modifies the current default workload to `name`.
     ///
     /// ### Borrows
@@ -662,6 +1251,59 @@ let i = world.run(sys1);
             .unwrap()
             .rename(&old_label, Box::new(new_label));
     }
+    /// Replaces the systems of the `label` workload with the ones of `workload`.
+    ///
+    /// Unlike removing and adding the workload again, there is no point in time where the workload is missing from the `World`.\
+    /// The workload stays the default one if it was.\
+    /// The evaluation counts of the conditions of systems present in both versions are kept, see [`RunIfInfo`](crate::info::RunIfInfo).\
+    /// Systems no other workload uses are removed from the `World`.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (exclusive)
+    /// - [`AllStorages`] (shared)
+    /// - Systems' storage (exclusive) to enable tracking
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed, this happens when a workload is running.
+    /// - No workload named `label`.
+    /// - Same errors as [`Workload::add_to_world`], the previous workload is kept if the new one can't be built.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Unique, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Version(u32);
+    ///
+    /// fn v1(mut version: UniqueViewMut<Version>) {
+    ///     version.0 = 1;
+    /// }
+    ///
+    /// fn v2(mut version: UniqueViewMut<Version>) {
+    ///     version.0 = 2;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Version(0));
+    ///
+    /// Workload::new("Update").with_system(v1).add_to_world(&world).unwrap();
+    ///
+    /// world
+    ///     .replace_workload("Update", Workload::new("").with_system(v2))
+    ///     .unwrap();
+    ///
+    /// world.run_default_workload().unwrap();
+    /// assert_eq!(world.get_unique::<&Version>().unwrap().0, 2);
+    /// ```
+    pub fn replace_workload<T>(
+        &self,
+        label: impl AsLabel<T>,
+        workload: Workload,
+    ) -> Result<(), error::AddWorkload> {
+        workload.rename(label).replace_in_world(self)
+    }
     /// Runs the `name` workload.
     ///
     /// ### Borrows
@@ -687,8 +1329,207 @@ let i = world.run(sys1);
         self.run_batches(
             &scheduler.systems,
             &scheduler.system_names,
+            &scheduler.system_generators,
+            batches,
+            &*label,
+            Deadline::none(),
+        )
+    }
+    /// Runs the `label` workload, its systems can borrow `data` with [`WorkloadData<T>`](crate::WorkloadData).
+    ///
+    /// Unlike a unique updated before each run, `data` is never borrowed exclusively,
+    /// systems only reading it can run in the same batch.\
+    /// `data` is dropped once the workload is done.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - AllStorages (shared) to pass `data`
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - AllStorages borrow failed.
+    /// - Storage borrow failed, including when another workload is running with data of the same type.
+    /// - User error returned by system.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Unique, UniqueView, Workload, WorkloadData, World};
+    ///
+    /// #[derive(Unique)]
+    /// struct Gravity(f32);
+    ///
+    /// fn fall(dt: WorkloadData<f32>, gravity: UniqueView<Gravity>) {
+    ///     let _fall = gravity.0 * *dt;
+    /// }
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Gravity(9.81));
+    ///
+    /// Workload::new("Update").with_system(fall).add_to_world(&world).unwrap();
+    ///
+    /// world.run_workload_with_data("Update", 1.0f32 / 60.0).unwrap();
+    /// ```
+    pub fn run_workload_with_data<T: Send + Sync + 'static, L>(
+        &self,
+        label: impl AsLabel<L>,
+        data: T,
+    ) -> Result<(), error::RunWorkload> {
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        let label = label.as_label();
+        let batches = scheduler.workload(&*label)?;
+
+        let _data = WorkloadDataGuard::new(&self.all_storages, data)
+            .map_err(|err| error::RunWorkload::Run((label.clone(), error::Run::GetStorage(err))))?;
+
+        self.run_batches(
+            &scheduler.systems,
+            &scheduler.system_names,
+            &scheduler.system_generators,
+            batches,
+            &*label,
+            Deadline::none(),
+        )
+    }
+    /// Runs all workloads under `namespace`, one after the other in label order.\
+    /// Workload labels are namespaced with `/`, `"render/shadows"` and `"render/ui"` are both under `"render"`.\
+    /// A workload labeled exactly `namespace` runs too. Only text labels can be namespaced.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - No workload under `namespace`.
+    /// - Storage borrow failed.
+    /// - User error returned by system, the following workloads don't run.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Unique, UniqueViewMut, Workload, World};
+    ///
+    /// #[derive(Unique, Default)]
+    /// struct Passes(Vec<&'static str>);
+    ///
+    /// let world = World::new();
+    /// world.add_unique(Passes::default());
+    ///
+    /// Workload::new("render/ui")
+    ///     .with_system(|mut passes: UniqueViewMut<Passes>| passes.0.push("ui"))
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    /// Workload::new("render/shadows")
+    ///     .with_system(|mut passes: UniqueViewMut<Passes>| passes.0.push("shadows"))
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    /// Workload::new("physics")
+    ///     .with_system(|mut passes: UniqueViewMut<Passes>| passes.0.push("physics"))
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// world.run_workloads_under("render").unwrap();
+    ///
+    /// assert_eq!(world.get_unique::<&Passes>().unwrap().0, ["shadows", "ui"]);
+    /// ```
+    pub fn run_workloads_under(&self, namespace: &str) -> Result<(), error::RunWorkload> {
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        let workloads = scheduler.workloads_under(namespace);
+
+        if workloads.is_empty() {
+            return Err(error::RunWorkload::MissingWorkload);
+        }
+
+        for (label, batches) in workloads {
+            self.run_batches(
+                &scheduler.systems,
+                &scheduler.system_names,
+                &scheduler.system_generators,
+                batches,
+                label,
+                Deadline::none(),
+            )?;
+        }
+
+        Ok(())
+    }
+    /// Runs the `name` workload but stops starting new systems once `timeout` has elapsed.
+    ///
+    /// Running systems are never interrupted, the deadline is checked before each system starts.\
+    /// When the workload runs in parallel, systems of the batch that already started still finish.
+    ///
+    /// ### Borrows
+    ///
+    /// - Scheduler (shared)
+    /// - Systems' borrow as they are executed
+    ///
+    /// ### Errors
+    ///
+    /// - Scheduler borrow failed.
+    /// - Workload did not exist.
+    /// - Storage borrow failed.
+    /// - User error returned by system.
+    /// - Deadline reached, the error lists the systems that didn't run.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Workload, World};
+    /// use std::time::Duration;
+    ///
+    /// fn physics() {}
+    /// fn ai() {}
+    ///
+    /// let world = World::new();
+    ///
+    /// Workload::new("Tick")
+    ///     .with_system(physics)
+    ///     .with_system(ai)
+    ///     .add_to_world(&world)
+    ///     .unwrap();
+    ///
+    /// match world.try_run_workload_with_timeout("Tick", Duration::from_millis(50)) {
+    ///     Ok(()) => {}
+    ///     Err(error::RunWorkload::Timeout(skipped)) => println!("Skipped {:?}", skipped),
+    ///     Err(err) => panic!("{:?}", err),
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn try_run_workload_with_timeout<T>(
+        &self,
+        label: impl AsLabel<T>,
+        timeout: std::time::Duration,
+    ) -> Result<(), error::RunWorkload> {
+        let deadline = Deadline::at(std::time::Instant::now() + timeout);
+
+        let scheduler = self
+            .scheduler
+            .borrow()
+            .map_err(|_| error::RunWorkload::Scheduler)?;
+
+        let label = label.as_label();
+        let batches = scheduler.workload(&*label)?;
+
+        self.run_batches(
+            &scheduler.systems,
+            &scheduler.system_names,
+            &scheduler.system_generators,
             batches,
             &*label,
+            deadline,
         )
     }
     /// Returns `true` if the world contains the `name` workload.
@@ -718,14 +1559,28 @@ let i = world.run(sys1);
 
         self.scheduler.borrow().unwrap().contains_workload(&*label)
     }
+    /// Frees the [`FrameArena`] allocations, if the unique exists and isn't borrowed.
+    #[cfg(feature = "std")]
+    fn reset_frame_arena(&self) {
+        if let Ok(all_storages) = self.all_storages.borrow() {
+            if let Ok(mut arena) = all_storages.custom_storage_mut::<UniqueStorage<FrameArena>>() {
+                arena.value.reset();
+            }
+        }
+    }
     #[allow(clippy::type_complexity)]
     pub(crate) fn run_batches(
         &self,
         systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
         system_names: &[Box<dyn Label>],
+        system_generators: &[Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>],
         batches: &Batches,
         workload_name: &dyn Label,
+        deadline: Deadline,
     ) -> Result<(), error::RunWorkload> {
+        self.workload_run
+            .store(next_workload_run(), Ordering::Release);
+
         if let Some(run_if) = &batches.run_if {
             if !run_if
                 .run(self)
@@ -735,14 +1590,31 @@ let i = world.run(sys1);
             }
         }
 
+        #[cfg(feature = "std")]
+        self.reset_frame_arena();
+
         #[cfg(feature = "parallel")]
         {
-            self.run_batches_parallel(systems, system_names, batches, workload_name)
+            self.run_batches_parallel(
+                systems,
+                system_names,
+                system_generators,
+                batches,
+                workload_name,
+                deadline,
+            )
         }
 
         #[cfg(not(feature = "parallel"))]
         {
-            self.run_batches_sequential(systems, system_names, batches, workload_name)
+            self.run_batches_sequential(
+                systems,
+                system_names,
+                system_generators,
+                batches,
+                workload_name,
+                deadline,
+            )
         }
     }
     /// Run the default workload if there is one.
@@ -767,44 +1639,143 @@ let i = world.run(sys1);
             self.run_batches(
                 &scheduler.systems,
                 &scheduler.system_names,
+                &scheduler.system_generators,
                 scheduler.default_workload(),
                 &scheduler.default,
+                Deadline::none(),
             )?
         }
         Ok(())
     }
-    /// Returns a `Ref<&AllStorages>`, used to implement custom storages.
-    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    /// Runs `f` with exclusive access to [`AllStorages`].\
+    /// Equivalent to running a system borrowing [`AllStoragesViewMut`] but makes the exclusivity explicit,
+    /// useful for blocks of structural changes.
     ///
-    /// ### Errors
+    /// ### Borrows
     ///
-    /// - `AllStorages` is already borrowed.
-    pub fn all_storages(&self) -> Result<ARef<'_, &'_ AllStorages>, error::Borrow> {
-        self.all_storages.borrow()
-    }
-    /// Returns a `RefMut<&mut AllStorages>`, used to implement custom storages.
-    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    /// - [`AllStorages`] (exclusive)
     ///
-    /// ### Errors
+    /// ### Panics
     ///
-    /// - `AllStorages` is already borrowed.
-    pub fn all_storages_mut(&self) -> Result<ARefMut<'_, &'_ mut AllStorages>, error::Borrow> {
-        self.all_storages.borrow_mut()
+    /// - [`AllStorages`] borrow failed.
+    ///   When the borrow is held by another `apply` call, the panic message includes where it was made.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.apply(|all_storages| {
+    ///     let entity = all_storages.add_entity(Health(10));
+    ///     all_storages.add_entity(Health(0));
+    ///
+    ///     entity
+    /// });
+    ///
+    /// assert!(world.is_entity_alive(entity));
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    /// [`AllStoragesViewMut`]: crate::AllStoragesViewMut
+    #[track_caller]
+    pub fn apply<R, F: FnOnce(&mut AllStorages) -> R>(&self, f: F) -> R {
+        match self.try_apply(f) {
+            Ok(r) => r,
+            Err(err) => panic!("{:?}", err),
+        }
     }
-    /// Inserts a custom storage to the `World`.
+    /// Runs `f` with exclusive access to [`AllStorages`].\
+    /// Equivalent to running a system borrowing [`AllStoragesViewMut`] but makes the exclusivity explicit,
+    /// useful for blocks of structural changes.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (exclusive)
     ///
     /// ### Errors
     ///
-    /// - `AllStorages` is already borrowed exclusively.
+    /// - [`AllStorages`] borrow failed.
+    ///   When the borrow is held by another `apply` call, the error includes where it was made.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let world = World::new();
+    ///
+    /// world.apply(|_| {
+    ///     let err = world.try_apply(|_| {}).unwrap_err();
+    ///
+    ///     assert!(err.held_at.is_some());
+    /// });
+    /// ```
+    ///
+    /// [`AllStorages`]: crate::AllStorages
+    /// [`AllStoragesViewMut`]: crate::AllStoragesViewMut
+    #[track_caller]
+    pub fn try_apply<R, F: FnOnce(&mut AllStorages) -> R>(
+        &self,
+        f: F,
+    ) -> Result<R, error::WorldApply> {
+        let mut all_storages = match self.all_storages.borrow_mut() {
+            Ok(all_storages) => all_storages,
+            Err(borrow) => {
+                let held_at = self.apply_location.load(Ordering::Acquire);
+
+                return Err(error::WorldApply {
+                    borrow,
+                    // SAFETY: only `&'static Location<'static>` are stored
+                    held_at: unsafe { held_at.as_ref() },
+                });
+            }
+        };
+
+        // the location is cleared before the borrow is released
+        let _location = ApplyLocationGuard::new(&self.apply_location, Location::caller());
+
+        Ok(f(&mut all_storages))
+    }
+    /// Returns a `Ref<&AllStorages>`, used to implement custom storages.
+    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` is already borrowed.
+    pub fn all_storages(&self) -> Result<ARef<'_, &'_ AllStorages>, error::Borrow> {
+        self.all_storages.borrow()
+    }
+    /// Returns a `RefMut<&mut AllStorages>`, used to implement custom storages.
+    /// To borrow `AllStorages` you should use `borrow` or `run` with `AllStoragesViewMut`.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` is already borrowed.
+    pub fn all_storages_mut(&self) -> Result<ARefMut<'_, &'_ mut AllStorages>, error::Borrow> {
+        self.all_storages.borrow_mut()
+    }
+    /// Inserts a custom storage to the `World`.
+    ///
+    /// ### Errors
+    ///
+    /// - `AllStorages` is already borrowed exclusively.
+    #[track_caller]
     pub fn add_custom_storage<S: 'static + Storage + Send + Sync>(
         &self,
         storage_id: StorageId,
         storage: S,
     ) -> Result<(), error::Borrow> {
-        let _ = self
-            .all_storages
+        let location = Location::caller();
+
+        self.all_storages
             .borrow()?
-            .custom_storage_or_insert_by_id(storage_id, || storage);
+            .storages
+            .write()
+            .entry(storage_id)
+            .or_insert_with(|| SBox::new(storage).with_location(location));
 
         Ok(())
     }
@@ -812,15 +1783,42 @@ let i = world.run(sys1);
     /// Increments the current tracking cycle and returns the previous value.
     #[inline]
     pub(crate) fn get_current(&self) -> TrackingTimestamp {
-        TrackingTimestamp::new(
-            self.counter
-                .fetch_add(1, core::sync::atomic::Ordering::Acquire),
-        )
+        TrackingTimestamp::new(self.counter.fetch_add(1, Ordering::Acquire))
     }
 
     /// Returns a timestamp used to clear tracking information.
     pub fn get_tracking_timestamp(&self) -> TrackingTimestamp {
-        TrackingTimestamp::new(self.counter.load(core::sync::atomic::Ordering::Acquire))
+        TrackingTimestamp::new(self.counter.load(Ordering::Acquire))
+    }
+    /// Advances the tracking cycle and returns the timestamp of this tick.
+    ///
+    /// Everything tracked between two ticks is within `(previous_tick, next_tick]`
+    /// according to [`TrackingTimestamp::is_within`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// #[track(Insertion)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let last = world.tick();
+    /// let entity = world.add_entity(U32(0));
+    /// let current = world.tick();
+    ///
+    /// let inserted_at = world
+    ///     .borrow::<View<U32>>()
+    ///     .unwrap()
+    ///     .insertion_timestamp(entity)
+    ///     .unwrap();
+    ///
+    /// assert!(inserted_at.is_within(last, current));
+    /// ```
+    pub fn tick(&self) -> TrackingTimestamp {
+        self.get_current()
     }
 }
 
@@ -844,9 +1842,120 @@ impl World {
     /// let entity0 = world.add_entity((U32(0),));
     /// let entity1 = world.add_entity((U32(1), USIZE(11)));
     /// ```
+    #[track_caller]
     #[inline]
     pub fn add_entity<C: TupleAddComponent>(&mut self, component: C) -> EntityId {
-        self.all_storages.get_mut().add_entity(component)
+        self.thawed_all_storages().add_entity(component)
+    }
+    /// Creates a new entity with the components passed as argument and returns an [`EntityMut`] to keep working on it.\
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Pos(f32);
+    ///
+    /// #[derive(Component)]
+    /// struct Vel(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world
+    ///     .add_entity_mut((Pos(0.0),))
+    ///     .add_component((Vel(1.0),))
+    ///     .id();
+    ///
+    /// assert_eq!(world.get::<&Vel>(entity).unwrap().0, 1.0);
+    /// ```
+    #[track_caller]
+    pub fn add_entity_mut<C: TupleAddComponent>(&mut self, component: C) -> EntityMut<'_> {
+        self.all_storages.get_mut().add_entity_mut(component)
+    }
+    /// Reserves an [`EntityId`] with only a shared borrow of `Entities`.\
+    /// Without the *thread_local* feature, the reservation can happen from any thread, for example in an async task holding an `Arc<World>`.
+    ///
+    /// The id can be used right away, the entity becomes alive once the ticket is redeemed with [`World::redeem`].
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    /// - `Entities` (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - [`AllStorages`] borrow failed.
+    /// - `Entities` borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Mesh(&'static str);
+    ///
+    /// fn load_mesh(world: &World) -> shipyard::ReservationTicket {
+    ///     world.reserve_entity().unwrap()
+    /// }
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let ticket = load_mesh(&world);
+    /// let id = ticket.id();
+    ///
+    /// assert_eq!(world.redeem(ticket, (Mesh("cube"),)), Ok(id));
+    /// assert!(world.is_entity_alive(id));
+    /// ```
+    #[inline]
+    pub fn reserve_entity(&self) -> Result<ReservationTicket, error::GetStorage> {
+        let entities = self.borrow::<EntitiesView<'_>>()?;
+
+        Ok(entities.reserve())
+    }
+    /// Makes the entity reserved with `ticket` alive and adds `component` to it.\
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// Returns an error if the entity was deleted since its reservation.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, Component, EntityId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Child(EntityId);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let ticket = world.reserve_entity().unwrap();
+    /// let entity = ticket.id();
+    ///
+    /// // the reserved id can be referenced before the entity is alive
+    /// world.add_entity((Child(entity),));
+    ///
+    /// assert_eq!(world.redeem(ticket, (U32(0),)), Ok(entity));
+    ///
+    /// let ticket = world.reserve_entity().unwrap();
+    /// let entity = ticket.id();
+    /// world.delete_entity(entity);
+    ///
+    /// assert_eq!(
+    ///     world.redeem(ticket, (U32(1),)),
+    ///     Err(error::AddComponent::EntityIsNotAlive)
+    /// );
+    /// ```
+    #[inline]
+    pub fn redeem<C: TupleAddComponent>(
+        &mut self,
+        ticket: ReservationTicket,
+        component: C,
+    ) -> Result<EntityId, error::AddComponent> {
+        self.thawed_all_storages().redeem(ticket, component)
     }
     /// Creates multiple new entities and returns an iterator yielding the new `EntityId`s.
     /// `source` must always yield a tuple, even for a single component.
@@ -876,7 +1985,7 @@ impl World {
     ///
     /// ### Panics
     ///
-    /// - `entity` is not alive.
+    /// - `entity` is not alive and the [`AddComponentPolicy`] is `Panic`.
     ///
     /// ### Example
     ///
@@ -901,7 +2010,121 @@ impl World {
     #[track_caller]
     #[inline]
     pub fn add_component<C: TupleAddComponent>(&mut self, entity: EntityId, component: C) {
-        self.all_storages.get_mut().add_component(entity, component)
+        self.thawed_all_storages().add_component(entity, component)
+    }
+    /// Adds components to multiple existing entities, in iteration order.\
+    /// If an entity already owned a component it will be replaced.\
+    /// Each item must always hold a tuple, even for a single component.
+    ///
+    /// All components share the same tracking timestamp and new components are added to their storage in iteration order,
+    /// the same input always results in the same tracking data.
+    ///
+    /// ### Panics
+    ///
+    /// - An entity is not alive and the [`AddComponentPolicy`] is `Panic`, the components of the previous entities are already added.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entities = [world.add_entity(()), world.add_entity(())];
+    ///
+    /// world.bulk_add_component(
+    ///     entities
+    ///         .iter()
+    ///         .enumerate()
+    ///         .map(|(i, &entity)| (entity, (U32(i as u32),))),
+    /// );
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn bulk_add_component<C: TupleAddComponent>(
+        &mut self,
+        components: impl IntoIterator<Item = (EntityId, C)>,
+    ) {
+        self.thawed_all_storages().bulk_add_component(components)
+    }
+    /// Adds components to an existing entity.
+    /// If the entity already owned a component it will be replaced.
+    /// `component` must always be a tuple, even for a single component.
+    ///
+    /// Returns an error if `entity` is not alive, regardless of the [`AddComponentPolicy`].
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{error, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    ///
+    /// assert_eq!(world.try_add_component(entity, (U32(0),)), Ok(()));
+    ///
+    /// world.delete_entity(entity);
+    ///
+    /// assert_eq!(
+    ///     world.try_add_component(entity, (U32(1),)),
+    ///     Err(error::AddComponent::EntityIsNotAlive)
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_add_component<C: TupleAddComponent>(
+        &mut self,
+        entity: EntityId,
+        component: C,
+    ) -> Result<(), error::AddComponent> {
+        self.thawed_all_storages()
+            .try_add_component(entity, component)
+    }
+    /// Sets what happens when adding components to an entity that is not alive.\
+    /// This affects `add_component` and `bulk_add_component` of `World` and `AllStorages`
+    /// and `add_component` and `add_distinct_component` of `Entities`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{AddComponentPolicy, Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    /// world.delete_entity(entity);
+    ///
+    /// world.set_add_component_policy(AddComponentPolicy::Ignore);
+    ///
+    /// // doesn't panic
+    /// world.add_component(entity, (U32(0),));
+    /// ```
+    pub fn set_add_component_policy(&mut self, policy: AddComponentPolicy) {
+        self.all_storages.get_mut().set_add_component_policy(policy);
+    }
+    /// Returns what happens when adding components to an entity that is not alive.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Entities (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - Entities borrow failed.
+    #[track_caller]
+    pub fn add_component_policy(&self) -> AddComponentPolicy {
+        self.all_storages.borrow().unwrap().add_component_policy()
     }
     /// Deletes components from an entity. As opposed to `remove`, `delete` doesn't return anything.
     /// `C` must always be a tuple, even for a single component.
@@ -925,7 +2148,7 @@ impl World {
     /// ```
     #[inline]
     pub fn delete_component<C: TupleDelete>(&mut self, entity: EntityId) {
-        self.all_storages.get_mut().delete_component::<C>(entity)
+        self.thawed_all_storages().delete_component::<C>(entity)
     }
     /// Removes components from an entity.
     /// `C` must always be a tuple, even for a single component.
@@ -950,7 +2173,80 @@ impl World {
     /// ```
     #[inline]
     pub fn remove<C: TupleRemove>(&mut self, entity: EntityId) -> C::Out {
-        self.all_storages.get_mut().remove::<C>(entity)
+        self.thawed_all_storages().remove::<C>(entity)
+    }
+    /// Replaces `entity`'s `T` component and returns the old one.\
+    /// The component is flagged as modified, insertion tracking and the insertion callback are not triggered.
+    ///
+    /// Returns `None` and drops `component` if `entity` doesn't have a `T` component.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((U32(0),));
+    ///
+    /// assert_eq!(world.replace_component(entity, U32(1)), Some(U32(0)));
+    /// assert_eq!(world.get::<&U32>(entity).as_deref(), Ok(&&U32(1)));
+    /// ```
+    #[inline]
+    pub fn replace_component<T: Send + Sync + Component>(
+        &mut self,
+        entity: EntityId,
+        component: T,
+    ) -> Option<T> {
+        self.thawed_all_storages()
+            .replace_component(entity, component)
+    }
+    /// Moves all `A` components to the `B` storage, converting them with `f`.\
+    /// Each entity keeps its component, under its new type. If it already had a `B` component, it is replaced.
+    ///
+    /// `A` components are tracked as removed and `B` components as inserted, all with the same timestamp.\
+    /// Disabled `A` components are left untouched.\
+    /// Nothing happens and the `B` storage isn't created if the `A` storage doesn't exist.
+    ///
+    /// Returns the number of components converted.
+    ///
+    /// ### Panics
+    ///
+    /// - `A` and `B` are the same type.
+    /// - `A` or `B` storage is already borrowed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// struct Vitality(f32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(Health(10));
+    ///
+    /// let converted = world.convert_component(|health: Health| Vitality(health.0 as f32 / 100.0));
+    ///
+    /// assert_eq!(converted, 1);
+    /// assert!(world.get::<&Health>(entity).is_err());
+    /// assert_eq!(world.get::<&Vitality>(entity).as_deref(), Ok(&&Vitality(0.1)));
+    /// ```
+    #[track_caller]
+    pub fn convert_component<A, B, F>(&mut self, f: F) -> usize
+    where
+        A: Send + Sync + Component,
+        B: Send + Sync + Component,
+        F: FnMut(A) -> B,
+    {
+        self.thawed_all_storages().convert_component(f)
     }
     /// Deletes an entity with all its components. Returns true if the entity were alive.
     ///
@@ -973,7 +2269,7 @@ impl World {
     /// ```
     #[inline]
     pub fn delete_entity(&mut self, entity: EntityId) -> bool {
-        self.all_storages.get_mut().delete_entity(entity)
+        self.thawed_all_storages().delete_entity(entity)
     }
     /// Deletes all components of an entity without deleting the entity.
     ///
@@ -996,134 +2292,629 @@ impl World {
     /// ```
     #[inline]
     pub fn strip(&mut self, entity: EntityId) {
-        self.all_storages.get_mut().strip(entity);
+        self.thawed_all_storages().strip(entity);
     }
-    /// Deletes all entities with any of the given components.
-    /// The storage's type has to be used and not the component.
-    /// `SparseSet` is the default storage.
+    /// Hides all components of `entity` from views and iteration without deleting them.\
+    /// The components can still be accessed with [`SparseSet::get_disabled`].\
+    /// Returns `true` if `entity` is alive.
     ///
     /// ### Example
     ///
     /// ```
-    /// use shipyard::{Component, SparseSet, World};
+    /// use shipyard::{Component, IntoIter, View, World};
     ///
-    /// #[derive(Component)]
+    /// #[derive(Component, Debug, PartialEq, Eq)]
     /// struct U32(u32);
     ///
-    /// #[derive(Component)]
-    /// struct USIZE(usize);
-    ///
-    /// #[derive(Component)]
-    /// struct STR(&'static str);
-    ///
     /// let mut world = World::new();
     ///
-    /// let entity0 = world.add_entity((U32(0),));
-    /// let entity1 = world.add_entity((USIZE(1),));
-    /// let entity2 = world.add_entity((STR("2"),));
+    /// let entity = world.add_entity(U32(0));
+    /// world.add_entity(U32(1));
     ///
-    /// // deletes `entity2`
-    /// world.delete_any::<SparseSet<STR>>();
-    /// // deletes `entity0` and `entity1`
-    /// world.delete_any::<(SparseSet<U32>, SparseSet<USIZE>)>();
+    /// world.disable_entity(entity);
+    ///
+    /// world.run(|u32s: View<U32>| {
+    ///     assert_eq!(u32s.iter().collect::<Vec<_>>(), vec![&U32(1)]);
+    ///     assert_eq!(u32s.get_disabled(entity), Some(&U32(0)));
+    /// });
+    /// ```
+    ///
+    /// [`SparseSet::get_disabled`]: crate::sparse_set::SparseSet::get_disabled
+    #[inline]
+    pub fn disable_entity(&mut self, entity: EntityId) -> bool {
+        self.thawed_all_storages().disable_entity(entity)
+    }
+    /// Makes all components of `entity` hidden by [`World::disable_entity`] visible again.\
+    /// Components added to `entity` while it was disabled replace the disabled ones.\
+    /// Returns `true` if `entity` is alive.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, Get, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(U32(0));
+    ///
+    /// world.disable_entity(entity);
+    /// world.enable_entity(entity);
+    ///
+    /// world.run(|u32s: View<U32>| {
+    ///     assert_eq!(u32s.get(entity), Ok(&U32(0)));
+    /// });
+    /// ```
+    #[inline]
+    pub fn enable_entity(&mut self, entity: EntityId) -> bool {
+        self.thawed_all_storages().enable_entity(entity)
+    }
+    /// Increments the generation of `entity` and moves its components to the new id.\
+    /// All copies of the previous id become dead, while the entity keeps its components.\
+    /// Returns the new id.
+    ///
+    /// Components storing the previous id, a hierarchy for example, are not updated.\
+    /// Components of custom storages keep the previous id.\
+    /// Storages shared with another `World` are skipped, their components keep the previous id.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive.
+    /// - `entity`'s generation can't be increased.
+    ///
+    /// Nothing is modified in these cases.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, EntityId, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Target(EntityId);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let player = world.add_entity(Health(0));
+    /// let projectile = world.add_entity(Target(player));
+    ///
+    /// // the player respawns, projectiles lose their target
+    /// let player = world.bump_generation(player).unwrap();
+    /// world.get::<&mut Health>(player).unwrap().0 = 100;
+    ///
+    /// let target = world.get::<&Target>(projectile).unwrap().0;
+    /// assert!(!world.is_entity_alive(target));
+    /// assert!(world.get::<&Health>(target).is_err());
+    /// ```
+    pub fn bump_generation(&mut self, entity: EntityId) -> Result<EntityId, error::BumpGeneration> {
+        self.thawed_all_storages().bump_generation(entity)
+    }
+    /// Deletes all entities with any of the given components.
+    /// The storage's type has to be used and not the component.
+    /// `SparseSet` is the default storage.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, SparseSet, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// #[derive(Component)]
+    /// struct STR(&'static str);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity((U32(0),));
+    /// let entity1 = world.add_entity((USIZE(1),));
+    /// let entity2 = world.add_entity((STR("2"),));
+    ///
+    /// // deletes `entity2`
+    /// world.delete_any::<SparseSet<STR>>();
+    /// // deletes `entity0` and `entity1`
+    /// world.delete_any::<(SparseSet<U32>, SparseSet<USIZE>)>();
     /// ```
     #[inline]
     pub fn delete_any<S: TupleDeleteAny>(&mut self) {
-        self.all_storages.get_mut().delete_any::<S>();
+        self.thawed_all_storages().delete_any::<S>();
+    }
+    /// Deletes any entity with at least one component in the storages identified by `storage_ids`.\
+    /// This is identical to `delete_any` but uses `StorageId` and not generics.\
+    /// Missing storages and storages not listing their entities are ignored.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, SparseSet, StorageId, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity0 = world.add_entity((U32(0),));
+    /// let entity1 = world.add_entity((USIZE(1),));
+    ///
+    /// // deletes `entity1`
+    /// world.delete_any_by_id(&[StorageId::of::<SparseSet<USIZE>>()]);
+    /// ```
+    #[inline]
+    pub fn delete_any_by_id(&mut self, storage_ids: &[StorageId]) {
+        self.thawed_all_storages().delete_any_by_id(storage_ids);
     }
     /// Deletes all components of an entity except the ones passed in `S`.
     /// The storage's type has to be used and not the component.
     /// `SparseSet` is the default storage.
     ///
     /// ### Example
-    ///
+    ///
+    /// ```
+    /// use shipyard::{Component, SparseSet, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((U32(0), USIZE(1)));
+    ///
+    /// world.retain_storage::<SparseSet<U32>>(entity);
+    /// ```
+    #[inline]
+    pub fn retain_storage<S: TupleRetainStorage>(&mut self, entity: EntityId) {
+        self.thawed_all_storages().retain_storage::<S>(entity);
+    }
+    /// Same as `retain_storage` but uses `StorageId` and not generics.
+    /// You should only use this method if you use a custom storage with a runtime id.
+    #[inline]
+    pub fn retain_storage_by_id(&mut self, entity: EntityId, excluded_storage: &[StorageId]) {
+        self.thawed_all_storages()
+            .retain_storage_by_id(entity, excluded_storage);
+    }
+    /// Deletes all entities and components in the `World`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.clear();
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.thawed_all_storages().clear();
+    }
+    /// In strict mode, borrowing a storage that doesn't exist returns a [`MissingStorage`] error instead of creating it.\
+    /// Storages can still be created with [`World::add_storage`], by adding components to an entity or by enabling tracking.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{error, Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Pos(f32);
+    ///
+    /// let mut world = World::new();
+    /// world.set_strict(true);
+    ///
+    /// assert!(matches!(
+    ///     world.borrow::<View<Pos>>(),
+    ///     Err(error::GetStorage::MissingStorage { .. })
+    /// ));
+    ///
+    /// world.add_storage::<Pos>();
+    ///
+    /// assert!(world.borrow::<View<Pos>>().is_ok());
+    /// ```
+    ///
+    /// [`MissingStorage`]: error::GetStorage::MissingStorage
+    pub fn set_strict(&mut self, strict: bool) {
+        self.all_storages.get_mut().set_strict(strict);
+    }
+    /// Returns `true` if borrowing a storage that doesn't exist returns an error instead of creating it.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    #[track_caller]
+    pub fn is_strict(&self) -> bool {
+        self.all_storages.borrow().unwrap().is_strict()
+    }
+    /// When enabled, panics from indexing a view with an entity missing the component include the entity's [`Name`].\
+    /// Disabled by default.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, Name, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world.set_entity_names_in_errors(true);
+    ///
+    /// let door = world.add_entity(Name::new("Boss_Door"));
+    ///
+    /// let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    ///     world.run(|healths: View<Health>| healths[door].0);
+    /// }))
+    /// .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     panic.downcast_ref::<String>().unwrap(),
+    ///     &format!("{:?} (\"Boss_Door\") does not have a {} component.", door, std::any::type_name::<Health>())
+    /// );
+    /// ```
+    ///
+    /// [`Name`]: crate::Name
+    pub fn set_entity_names_in_errors(&mut self, enabled: bool) {
+        self.all_storages
+            .get_mut()
+            .set_entity_names_in_errors(enabled);
+    }
+    /// Returns `true` if panics about a missing component include the entity's [`Name`](crate::Name).
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    #[track_caller]
+    pub fn entity_names_in_errors(&self) -> bool {
+        self.all_storages.borrow().unwrap().entity_names_in_errors()
+    }
+    /// Creates `T`'s storage if it doesn't exist.
+    ///
+    /// ### Borrows
+    ///
+    /// - [`AllStorages`] (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - [`AllStorages`] borrow failed.
+    #[track_caller]
+    pub fn add_storage<T: Send + Sync + Component>(&self) {
+        self.all_storages.borrow().unwrap().add_storage::<T>();
+    }
+    /// Makes `T`'s storage keep the components it deletes instead of dropping them.\
+    /// They can then be collected with [`World::take_deferred_drops`].
+    ///
+    /// Useful when dropping a lot of components at once would take too long.
+    pub fn defer_drops<T: Send + Sync + Component>(&mut self) {
+        self.all_storages.get_mut().defer_drops::<T>();
+    }
+    /// Collects the components kept by storages deferring their drops.\
+    /// With the `thread_local` feature, `!Send` components stay in their storage, `take_local_deferred_drops` collects them.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Mesh(Vec<f32>);
+    ///
+    /// let mut world = World::new();
+    /// world.defer_drops::<Mesh>();
+    ///
+    /// let entity = world.add_entity((Mesh(vec![0.0; 1000]),));
+    /// world.delete_entity(entity);
+    ///
+    /// let drop_queue = world.take_deferred_drops();
+    /// assert_eq!(drop_queue.len(), 1);
+    ///
+    /// std::thread::spawn(move || drop(drop_queue));
+    /// ```
+    pub fn take_deferred_drops(&mut self) -> DropQueue {
+        self.all_storages.get_mut().take_deferred_drops()
+    }
+    /// Collects the `!Send` components kept by storages deferring their drops.\
+    /// They have to be dropped on the `World`'s thread.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{AddComponent, Component, NonSendSync, ViewMut, World};
+    /// use std::rc::Rc;
+    ///
+    /// #[derive(Component)]
+    /// struct Texture(Rc<Vec<u8>>);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    /// world.run(|mut textures: NonSendSync<ViewMut<Texture>>| {
+    ///     textures.defer_drops();
+    ///     textures.add_component_unchecked(entity, Texture(Rc::new(vec![0; 1000])));
+    /// });
+    ///
+    /// world.delete_entity(entity);
+    ///
+    /// assert!(world.take_deferred_drops().is_empty());
+    ///
+    /// let mut local_drop_queue = world.take_local_deferred_drops();
+    /// assert_eq!(local_drop_queue.drop_some(1), 1);
+    /// ```
+    #[cfg(feature = "thread_local")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thread_local")))]
+    pub fn take_local_deferred_drops(&mut self) -> LocalDropQueue {
+        self.all_storages.get_mut().take_local_deferred_drops()
+    }
+    /// Clear all deletion and removal tracking data.
+    pub fn clear_all_removed_and_deleted(&mut self) {
+        self.all_storages.get_mut().clear_all_removed_and_deleted()
+    }
+    /// Clear all deletion and removal tracking data older than some timestamp.
+    pub fn clear_all_removed_and_deleted_older_than_timestamp(
+        &mut self,
+        timestamp: TrackingTimestamp,
+    ) {
+        self.all_storages
+            .get_mut()
+            .clear_all_removed_and_deleted_older_than_timestamp(timestamp)
+    }
+    /// Make the given entity alive.
+    /// Does nothing if an entity with a greater generation is already at this index.
+    /// Returns `true` if the entity is successfully spawned.
+    #[inline]
+    pub fn spawn(&mut self, entity: EntityId) -> bool {
+        self.all_storages.get_mut().spawn(entity)
+    }
+
+    /// Deletes all components for which `f(id, &component)` returns `false`.
+    ///
+    /// # Panics
+    ///
+    /// - Storage borrow failed.
+    pub fn retain<T: Component + Send + Sync>(&mut self, f: impl FnMut(EntityId, &T) -> bool) {
+        self.thawed_all_storages().retain(f);
+    }
+
+    /// Deletes all components for which `f(id, Mut<component>)` returns `false`.
+    ///
+    /// # Panics
+    ///
+    /// - Storage borrow failed.
+    pub fn retain_mut<T: Component + Send + Sync>(
+        &mut self,
+        f: impl FnMut(EntityId, Mut<'_, T>) -> bool,
+    ) {
+        self.thawed_all_storages().retain_mut(f);
+    }
+
+    /// Returns the number of living entities.\
+    /// Only borrows `Entities`, no view is created.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Entities (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - Entities borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    /// world.add_entity(());
+    /// world.delete_entity(entity);
+    ///
+    /// assert_eq!(world.entity_count(), 1);
+    /// ```
+    #[track_caller]
+    pub fn entity_count(&self) -> usize {
+        self.all_storages
+            .borrow()
+            .unwrap()
+            .custom_storage::<Entities>()
+            .unwrap()
+            .iter()
+            .count()
+    }
+    /// Returns the number of components in `T`'s storage.\
+    /// Returns 0 if the storage doesn't exist yet. Only borrows the storage, no view is created.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - `T` storage (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - `T` storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// assert_eq!(world.storage_len::<U32>(), 0);
+    ///
+    /// world.add_entity(U32(0));
+    /// world.add_entity(U32(1));
+    ///
+    /// assert_eq!(world.storage_len::<U32>(), 2);
+    /// ```
+    #[track_caller]
+    pub fn storage_len<T: Component>(&self) -> usize {
+        let all_storages = self.all_storages.borrow().unwrap();
+
+        let len = match all_storages.custom_storage::<SparseSet<T>>() {
+            Ok(sparse_set) => sparse_set.len(),
+            Err(error::GetStorage::MissingStorage { .. }) => 0,
+            Err(err) => panic!("{:?}", err),
+        };
+
+        len
+    }
+    /// Returns `true` if `T`'s storage has no component or doesn't exist yet.\
+    /// Only borrows the storage, no view is created.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - `T` storage (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - `T` storage borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// assert!(world.is_storage_empty::<U32>());
+    ///
+    /// world.add_entity(U32(0));
+    ///
+    /// assert!(!world.is_storage_empty::<U32>());
+    /// ```
+    #[track_caller]
+    pub fn is_storage_empty<T: Component>(&self) -> bool {
+        self.storage_len::<T>() == 0
+    }
+    /// Displays storages memory information.
+    pub fn memory_usage(&self) -> WorldMemoryUsage<'_> {
+        WorldMemoryUsage(self)
+    }
+    /// Returns the name, size, thread safety and last modification of every unique, sorted by name.\
+    /// Uniques that can't be borrowed, because they are borrowed exclusively or `!Send` and bound to another thread, are skipped.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    ///
+    /// ### Example
     /// ```
-    /// use shipyard::{Component, SparseSet, World};
+    /// use shipyard::{Unique, UniqueViewMut, World};
     ///
-    /// #[derive(Component)]
-    /// struct U32(u32);
+    /// #[derive(Unique)]
+    /// struct AssetCache(Vec<u8>);
     ///
-    /// #[derive(Component)]
-    /// struct USIZE(usize);
+    /// let world = World::new();
+    /// world.add_unique(AssetCache(Vec::new()));
     ///
-    /// let mut world = World::new();
+    /// assert_eq!(world.uniques_info()[0].last_modified, None);
     ///
-    /// let entity = world.add_entity((U32(0), USIZE(1)));
+    /// world.run(|mut cache: UniqueViewMut<AssetCache>| cache.0.push(0));
     ///
-    /// world.retain_storage::<SparseSet<U32>>(entity);
+    /// assert!(world.uniques_info()[0].last_modified.is_some());
     /// ```
-    #[inline]
-    pub fn retain_storage<S: TupleRetainStorage>(&mut self, entity: EntityId) {
-        self.all_storages.get_mut().retain_storage::<S>(entity);
-    }
-    /// Same as `retain_storage` but uses `StorageId` and not generics.
-    /// You should only use this method if you use a custom storage with a runtime id.
-    #[inline]
-    pub fn retain_storage_by_id(&mut self, entity: EntityId, excluded_storage: &[StorageId]) {
-        self.all_storages
-            .get_mut()
-            .retain_storage_by_id(entity, excluded_storage);
+    #[track_caller]
+    pub fn uniques_info(&self) -> Vec<UniqueInfo> {
+        self.all_storages.borrow().unwrap().uniques_info()
     }
-    /// Deletes all entities and components in the `World`.
+    /// Returns the id, name and, with the `storage_location` feature, creation location of every storage.
     ///
-    /// ### Example
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
     ///
+    /// ### Example
     /// ```
-    /// use shipyard::World;
+    /// use shipyard::{Component, StorageId, SparseSet, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
     ///
     /// let mut world = World::new();
     ///
-    /// world.clear();
+    /// world.add_entity((U32(0),));
+    ///
+    /// let info = world
+    ///     .storages_info()
+    ///     .into_iter()
+    ///     .find(|info| info.id == StorageId::of::<SparseSet<U32>>())
+    ///     .unwrap();
+    ///
+    /// assert!(info.name.unwrap().contains("U32"));
     /// ```
-    #[inline]
-    pub fn clear(&mut self) {
-        self.all_storages.get_mut().clear();
-    }
-    /// Clear all deletion and removal tracking data.
-    pub fn clear_all_removed_and_deleted(&mut self) {
-        self.all_storages.get_mut().clear_all_removed_and_deleted()
-    }
-    /// Clear all deletion and removal tracking data older than some timestamp.
-    pub fn clear_all_removed_and_deleted_older_than_timestamp(
-        &mut self,
-        timestamp: TrackingTimestamp,
-    ) {
-        self.all_storages
-            .get_mut()
-            .clear_all_removed_and_deleted_older_than_timestamp(timestamp)
-    }
-    /// Make the given entity alive.
-    /// Does nothing if an entity with a greater generation is already at this index.
-    /// Returns `true` if the entity is successfully spawned.
-    #[inline]
-    pub fn spawn(&mut self, entity: EntityId) -> bool {
-        self.all_storages.get_mut().spawn(entity)
+    #[track_caller]
+    pub fn storages_info(&self) -> Vec<StorageInfo> {
+        self.all_storages.borrow().unwrap().storages_info()
     }
-
-    /// Deletes all components for which `f(id, &component)` returns `false`.
+    /// Checks that every storage agrees with the entities of the `World`, typically after deserializing it.\
+    /// An empty `Vec` means no inconsistency was found.
     ///
-    /// # Panics
+    /// Component storages are checked for components owned by dead entities, sparse and dense arrays disagreeing
+    /// in both directions and tracking timestamps more recent than the current one.\
+    /// Storages shared with other `World`s are not checked.
     ///
-    /// - Storage borrow failed.
-    pub fn retain<T: Component + Send + Sync>(&mut self, f: impl FnMut(EntityId, &T) -> bool) {
-        self.all_storages.get_mut().retain(f);
-    }
-
-    /// Deletes all components for which `f(id, Mut<component>)` returns `false`.
+    /// ### Borrows
     ///
-    /// # Panics
+    /// - AllStorages (shared)
+    /// - Entities (shared)
+    /// - All storages (shared)
+    ///
+    /// ### Errors
     ///
+    /// - AllStorages borrow failed.
+    /// - Entities borrow failed.
     /// - Storage borrow failed.
-    pub fn retain_mut<T: Component + Send + Sync>(
-        &mut self,
-        f: impl FnMut(EntityId, Mut<'_, T>) -> bool,
-    ) {
-        self.all_storages.get_mut().retain_mut(f);
-    }
-
-    /// Displays storages memory information.
-    pub fn memory_usage(&self) -> WorldMemoryUsage<'_> {
-        WorldMemoryUsage(self)
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component)]
+    /// struct U32(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity(U32(0));
+    ///
+    /// assert!(world.validate_integrity().unwrap().is_empty());
+    /// ```
+    pub fn validate_integrity(&self) -> Result<Vec<error::IntegrityError>, error::GetStorage> {
+        self.all_storages
+            .borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .validate_integrity()
     }
     /// Returns a list of workloads and all information related to them.
     ///
@@ -1138,13 +2929,131 @@ impl World {
     pub fn workloads_info(&self) -> WorkloadsInfo {
         let scheduler = self.scheduler.borrow().unwrap();
 
-        WorkloadsInfo(
+        let mut workloads_info = WorkloadsInfo(
             scheduler
                 .workloads_info
                 .iter()
                 .map(|(name, workload_info)| (format!("{:?}", name), workload_info.clone()))
                 .collect(),
-        )
+        );
+
+        self.stable_names.apply(&mut workloads_info);
+
+        workloads_info
+    }
+    /// Returns the names registered to replace `TypeId`s in exported information.
+    pub fn stable_names(&self) -> &StableNames {
+        &self.stable_names
+    }
+    /// Returns the names registered to replace `TypeId`s in exported information.\
+    /// See [`StableNames`] to register names.
+    pub fn stable_names_mut(&mut self) -> &mut StableNames {
+        &mut self.stable_names
+    }
+    /// Starts recording how long workloads, batches, systems and storage borrows take.\
+    /// If profiling was already started, the events recorded so far are discarded.
+    ///
+    /// Only workloads are profiled, not systems run with [`World::run`].
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, World};
+    ///
+    /// #[derive(Component)]
+    /// struct Position;
+    ///
+    /// fn sys(_: View<Position>) {}
+    ///
+    /// let mut world = World::new();
+    /// world.add_workload(|| sys);
+    ///
+    /// world.start_profiling();
+    /// world.run_default_workload().unwrap();
+    /// let profile = world.stop_profiling();
+    ///
+    /// let trace_path = std::env::temp_dir().join("shipyard_profile.json");
+    /// profile.write_chrome_trace(&trace_path).unwrap();
+    /// # std::fs::remove_file(trace_path).unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn start_profiling(&mut self) {
+        self.profiler = Some(Profiler::new());
+    }
+    /// Stops profiling and returns the recorded events.\
+    /// If profiling wasn't started, the returned [`Profile`] is empty.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn stop_profiling(&mut self) -> Profile {
+        self.profiler
+            .take()
+            .map(Profiler::finish)
+            .unwrap_or_default()
+    }
+    /// Starts counting the storages borrowed by each system.\
+    /// If access statistics were already started, the accesses recorded so far are discarded.
+    ///
+    /// Only workloads are recorded, not systems run with [`World::run`].
+    #[cfg(feature = "access_stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "access_stats")))]
+    pub fn start_access_stats(&mut self) {
+        self.access_recorder = Some(AccessRecorder::new());
+    }
+    /// Returns the accesses recorded since [`World::start_access_stats`], see [`AccessStats`].\
+    /// If access statistics weren't started, the returned [`AccessStats`] is empty.
+    #[cfg(feature = "access_stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "access_stats")))]
+    pub fn access_stats(&self) -> AccessStats {
+        self.access_recorder
+            .as_ref()
+            .map(AccessRecorder::stats)
+            .unwrap_or_default()
+    }
+    /// Stops counting accesses and returns the ones recorded.\
+    /// If access statistics weren't started, the returned [`AccessStats`] is empty.
+    #[cfg(feature = "access_stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "access_stats")))]
+    pub fn stop_access_stats(&mut self) -> AccessStats {
+        self.access_recorder
+            .take()
+            .as_ref()
+            .map(AccessRecorder::stats)
+            .unwrap_or_default()
+    }
+    /// Returns the time at which an event starts if profiling is active.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) fn profiling_start(&self) -> Option<std::time::Instant> {
+        self.profiler.as_ref().map(|_| std::time::Instant::now())
+    }
+    /// Borrows a system's views, recording how long it took if profiling is active.
+    #[inline]
+    pub(crate) fn profile_borrow<V, B: FnOnce() -> Result<V, error::GetStorage>>(
+        &self,
+        borrow: B,
+    ) -> Result<V, error::GetStorage> {
+        #[cfg(feature = "std")]
+        let start = self.profiling_start();
+
+        let views = borrow();
+
+        #[cfg(feature = "std")]
+        self.profiling_record(ProfileEventKind::Borrow, || "borrow".into(), start);
+
+        views
+    }
+    /// Records an event started at `start`, `name` is only called when profiling is active.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) fn profiling_record<N: FnOnce() -> String>(
+        &self,
+        kind: ProfileEventKind,
+        name: N,
+        start: Option<std::time::Instant>,
+    ) {
+        if let (Some(profiler), Some(start)) = (&self.profiler, start) {
+            profiler.record(kind, name(), start);
+        }
     }
 
     /// Enable insertion tracking for the given components.
@@ -1433,6 +3342,117 @@ for (i, j) in &mut iter {
             .unwrap()
             .is_alive(entity)
     }
+    /// Returns true if entity matches a living entity.\
+    /// Unlike [`World::is_entity_alive`] only a shared access to the `World` is needed.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Entities (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - AllStorages borrow failed.
+    /// - Entities borrow failed.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity(());
+    /// assert!(world.entity_exists(entity));
+    ///
+    /// world.delete_entity(entity);
+    /// assert!(!world.entity_exists(entity));
+    /// ```
+    #[track_caller]
+    pub fn entity_exists(&self, entity: EntityId) -> bool {
+        self.all_storages.borrow().unwrap().entity_exists(entity)
+    }
+    /// Serializes the `C` components of the entities present in all `F` storages.\
+    /// Entities without any `C` component are skipped.
+    ///
+    /// The output is a sequence of `(EntityId, (Option<C0>, Option<C1>, ...))`.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Entities (shared)
+    /// - `F` storages (shared)
+    /// - `C` storages (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - AllStorages or storage borrow failed, reported as a custom serialization error.
+    /// - Serialization error.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, serde::Serialize)]
+    /// struct Pos(u32);
+    ///
+    /// #[derive(Component, serde::Serialize)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Persistent;
+    ///
+    /// let mut world = World::new();
+    ///
+    /// world.add_entity((Pos(0), Persistent));
+    /// world.add_entity((Pos(1), Health(100), Persistent));
+    /// // particle, not saved
+    /// world.add_entity((Pos(2),));
+    ///
+    /// let mut save = Vec::new();
+    /// world
+    ///     .serialize_query::<(Pos, Health), (Persistent,), _>(&mut serde_json::Serializer::new(
+    ///         &mut save,
+    ///     ))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     save,
+    ///     br#"[[{"index":0,"gen":0},[0,null]],[{"index":1,"gen":0},[1,100]]]"#
+    /// );
+    /// ```
+    #[cfg(feature = "serde1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde1")))]
+    pub fn serialize_query<C: TupleSerialize, F: TupleFilter, S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+
+        self.all_storages
+            .borrow()
+            .map_err(|err| S::Error::custom(error::GetStorage::AllStoragesBorrow(err)))?
+            .serialize_query::<C, F, S>(serializer)
+    }
+    /// Returns the alive entities that don't have any component.\
+    /// Storages that can't be borrowed, for example because they are borrowed exclusively, are skipped,
+    /// entities only having components in these storages are returned.
+    ///
+    /// ### Borrows
+    ///
+    /// - AllStorages (shared)
+    /// - Entities (shared)
+    /// - All storages that aren't borrowed exclusively (shared)
+    ///
+    /// ### Errors
+    ///
+    /// - AllStorages borrow failed.
+    /// - Entities borrow failed.
+    pub fn orphans(&self) -> Result<Vec<EntityId>, error::GetStorage> {
+        self.all_storages
+            .borrow()
+            .map_err(error::GetStorage::AllStoragesBorrow)?
+            .orphans()
+    }
 
     /// Moves an entity from a `World` to another.
     ///
@@ -1455,11 +3475,10 @@ for (i, j) in &mut iter {
     #[inline]
     #[track_caller]
     pub fn move_entity(&mut self, other: &mut World, entity: EntityId) {
-        let other_all_storages = other.all_storages.get_mut();
+        let mut other_all_storages = other.thawed_all_storages();
 
-        self.all_storages
-            .get_mut()
-            .move_entity(other_all_storages, entity);
+        self.thawed_all_storages()
+            .move_entity(&mut other_all_storages, entity);
     }
 
     /// Moves all components from an entity to another in another `World`.
@@ -1483,11 +3502,105 @@ for (i, j) in &mut iter {
     /// ```
     #[inline]
     pub fn move_components(&mut self, other: &mut World, from: EntityId, to: EntityId) {
-        let other_all_storages = other.all_storages.get_mut();
+        let mut other_all_storages = other.thawed_all_storages();
 
-        self.all_storages
-            .get_mut()
-            .move_components(other_all_storages, from, to);
+        self.thawed_all_storages()
+            .move_components(&mut other_all_storages, from, to);
+    }
+    /// Exchanges `T`'s storage with `other`'s, without moving any component.\
+    /// Storages that don't exist are created empty first.
+    ///
+    /// Useful to flip between two states of a simulation, like a double buffer.
+    ///
+    /// Entities are not checked, both `World`s are expected to have the same entities alive.\
+    /// Tracking information keeps the timestamps of the `World` it was recorded in.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// struct Position(f32);
+    ///
+    /// let mut front = World::new();
+    /// let mut back = World::new();
+    ///
+    /// let entity = front.add_entity((Position(0.0),));
+    /// back.spawn(entity);
+    /// back.add_component(entity, (Position(1.0),));
+    ///
+    /// front.swap_storage::<Position>(&mut back);
+    ///
+    /// assert_eq!(front.get::<&Position>(entity).as_deref(), Ok(&&Position(1.0)));
+    /// assert_eq!(back.get::<&Position>(entity).as_deref(), Ok(&&Position(0.0)));
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// - One of the storages is immutable or borrowed by another `World` it is shared with.
+    #[inline]
+    #[track_caller]
+    pub fn swap_storage<T: Send + Sync + Component>(&mut self, other: &mut World) {
+        let mut other_all_storages = other.thawed_all_storages();
+
+        self.thawed_all_storages()
+            .swap_storage::<T>(&mut other_all_storages);
+    }
+    /// Deletes `entity` and returns its components.
+    ///
+    /// The components can be added back to a new entity with [`World::unarchive`].\
+    /// Components in storages shared with another `World` are not archived, they keep `entity`'s id like with [`World::delete_entity`].\
+    /// Custom storages can't archive their components. If they list them in their [`SparseArray`](crate::sparse_set::SparseArray),
+    /// archiving `entity` fails, otherwise they are deleted.
+    ///
+    /// ### Errors
+    ///
+    /// - `entity` is not alive.
+    /// - `entity` has components that can't be archived, like `!Send` or `!Sync` components.
+    ///
+    /// Nothing is modified in these cases.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((USIZE(1),));
+    /// let archived = world.archive(entity).unwrap();
+    ///
+    /// assert!(!world.is_entity_alive(entity));
+    /// assert_eq!(archived.get::<USIZE>(), Some(&USIZE(1)));
+    /// ```
+    #[inline]
+    pub fn archive(&mut self, entity: EntityId) -> Result<ArchivedEntity, error::Archive> {
+        self.thawed_all_storages().archive(entity)
+    }
+    /// Creates a new entity with the components of `archived` and returns its `EntityId`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct USIZE(usize);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let entity = world.add_entity((USIZE(1),));
+    /// let archived = world.archive(entity).unwrap();
+    /// let entity = world.unarchive(archived);
+    ///
+    /// assert_eq!(world.get::<&USIZE>(entity).as_deref(), Ok(&&USIZE(1)));
+    /// ```
+    #[inline]
+    pub fn unarchive(&mut self, archived: ArchivedEntity) -> EntityId {
+        self.thawed_all_storages().unarchive(archived)
     }
 }
 
@@ -1,16 +1,81 @@
 use crate::all_storages::{AllStoragesBuilder, LockPresent, ThreadIdPresent};
 use crate::atomic_refcell::AtomicRefCell;
+use crate::component::{Component, Unique};
 use crate::public_transport::ShipyardRwLock;
+use crate::scheduler::IntoWorkload;
+use crate::sparse_set::SparseSet;
+use crate::stable_names::StableNames;
+use crate::storage::{SBox, StorageId};
+use crate::tracking::{Tracking, TupleTrack};
 use crate::world::World;
+use crate::ShipHashMap;
 use alloc::sync::Arc;
-use core::sync::atomic::AtomicU32;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicPtr, AtomicU32};
 
 /// Builder for [`World`] when one wants custom lock, custom thread pool
 /// or custom thread id provider function.
+///
+/// Tracking, uniques and workloads can also be set up front.\
+/// The builder can be cloned and used as a template to create many similar [`Worlds`](World).
+///
+/// ### Example
+/// ```
+/// use shipyard::{track, Component, IntoIter, Unique, UniqueView, View, World};
+///
+/// #[derive(Component)]
+/// struct Position(f32);
+///
+/// #[derive(Unique, Clone)]
+/// struct Gravity(f32);
+///
+/// fn fall(
+///     gravity: UniqueView<Gravity>,
+///     positions: View<Position, track::InsertionAndModification>,
+/// ) {
+///     for position in positions.inserted_or_modified().iter() {}
+/// }
+///
+/// let template = World::builder()
+///     .with_tracking::<Position>(track::InsertionAndModification)
+///     .with_unique(Gravity(9.81))
+///     .with_workload(|| fall);
+///
+/// let room1 = template.clone().build();
+/// let room2 = template.build();
+///
+/// room1.run_default_workload().unwrap();
+/// room2.run_default_workload().unwrap();
+/// ```
 pub struct WorldBuilder<Lock, ThreadId> {
     all_storages_builder: AllStoragesBuilder<Lock, ThreadId>,
+    shared_storages: Vec<(StorageId, SBox)>,
+    #[allow(clippy::type_complexity)]
+    initializers: Vec<Arc<dyn Fn(&mut World) + Send + Sync>>,
     #[cfg(feature = "parallel")]
     thread_pool: Option<rayon::ThreadPool>,
+    #[cfg(feature = "parallel")]
+    thread_pool_fn: Option<Arc<dyn Fn() -> rayon::ThreadPool + Send + Sync>>,
+}
+
+/// A thread pool added with [`with_local_thread_pool`](WorldBuilder::with_local_thread_pool) is not cloned,
+/// use [`with_thread_pool`](WorldBuilder::with_thread_pool) to give each [`World`] its own.
+impl<Lock, ThreadId> Clone for WorldBuilder<Lock, ThreadId> {
+    fn clone(&self) -> Self {
+        WorldBuilder {
+            all_storages_builder: self.all_storages_builder.clone(),
+            shared_storages: self
+                .shared_storages
+                .iter()
+                .map(|(storage_id, sbox)| (*storage_id, sbox.clone_shared()))
+                .collect(),
+            initializers: self.initializers.clone(),
+            #[cfg(feature = "parallel")]
+            thread_pool: None,
+            #[cfg(feature = "parallel")]
+            thread_pool_fn: self.thread_pool_fn.clone(),
+        }
+    }
 }
 
 impl World {
@@ -20,8 +85,12 @@ impl World {
     pub fn builder() -> WorldBuilder<LockPresent, ThreadIdPresent> {
         WorldBuilder {
             all_storages_builder: AllStoragesBuilder::<LockPresent, ThreadIdPresent>::new(),
+            shared_storages: Vec::new(),
+            initializers: Vec::new(),
             #[cfg(feature = "parallel")]
             thread_pool: None,
+            #[cfg(feature = "parallel")]
+            thread_pool_fn: None,
         }
     }
 
@@ -34,6 +103,8 @@ impl World {
                 crate::all_storages::MissingLock,
                 ThreadIdPresent,
             >::new(),
+            shared_storages: Vec::new(),
+            initializers: Vec::new(),
         }
     }
 
@@ -47,6 +118,8 @@ impl World {
                 crate::all_storages::MissingLock,
                 crate::all_storages::MissingThreadId,
             >::new(),
+            shared_storages: Vec::new(),
+            initializers: Vec::new(),
         }
     }
 }
@@ -60,8 +133,12 @@ impl<Lock, ThreadId> WorldBuilder<Lock, ThreadId> {
     ) -> WorldBuilder<LockPresent, ThreadId> {
         WorldBuilder {
             all_storages_builder: self.all_storages_builder.with_custom_lock::<L>(),
+            shared_storages: self.shared_storages,
+            initializers: self.initializers,
             #[cfg(feature = "parallel")]
             thread_pool: self.thread_pool,
+            #[cfg(feature = "parallel")]
+            thread_pool_fn: self.thread_pool_fn,
         }
     }
 
@@ -81,8 +158,12 @@ impl<Lock, ThreadId> WorldBuilder<Lock, ThreadId> {
     ) -> WorldBuilder<Lock, ThreadIdPresent> {
         WorldBuilder {
             all_storages_builder: self.all_storages_builder.with_custom_thread_id(thread_id),
+            shared_storages: self.shared_storages,
+            initializers: self.initializers,
             #[cfg(feature = "parallel")]
             thread_pool: self.thread_pool,
+            #[cfg(feature = "parallel")]
+            thread_pool_fn: self.thread_pool_fn,
         }
     }
 
@@ -100,6 +181,143 @@ impl<Lock, ThreadId> WorldBuilder<Lock, ThreadId> {
 
         self
     }
+
+    /// Creates a new [`ThreadPool`](rayon::ThreadPool) with `thread_pool` for each [`World`] built.
+    ///
+    /// Unlike [`with_local_thread_pool`](WorldBuilder::with_local_thread_pool), it is kept when the builder is cloned.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::World;
+    ///
+    /// let world = World::builder()
+    ///     .with_thread_pool(|| {
+    ///         rayon::ThreadPoolBuilder::new()
+    ///             .num_threads(2)
+    ///             .build()
+    ///             .unwrap()
+    ///     })
+    ///     .build();
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn with_thread_pool(
+        mut self,
+        thread_pool: impl Fn() -> rayon::ThreadPool + Send + Sync + 'static,
+    ) -> WorldBuilder<Lock, ThreadId> {
+        self.thread_pool_fn = Some(Arc::new(thread_pool));
+
+        self
+    }
+
+    /// Enables `tracking` on the storage of `T`.\
+    /// `T` can be a single component or a tuple of components.
+    pub fn with_tracking<T: TupleTrack>(mut self, tracking: impl Tracking) -> Self {
+        fn enable_tracking<T: TupleTrack, Track: Tracking>(
+            _: Track,
+        ) -> Arc<dyn Fn(&mut World) + Send + Sync> {
+            Arc::new(|world: &mut World| {
+                let all_storages = world.all_storages.get_mut();
+
+                if Track::track_insertion() {
+                    T::track_insertion(all_storages);
+                }
+                if Track::track_modification() {
+                    T::track_modification(all_storages);
+                }
+                if Track::track_deletion() {
+                    T::track_deletion(all_storages);
+                }
+                if Track::track_removal() {
+                    T::track_removal(all_storages);
+                }
+            })
+        }
+
+        self.initializers.push(enable_tracking::<T, _>(tracking));
+
+        self
+    }
+
+    /// Adds `unique` to each [`World`] built.\
+    /// The value is cloned for each [`World`].
+    pub fn with_unique<T: Unique + Send + Sync + Clone>(mut self, unique: T) -> Self {
+        self.initializers.push(Arc::new(move |world: &mut World| {
+            world.add_unique(unique.clone())
+        }));
+
+        self
+    }
+
+    /// Adds the workload returned by `workload` to each [`World`] built.\
+    /// The workload is added the same way [`World::add_workload`] does.
+    ///
+    /// ### Panics
+    ///
+    /// - The workload can't be added to the [`World`], see [`Workload::add_to_world`](crate::Workload::add_to_world).
+    pub fn with_workload<Views, R, W, F>(mut self, workload: F) -> Self
+    where
+        W: IntoWorkload<Views, R>,
+        F: FnOnce() -> W + Clone + Send + Sync + 'static,
+    {
+        self.initializers.push(Arc::new(move |world: &mut World| {
+            world.add_workload(workload.clone())
+        }));
+
+        self
+    }
+
+    /// Shares `T`'s storage with `other`, both [`Worlds`](World) will access the same components.\
+    /// The storage is created in `other` if it doesn't exist yet.
+    ///
+    /// This is meant for read-only data used by many [`Worlds`](World), like asset metadata.\
+    /// Entity ids are not shared, the same id can refer to different entities in each [`World`].\
+    /// For this reason operations working on all storages at once (deleting an entity, bumping its generation, clearing the [`World`], ...) skip shared storages,
+    /// their components have to be removed explicitly, with [`World::remove`] or a [`ViewMut`](crate::ViewMut) for example.\
+    /// Operations on a single storage, like [`World::add_component`], borrow the shared storage and panic if another [`World`] is borrowing it.
+    ///
+    /// The storage mixes the entity ids and tracking timestamps of all [`Worlds`](World) writing to it.\
+    /// A component inserted from one [`World`] is stored under that [`World`]'s id, and a view in another [`World`] can find it under an unrelated entity.\
+    /// Timestamps are only comparable to the [`World`] that recorded them, tracking (e.g. [`View::inserted`](crate::View::inserted)) is meaningless for writes made from another [`World`].
+    ///
+    /// ### Borrows
+    ///
+    /// - `other`'s [`AllStorages`](crate::AllStorages) (shared)
+    ///
+    /// ### Panics
+    ///
+    /// - `other`'s [`AllStorages`](crate::AllStorages) borrow failed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use shipyard::{Component, Get, View, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq, Eq)]
+    /// struct Mesh(u32);
+    ///
+    /// let mut assets = World::new();
+    /// let mesh = assets.add_entity(Mesh(0));
+    ///
+    /// let room = World::builder().share_storage::<Mesh>(&assets).build();
+    ///
+    /// room.run(|meshes: View<Mesh>| {
+    ///     assert_eq!(meshes.get(mesh), Ok(&Mesh(0)));
+    /// });
+    /// ```
+    #[track_caller]
+    pub fn share_storage<T: Component + Send + Sync>(mut self, other: &World) -> Self {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        let sbox = other
+            .all_storages
+            .borrow()
+            .unwrap()
+            .share_storage_or_insert(storage_id, SparseSet::<T>::new);
+
+        self.shared_storages.push((storage_id, sbox));
+
+        self
+    }
 }
 
 impl WorldBuilder<LockPresent, ThreadIdPresent> {
@@ -107,14 +325,35 @@ impl WorldBuilder<LockPresent, ThreadIdPresent> {
     pub fn build(self) -> World {
         let counter = Arc::new(AtomicU32::new(1));
 
-        let all_storages = self.all_storages_builder.build(counter.clone());
+        let mut all_storages = self.all_storages_builder.build(counter.clone());
+
+        let storages = all_storages.get_mut().storages.get_mut();
+        for (storage_id, sbox) in self.shared_storages {
+            storages.insert(storage_id, sbox);
+        }
 
-        World {
+        let mut world = World {
             all_storages,
             scheduler: AtomicRefCell::new(Default::default()),
             counter,
             #[cfg(feature = "parallel")]
-            thread_pool: self.thread_pool,
+            thread_pool: self
+                .thread_pool
+                .or_else(|| self.thread_pool_fn.map(|thread_pool| (thread_pool)())),
+            stable_names: StableNames::new(),
+            #[cfg(feature = "std")]
+            profiler: None,
+            #[cfg(feature = "access_stats")]
+            access_recorder: None,
+            immutable_storages: ShipHashMap::default(),
+            apply_location: AtomicPtr::new(core::ptr::null_mut()),
+            workload_run: AtomicU32::new(0),
+        };
+
+        for initializer in &self.initializers {
+            (initializer)(&mut world);
         }
+
+        world
     }
 }
@@ -0,0 +1,223 @@
+use crate::all_storages::AllStorages;
+use crate::component::Component;
+use crate::entity_id::EntityId;
+use crate::error;
+use crate::sparse_set::SparseSet;
+use crate::storage::{KeepAlive, SBox, StorageId};
+use crate::world::World;
+use crate::ShipHashMap;
+use core::any::{type_name, Any};
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+/// Pointer to an immutable `SparseSet`.
+///
+/// `keep_alive` keeps the storage alive even if it is dropped from `AllStorages`.
+pub(crate) struct ImmutableStorage {
+    keep_alive: KeepAlive,
+    sparse_set: NonNull<dyn Any + Send + Sync>,
+}
+
+// SAFE `sparse_set` points to a Send + Sync storage, it can only be read while it is immutable
+#[cfg(not(feature = "thread_local"))]
+unsafe impl Send for ImmutableStorage {}
+unsafe impl Sync for ImmutableStorage {}
+
+/// `AllStorages` with the `World`'s immutable storages made mutable until it is dropped.
+pub(crate) struct ThawedAllStorages<'w> {
+    all_storages: &'w mut AllStorages,
+    immutable_storages: &'w ShipHashMap<StorageId, ImmutableStorage>,
+}
+
+impl ThawedAllStorages<'_> {
+    /// Calls `f` on all immutable storages still present in `AllStorages`.
+    fn for_each_sbox(&mut self, mut f: impl FnMut(&mut SBox)) {
+        let storages = self.all_storages.storages.get_mut();
+
+        for (storage_id, immutable_storage) in self.immutable_storages {
+            // The storage could have been moved to another `World` with its `AllStorages`
+            if let Some(sbox) = storages
+                .get_mut(storage_id)
+                .filter(|sbox| immutable_storage.keep_alive.keeps_alive(sbox))
+            {
+                f(sbox);
+            }
+        }
+    }
+}
+
+impl Deref for ThawedAllStorages<'_> {
+    type Target = AllStorages;
+
+    #[inline]
+    fn deref(&self) -> &AllStorages {
+        self.all_storages
+    }
+}
+
+impl DerefMut for ThawedAllStorages<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut AllStorages {
+        self.all_storages
+    }
+}
+
+impl Drop for ThawedAllStorages<'_> {
+    fn drop(&mut self) {
+        self.for_each_sbox(|sbox| {
+            // No borrow can outlive the exclusive access to `AllStorages`
+            let _ = sbox.make_immutable();
+        });
+    }
+}
+
+impl World {
+    /// Makes `T`'s storage immutable.\
+    /// Its components can then be read with [`World::peek`] without borrowing the storage.
+    ///
+    /// While immutable, borrowing the storage exclusively (e.g. [`ViewMut`](crate::ViewMut)) returns an error.\
+    /// Methods taking `&mut World`, like [`World::add_component`] or [`World::delete_entity`], can still modify it.\
+    /// Modifying it through an [`AllStorages`] borrow, for example deleting an entity with a `T` component in a system, panics.
+    ///
+    /// ### Errors
+    ///
+    /// - `T`'s storage doesn't exist.
+    /// - `T`'s storage is borrowed exclusively by another `World` it is shared with.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, View, ViewMut, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// struct NavCell(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let cell = world.add_entity(NavCell(1));
+    ///
+    /// world.make_immutable::<NavCell>().unwrap();
+    ///
+    /// assert_eq!(world.peek::<NavCell>(cell), Some(&NavCell(1)));
+    /// assert!(world.borrow::<View<NavCell>>().is_ok());
+    /// assert!(world.borrow::<ViewMut<NavCell>>().is_err());
+    /// ```
+    pub fn make_immutable<T: Component + Send + Sync>(&mut self) -> Result<(), error::GetStorage> {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        if self.immutable_storages.contains_key(&storage_id) {
+            return Ok(());
+        }
+
+        let sbox = self
+            .all_storages
+            .get_mut()
+            .storages
+            .get_mut()
+            .get_mut(&storage_id)
+            .ok_or(error::GetStorage::MissingStorage {
+                name: Some(type_name::<SparseSet<T>>()),
+                id: storage_id,
+            })?;
+
+        sbox.make_immutable()
+            .map_err(|borrow| error::GetStorage::StorageBorrow {
+                name: Some(type_name::<SparseSet<T>>()),
+                id: storage_id,
+                borrow,
+            })?;
+
+        // SAFE the storage was just made immutable
+        let sparse_set: &(dyn Any + Send + Sync) = unsafe { sbox.get_immutable() }
+            .as_any()
+            .downcast_ref::<SparseSet<T>>()
+            .unwrap();
+        let sparse_set = NonNull::from(sparse_set);
+
+        self.immutable_storages.insert(
+            storage_id,
+            ImmutableStorage {
+                keep_alive: sbox.keep_alive(),
+                sparse_set,
+            },
+        );
+
+        Ok(())
+    }
+    /// Makes `T`'s storage mutable again.\
+    /// Returns `false` if the storage wasn't immutable.
+    pub fn make_mutable<T: Component + Send + Sync>(&mut self) -> bool {
+        let storage_id = StorageId::of::<SparseSet<T>>();
+
+        let immutable_storage = match self.immutable_storages.remove(&storage_id) {
+            Some(immutable_storage) => immutable_storage,
+            None => return false,
+        };
+
+        let sbox = self
+            .all_storages
+            .get_mut()
+            .storages
+            .get_mut()
+            .get_mut(&storage_id);
+
+        // The storage could have been moved to another `World` with its `AllStorages`
+        if let Some(sbox) = sbox.filter(|sbox| immutable_storage.keep_alive.keeps_alive(sbox)) {
+            drop(immutable_storage);
+
+            // SAFE references returned by `peek` borrow the `World`, they can't be alive
+            unsafe { sbox.make_mutable() };
+        }
+
+        true
+    }
+    /// Returns `entity`'s `T` component without borrowing its storage.\
+    /// `T`'s storage has to be made immutable with [`World::make_immutable`] first.
+    ///
+    /// Returns `None` if `T`'s storage isn't immutable or `entity` doesn't have a `T` component.
+    ///
+    /// ### Example
+    /// ```
+    /// use shipyard::{Component, World};
+    ///
+    /// #[derive(Component, Debug, PartialEq)]
+    /// struct NavCell(u32);
+    ///
+    /// let mut world = World::new();
+    ///
+    /// let cell = world.add_entity(NavCell(1));
+    ///
+    /// assert_eq!(world.peek::<NavCell>(cell), None);
+    ///
+    /// world.make_immutable::<NavCell>().unwrap();
+    ///
+    /// assert_eq!(world.peek::<NavCell>(cell), Some(&NavCell(1)));
+    /// ```
+    #[inline]
+    pub fn peek<T: Component + Send + Sync>(&self, entity: EntityId) -> Option<&T> {
+        let storage = self
+            .immutable_storages
+            .get(&StorageId::of::<SparseSet<T>>())?;
+
+        // SAFE the storage can only be modified with an exclusive access to the World
+        // and `storage.keep_alive` keeps it alive
+        let sparse_set = unsafe { storage.sparse_set.as_ref() };
+
+        sparse_set
+            .downcast_ref::<SparseSet<T>>()?
+            .private_get(entity)
+    }
+    /// Returns `AllStorages` with the immutable storages made mutable until the guard is dropped.
+    pub(crate) fn thawed_all_storages(&mut self) -> ThawedAllStorages<'_> {
+        let mut all_storages = ThawedAllStorages {
+            all_storages: self.all_storages.get_mut(),
+            immutable_storages: &self.immutable_storages,
+        };
+
+        all_storages.for_each_sbox(|sbox| {
+            // SAFE references returned by `peek` borrow the `World`, they can't be alive
+            unsafe { sbox.make_mutable() };
+        });
+
+        all_storages
+    }
+}
@@ -1,6 +1,70 @@
+use crate::all_storages::AllStorages;
+#[cfg(feature = "tracing")]
+use crate::borrow::Mutability;
 use crate::error;
-use crate::scheduler::{Batches, Label};
+#[cfg(feature = "std")]
+use crate::profiler::ProfileEventKind;
+use crate::scheduler::info::TypeInfo;
+use crate::scheduler::{Batches, ErrorPolicy, Label};
+use crate::type_id::TypeId;
+#[cfg(feature = "std")]
+use crate::views::{CurrentSystemGuard, RngStream};
 use crate::world::World;
+use alloc::boxed::Box;
+#[cfg(any(feature = "std", feature = "tracing"))]
+use alloc::format;
+#[cfg(feature = "tracing")]
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::sync::atomic::Ordering;
+
+/// Point in time after which a workload stops starting new systems.
+#[derive(Clone, Copy)]
+pub(crate) struct Deadline(#[cfg(feature = "std")] Option<std::time::Instant>);
+
+impl Deadline {
+    /// Lets the workload run to completion.
+    pub(crate) fn none() -> Deadline {
+        #[cfg(feature = "std")]
+        {
+            Deadline(None)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Deadline()
+        }
+    }
+    #[cfg(feature = "std")]
+    pub(crate) fn at(instant: std::time::Instant) -> Deadline {
+        Deadline(Some(instant))
+    }
+    fn is_over(self) -> bool {
+        #[cfg(feature = "std")]
+        {
+            self.0
+                .is_some_and(|deadline| std::time::Instant::now() >= deadline)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            false
+        }
+    }
+}
+
+/// Data shared by all systems of a workload run.
+#[allow(clippy::type_complexity)]
+struct RunContext<'a> {
+    systems: &'a [Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync>],
+    system_names: &'a [Box<dyn Label>],
+    #[cfg_attr(not(any(feature = "tracing", feature = "access_stats")), allow(unused))]
+    system_generators: &'a [Box<dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static>],
+    batches: &'a Batches,
+    #[cfg_attr(not(feature = "tracing"), allow(unused))]
+    workload_name: &'a dyn Label,
+    /// Number of times the workload ran before this run.
+    #[cfg_attr(not(feature = "std"), allow(unused))]
+    workload_run: u32,
+}
 
 impl World {
     #[cfg(feature = "parallel")]
@@ -9,17 +73,83 @@ impl World {
         &self,
         systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
         system_names: &[Box<dyn Label>],
+        #[cfg_attr(not(any(feature = "tracing", feature = "access_stats")), allow(unused))]
+        system_generators: &[Box<
+            dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static,
+        >],
         batches: &Batches,
-        #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
+        workload_name: &dyn Label,
+        deadline: Deadline,
     ) -> Result<(), error::RunWorkload> {
         #[cfg(feature = "tracing")]
-        let parent_span = tracing::info_span!("workload", name = ?workload_name);
+        let parent_span = tracing::info_span!(
+            "workload",
+            name = ?workload_name,
+            batches = batches.parallel.len()
+        );
         #[cfg(feature = "tracing")]
         let _parent_span = parent_span.enter();
+        #[cfg(feature = "std")]
+        let workload_start = self.profiling_start();
+        let context = RunContext {
+            systems,
+            system_names,
+            system_generators,
+            batches,
+            workload_name,
+            workload_run: batches.rng_streams.runs.fetch_add(1, Ordering::Relaxed),
+        };
 
         let run_batch = || -> Result<(), error::RunWorkload> {
-            for (batch, batches_run_if) in batches.parallel.iter().zip(&batches.parallel_run_if) {
-                let mut result = Ok(());
+            for (batch_index, (batch, batches_run_if)) in batches
+                .parallel
+                .iter()
+                .zip(&batches.parallel_run_if)
+                .enumerate()
+            {
+                if deadline.is_over() {
+                    return Err(error::RunWorkload::Timeout(
+                        batches.parallel[batch_index..]
+                            .iter()
+                            .flat_map(|(single_system, systems)| {
+                                single_system.iter().chain(systems)
+                            })
+                            .map(|&index| system_names[index].clone())
+                            .collect(),
+                    ));
+                }
+
+                #[cfg(feature = "tracing")]
+                let batch_span = tracing::info_span!(
+                    parent: parent_span.clone(),
+                    "batch",
+                    workload = ?workload_name,
+                    index = batch_index,
+                    systems = usize::from(batch.0.is_some()) + batch.1.len()
+                );
+                #[cfg(feature = "tracing")]
+                let _batch_span = batch_span.enter();
+                #[cfg(feature = "std")]
+                let batch_start = self.profiling_start();
+
+                // Returns `false` when the deadline is reached and the system doesn't run
+                let run_system = |index: usize,
+                                  position: usize|
+                 -> Result<bool, error::RunWorkload> {
+                    if deadline.is_over() {
+                        return Ok(false);
+                    }
+
+                    #[cfg(feature = "tracing")]
+                    self.run_single_system(&context, batch_index, &batch_span, position, index)?;
+                    #[cfg(not(feature = "tracing"))]
+                    self.run_single_system(&context, batch_index, position, index)?;
+
+                    Ok(true)
+                };
+
+                let mut result = Ok(Vec::new());
+                let mut skipped = Vec::new();
                 let run_if = (
                     if let Some(run_if_index) = batches_run_if.0 {
                         if let Some(run_if) = &batches.sequential_run_if[run_if_index] {
@@ -54,70 +184,118 @@ impl World {
                 );
 
                 let mut start = 0;
-                let single_system = batch.0.filter(|_| run_if.0).or_else(|| {
-                    let system = batch.1.first().copied().filter(|_| run_if.1[0]);
+                let single_system =
+                    batch
+                        .0
+                        .zip(batches_run_if.0)
+                        .filter(|_| run_if.0)
+                        .or_else(|| {
+                            let system = batch
+                                .1
+                                .first()
+                                .copied()
+                                .zip(batches_run_if.1.first().copied())
+                                .filter(|_| run_if.1[0]);
 
-                    if system.is_some() {
-                        start = 1;
-                    }
+                            if system.is_some() {
+                                start = 1;
+                            }
+
+                            system
+                        });
 
-                    system
-                });
+                // Number of threads the systems of `batch.1` can use on top of the current one
+                let parallel_threads = batches
+                    .max_threads
+                    .map(|max_threads| max_threads.get() - usize::from(single_system.is_some()));
+
+                let parallel_len = batch.1.len().saturating_sub(start);
+                let min_len = match parallel_threads {
+                    Some(threads) if threads > 0 => parallel_len.div_ceil(threads),
+                    _ => 1,
+                };
 
                 rayon::in_place_scope(|scope| {
                     // This check exists to avoid spawning a parallel job when possible.
                     // On wasm it causes a "condvar wait not supported" error.
-                    if start < batch.1.len() {
+                    if start < batch.1.len() && parallel_threads != Some(0) {
                         scope.spawn(|_| {
                             use rayon::prelude::*;
 
                             result = batch.1[start..]
                                 .par_iter()
+                                .zip(&batches_run_if.1[start..])
                                 .zip(&run_if.1[start..])
-                                .try_for_each(|(&index, should_run)| {
-                                    if !should_run {
-                                        return Ok(());
-                                    }
-
-                                    #[cfg(feature = "tracing")]
-                                    {
-                                        self.run_single_system(
-                                            systems,
-                                            system_names,
-                                            &parent_span,
-                                            index,
-                                        )
-                                    }
-                                    #[cfg(not(feature = "tracing"))]
-                                    {
-                                        self.run_single_system(systems, system_names, index)
-                                    }
-                                });
+                                .with_min_len(min_len)
+                                .filter(|(_, should_run)| **should_run)
+                                .map(|((&index, &position), _)| {
+                                    Ok((!run_system(index, position)?).then_some(index))
+                                })
+                                .collect::<Result<Vec<_>, error::RunWorkload>>();
                         });
                     }
 
-                    if let Some(index) = single_system {
-                        #[cfg(feature = "tracing")]
-                        self.run_single_system(systems, system_names, &parent_span, index)?;
-                        #[cfg(not(feature = "tracing"))]
-                        self.run_single_system(systems, system_names, index)?;
+                    if let Some((index, position)) = single_system {
+                        if !run_system(index, position)? {
+                            skipped.push(index);
+                        }
+                    }
+
+                    if parallel_threads == Some(0) {
+                        for ((&index, &position), should_run) in batch.1[start..]
+                            .iter()
+                            .zip(&batches_run_if.1[start..])
+                            .zip(&run_if.1[start..])
+                        {
+                            if *should_run && !run_system(index, position)? {
+                                skipped.push(index);
+                            }
+                        }
                     }
 
                     Ok(())
                 })?;
 
-                result?;
+                skipped.extend(result?.into_iter().flatten());
+
+                if !skipped.is_empty() {
+                    return Err(error::RunWorkload::Timeout(
+                        skipped
+                            .iter()
+                            .chain(batches.parallel[batch_index + 1..].iter().flat_map(
+                                |(single_system, systems)| single_system.iter().chain(systems),
+                            ))
+                            .map(|&index| system_names[index].clone())
+                            .collect(),
+                    ));
+                }
+
+                #[cfg(feature = "std")]
+                self.profiling_record(
+                    ProfileEventKind::Batch,
+                    || format!("{:?} batch {}", workload_name, batch_index),
+                    batch_start,
+                );
             }
 
             Ok(())
         };
 
-        if let Some(thread_pool) = &self.thread_pool {
+        let result = if let Some(thread_pool) = &self.thread_pool {
             thread_pool.scope(|_| run_batch())
         } else {
             // Use non local ThreadPool
             run_batch()
-        }
+        };
+
+        #[cfg(feature = "std")]
+        self.profiling_record(
+            ProfileEventKind::Workload,
+            || format!("{:?}", workload_name),
+            workload_start,
+        );
+
+        result
     }
 
     #[cfg(not(feature = "parallel"))]
@@ -126,19 +304,48 @@ impl World {
         &self,
         systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync + 'static>],
         system_names: &[Box<dyn Label>],
+        #[cfg_attr(not(any(feature = "tracing", feature = "access_stats")), allow(unused))]
+        system_generators: &[Box<
+            dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync + 'static,
+        >],
         batches: &Batches,
-        #[cfg_attr(not(feature = "tracing"), allow(unused))] workload_name: &dyn Label,
+        workload_name: &dyn Label,
+        deadline: Deadline,
     ) -> Result<(), error::RunWorkload> {
         #[cfg(feature = "tracing")]
-        let parent_span = tracing::info_span!("workload", name = ?workload_name);
+        let parent_span = tracing::info_span!(
+            "workload",
+            name = ?workload_name,
+            batches = batches.sequential.len()
+        );
         #[cfg(feature = "tracing")]
         let _parent_span = parent_span.enter();
+        #[cfg(feature = "std")]
+        let workload_start = self.profiling_start();
+        let context = RunContext {
+            systems,
+            system_names,
+            system_generators,
+            batches,
+            workload_name,
+            workload_run: batches.rng_streams.runs.fetch_add(1, Ordering::Relaxed),
+        };
 
-        batches
+        let result = batches
             .sequential
             .iter()
             .zip(&batches.sequential_run_if)
-            .try_for_each(|(&index, run_if)| {
+            .enumerate()
+            .try_for_each(|(batch_index, (&index, run_if))| {
+                if deadline.is_over() {
+                    return Err(error::RunWorkload::Timeout(
+                        batches.sequential[batch_index..]
+                            .iter()
+                            .map(|&index| system_names[index].clone())
+                            .collect(),
+                    ));
+                }
+
                 if let Some(run_if) = run_if.as_ref() {
                     let should_run = (run_if)(self).map_err(|err| {
                         error::RunWorkload::Run((system_names[index].clone(), err))
@@ -151,30 +358,166 @@ impl World {
 
                 #[cfg(feature = "tracing")]
                 {
-                    self.run_single_system(systems, system_names, &parent_span, index)
+                    let batch_span = tracing::info_span!(
+                        parent: parent_span.clone(),
+                        "batch",
+                        workload = ?workload_name,
+                        index = batch_index,
+                        systems = 1usize
+                    );
+                    let _batch_span = batch_span.enter();
+
+                    self.run_single_system(&context, batch_index, &batch_span, batch_index, index)
                 }
                 #[cfg(not(feature = "tracing"))]
                 {
-                    self.run_single_system(systems, system_names, index)
+                    self.run_single_system(&context, batch_index, batch_index, index)
                 }
-            })
+            });
+
+        #[cfg(feature = "std")]
+        self.profiling_record(
+            ProfileEventKind::Workload,
+            || format!("{:?}", workload_name),
+            workload_start,
+        );
+
+        result
     }
 
-    #[allow(clippy::type_complexity)]
+    /// Reads storages to bring them in the cache of the current thread before a system runs.\
+    /// Skipped if `AllStorages` can't be borrowed.
+    fn prefetch(&self, prefetch: &[fn(&AllStorages)], system_name: &dyn Label) {
+        if prefetch.is_empty() {
+            return;
+        }
+
+        #[cfg(feature = "std")]
+        let prefetch_start = self.profiling_start();
+
+        if let Ok(all_storages) = self.all_storages.borrow() {
+            for prefetch_fn in prefetch {
+                (prefetch_fn)(&all_storages);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        self.profiling_record(
+            ProfileEventKind::Prefetch,
+            || format!("{:?}", system_name),
+            prefetch_start,
+        );
+    }
+
+    /// Runs the system at `index`, `position` is its position in the sequential order.\
+    /// When the system fails, the workload's error handler can ask to run it again, as many times as it wants.
     fn run_single_system(
         &self,
-        systems: &[Box<dyn Fn(&World) -> Result<(), error::Run> + Send + Sync>],
-        system_names: &[Box<dyn Label>],
+        context: &RunContext<'_>,
+        #[cfg_attr(not(any(feature = "std", feature = "tracing")), allow(unused))]
+        batch_index: usize,
         #[cfg(feature = "tracing")] parent_span: &tracing::Span,
+        position: usize,
         index: usize,
     ) -> Result<(), error::RunWorkload> {
+        let RunContext {
+            systems,
+            system_names,
+            batches,
+            workload_run,
+            ..
+        } = *context;
+
         #[cfg(feature = "tracing")]
-        let system_span =
-            tracing::info_span!(parent: parent_span.clone(), "system", name = ?system_names[index]);
+        let system_span = tracing::info_span!(
+            parent: parent_span.clone(),
+            "system",
+            name = ?system_names[index],
+            workload = ?context.workload_name,
+            batch = batch_index,
+            borrows = tracing::field::Empty
+        );
+        #[cfg(feature = "tracing")]
+        if !system_span.is_disabled() {
+            system_span.record(
+                "borrows",
+                system_borrows(&*context.system_generators[index]).as_str(),
+            );
+        }
         #[cfg(feature = "tracing")]
         let _system_span = system_span.enter();
+        #[cfg(feature = "std")]
+        let _current_system = CurrentSystemGuard::new(
+            system_names,
+            index,
+            batches,
+            batch_index,
+            RngStream {
+                key: batches.rng_streams.keys[position],
+                run: workload_run,
+            },
+        );
+
+        self.prefetch(
+            &batches.sequential_prefetch[position],
+            &*system_names[index],
+        );
+
+        let mut retries = 0;
+        loop {
+            #[cfg(feature = "std")]
+            let system_start = self.profiling_start();
+
+            let result = (systems[index])(self);
+
+            #[cfg(feature = "std")]
+            self.profiling_record(
+                ProfileEventKind::System,
+                || format!("{:?}", system_names[index]),
+                system_start,
+            );
+
+            #[cfg(feature = "access_stats")]
+            if let Some(access_recorder) = &self.access_recorder {
+                access_recorder.record(
+                    index,
+                    &*system_names[index],
+                    &*context.system_generators[index],
+                );
+            }
+
+            let err = match result {
+                Ok(()) => return Ok(()),
+                Err(err) => err,
+            };
 
-        (systems[index])(self)
-            .map_err(|err| error::RunWorkload::Run((system_names[index].clone(), err)))
+            match batches
+                .on_error
+                .as_deref()
+                .map(|on_error| (on_error)(&*system_names[index], &err, retries))
+            {
+                Some(ErrorPolicy::Skip) => return Ok(()),
+                Some(ErrorPolicy::Retry) => retries += 1,
+                Some(ErrorPolicy::Abort) | None => {
+                    return Err(error::RunWorkload::Run((system_names[index].clone(), err)))
+                }
+            }
+        }
     }
 }
+
+/// Lists the storages borrowed by a system, for example `&Pos, &mut Vel`.
+#[cfg(feature = "tracing")]
+fn system_borrows(generator: &(dyn Fn(&mut Vec<TypeInfo>) -> TypeId + Send + Sync)) -> String {
+    let mut borrows = Vec::new();
+    generator(&mut borrows);
+
+    borrows
+        .iter()
+        .map(|info| match info.mutability {
+            Mutability::Shared => format!("&{}", info.name),
+            Mutability::Exclusive => format!("&mut {}", info.name),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "access_stats")]
+
+use shipyard::*;
+
+struct Position;
+impl Component for Position {
+    type Tracking = track::Untracked;
+}
+
+struct Velocity;
+impl Component for Velocity {
+    type Tracking = track::Untracked;
+}
+
+struct Acceleration;
+impl Component for Acceleration {
+    type Tracking = track::Untracked;
+}
+
+fn movement(_: ViewMut<Position>, _: View<Velocity>) {}
+fn render(_: View<Position>) {}
+fn accelerate(_: ViewMut<Velocity>, _: View<Acceleration>) {}
+fn integrate(_: View<Velocity>, _: View<Acceleration>) {}
+
+#[test]
+fn count_accesses() {
+    let mut world = World::new();
+    world.add_workload(|| (movement, render));
+
+    world.start_access_stats();
+    world.run_default_workload().unwrap();
+    world.run_default_workload().unwrap();
+    world.run_default_workload().unwrap();
+    let stats = world.stop_access_stats();
+
+    assert_eq!(stats.systems().len(), 2);
+    let movement_stats = stats
+        .systems()
+        .iter()
+        .find(|system| system.name.ends_with("movement"))
+        .unwrap();
+    assert_eq!(movement_stats.runs, 3);
+    assert_eq!(movement_stats.storages.len(), 2);
+
+    let storages = stats.storages();
+    let position = storages
+        .iter()
+        .find(|storage| storage.storage_id == StorageId::of::<SparseSet<Position>>())
+        .unwrap();
+    assert_eq!(position.shared_runs, 3);
+    assert_eq!(position.exclusive_runs, 3);
+
+    let velocity = storages
+        .iter()
+        .find(|storage| storage.storage_id == StorageId::of::<SparseSet<Velocity>>())
+        .unwrap();
+    assert_eq!(velocity.shared_runs, 3);
+    assert_eq!(velocity.exclusive_runs, 0);
+}
+
+#[test]
+fn accessed_together() {
+    let mut world = World::new();
+    world.add_workload(|| (movement, render, accelerate, integrate));
+
+    world.start_access_stats();
+    world.run_default_workload().unwrap();
+    world.run_default_workload().unwrap();
+
+    let accessed_together = world.access_stats().accessed_together();
+
+    assert_eq!(accessed_together.len(), 2);
+
+    // Velocity and Acceleration are accessed by accelerate and integrate
+    assert_eq!(accessed_together[0].runs, 4);
+    assert!(!accessed_together[0].always);
+    assert!(accessed_together[0]
+        .storages
+        .iter()
+        .any(|name| name.contains("Acceleration")));
+
+    assert_eq!(accessed_together[1].runs, 2);
+    assert!(!accessed_together[1].always);
+}
+
+#[test]
+fn always_accessed_together() {
+    let mut world = World::new();
+    world.add_workload(|| (accelerate, integrate));
+
+    world.start_access_stats();
+    world.run_default_workload().unwrap();
+
+    let accessed_together = world.access_stats().accessed_together();
+
+    assert_eq!(accessed_together.len(), 1);
+    assert_eq!(accessed_together[0].runs, 2);
+    assert!(accessed_together[0].always);
+}
+
+#[test]
+fn never_read() {
+    let mut world = World::new();
+    world.add_workload(|| (movement, accelerate));
+
+    world.start_access_stats();
+    world.run_default_workload().unwrap();
+
+    let never_read = world.access_stats().never_read();
+
+    assert_eq!(never_read.len(), 1);
+    assert_eq!(
+        never_read[0].storage_id,
+        StorageId::of::<SparseSet<Position>>()
+    );
+    assert_eq!(never_read[0].exclusive_runs, 1);
+}
+
+#[test]
+fn not_recording() {
+    let mut world = World::new();
+    world.add_workload(|| movement);
+
+    world.run_default_workload().unwrap();
+    assert!(world.access_stats().systems().is_empty());
+
+    world.start_access_stats();
+    world.run_default_workload().unwrap();
+    world.run(movement);
+    assert_eq!(world.access_stats().systems()[0].runs, 1);
+
+    // restarting discards previous accesses
+    world.start_access_stats();
+    assert!(world.access_stats().systems().is_empty());
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.stop_access_stats().systems()[0].runs, 1);
+    assert!(world.access_stats().systems().is_empty());
+}
+
+#[test]
+fn scheduled_workload() {
+    let mut world = World::new();
+    world.add_workload(|| movement);
+
+    let (scheduled, _) = Workload::new("").with_system(render).build().unwrap();
+
+    world.start_access_stats();
+    world.run_default_workload().unwrap();
+    scheduled.run_with_world(&world).unwrap();
+    world.run_default_workload().unwrap();
+
+    // both systems are at index 0 of their scheduler
+    let stats = world.stop_access_stats();
+    assert_eq!(stats.systems().len(), 2);
+    assert!(stats.systems()[0].name.ends_with("movement"));
+    assert_eq!(stats.systems()[0].runs, 2);
+    assert!(stats.systems()[1].name.ends_with("render"));
+    assert_eq!(stats.systems()[1].runs, 1);
+}
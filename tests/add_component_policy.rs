@@ -0,0 +1,68 @@
+use shipyard::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn try_add_component() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    world.delete_entity(entity);
+
+    assert_eq!(
+        world.try_add_component(entity, (U32(0),)),
+        Err(error::AddComponent::EntityIsNotAlive)
+    );
+
+    world.run(|entities: EntitiesView, mut u32s: ViewMut<U32>| {
+        assert_eq!(
+            entities.try_add_component(entity, &mut u32s, U32(0)),
+            Err(error::AddComponent::EntityIsNotAlive)
+        );
+        assert!(u32s.is_empty());
+    });
+}
+
+#[test]
+#[should_panic(expected = "Entity has to be alive to add component to it.")]
+fn add_component_dead_entity() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    world.delete_entity(entity);
+
+    world.add_component(entity, (U32(0),));
+}
+
+#[test]
+fn ignore_policy() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    world.delete_entity(entity);
+
+    world.set_add_component_policy(AddComponentPolicy::Ignore);
+    assert_eq!(world.add_component_policy(), AddComponentPolicy::Ignore);
+
+    world.add_component(entity, (U32(0),));
+
+    world.run(|all_storages: AllStoragesView| {
+        assert_eq!(
+            all_storages.add_component_policy(),
+            AddComponentPolicy::Ignore
+        );
+    });
+
+    world.run(|entities: EntitiesView, mut u32s: ViewMut<U32>| {
+        entities.add_component(entity, &mut u32s, U32(1));
+        assert!(!entities.add_distinct_component(entity, &mut u32s, U32(2)));
+        assert!(u32s.is_empty());
+    });
+
+    // the policy doesn't affect `try_add_component`
+    assert!(world.try_add_component(entity, (U32(0),)).is_err());
+}
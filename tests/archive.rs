@@ -0,0 +1,95 @@
+use shipyard::*;
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct Pos(u32, u32);
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct Health(u32);
+
+#[derive(Component, Debug, PartialEq, Eq)]
+#[track(Removal)]
+struct Tag;
+
+#[test]
+fn archive_unarchive() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((Pos(1, 2), Health(10), Tag));
+    let other = world.add_entity((Pos(3, 4),));
+
+    let mut archived = world.archive(entity).unwrap();
+
+    assert!(!world.is_entity_alive(entity));
+    assert!(world.get::<&Pos>(entity).is_err());
+    assert_eq!(world.get::<&Pos>(other).as_deref(), Ok(&&Pos(3, 4)));
+    assert_eq!(archived.len(), 3);
+    assert!(archived.contains::<Tag>());
+    assert_eq!(archived.get::<Pos>(), Some(&Pos(1, 2)));
+
+    world.run(|tags: View<Tag>| {
+        assert_eq!(tags.removed().collect::<Vec<_>>(), vec![entity]);
+    });
+
+    archived.get_mut::<Health>().unwrap().0 = 5;
+    assert_eq!(archived.remove::<Tag>(), Some(Tag));
+    assert!(!archived.insert(Pos(5, 6)));
+
+    let restored = world.unarchive(archived);
+
+    assert_ne!(restored, entity);
+    assert_eq!(world.get::<&Pos>(restored).as_deref(), Ok(&&Pos(5, 6)));
+    assert_eq!(world.get::<&Health>(restored).as_deref(), Ok(&&Health(5)));
+    assert!(world.get::<&Tag>(restored).is_err());
+}
+
+#[test]
+fn archive_dead_entity() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((Health(10),));
+    world.delete_entity(entity);
+
+    assert_eq!(
+        world.archive(entity).err(),
+        Some(error::Archive::EntityIsNotAlive)
+    );
+}
+
+#[test]
+fn archive_disabled() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((Health(10),));
+    world.disable_entity(entity);
+
+    let archived = world.archive(entity).unwrap();
+    assert_eq!(archived.get::<Health>(), Some(&Health(10)));
+
+    let restored = world.unarchive(archived);
+    assert_eq!(world.get::<&Health>(restored).as_deref(), Ok(&&Health(10)));
+}
+
+#[test]
+fn archive_unarchivable() {
+    #[derive(Clone, Hash, PartialEq, Eq, Debug)]
+    struct Terrain;
+
+    let mut world = World::new();
+
+    let entity = world.add_entity((Health(10),));
+    world.run(|mut terrains: SharedComponentViewMut<Terrain>| {
+        terrains.insert(entity, Terrain);
+    });
+
+    assert!(matches!(
+        world.archive(entity),
+        Err(error::Archive::Unarchivable(storages)) if storages.len() == 1
+    ));
+
+    // nothing was deleted
+    assert!(world.is_entity_alive(entity));
+    assert_eq!(world.get::<&Health>(entity).as_deref(), Ok(&&Health(10)));
+    world.run(|terrains: SharedComponentView<Terrain>| {
+        assert_eq!(terrains.get(entity), Some(&Terrain));
+    });
+}
@@ -0,0 +1,55 @@
+use shipyard::*;
+
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(C)]
+#[track(Modification)]
+struct Vertex {
+    x: f32,
+    y: f32,
+}
+
+fn upload(buffer: &mut Vec<Vertex>, vertices: impl AsRef<[Vertex]>) {
+    buffer.extend_from_slice(vertices.as_ref());
+}
+
+#[test]
+fn as_ref() {
+    let mut world = World::new();
+
+    world.add_entity(Vertex { x: 0.0, y: 1.0 });
+    world.add_entity(Vertex { x: 2.0, y: 3.0 });
+
+    let mut buffer = Vec::new();
+    world.run(|vertices: View<Vertex>| upload(&mut buffer, vertices));
+    world.run(|vertices: ViewMut<Vertex>| upload(&mut buffer, vertices));
+
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(buffer[..2], buffer[2..]);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn as_bytes() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(Vertex { x: 0.0, y: 1.0 });
+    let entity1 = world.add_entity(Vertex { x: 2.0, y: 3.0 });
+
+    world.run(|vertices: View<Vertex>| {
+        assert_eq!(
+            vertices.as_bytes(),
+            bytemuck::cast_slice::<f32, u8>(&[0.0, 1.0, 2.0, 3.0])
+        );
+    });
+
+    world.run(|mut vertices: ViewMut<Vertex>| {
+        vertices
+            .as_bytes_mut()
+            .copy_from_slice(bytemuck::cast_slice::<f32, u8>(&[4.0, 5.0, 6.0, 7.0]));
+
+        assert!(vertices.is_modified(entity0));
+        assert!(vertices.is_modified(entity1));
+        assert_eq!(vertices[entity1], Vertex { x: 6.0, y: 7.0 });
+    });
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "parallel")]
+
+use shipyard::*;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[derive(Component, Default)]
+struct Damage(AtomicU32);
+
+#[derive(Component, Debug, PartialEq, Eq)]
+#[track(Modification)]
+struct Health(u32);
+
+#[derive(Unique, Default)]
+struct Stats {
+    hits: AtomicU32,
+}
+
+impl AtomicUnique for Stats {}
+
+fn fire(damage: AtomicViewMut<Damage>) {
+    damage.par_update(|_, damage| {
+        damage.0.fetch_add(2, Ordering::Relaxed);
+    });
+}
+
+fn poison(damage: AtomicViewMut<Damage>) {
+    for damage in damage.iter() {
+        damage.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn same_batch() {
+    let mut world = World::new();
+
+    let entities: Vec<_> = (0..100)
+        .map(|_| world.add_entity((Damage::default(),)))
+        .collect();
+
+    Workload::new("Damage")
+        .with_system(fire)
+        .with_system(poison)
+        .add_to_world(&world)
+        .unwrap();
+
+    let workloads_info = world.workloads_info();
+    let workload_info = workloads_info.0.values().next().unwrap();
+    assert_eq!(workload_info.batch_info.len(), 1);
+
+    world.run_workload("Damage").unwrap();
+
+    world.run(|damage: AtomicViewMut<Damage>| {
+        for &entity in &entities {
+            assert_eq!(
+                damage.update(entity, |damage| damage.0.load(Ordering::Relaxed)),
+                Ok(3)
+            );
+        }
+
+        assert!(damage.update(EntityId::dead(), |_| {}).is_err());
+    });
+}
+
+#[test]
+fn par_update() {
+    let mut world = World::new();
+
+    let entities: Vec<_> = (0..100).map(|i| world.add_entity((Health(i),))).collect();
+
+    world.run(|healths: ViewMut<Health>| {
+        healths.clear_all_modified();
+    });
+
+    world.run(|mut healths: ViewMut<Health>| {
+        healths.par_update(|_, mut health| health.0 += 1);
+
+        for (i, &entity) in entities.iter().enumerate() {
+            assert_eq!(healths[entity], Health(i as u32 + 1));
+            assert!(healths.is_modified(entity));
+        }
+    });
+
+    world.run(|healths: ViewMut<Health>| {
+        healths.clear_all_modified();
+    });
+
+    world.run(|mut healths: ViewMut<Health>| {
+        healths.par_update(|_, mut health| {
+            if health.0 % 2 == 0 {
+                health.0 += 1;
+            }
+        });
+
+        for (i, &entity) in entities.iter().enumerate() {
+            assert_eq!(healths.is_modified(entity), i % 2 == 1);
+        }
+    });
+}
+
+#[test]
+fn unique_same_batch() {
+    fn hit(stats: UniqueAtomicView<Stats>) {
+        stats.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn double_hit(stats: UniqueAtomicView<Stats>) {
+        stats.hits.fetch_add(2, Ordering::Relaxed);
+    }
+
+    let world = World::new();
+    world.add_unique(Stats::default());
+
+    world.run(|stats: UniqueViewMut<Stats>| stats.clear_modified());
+
+    Workload::new("Hits")
+        .with_system(hit)
+        .with_system(double_hit)
+        .add_to_world(&world)
+        .unwrap();
+
+    let workloads_info = world.workloads_info();
+    let workload_info = workloads_info.0.values().next().unwrap();
+    assert_eq!(workload_info.batch_info.len(), 1);
+
+    world.run_workload("Hits").unwrap();
+
+    world.run(|stats: UniqueView<Stats>| {
+        assert_eq!(stats.hits.load(Ordering::Relaxed), 3);
+        assert!(!stats.is_modified());
+    });
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "bench")]
+
+use shipyard::bench::{self, Population};
+use shipyard::*;
+
+struct Pos(u32);
+impl Component for Pos {
+    type Tracking = track::Untracked;
+}
+
+struct Vel;
+impl Component for Vel {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn population() {
+    let mut world = World::new();
+
+    let entities = Population::new()
+        .with_archetype(2, |i| (Pos(i as u32), Vel))
+        .with_archetype(0, |_| (Vel,))
+        .with_archetype(1, |i| (Pos(i as u32),))
+        .populate(&mut world, 7);
+
+    assert_eq!(entities.len(), 7);
+
+    world.run(|positions: View<Pos>, velocities: View<Vel>| {
+        assert_eq!(positions.len(), 7);
+        assert_eq!(velocities.len(), 5);
+
+        for (index, entity) in entities.iter().enumerate() {
+            assert_eq!(positions[*entity].0, index as u32);
+            assert_eq!(velocities.contains(*entity), index % 3 != 2);
+        }
+    });
+}
+
+#[test]
+#[should_panic(expected = "Cannot populate a World without any weighted archetype.")]
+fn population_without_weight() {
+    Population::new().with_archetype(0, |_| (Vel,)).build(1);
+}
+
+fn movement(mut positions: ViewMut<Pos>) {
+    for pos in (&mut positions).iter() {
+        pos.0 += 1;
+    }
+}
+
+#[test]
+fn run_workload() {
+    let world = Population::new()
+        .with_archetype(1, |i| (Pos(i as u32),))
+        .build(10);
+
+    assert!(matches!(
+        bench::run_workload(&world, "Update", 5),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+
+    Workload::new("Update")
+        .with_system(movement)
+        .add_to_world(&world)
+        .unwrap();
+
+    let stats = bench::run_workload(&world, "Update", 5).unwrap();
+
+    assert_eq!(stats.iterations(), 5);
+    assert_eq!(stats.samples().len(), 5);
+    assert!(stats.min() <= stats.median() && stats.median() <= stats.max());
+    assert_eq!(stats.total(), stats.samples().iter().sum());
+
+    world.run(|positions: View<Pos>| {
+        for (index, pos) in positions.iter().enumerate() {
+            assert_eq!(pos.0, index as u32 + 5);
+        }
+    });
+}
+
+#[test]
+fn measure_stops_on_error() {
+    let mut count = 0;
+
+    let result = bench::measure(10, || {
+        count += 1;
+
+        if count == 3 {
+            Err("error")
+        } else {
+            Ok(())
+        }
+    });
+
+    assert_eq!(result, Err("error"));
+    assert_eq!(count, 3);
+}
@@ -1,6 +1,7 @@
 use super::Pos;
 use shipyard::*;
 use std::cmp::PartialOrd;
+use std::collections::HashSet;
 
 // ANCHOR: parent
 #[derive(Component)]
@@ -425,3 +426,211 @@ fn test_sorting() {
         .eq([e3, e4, e1, e2, e0].iter().cloned()));
 }
 // ANCHOR_END: test_sorting
+
+// ANCHOR: transform_components
+#[derive(Component, Clone, Copy)]
+struct Position(f32, f32);
+
+// In radians
+#[derive(Component, Clone, Copy)]
+struct Rotation(f32);
+
+#[derive(Component, Clone, Copy)]
+struct Scale(f32);
+
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+struct GlobalTransform {
+    position: (f32, f32),
+    rotation: f32,
+    scale: f32,
+}
+
+impl GlobalTransform {
+    const IDENTITY: GlobalTransform = GlobalTransform {
+        position: (0.0, 0.0),
+        rotation: 0.0,
+        scale: 1.0,
+    };
+
+    // Applies a local transform on top of this one.
+    fn then(&self, position: Position, rotation: Rotation, scale: Scale) -> GlobalTransform {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (x, y) = (position.0 * self.scale, position.1 * self.scale);
+
+        GlobalTransform {
+            position: (
+                self.position.0 + x * cos - y * sin,
+                self.position.1 + x * sin + y * cos,
+            ),
+            rotation: self.rotation + rotation.0,
+            scale: self.scale * scale.0,
+        }
+    }
+}
+// ANCHOR_END: transform_components
+
+// ANCHOR: propagate_transforms
+fn propagate_transforms(
+    parents: View<Parent>,
+    children: View<Child, track::All>,
+    positions: View<Position, track::InsertionAndModification>,
+    rotations: View<Rotation, track::InsertionAndModification>,
+    scales: View<Scale, track::InsertionAndModification>,
+    mut globals: ViewMut<GlobalTransform>,
+) {
+    // entities whose local transform or parent changed since the last run
+    let mut dirty = positions
+        .inserted_or_modified()
+        .iter()
+        .ids()
+        .chain(rotations.inserted_or_modified().iter().ids())
+        .chain(scales.inserted_or_modified().iter().ids())
+        .chain(children.inserted_or_modified().iter().ids())
+        .chain(children.removed_or_deleted())
+        .collect::<Vec<_>>();
+    // parents first, updating a subtree then also updates its dirty descendants
+    dirty.sort_unstable_by_key(|&id| ((&parents, &children).ancestors(id).count(), id));
+    dirty.dedup();
+
+    let local = |id| {
+        (
+            positions.get(id).copied().unwrap_or(Position(0.0, 0.0)),
+            rotations.get(id).copied().unwrap_or(Rotation(0.0)),
+            scales.get(id).copied().unwrap_or(Scale(1.0)),
+        )
+    };
+
+    let mut updated = HashSet::new();
+    for id in dirty {
+        // this subtree was already updated with its dirty ancestor's
+        if updated.contains(&id) {
+            continue;
+        }
+
+        for id in std::iter::once(id).chain((&parents, &children).descendants(id)) {
+            updated.insert(id);
+
+            let parent_global = children
+                .get(id)
+                .ok()
+                .and_then(|child| globals.get(child.parent).ok().copied())
+                .unwrap_or(GlobalTransform::IDENTITY);
+            let (position, rotation, scale) = local(id);
+
+            // deleted entities and entities without GlobalTransform
+            if let Ok(mut global) = (&mut globals).get(id) {
+                *global = parent_global.then(position, rotation, scale);
+            }
+        }
+    }
+}
+// ANCHOR_END: propagate_transforms
+
+// ANCHOR: test_transforms
+#[test]
+fn test_transforms() {
+    let mut world = World::new();
+    world.add_workload(|| propagate_transforms);
+
+    let transform = (Position(0.0, 0.0), GlobalTransform::IDENTITY);
+    let root = world.add_entity(transform);
+    let other_root = world.add_entity(transform);
+
+    let (child, grandchild, other_child) = world.run(
+        |mut hierarchy: (EntitiesViewMut, ViewMut<Parent>, ViewMut<Child>),
+         mut positions: ViewMut<Position>,
+         mut globals: ViewMut<GlobalTransform>| {
+            let child = hierarchy.attach_new(root);
+            let grandchild = hierarchy.attach_new(child);
+            let other_child = hierarchy.attach_new(other_root);
+
+            for id in [child, grandchild, other_child] {
+                hierarchy.0.add_component(
+                    id,
+                    (&mut positions, &mut globals),
+                    (Position(1.0, 0.0), GlobalTransform::IDENTITY),
+                );
+            }
+
+            (child, grandchild, other_child)
+        },
+    );
+
+    world.run_default_workload().unwrap();
+
+    assert_eq!(
+        world.get::<&GlobalTransform>(grandchild).unwrap().position,
+        (2.0, 0.0)
+    );
+
+    // only the dirty subtree is updated, other_child keeps its outdated value
+    world
+        .get::<&mut GlobalTransform>(other_child)
+        .unwrap()
+        .position = (-1.0, -1.0);
+    world.get::<&mut Position>(root).unwrap().1 = 5.0;
+
+    world.run_default_workload().unwrap();
+
+    assert_eq!(
+        world.get::<&GlobalTransform>(child).unwrap().position,
+        (1.0, 5.0)
+    );
+    assert_eq!(
+        world.get::<&GlobalTransform>(grandchild).unwrap().position,
+        (2.0, 5.0)
+    );
+    assert_eq!(
+        world.get::<&GlobalTransform>(other_child).unwrap().position,
+        (-1.0, -1.0)
+    );
+}
+// ANCHOR_END: test_transforms
+
+#[test]
+fn test_transforms_partial_hierarchy() {
+    let mut world = World::new();
+    world.add_workload(|| propagate_transforms);
+
+    let root = world.add_entity(Position(0.0, 0.0));
+
+    let (child, grandchild) = world.run(
+        |mut hierarchy: (EntitiesViewMut, ViewMut<Parent>, ViewMut<Child>),
+         mut positions: ViewMut<Position>,
+         mut globals: ViewMut<GlobalTransform>| {
+            let child = hierarchy.attach_new(root);
+            let grandchild = hierarchy.attach_new(child);
+
+            // child doesn't have a GlobalTransform
+            hierarchy
+                .0
+                .add_component(child, &mut positions, Position(1.0, 0.0));
+            hierarchy.0.add_component(
+                grandchild,
+                (&mut positions, &mut globals),
+                (Position(1.0, 0.0), GlobalTransform::IDENTITY),
+            );
+
+            (child, grandchild)
+        },
+    );
+
+    world.run_default_workload().unwrap();
+
+    assert!(world.get::<&GlobalTransform>(child).is_err());
+    assert_eq!(
+        world.get::<&GlobalTransform>(grandchild).unwrap().position,
+        (1.0, 0.0)
+    );
+
+    // root isn't updated but its descendants are
+    world.get::<&mut Position>(grandchild).unwrap().1 = 2.0;
+    world.get::<&mut Position>(root).unwrap().1 = 5.0;
+
+    world.run_default_workload().unwrap();
+
+    assert_eq!(
+        world.get::<&GlobalTransform>(grandchild).unwrap().position,
+        (1.0, 2.0)
+    );
+}
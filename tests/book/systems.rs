@@ -94,3 +94,59 @@ world.add_workload(main_loop);
 world.run_workload(main_loop).unwrap();
 // ANCHOR_END: nested_workload
 }
+
+#[test]
+#[allow(unused, clippy::type_complexity)]
+#[rustfmt::skip]
+fn many_views() {
+#[derive(Component)]
+struct Acceleration;
+#[derive(Component)]
+struct Mass;
+#[derive(Component)]
+struct Friction;
+#[derive(Component)]
+struct Name;
+#[derive(Component)]
+struct Team;
+#[derive(Component)]
+struct Target;
+#[derive(Component)]
+struct Weapon;
+#[derive(Component)]
+struct Armor;
+#[derive(Component)]
+struct Shield;
+#[derive(Component)]
+struct Sprite;
+#[derive(Component)]
+struct Sound;
+
+// ANCHOR: many_views
+fn simulate(
+    (positions, mut velocities, accelerations, masses, frictions): (
+        View<Position>,
+        ViewMut<Vel>,
+        View<Acceleration>,
+        View<Mass>,
+        View<Friction>,
+    ),
+    (healths, names, teams, targets, weapons): (
+        View<Health>,
+        View<Name>,
+        View<Team>,
+        View<Target>,
+        View<Weapon>,
+    ),
+    (armors, shields, sprites, sounds): (View<Armor>, View<Shield>, View<Sprite>, View<Sound>),
+) {
+    // -- snip --
+}
+
+let world = World::new();
+
+world.add_workload(|| simulate.into_workload());
+
+world.run_default_workload().unwrap();
+// ANCHOR_END: many_views
+}
@@ -161,15 +161,24 @@ fn all_storages_option_borrow() {
 #[cfg(feature = "thread_local")]
 fn non_send_storage_in_other_thread() {
     let world = World::new();
+    let main_thread_id = world.main_thread_id();
     rayon::join(
         || {
-            assert_eq!(
-                world.borrow::<NonSend<ViewMut<NotSend>>>().err(),
-                Some(error::GetStorage::StorageBorrow {
-                    name: Some(type_name::<NonSend<SparseSet<NotSend>>>()),
-                    id: StorageId::of::<NonSend<SparseSet<NotSend>>>(),
-                    borrow: error::Borrow::WrongThread
-                })
+            let err = world.borrow::<NonSend<ViewMut<NotSend>>>().err();
+            assert!(
+                matches!(
+                    err,
+                    Some(error::GetStorage::StorageBorrow {
+                        name: Some(name),
+                        id,
+                        borrow: error::Borrow::WrongThread { expected, actual }
+                    }) if name == type_name::<NonSend<SparseSet<NotSend>>>()
+                        && id == StorageId::of::<NonSend<SparseSet<NotSend>>>()
+                        && expected == main_thread_id
+                        && actual != main_thread_id
+                ),
+                "{:?}",
+                err
             )
         },
         || {},
@@ -180,21 +189,86 @@ fn non_send_storage_in_other_thread() {
 #[cfg(feature = "thread_local")]
 fn non_send_sync_storage_in_other_thread() {
     let world = World::new();
+    let main_thread_id = world.main_thread_id();
     rayon::join(
         || {
-            assert_eq!(
-                world.borrow::<NonSendSync<View<NotSendSync>>>().err(),
-                Some(error::GetStorage::StorageBorrow {
-                    name: Some(type_name::<NonSendSync<SparseSet<NotSendSync>>>()),
-                    id: StorageId::of::<NonSendSync<SparseSet<NotSendSync>>>(),
-                    borrow: error::Borrow::WrongThread
-                })
+            let err = world.borrow::<NonSendSync<View<NotSendSync>>>().err();
+            assert!(
+                matches!(
+                    err,
+                    Some(error::GetStorage::StorageBorrow {
+                        name: Some(name),
+                        id,
+                        borrow: error::Borrow::WrongThread { expected, actual }
+                    }) if name == type_name::<NonSendSync<SparseSet<NotSendSync>>>()
+                        && id == StorageId::of::<NonSendSync<SparseSet<NotSendSync>>>()
+                        && expected == main_thread_id
+                        && actual != main_thread_id
+                ),
+                "{:?}",
+                err
             )
         },
         || {},
     );
 }
 
+#[test]
+#[cfg(feature = "thread_local")]
+fn rebind_main_thread() {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    let thread = Arc::new(AtomicU64::new(0));
+    let thread_id = thread.clone();
+    let mut world = World::builder()
+        .with_custom_thread_id(move || thread_id.load(Ordering::Relaxed))
+        .build();
+
+    world.add_unique(U32(0));
+    world.add_unique_non_send(NotSend(core::ptr::null()));
+    world.borrow::<NonSend<ViewMut<NotSend>>>().unwrap();
+
+    let mut non_send_storages = world.non_send_storages();
+    non_send_storages.sort_unstable();
+    let mut expected = vec![
+        (StorageId::of::<UniqueStorage<NotSend>>(), 0),
+        (StorageId::of::<NonSend<SparseSet<NotSend>>>(), 0),
+    ];
+    expected.sort_unstable();
+    assert_eq!(non_send_storages, expected);
+
+    thread.store(1, Ordering::Relaxed);
+
+    let err = world
+        .all_storages()
+        .unwrap()
+        .borrow::<NonSend<ViewMut<NotSend>>>()
+        .err();
+    assert_eq!(
+        err,
+        Some(error::GetStorage::StorageBorrow {
+            name: Some(type_name::<NonSend<SparseSet<NotSend>>>()),
+            id: StorageId::of::<NonSend<SparseSet<NotSend>>>(),
+            borrow: error::Borrow::WrongThread {
+                expected: 0,
+                actual: 1
+            }
+        })
+    );
+
+    world.rebind_main_thread();
+
+    assert_eq!(world.main_thread_id(), 1);
+    assert!(world
+        .non_send_storages()
+        .iter()
+        .all(|&(_, thread_id)| thread_id == 1));
+    world.borrow::<NonSend<UniqueViewMut<NotSend>>>().unwrap();
+    world.borrow::<NonSend<ViewMut<NotSend>>>().unwrap();
+    world.borrow::<UniqueView<U32>>().unwrap();
+}
+
 #[test]
 fn add_unique_while_borrowing() {
     let world = World::new();
@@ -267,3 +341,67 @@ fn unique_exhaustive_list() {
         )>();
     });
 }
+
+type NumberViews<'v> = (ViewMut<'v, U32>, View<'v, I32>);
+
+#[derive(Borrow, BorrowInfo)]
+struct NumberBundle<'v> {
+    numbers: NumberViews<'v>,
+    entities: EntitiesView<'v>,
+}
+
+#[test]
+fn borrow_set() {
+    fn add_i32((mut u32s, i32s): NumberViews, usizes: View<USIZE>) {
+        assert_eq!(usizes.len(), 0);
+
+        for (u, i) in (&mut u32s, &i32s).iter() {
+            u.0 += i.0 as u32;
+        }
+    }
+
+    fn count(bundle: NumberBundle) {
+        assert_eq!(bundle.numbers.0.len(), 1);
+        assert_eq!(bundle.entities.iter().count(), 1);
+    }
+
+    fn read_i32(_: View<I32>) {}
+
+    let mut world = World::new();
+    let entity = world.add_entity((U32(1), I32(2)));
+
+    Workload::new("Numbers")
+        .with_system(add_i32)
+        .with_system(count)
+        .with_system(read_i32)
+        .add_to_world(&world)
+        .unwrap();
+
+    let workloads_info = world.workloads_info();
+    let batch_info = &workloads_info.0["Numbers"].batch_info;
+    assert_eq!(batch_info.len(), 2);
+
+    let add_i32_info = batch_info[0].systems().next().unwrap();
+    assert_eq!(
+        add_i32_info
+            .borrow
+            .iter()
+            .map(|type_info| (type_info.storage_id, type_info.mutability))
+            .collect::<Vec<_>>(),
+        vec![
+            (StorageId::of::<SparseSet<U32>>(), Mutability::Exclusive),
+            (StorageId::of::<SparseSet<I32>>(), Mutability::Shared),
+            (StorageId::of::<SparseSet<USIZE>>(), Mutability::Shared),
+        ]
+    );
+
+    world.run_workload("Numbers").unwrap();
+    world.run(|(u32s, _): NumberViews| assert_eq!(u32s[entity], U32(3)));
+
+    fn conflict(_: NumberViews, _: View<U32>) {}
+
+    assert_eq!(
+        conflict.into_workload_system().err(),
+        Some(error::InvalidSystem::MultipleViews)
+    );
+}
@@ -0,0 +1,155 @@
+use shipyard::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::All;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct USIZE(usize);
+impl Component for USIZE {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Team(u32);
+
+#[test]
+fn keep_components() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((U32(0), USIZE(0)));
+    let entity1 = world.add_entity(U32(1));
+
+    world.run(|u32s: ViewMut<U32>| u32s.clear_all_inserted());
+
+    let new_entity0 = world.bump_generation(entity0).unwrap();
+
+    assert_eq!(new_entity0.index(), entity0.index());
+    assert_eq!(new_entity0.gen(), entity0.gen() + 1);
+    assert!(!world.is_entity_alive(entity0));
+    assert!(world.is_entity_alive(new_entity0));
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert!(u32s.get(entity0).is_err());
+        assert!(usizes.get(entity0).is_err());
+        assert_eq!(u32s.get(new_entity0), Ok(&U32(0)));
+        assert_eq!(usizes.get(new_entity0), Ok(&USIZE(0)));
+        assert_eq!(u32s.get(entity1), Ok(&U32(1)));
+        assert_eq!(
+            (&u32s, &usizes).iter().with_id().collect::<Vec<_>>(),
+            vec![(new_entity0, (&U32(0), &USIZE(0)))]
+        );
+
+        // bumping the generation is not a modification
+        assert_eq!(u32s.inserted_or_modified().iter().count(), 0);
+    });
+
+    assert!(world.validate_integrity().unwrap().is_empty());
+
+    assert!(world.delete_entity(new_entity0));
+    world.run(|u32s: View<U32>| {
+        assert_eq!(u32s.len(), 1);
+    });
+}
+
+#[test]
+fn disabled_and_shared_components() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+    world.run(|mut teams: SharedComponentViewMut<Team>| {
+        teams.insert(entity, Team(1));
+    });
+    world.disable_entity(entity);
+
+    let new_entity = world.bump_generation(entity).unwrap();
+
+    world.run(|u32s: View<U32>, teams: SharedComponentView<Team>| {
+        assert_eq!(u32s.get_disabled(entity), None);
+        assert_eq!(u32s.get_disabled(new_entity), Some(&U32(0)));
+        assert_eq!(teams.get(entity), None);
+        assert_eq!(teams.get(new_entity), Some(&Team(1)));
+    });
+
+    assert!(world.enable_entity(new_entity));
+    assert_eq!(world.get::<&U32>(new_entity).unwrap().0, 0);
+}
+
+#[test]
+fn dead_entity() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+    let new_entity = world.bump_generation(entity).unwrap();
+
+    assert_eq!(
+        world.bump_generation(entity),
+        Err(error::BumpGeneration::EntityIsNotAlive)
+    );
+
+    world.delete_entity(new_entity);
+    assert_eq!(
+        world.bump_generation(new_entity),
+        Err(error::BumpGeneration::EntityIsNotAlive)
+    );
+    assert_eq!(
+        world.bump_generation(EntityId::dead()),
+        Err(error::BumpGeneration::EntityIsNotAlive)
+    );
+}
+
+#[test]
+fn immutable_storage() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((U32(0), USIZE(0)));
+    world.make_immutable::<U32>().unwrap();
+
+    // `World` can modify immutable storages
+    let new_entity = world.bump_generation(entity).unwrap();
+    assert_eq!(world.peek::<U32>(new_entity), Some(&U32(0)));
+
+    // `AllStorages` can't, nothing is modified
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        assert_eq!(
+            all_storages.bump_generation(new_entity),
+            Err(error::BumpGeneration::ImmutableStorage(StorageId::of::<
+                SparseSet<U32>,
+            >()))
+        );
+    });
+
+    assert!(world.is_entity_alive(new_entity));
+    assert_eq!(world.peek::<U32>(new_entity), Some(&U32(0)));
+    assert_eq!(world.get::<&USIZE>(new_entity).as_deref(), Ok(&&USIZE(0)));
+    assert!(world.validate_integrity().unwrap().is_empty());
+}
+
+#[test]
+fn shared_storage() {
+    let mut assets = World::new();
+    let asset = assets.add_entity(USIZE(0));
+
+    let mut room = World::builder().share_storage::<USIZE>(&assets).build();
+
+    let entity = room.add_entity(U32(0));
+    assert_eq!(entity, asset);
+
+    // the shared storage is skipped, `asset` is still alive in `assets`
+    let new_entity = room.bump_generation(entity).unwrap();
+
+    assert_eq!(room.get::<&U32>(new_entity).as_deref(), Ok(&&U32(0)));
+    assets.run(|usizes: View<USIZE>| {
+        assert_eq!(usizes.get(asset), Ok(&USIZE(0)));
+        assert!(usizes.get(new_entity).is_err());
+    });
+    assert!(assets.is_entity_alive(asset));
+    assert!(assets.validate_integrity().unwrap().is_empty());
+
+    // the shared storage being borrowed doesn't matter
+    let usizes = assets.borrow::<View<USIZE>>().unwrap();
+    assert!(room.bump_generation(new_entity).is_ok());
+    drop(usizes);
+}
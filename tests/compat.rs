@@ -0,0 +1,66 @@
+#![cfg(feature = "compat")]
+#![allow(deprecated)]
+
+use shipyard::*;
+
+struct U32;
+struct Counter(u32);
+
+compat_component!(U32);
+compat_unique!(Counter);
+
+fn increment(mut counter: UniqueViewMut<Counter>) {
+    counter.0 += 1;
+}
+
+#[test]
+fn world_shims() {
+    let mut world = World::new();
+
+    world.add_entity(U32);
+    world.add_unique(Counter(0));
+
+    assert_eq!(world.try_borrow::<View<U32>>().unwrap().len(), 1);
+    assert_eq!(world.try_run(|u32s: View<U32>| u32s.len()).unwrap(), 1);
+    assert_eq!(
+        world
+            .try_run_with_data(|data: u32, u32s: View<U32>| data + u32s.len() as u32, 1)
+            .unwrap(),
+        2
+    );
+
+    WorkloadBuilder::builder("Increment")
+        .with_system(increment)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.try_run_workload("Increment").unwrap();
+    world.run_default().unwrap();
+    world.try_run_default().unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<Counter>>().unwrap().0, 3);
+}
+
+#[test]
+fn try_run_borrow_error() {
+    let world = World::new();
+
+    world.run(|_: ViewMut<U32>| {
+        assert!(world.try_run(|_: View<U32>| {}).is_err());
+    });
+}
+
+#[test]
+fn all_storages_shims() {
+    let world = World::new();
+
+    world.run(|all_storages: AllStoragesViewMut| {
+        assert_eq!(
+            all_storages.try_run(|u32s: View<U32>| u32s.len()).unwrap(),
+            0
+        );
+        assert!(all_storages
+            .try_run_with_data(|_: (), _: ViewMut<U32>| {}, ())
+            .is_ok());
+    });
+}
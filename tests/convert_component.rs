@@ -0,0 +1,63 @@
+use shipyard::*;
+
+#[derive(Component, Debug, PartialEq, Eq)]
+#[track(Removal)]
+struct Old(u32);
+
+#[derive(Component, Debug, PartialEq, Eq)]
+#[track(Insertion)]
+struct New(u64);
+
+#[test]
+fn convert() {
+    let mut world = World::new();
+
+    let first = world.add_entity(Old(1));
+    let second = world.add_entity((Old(2), New(0)));
+    let third = world.add_entity(New(3));
+
+    world.run(|news: ViewMut<New>| news.clear_all_inserted());
+
+    assert_eq!(
+        world.convert_component(|old: Old| New(old.0 as u64 * 10)),
+        2
+    );
+
+    world.run(|olds: View<Old>, news: View<New>| {
+        assert!(olds.is_empty());
+        assert_eq!(olds.removed().count(), 2);
+
+        assert_eq!(news[first], New(10));
+        assert_eq!(news[second], New(20));
+        assert_eq!(news[third], New(3));
+
+        assert!(news.is_inserted(first));
+        assert!(news.is_inserted(second));
+        assert!(!news.is_inserted(third));
+        assert_eq!(
+            news.insertion_timestamp(first),
+            news.insertion_timestamp(second)
+        );
+    });
+
+    assert_eq!(world.convert_component(|old: Old| New(old.0 as u64)), 0);
+}
+
+#[test]
+fn missing_storage() {
+    let mut world = World::new();
+
+    assert_eq!(world.convert_component(|old: Old| New(old.0 as u64)), 0);
+
+    assert!(!world.run(|news: MaybeView<New>| news.is_present()));
+}
+
+#[test]
+#[should_panic(expected = "Can't convert")]
+fn same_type() {
+    let mut world = World::new();
+
+    world.add_entity(Old(0));
+
+    world.convert_component(|old: Old| old);
+}
@@ -38,3 +38,35 @@ fn simple() {
         });
     });
 }
+
+#[test]
+fn by_id() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(U32(0));
+    let entity1 = world.add_entity(());
+    let entity2 = world.add_entity(USIZE(1));
+    let entity3 = world.add_entity((U32(2), USIZE(3)));
+
+    world.delete_any_by_id(&[StorageId::of::<SparseSet<U32>>()]);
+
+    let entities = world.borrow::<EntitiesView>().unwrap();
+    assert!(!entities.is_alive(entity0));
+    assert!(entities.is_alive(entity1));
+    assert!(entities.is_alive(entity2));
+    assert!(!entities.is_alive(entity3));
+    drop(entities);
+
+    let entity4 = world.add_entity(U32(4));
+
+    world.delete_any_by_id(&[
+        StorageId::of::<SparseSet<U32>>(),
+        StorageId::of::<SparseSet<USIZE>>(),
+        StorageId::Custom(0),
+    ]);
+
+    let entities = world.borrow::<EntitiesView>().unwrap();
+    assert!(entities.is_alive(entity1));
+    assert!(!entities.is_alive(entity2));
+    assert!(!entities.is_alive(entity4));
+}
@@ -111,3 +111,51 @@ fn on_deletion() {
         EntityId::new_from_index_and_gen(0, 0).inner()
     );
 }
+
+#[test]
+fn entities_deleted_since() {
+    struct Pos;
+    impl Component for Pos {
+        type Tracking = track::Deletion;
+    }
+
+    struct Vel;
+    impl Component for Vel {
+        type Tracking = track::All;
+    }
+
+    let mut world = World::new();
+
+    let e0 = world.add_entity((Pos, Vel));
+    let e1 = world.add_entity((Pos,));
+    let e2 = world.add_entity((Pos, Vel, U32(2)));
+    let e3 = world.add_entity((U32(3),));
+
+    world.delete_entity(e1);
+
+    let last_frame = world.tick();
+
+    world.delete_entity(e0);
+    world.delete_entity(e3);
+    // deleting a component doesn't count
+    world.delete_component::<Vel>(e2);
+
+    let deleted: Vec<_> = world.entities_deleted_since(last_frame).unwrap().collect();
+    // e3 only had an untracked component
+    assert_eq!(deleted, [e0]);
+
+    let deleted: Vec<_> = world
+        .entities_deleted_since(TrackingTimestamp::new(0))
+        .unwrap()
+        .collect();
+    assert_eq!(deleted, [e0, e1]);
+
+    // busy storages are skipped
+    world.run(|_u32s: ViewMut<U32>, _vels: ViewMut<Vel>| {
+        let deleted: Vec<_> = world
+            .entities_deleted_since(TrackingTimestamp::new(0))
+            .unwrap()
+            .collect();
+        assert_eq!(deleted, [e0, e1]);
+    });
+}
@@ -56,6 +56,60 @@ fn default() {
 #[derive(Hash, Debug, PartialEq, Clone, Label)]
 struct MyLabel;
 
+#[derive(Hash, Debug, PartialEq, Clone, Label)]
+enum Phase {
+    Update,
+    Render,
+}
+
+#[derive(Hash, Debug, PartialEq, Clone, Label)]
+struct Stage<T>(T);
+
+#[test]
+fn label_enum_and_generic() {
+    #[derive(Unique)]
+    struct Count(u32);
+
+    fn increment(mut count: UniqueViewMut<Count>) {
+        count.0 += 1;
+    }
+
+    let world = World::new();
+    world.add_unique(Count(0));
+
+    Workload::new(Phase::Update)
+        .with_system(increment)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new(Phase::Render)
+        .with_system(increment)
+        .with_system(increment)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new(Stage(0u32))
+        .with_system(increment.tag(3u32))
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new(Stage("stage"))
+        .with_system(increment)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload(Phase::Update).unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 1);
+
+    world.run_workload(Phase::Render).unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 3);
+
+    world.run_workload(Stage(0u32)).unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 4);
+
+    world.run_workload(Stage("stage")).unwrap();
+    assert_eq!(world.borrow::<UniqueView<Count>>().unwrap().0, 5);
+
+    assert!(world.run_workload(Stage(1u32)).is_err());
+}
+
 #[test]
 fn into_iter_rename() {
     #[derive(Component)]
@@ -90,3 +144,120 @@ fn into_iter_rename() {
         }
     });
 }
+
+#[test]
+fn generic_views() {
+    #[derive(Component)]
+    struct Position<const N: usize>([f32; N]);
+
+    #[derive(Borrow, BorrowInfo)]
+    struct PhysicsViews<'a, const N: usize> {
+        positions: View<'a, Position<N>>,
+    }
+
+    #[derive(Borrow, BorrowInfo)]
+    struct GenericView<'v, T: Component + Send + Sync>(ViewMut<'v, T>);
+
+    #[derive(WorldBorrow, BorrowInfo)]
+    struct WorldViews<'a, 'b, T: Component + Send + Sync, const N: usize>
+    where
+        T: Sync,
+    {
+        entities: EntitiesView<'a>,
+        positions: View<'b, Position<N>>,
+        values: View<'b, T>,
+    }
+
+    let mut world = World::new();
+
+    world.add_entity((Position([1.0, 2.0]), Position([0.0; 3])));
+
+    world.run(|physics: PhysicsViews<2>| {
+        assert_eq!(physics.positions.iter().count(), 1);
+    });
+    world.run(|view: GenericView<Position<3>>| {
+        assert_eq!(view.0.len(), 1);
+    });
+    world.run(|views: WorldViews<Position<3>, 2>| {
+        assert_eq!(views.positions.len(), 1);
+        assert_eq!(views.values.len(), 1);
+        assert_eq!(views.entities.iter().count(), 1);
+    });
+    Workload::new("")
+        .with_system(|_: PhysicsViews<2>, _: WorldViews<Position<2>, 3>| {})
+        .add_to_world(&world)
+        .unwrap();
+}
+
+#[test]
+fn unique_bundle() {
+    #[derive(Unique)]
+    struct Camera(u32);
+
+    #[derive(Unique)]
+    struct DebugSettings;
+
+    #[derive(Unique, Default)]
+    struct Time(u32);
+
+    #[derive(WorldBorrow, BorrowInfo)]
+    struct RenderUniques<'v> {
+        camera: UniqueView<'v, Camera>,
+        debug: Option<UniqueView<'v, DebugSettings>>,
+        time: UniqueOrDefaultViewMut<'v, Time>,
+    }
+
+    fn render(mut uniques: RenderUniques) {
+        assert_eq!(uniques.camera.0, 1);
+        assert!(uniques.debug.is_none());
+        uniques.time.0 += 1;
+    }
+
+    let world = World::new();
+    world.add_unique(Camera(1));
+
+    let workload = Workload::new("").with_system(render);
+    workload.validate(&world).unwrap();
+    workload.add_to_world(&world).unwrap();
+
+    world.run_workload("").unwrap();
+    world.run_workload("").unwrap();
+
+    assert_eq!(world.borrow::<UniqueView<Time>>().unwrap().0, 2);
+}
+
+#[test]
+fn component_deref() {
+    #[derive(Component)]
+    #[track(Modification)]
+    #[component(deref)]
+    struct Health(u32);
+
+    #[derive(Component)]
+    #[component(deref)]
+    struct Name {
+        name: String,
+    }
+
+    let mut world = World::new();
+
+    let entity = world.add_entity((
+        Health(10),
+        Name {
+            name: "hero".into(),
+        },
+    ));
+
+    world.run(|mut healths: ViewMut<Health>, names: View<Name>| {
+        assert_eq!(names[entity].len(), 4);
+
+        let health = (&mut healths).get(entity).unwrap();
+        assert_eq!(**health, 10);
+        assert!(!healths.is_modified(entity));
+
+        let mut health = (&mut healths).get(entity).unwrap();
+        **health -= 1;
+        assert_eq!(healths[entity].0, 9);
+        assert!(healths.is_modified(entity));
+    });
+}
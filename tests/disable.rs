@@ -0,0 +1,145 @@
+use shipyard::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::All;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct USIZE(usize);
+impl Component for USIZE {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn disable_enable() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((U32(0), USIZE(0)));
+    let entity1 = world.add_entity((U32(1), USIZE(1)));
+    let entity2 = world.add_entity(U32(2));
+
+    assert!(world.disable_entity(entity0));
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert_eq!(u32s.len(), 2);
+        assert!(u32s.get(entity0).is_err());
+        assert!(usizes.get(entity0).is_err());
+        assert_eq!(u32s.get(entity1), Ok(&U32(1)));
+        assert_eq!(u32s.get(entity2), Ok(&U32(2)));
+        assert_eq!(
+            (&u32s, &usizes).iter().collect::<Vec<_>>(),
+            vec![(&U32(1), &USIZE(1))]
+        );
+        assert!(u32s.is_disabled(entity0));
+        assert_eq!(u32s.get_disabled(entity0), Some(&U32(0)));
+        assert_eq!(usizes.get_disabled(entity0), Some(&USIZE(0)));
+        assert_eq!(u32s.removed().count(), 0);
+    });
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        u32s.get_disabled_mut(entity0).unwrap().0 = 10;
+    });
+
+    assert!(world.enable_entity(entity0));
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert_eq!(u32s.len(), 3);
+        assert!(!u32s.is_disabled(entity0));
+        assert_eq!(u32s.get(entity0), Ok(&U32(10)));
+        assert_eq!(usizes.get(entity0), Ok(&USIZE(0)));
+        assert_eq!(u32s.get(entity1), Ok(&U32(1)));
+        assert_eq!(u32s.get(entity2), Ok(&U32(2)));
+    });
+}
+
+#[test]
+fn delete_disabled() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+
+    world.disable_entity(entity);
+    assert!(world.delete_entity(entity));
+
+    assert!(!world.disable_entity(entity));
+    assert!(!world.enable_entity(entity));
+
+    world.run(|u32s: View<U32>| {
+        assert_eq!(u32s.get_disabled(entity), None);
+        assert_eq!(u32s.deleted().count(), 1);
+    });
+}
+
+#[test]
+fn add_while_disabled() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+    world.add_entity(U32(1));
+
+    world.disable_entity(entity);
+    world.add_component(entity, U32(10));
+
+    assert!(world.enable_entity(entity));
+
+    world.run(|u32s: View<U32>| {
+        assert_eq!(u32s.len(), 2);
+        assert_eq!(u32s.disabled_len(), 0);
+        assert_eq!(u32s.get(entity), Ok(&U32(10)));
+        assert_eq!(u32s.iter().count(), 2);
+    });
+
+    assert!(world.validate_integrity().unwrap().is_empty());
+}
+
+#[test]
+fn remove_disabled() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((U32(0), USIZE(0)));
+
+    world.disable_entity(entity);
+
+    assert_eq!(world.remove::<(U32,)>(entity), (Some(U32(0)),));
+
+    world.run(|u32s: View<U32>| {
+        assert_eq!(u32s.disabled_len(), 0);
+        assert_eq!(u32s.removed().collect::<Vec<_>>(), vec![entity]);
+    });
+
+    assert!(world.enable_entity(entity));
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert!(u32s.get(entity).is_err());
+        assert_eq!(usizes.get(entity), Ok(&USIZE(0)));
+    });
+}
+
+#[test]
+fn drain_disabled() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((U32(0), USIZE(0)));
+    let entity1 = world.add_entity(U32(1));
+
+    world.disable_entity(entity0);
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        assert_eq!(u32s.drain().collect::<Vec<_>>(), vec![U32(1)]);
+        assert_eq!(u32s.disabled_len(), 0);
+        assert_eq!(u32s.removed().count(), 2);
+    });
+
+    world.add_component(entity0, U32(10));
+    assert!(world.enable_entity(entity0));
+
+    world.run(|u32s: View<U32>, usizes: View<USIZE>| {
+        assert_eq!(u32s.get(entity0), Ok(&U32(10)));
+        assert!(u32s.get(entity1).is_err());
+        assert_eq!(usizes.get(entity0), Ok(&USIZE(0)));
+    });
+
+    assert!(world.validate_integrity().unwrap().is_empty());
+}
@@ -0,0 +1,186 @@
+use shipyard::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+struct Droppable(Arc<AtomicUsize>);
+impl Component for Droppable {
+    type Tracking = track::Untracked;
+}
+
+impl Drop for Droppable {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn deferred_drops() {
+    let dropped = Arc::new(AtomicUsize::new(0));
+    let mut world = World::new();
+
+    world.defer_drops::<Droppable>();
+
+    let entities: Vec<_> = (0..10)
+        .map(|_| world.add_entity((Droppable(dropped.clone()),)))
+        .collect();
+
+    world.delete_entity(entities[0]);
+    world.delete_component::<(Droppable,)>(entities[1]);
+    world.run(|mut droppables: ViewMut<Droppable>| {
+        droppables.delete(entities[2]);
+    });
+    // removing gives the component back, it isn't deferred
+    drop(world.remove::<(Droppable,)>(entities[3]));
+
+    assert_eq!(dropped.load(Ordering::Relaxed), 1);
+
+    world.clear();
+
+    assert_eq!(dropped.load(Ordering::Relaxed), 1);
+
+    let mut drop_queue = world.take_deferred_drops();
+
+    assert_eq!(drop_queue.len(), 9);
+    assert!(world.take_deferred_drops().is_empty());
+
+    assert_eq!(drop_queue.drop_some(4), 4);
+    assert_eq!(dropped.load(Ordering::Relaxed), 5);
+    assert_eq!(drop_queue.len(), 5);
+
+    assert_eq!(drop_queue.drop_some(10), 5);
+    assert_eq!(dropped.load(Ordering::Relaxed), 10);
+    assert!(drop_queue.is_empty());
+}
+
+#[test]
+fn deletion_tracking_keeps_components() {
+    let dropped = Arc::new(AtomicUsize::new(0));
+    let mut world = World::new();
+
+    world.defer_drops::<Droppable>();
+    world.track_deletion::<Droppable>();
+
+    let entity = world.add_entity((Droppable(dropped.clone()),));
+    world.delete_entity(entity);
+
+    assert!(world.take_deferred_drops().is_empty());
+
+    // clearing the tracking data defers the drop
+    world.clear_all_removed_and_deleted();
+
+    assert_eq!(dropped.load(Ordering::Relaxed), 0);
+
+    let mut drop_queue = world.take_deferred_drops();
+    assert_eq!(drop_queue.len(), 1);
+
+    drop_queue.drop_all();
+    assert_eq!(dropped.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn deletion_tracking_older_than() {
+    let dropped = Arc::new(AtomicUsize::new(0));
+    let mut world = World::new();
+
+    world.defer_drops::<Droppable>();
+    world.track_deletion::<Droppable>();
+
+    let entity = world.add_entity((Droppable(dropped.clone()),));
+    world.delete_entity(entity);
+    let timestamp = world.get_tracking_timestamp();
+    let entity = world.add_entity((Droppable(dropped.clone()),));
+    world.delete_entity(entity);
+
+    world.clear_all_removed_and_deleted_older_than_timestamp(timestamp);
+
+    assert_eq!(world.take_deferred_drops().len(), 1);
+    assert_eq!(dropped.load(Ordering::Relaxed), 1);
+}
+
+#[cfg(feature = "thread_local")]
+#[test]
+fn non_send_storages() {
+    use std::rc::Rc;
+
+    struct NotSend(Rc<()>, Arc<AtomicUsize>);
+    impl Component for NotSend {
+        type Tracking = track::Untracked;
+    }
+    impl Drop for NotSend {
+        fn drop(&mut self) {
+            self.1.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    struct NotSync(std::cell::Cell<u32>, Arc<AtomicUsize>);
+    impl Component for NotSync {
+        type Tracking = track::Untracked;
+    }
+    impl Drop for NotSync {
+        fn drop(&mut self) {
+            self.1.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let dropped = Arc::new(AtomicUsize::new(0));
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    world.run(
+        |mut not_sends: NonSendSync<ViewMut<NotSend>>, mut not_syncs: NonSync<ViewMut<NotSync>>| {
+            not_sends.defer_drops();
+            not_syncs.defer_drops();
+
+            not_sends.add_component_unchecked(entity, NotSend(Rc::new(()), dropped.clone()));
+            not_syncs.add_component_unchecked(entity, NotSync(Default::default(), dropped.clone()));
+
+            assert_eq!(Rc::strong_count(&not_sends[entity].0), 1);
+            assert_eq!(not_syncs[entity].0.get(), 0);
+        },
+    );
+
+    world.delete_entity(entity);
+
+    assert_eq!(dropped.load(Ordering::Relaxed), 0);
+
+    let drop_queue = world.take_deferred_drops();
+    assert_eq!(drop_queue.len(), 1);
+
+    std::thread::spawn(move || drop(drop_queue)).join().unwrap();
+    assert_eq!(dropped.load(Ordering::Relaxed), 1);
+
+    let mut local_drop_queue = world.take_local_deferred_drops();
+    assert_eq!(local_drop_queue.len(), 1);
+
+    assert_eq!(local_drop_queue.drop_some(1), 1);
+    assert_eq!(dropped.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn drop_queue_unique() {
+    let dropped = Arc::new(AtomicUsize::new(0));
+    let mut world = World::new();
+
+    world.defer_drops::<Droppable>();
+    world.add_unique(DropQueue::new());
+
+    for _ in 0..3 {
+        world.add_entity((Droppable(dropped.clone()),));
+    }
+    world.clear();
+
+    let mut deferred = world.take_deferred_drops();
+
+    world.run(|mut drop_queue: UniqueViewMut<DropQueue>| {
+        drop_queue.append(&mut deferred);
+
+        assert_eq!(drop_queue.drop_some(2), 2);
+    });
+
+    assert!(deferred.is_empty());
+    assert_eq!(dropped.load(Ordering::Relaxed), 2);
+
+    world.remove_unique::<DropQueue>().unwrap();
+
+    assert_eq!(dropped.load(Ordering::Relaxed), 3);
+}
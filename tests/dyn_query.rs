@@ -0,0 +1,72 @@
+use shipyard::*;
+
+struct Pos;
+impl Component for Pos {
+    type Tracking = track::Untracked;
+}
+
+struct Vel;
+impl Component for Vel {
+    type Tracking = track::Untracked;
+}
+
+struct Frozen;
+impl Component for Frozen {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn include_exclude() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity((Pos,));
+    let entity1 = world.add_entity((Pos, Vel));
+    let entity2 = world.add_entity((Pos, Vel, Frozen));
+    let entity3 = world.add_entity((Vel,));
+
+    let pos = StorageId::of::<SparseSet<Pos>>();
+    let vel = StorageId::of::<SparseSet<Vel>>();
+    let frozen = StorageId::of::<SparseSet<Frozen>>();
+
+    assert_eq!(
+        world.dyn_query(&[pos], &[]).unwrap(),
+        [entity0, entity1, entity2]
+    );
+    assert_eq!(
+        world.dyn_query(&[vel, pos], &[]).unwrap(),
+        [entity1, entity2]
+    );
+    assert_eq!(world.dyn_query(&[pos, vel], &[frozen]).unwrap(), [entity1]);
+    assert_eq!(world.dyn_query(&[], &[pos]).unwrap(), [entity3]);
+
+    world.delete_entity(entity1);
+
+    assert_eq!(world.dyn_query(&[pos, vel], &[]).unwrap(), [entity2]);
+}
+
+#[test]
+fn missing_storage() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((Pos,));
+
+    let pos = StorageId::of::<SparseSet<Pos>>();
+    let missing = StorageId::Custom(0);
+
+    assert!(world.dyn_query(&[pos, missing], &[]).unwrap().is_empty());
+    assert_eq!(world.dyn_query(&[pos], &[missing]).unwrap(), [entity]);
+}
+
+#[test]
+fn borrowed_storage() {
+    let mut world = World::new();
+
+    world.add_entity((Pos,));
+
+    let _pos = world.borrow::<ViewMut<Pos>>().unwrap();
+
+    assert!(matches!(
+        world.dyn_query(&[StorageId::of::<SparseSet<Pos>>()], &[]),
+        Err(error::GetStorage::StorageBorrow { .. })
+    ));
+}
@@ -0,0 +1,116 @@
+use shipyard::error::DecodeEntityIdSet;
+use shipyard::*;
+
+#[test]
+fn insert_remove() {
+    let mut set = EntityIdSet::new();
+
+    let entity = EntityId::new_from_index_and_gen(3, 0);
+    let newer = EntityId::new_from_index_and_gen(3, 1);
+
+    assert!(set.insert(entity));
+    assert!(!set.insert(entity));
+    assert!(set.contains(entity));
+
+    assert!(set.insert(newer));
+    assert!(!set.contains(entity));
+    assert_eq!(set.len(), 1);
+
+    assert!(!set.remove(entity));
+    assert!(set.remove(newer));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn set_operations() {
+    let a: EntityIdSet = (0..10)
+        .map(|index| EntityId::new_from_index_and_gen(index, 0))
+        .collect();
+    let mut b: EntityIdSet = (5..15)
+        .map(|index| EntityId::new_from_index_and_gen(index, 0))
+        .collect();
+    b.insert(EntityId::new_from_index_and_gen(7, 2));
+
+    let union = a.union(&b);
+    assert_eq!(union.len(), 15);
+    assert!(union.contains(EntityId::new_from_index_and_gen(7, 2)));
+
+    let intersection = a.intersection(&b);
+    assert_eq!(
+        intersection.iter().map(EntityId::index).collect::<Vec<_>>(),
+        [5, 6, 8, 9]
+    );
+
+    let difference = a.difference(&b);
+    assert_eq!(
+        difference.iter().map(EntityId::index).collect::<Vec<_>>(),
+        [0, 1, 2, 3, 4, 7]
+    );
+}
+
+#[test]
+fn roundtrip() {
+    let sparse: EntityIdSet = [0, 1_000, 50_000, 1 << 40]
+        .into_iter()
+        .map(|index| EntityId::new_from_index_and_gen(index, 3))
+        .chain(core::iter::once(EntityId::dead()))
+        .collect();
+    let bytes = sparse.encode();
+    assert_eq!(bytes[0], 0);
+    assert_eq!(EntityIdSet::decode(&bytes), Ok(sparse));
+
+    let dense: EntityIdSet = (100..1_100)
+        .step_by(2)
+        .map(|index| EntityId::new_from_index_and_gen(index, (index % 7) as u16))
+        .collect();
+    let bytes = dense.encode();
+    assert_eq!(bytes[0], 1);
+    assert!(bytes.len() < dense.len() * 2);
+    assert_eq!(EntityIdSet::decode(&bytes), Ok(dense));
+
+    let empty = EntityIdSet::new();
+    assert_eq!(EntityIdSet::decode(&empty.encode()), Ok(empty));
+}
+
+#[test]
+fn decode_errors() {
+    let set: EntityIdSet = (0..64)
+        .map(|index| EntityId::new_from_index_and_gen(index, 0))
+        .collect();
+    let bytes = set.encode();
+
+    assert_eq!(
+        EntityIdSet::decode(&bytes[..bytes.len() - 1]),
+        Err(DecodeEntityIdSet::UnexpectedEnd)
+    );
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        EntityIdSet::decode(&trailing),
+        Err(DecodeEntityIdSet::TrailingBytes)
+    );
+
+    assert_eq!(
+        EntityIdSet::decode(&[7, 0]),
+        Err(DecodeEntityIdSet::UnknownEncoding(7))
+    );
+
+    // bitmap announcing 2 entities but containing a single one
+    assert_eq!(
+        EntityIdSet::decode(&[1, 2, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+        Err(DecodeEntityIdSet::LengthMismatch)
+    );
+
+    // generation larger than u16
+    assert_eq!(
+        EntityIdSet::decode(&[0, 1, 0, 0xff, 0xff, 0x04]),
+        Err(DecodeEntityIdSet::Overflow)
+    );
+
+    // huge announced length doesn't allocate
+    assert_eq!(
+        EntityIdSet::decode(&[0, 0xff, 0xff, 0xff, 0xff, 0x0f]),
+        Err(DecodeEntityIdSet::UnexpectedEnd)
+    );
+}
@@ -0,0 +1,59 @@
+use shipyard::*;
+
+#[derive(Component)]
+struct Position(u32);
+
+fn collect(arena: UniqueView<FrameArena>, positions: View<Position>) {
+    let values = arena.alloc_slice_fill_with(positions.len(), |i| positions.as_slice()[i].0);
+    let total = arena.alloc(values.iter().sum::<u32>());
+
+    assert_eq!(*total, 6);
+    assert_eq!(arena.allocated_bytes(), 4 * 4);
+}
+
+#[test]
+fn reset_by_workload() {
+    let mut world = World::new();
+
+    world.add_unique(FrameArena::new());
+    world.bulk_add_entity((1..4).map(Position));
+
+    Workload::new("Frame")
+        .with_system(collect)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Frame").unwrap();
+    world.run_workload("Frame").unwrap();
+
+    let arena = world.borrow::<UniqueView<FrameArena>>().unwrap();
+    assert_eq!(arena.allocated_bytes(), 4 * 4);
+}
+
+#[test]
+fn grow_and_reset() {
+    let mut arena = FrameArena::with_capacity(8);
+
+    let small = arena.alloc(1u8);
+    assert_eq!(*small, 1);
+    let aligned = arena.alloc(2u64);
+    assert_eq!(
+        aligned as *mut u64 as usize % core::mem::align_of::<u64>(),
+        0
+    );
+
+    let big = arena.alloc_slice(&[3u32; 1000]);
+    assert!(big.iter().all(|&value| value == 3));
+    assert!(arena.capacity() >= 8 + 4000);
+
+    arena.alloc_slice::<()>(&[(); 10]);
+
+    let capacity = arena.capacity();
+    arena.reset();
+
+    assert_eq!(arena.allocated_bytes(), 0);
+    assert_eq!(arena.capacity(), capacity);
+
+    arena.alloc_slice(&[3u32; 1000]);
+    assert_eq!(arena.capacity(), capacity);
+}
@@ -139,3 +139,107 @@ fn old_id() {
         assert!(u32s.get(entity1).is_err());
     });
 }
+
+#[test]
+fn missing_component_cause() {
+    #[allow(unused)]
+    #[derive(Debug)]
+    struct U32(u32);
+    impl Component for U32 {
+        type Tracking = track::Untracked;
+    }
+
+    #[allow(unused)]
+    struct I16(i16);
+    impl Component for I16 {
+        type Tracking = track::Untracked;
+    }
+
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+    let other_entity = world.add_entity(I16(0));
+
+    world.run(|entities: EntitiesView, u32s: View<U32>| {
+        assert_eq!(
+            u32s.get(EntityId::placeholder())
+                .unwrap_err()
+                .cause(&entities),
+            error::MissingComponentCause::Placeholder
+        );
+        assert_eq!(
+            u32s.get(other_entity).unwrap_err().cause(&entities),
+            error::MissingComponentCause::Missing
+        );
+    });
+
+    world.delete_entity(entity);
+
+    match world.get::<&U32>(entity) {
+        Err(error::GetComponent::MissingComponent(missing_component)) => {
+            assert_eq!(
+                world.run(|entities: EntitiesView| missing_component.cause(&entities)),
+                error::MissingComponentCause::DeadEntity
+            );
+        }
+        _ => panic!(),
+    }
+
+    let new_entity = world.add_entity(U32(1));
+    assert_eq!(new_entity.index(), entity.index());
+
+    world.run(|entities: EntitiesView, u32s: View<U32>| {
+        assert_eq!(
+            u32s.get(entity).unwrap_err().cause(&entities),
+            error::MissingComponentCause::DeadEntity
+        );
+    });
+
+    assert!(EntityId::placeholder().is_placeholder());
+    assert!(!entity.is_placeholder());
+}
+
+#[test]
+fn tuple_missing_components() {
+    struct U32(u32);
+    impl Component for U32 {
+        type Tracking = track::Untracked;
+    }
+
+    #[allow(unused)]
+    struct I16(i16);
+    impl Component for I16 {
+        type Tracking = track::Untracked;
+    }
+
+    struct USIZE(usize);
+    impl Component for USIZE {
+        type Tracking = track::Untracked;
+    }
+
+    let mut world = World::new();
+
+    let entity = world.add_entity((I16(0),));
+
+    world.run(|u32s: View<U32>, i16s: View<I16>, usizes: View<USIZE>| {
+        let missing_component = (&u32s, &i16s, &usizes).get(entity).err().unwrap();
+
+        assert_eq!(missing_component.id, entity);
+        assert_eq!(missing_component.name, core::any::type_name::<U32>());
+
+        assert!((&i16s,).get(entity).is_ok());
+    });
+
+    match world.get::<(&U32, &I16, &USIZE)>(entity) {
+        Err(error::GetComponent::MissingComponent(missing_component)) => {
+            assert_eq!(missing_component.name, core::any::type_name::<U32>());
+        }
+        _ => panic!(),
+    }
+
+    world.add_component(entity, (U32(1), USIZE(2)));
+
+    let (a, b) = world.get::<(&U32, &USIZE)>(entity).unwrap();
+    assert_eq!(a.0, 1);
+    assert_eq!(b.0, 2);
+}
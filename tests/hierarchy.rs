@@ -0,0 +1,88 @@
+use shipyard::*;
+
+#[derive(Component)]
+#[track(Insertion, Modification)]
+struct Transform(f32);
+
+#[derive(Component)]
+struct ChildOf(EntityId);
+
+impl HierarchyParent for ChildOf {
+    fn parent(&self) -> EntityId {
+        self.0
+    }
+}
+
+#[derive(Component, Default, Debug, PartialEq, Eq)]
+struct Dirty(u32);
+
+#[test]
+fn propagate() {
+    let mut world = World::new();
+
+    let root = world.add_entity(Transform(0.0));
+    let child = world.add_entity((Transform(0.0), ChildOf(root)));
+    let grand_child = world.add_entity((Transform(0.0), ChildOf(child)));
+    let sibling = world.add_entity((Transform(0.0), ChildOf(root)));
+    let other_root = world.add_entity(Transform(0.0));
+    let other_child = world.add_entity(ChildOf(other_root));
+
+    world.run(|transforms: ViewMut<Transform>| {
+        transforms.clear_all_inserted_and_modified();
+    });
+
+    world.run(|mut transforms: ViewMut<Transform>| {
+        transforms[child].0 += 1.0;
+    });
+
+    world.run(
+        |transforms: View<Transform>, parents: View<ChildOf>, mut dirty: ViewMut<Dirty>| {
+            assert_eq!(propagate_dirty(&transforms, &parents, &mut dirty), 2);
+
+            assert!(!dirty.contains(root));
+            assert!(dirty.contains(child));
+            assert!(dirty.contains(grand_child));
+            assert!(!dirty.contains(sibling));
+            assert!(!dirty.contains(other_root));
+            assert!(!dirty.contains(other_child));
+        },
+    );
+
+    world.run(
+        |mut transforms: ViewMut<Transform>, mut dirty: ViewMut<Dirty>| {
+            transforms.add_component_unchecked(other_root, Transform(1.0));
+            dirty[child].0 = 1;
+        },
+    );
+
+    world.run(
+        |transforms: View<Transform>, parents: View<ChildOf>, mut dirty: ViewMut<Dirty>| {
+            assert_eq!(propagate_dirty(&transforms, &parents, &mut dirty), 2);
+
+            assert_eq!(dirty[child], Dirty(1));
+            assert!(dirty.contains(other_root));
+            assert!(dirty.contains(other_child));
+        },
+    );
+}
+
+#[test]
+fn cycle() {
+    let mut world = World::new();
+
+    let first = world.add_entity(());
+    let second = world.add_entity(ChildOf(first));
+    world.add_component(first, ChildOf(second));
+    let child = world.add_entity((Transform(0.0), ChildOf(first)));
+
+    world.run(|transforms: ViewMut<Transform>| {
+        transforms.clear_all_inserted_and_modified();
+    });
+
+    world.run(
+        |transforms: View<Transform>, parents: View<ChildOf>, mut dirty: ViewMut<Dirty>| {
+            assert_eq!(propagate_dirty(&transforms, &parents, &mut dirty), 0);
+            assert!(!dirty.contains(child));
+        },
+    );
+}
@@ -0,0 +1,157 @@
+use shipyard::error;
+use shipyard::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct NavCell(u32);
+impl Component for NavCell {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Position(u32);
+impl Component for Position {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn peek() {
+    let mut world = World::new();
+
+    let cell0 = world.add_entity(NavCell(0));
+    let cell1 = world.add_entity((NavCell(1), Position(1)));
+    let other = world.add_entity(Position(2));
+
+    assert_eq!(world.peek::<NavCell>(cell0), None);
+
+    world.make_immutable::<NavCell>().unwrap();
+    // making it immutable twice is fine
+    world.make_immutable::<NavCell>().unwrap();
+
+    assert_eq!(world.peek::<NavCell>(cell0), Some(&NavCell(0)));
+    assert_eq!(world.peek::<NavCell>(cell1), Some(&NavCell(1)));
+    assert_eq!(world.peek::<NavCell>(other), None);
+    assert_eq!(world.peek::<Position>(other), None);
+
+    let cell = world.peek::<NavCell>(cell0).unwrap();
+    world.run(|mut positions: ViewMut<Position>| {
+        positions[other].0 += 1;
+    });
+    assert_eq!(cell, &NavCell(0));
+}
+
+#[test]
+fn exclusive_borrow() {
+    let mut world = World::new();
+
+    let cell = world.add_entity(NavCell(0));
+
+    world.make_immutable::<NavCell>().unwrap();
+
+    assert!(world.borrow::<View<NavCell>>().is_ok());
+    assert!(matches!(
+        world.borrow::<ViewMut<NavCell>>(),
+        Err(error::GetStorage::StorageBorrow {
+            borrow: error::Borrow::Shared,
+            ..
+        })
+    ));
+
+    assert!(world.make_mutable::<NavCell>());
+    assert!(!world.make_mutable::<NavCell>());
+
+    assert_eq!(world.peek::<NavCell>(cell), None);
+    assert!(world.borrow::<ViewMut<NavCell>>().is_ok());
+
+    world.clear();
+    assert!(world.borrow::<View<NavCell>>().unwrap().is_empty());
+}
+
+#[test]
+fn modify_with_exclusive_world() {
+    let mut world = World::new();
+
+    let cell0 = world.add_entity(NavCell(0));
+    let cell1 = world.add_entity(NavCell(1));
+
+    world.make_immutable::<NavCell>().unwrap();
+
+    world.delete_entity(cell0);
+    world.add_component(cell1, NavCell(2));
+    let cell2 = world.add_entity(NavCell(3));
+
+    assert_eq!(world.peek::<NavCell>(cell0), None);
+    assert_eq!(world.peek::<NavCell>(cell1), Some(&NavCell(2)));
+    assert_eq!(world.peek::<NavCell>(cell2), Some(&NavCell(3)));
+    assert!(world.validate_integrity().unwrap().is_empty());
+
+    // the storage is still immutable
+    assert!(world.borrow::<ViewMut<NavCell>>().is_err());
+
+    world.clear();
+    assert_eq!(world.peek::<NavCell>(cell1), None);
+    assert!(world.borrow::<View<NavCell>>().unwrap().is_empty());
+}
+
+#[test]
+fn missing_storage() {
+    let mut world = World::new();
+
+    assert!(matches!(
+        world.make_immutable::<NavCell>(),
+        Err(error::GetStorage::MissingStorage { .. })
+    ));
+}
+
+#[test]
+fn swapped_all_storages() {
+    let mut world1 = World::new();
+    let world2 = World::new();
+
+    let cell = world1.add_entity(NavCell(0));
+    world1.make_immutable::<NavCell>().unwrap();
+
+    core::mem::swap(
+        &mut *world1.all_storages_mut().unwrap(),
+        &mut *world2.all_storages_mut().unwrap(),
+    );
+    drop(world2);
+
+    assert_eq!(world1.peek::<NavCell>(cell), Some(&NavCell(0)));
+    assert!(world1.make_mutable::<NavCell>());
+}
+
+#[test]
+fn modify_with_all_storages() {
+    let mut world = World::new();
+
+    let cell = world.add_entity((NavCell(0), Position(0)));
+    let other = world.add_entity(Position(1));
+
+    world.make_immutable::<NavCell>().unwrap();
+
+    // entities without an immutable component can still be deleted
+    assert!(world.run(|mut all_storages: AllStoragesViewMut| all_storages.delete_entity(other)));
+
+    for modify in [
+        |all_storages: &mut AllStorages, cell| {
+            all_storages.delete_entity(cell);
+        },
+        |all_storages: &mut AllStorages, cell| all_storages.strip(cell),
+        |all_storages: &mut AllStorages, _| all_storages.clear(),
+        |all_storages: &mut AllStorages, _| all_storages.delete_any::<SparseSet<NavCell>>(),
+    ] {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            world.run(|mut all_storages: AllStoragesViewMut| modify(&mut all_storages, cell))
+        }));
+
+        assert!(result.is_err());
+        assert!(world.is_entity_alive(cell));
+        assert_eq!(world.peek::<NavCell>(cell), Some(&NavCell(0)));
+        assert_eq!(world.get::<&Position>(cell).as_deref(), Ok(&&Position(0)));
+        assert!(world.validate_integrity().unwrap().is_empty());
+    }
+
+    world.make_mutable::<NavCell>();
+    assert!(world.run(|mut all_storages: AllStoragesViewMut| all_storages.delete_entity(cell)));
+    assert!(world.validate_integrity().unwrap().is_empty());
+}
@@ -0,0 +1,55 @@
+use shipyard::*;
+
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::All;
+}
+
+#[test]
+fn valid_world() {
+    let mut world = World::new();
+
+    let entity0 = world.add_entity(U32(0));
+    let entity1 = world.add_entity(U32(1));
+    world.add_entity(U32(2));
+
+    world.get::<&mut U32>(entity0).unwrap().0 += 1;
+    world.delete_entity(entity1);
+
+    assert!(world.validate_integrity().unwrap().is_empty());
+}
+
+#[test]
+fn dead_entity() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    world.delete_entity(entity);
+
+    world.run(|mut u32s: ViewMut<U32>| u32s.add_component_unchecked(entity, U32(0)));
+
+    let errors = world.validate_integrity().unwrap();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        &errors[0],
+        error::IntegrityError::DeadEntity { entity: dead, .. } if *dead == entity
+    ));
+}
+
+#[test]
+fn busy_storage() {
+    let mut world = World::new();
+
+    world.add_entity(U32(0));
+
+    let _u32s = world.borrow::<ViewMut<U32>>().unwrap();
+
+    assert!(matches!(
+        world.validate_integrity(),
+        Err(error::GetStorage::StorageBorrow {
+            borrow: error::Borrow::Unique,
+            ..
+        })
+    ));
+}
@@ -319,6 +319,41 @@ fn or() {
     }
 }
 
+#[test]
+fn count_matches() {
+    let mut world = World::new();
+
+    world.add_entity((U32(0), I16(10)));
+    world.add_entity((U32(1),));
+    world.add_entity((U32(2), I16(12)));
+    world.add_entity((I16(13),));
+    world.add_entity((U32(4), I16(14), USIZE(24)));
+    world.add_entity((I16(15), USIZE(25)));
+
+    let (u32s, i16s, usizes) = world
+        .borrow::<(View<U32>, View<I16>, View<USIZE>)>()
+        .unwrap();
+
+    assert_eq!((&u32s).count_matches(), 4);
+    assert_eq!((&u32s, &i16s).count_matches(), 3);
+    assert_eq!((&u32s, !&i16s).count_matches(), 1);
+    assert_eq!((&u32s | &i16s, &usizes).count_matches(), 2);
+    assert_eq!(
+        (&u32s, &i16s).count_matches(),
+        (&u32s, &i16s).iter().count()
+    );
+
+    assert!(!(&u32s, &i16s, &usizes).is_empty());
+    assert!((&u32s, !&i16s, &usizes).is_empty());
+    assert!((&u32s, &i16s).iter().nth(3).is_none());
+    drop((u32s, i16s, usizes));
+
+    let (u32s, usizes) = world.borrow::<(View<U32>, View<USIZE>)>().unwrap();
+    let mut iter = (&u32s, &usizes).iter();
+    assert!(iter.next().is_some());
+    assert!(iter.is_empty());
+}
+
 // #[test]
 // fn chunk() {
 //     let mut world = World::new();
@@ -0,0 +1,116 @@
+use shipyard::*;
+
+#[derive(PartialEq, Eq, Debug)]
+struct Input(u32);
+impl Component for Input {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn independent_storages() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(Input(0));
+
+    world
+        .view_labeled_mut::<Input>("player 1")
+        .unwrap()
+        .add_component_unchecked(entity, Input(1));
+    world
+        .view_labeled_mut::<Input>("player 2")
+        .unwrap()
+        .add_component_unchecked(entity, Input(2));
+
+    assert_eq!(world.get::<&Input>(entity).unwrap().0, 0);
+    assert_eq!(
+        world.view_labeled::<Input>("player 1").unwrap().get(entity),
+        Ok(&Input(1))
+    );
+    assert_eq!(
+        world.view_labeled::<Input>("player 2").unwrap().get(entity),
+        Ok(&Input(2))
+    );
+    assert!(world.view_labeled::<Input>("player 3").unwrap().is_empty());
+
+    assert_ne!(
+        StorageId::of_labeled::<Input>("player 1"),
+        StorageId::of_labeled::<Input>("player 2")
+    );
+}
+
+#[test]
+fn borrow_in_parallel() {
+    let world = World::new();
+
+    let _player1 = world.view_labeled_mut::<Input>(1u32).unwrap();
+    let _player2 = world.view_labeled_mut::<Input>(2u32).unwrap();
+
+    assert!(world.view_labeled::<Input>(1u32).is_err());
+}
+
+#[test]
+fn delete_entity() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    world
+        .view_labeled_mut::<Input>(0u32)
+        .unwrap()
+        .add_component_unchecked(entity, Input(0));
+
+    world.delete_entity(entity);
+
+    assert!(world.view_labeled::<Input>(0u32).unwrap().is_empty());
+}
+
+#[test]
+fn system_views() {
+    fn player1(mut inputs: ViewLabeledMut<Input, 1>) {
+        for input in (&mut *inputs).iter() {
+            input.0 += 1;
+        }
+    }
+
+    fn player2(mut inputs: ViewLabeledMut<Input, 2>) {
+        for input in (&mut *inputs).iter() {
+            input.0 += 2;
+        }
+    }
+
+    fn read_player1(_: ViewLabeled<Input, 1>) {}
+
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    world
+        .view_labeled_mut::<Input>(1u64)
+        .unwrap()
+        .add_component_unchecked(entity, Input(0));
+    world
+        .view_labeled_mut::<Input>(2u64)
+        .unwrap()
+        .add_component_unchecked(entity, Input(0));
+
+    let (_, info) = Workload::new("")
+        .with_system(player1)
+        .with_system(player2)
+        .with_system(read_player1)
+        .build()
+        .unwrap();
+
+    // only systems borrowing the same label conflict
+    assert_eq!(info.batch_info.len(), 2);
+    assert_eq!(info.batch_info[0].systems().count(), 2);
+
+    world.run(player1);
+    world.run(player2);
+
+    assert_eq!(
+        world.view_labeled::<Input>(1u64).unwrap().get(entity),
+        Ok(&Input(1))
+    );
+    world.run(|inputs: ViewLabeled<Input, 2>| {
+        assert_eq!(inputs.get(entity), Ok(&Input(2)));
+    });
+    assert_eq!(world.get::<&Input>(entity).ok().map(|input| input.0), None);
+}
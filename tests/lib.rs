@@ -190,6 +190,65 @@ fn parallel_iterator() {
     });
 }
 
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn indexed_parallel_iterator() {
+    use rayon::prelude::*;
+
+    let mut world = World::new();
+
+    world.add_entity((USIZE(0), U32(1)));
+    world.add_entity((USIZE(2), U32(3)));
+    world.add_entity(USIZE(4));
+
+    world.run(|usizes: View<USIZE>, u32s: View<U32>| {
+        let mut values = Vec::new();
+        usizes
+            .par_iter()
+            .into_indexed()
+            .ok()
+            .unwrap()
+            .zip(vec![10, 20, 30])
+            .map(|(x, y)| x.0 + y)
+            .collect_into_vec(&mut values);
+
+        assert_eq!(values, vec![10, 22, 34]);
+
+        assert!((&usizes, &u32s).par_iter().into_indexed().is_err());
+    });
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn run_parallel() {
+    let mut world = World::new();
+
+    world.add_entity((USIZE(0), U32(1)));
+    world.add_entity((USIZE(2), U32(3)));
+
+    let (usizes_sum, u32s_sum, ()) = world.run_parallel((
+        |usizes: View<USIZE>| usizes.iter().map(|x| x.0).sum::<usize>(),
+        |u32s: View<U32>| u32s.iter().map(|x| x.0).sum::<u32>(),
+        || {},
+    ));
+
+    assert_eq!(usizes_sum, 2);
+    assert_eq!(u32s_sum, 4);
+
+    // conflicting borrows run in order
+    let ((), usizes_sum) = world.run_parallel((
+        |mut usizes: ViewMut<USIZE>| {
+            for x in (&mut usizes).iter() {
+                x.0 += 1;
+            }
+        },
+        |usizes: View<USIZE>| usizes.iter().map(|x| x.0).sum::<usize>(),
+    ));
+
+    assert_eq!(usizes_sum, 4);
+}
+
 #[cfg(feature = "parallel")]
 #[cfg_attr(miri, ignore)]
 #[test]
@@ -314,6 +373,92 @@ fn par_update_pack() {
     });
 }
 
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn par_iter_min_len() {
+    use rayon::prelude::*;
+
+    let mut world = World::new();
+
+    world.bulk_add_entity((0..100).map(|i| (USIZE(i), U32(i as u32))));
+
+    world.run(|mut usizes: ViewMut<USIZE>, u32s: View<U32>| {
+        assert_eq!(u32s.par_min_len(), 1024 / core::mem::size_of::<U32>());
+        assert_eq!(usizes.par_iter().min_len(), usizes.par_min_len());
+        assert_eq!(
+            (&usizes, &u32s).par_iter().min_len(),
+            u32s.par_min_len().min(usizes.par_min_len())
+        );
+
+        usizes.set_par_min_len(Some(10));
+        assert_eq!(usizes.par_iter().min_len(), 10);
+        assert_eq!((&usizes, &u32s).par_iter().min_len(), 10);
+
+        usizes.set_par_min_len(None);
+        assert_eq!(usizes.par_min_len(), 1024 / core::mem::size_of::<USIZE>());
+
+        let chunks: Vec<usize> = usizes
+            .par_iter()
+            .with_min_len(25)
+            .fold(|| 0, |count, _| count + 1)
+            .collect();
+        assert_eq!(chunks.iter().sum::<usize>(), 100);
+        assert!(chunks.iter().all(|&count| count >= 25));
+
+        let chunks: Vec<usize> = (&usizes, &u32s)
+            .par_iter()
+            .with_min_len(25)
+            .fold(|| 0, |count, _| count + 1)
+            .collect();
+        assert_eq!(chunks.iter().sum::<usize>(), 100);
+        assert!(chunks.iter().all(|&count| count >= 25));
+    });
+}
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn par_iter_short_circuit() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use rayon::prelude::*;
+
+    let mut world = World::new();
+
+    world.bulk_add_entity((0..100).map(|i| (USIZE(i), U32(i as u32))));
+
+    world.run(|usizes: View<USIZE>, u32s: View<U32>| {
+        assert!(usizes.par_iter().any(|x| x.0 == 99));
+        assert!(!usizes.par_iter().any(|x| x.0 == 100));
+        assert_eq!(
+            (&usizes, &u32s)
+                .par_iter()
+                .find_any(|(x, y)| x.0 == 42 && y.0 == 42)
+                .map(|(x, _)| x.0),
+            Some(42)
+        );
+
+        // a single task stops as soon as it finds a match
+        let visited = AtomicUsize::new(0);
+        assert!(usizes.par_iter().with_min_len(100).any(|_| {
+            visited.fetch_add(1, Ordering::Relaxed);
+            true
+        }));
+        assert_eq!(visited.load(Ordering::Relaxed), 1);
+
+        let visited = AtomicUsize::new(0);
+        assert!((&usizes, &u32s)
+            .par_iter()
+            .with_min_len(100)
+            .find_any(|_| {
+                visited.fetch_add(1, Ordering::Relaxed);
+                true
+            })
+            .is_some());
+        assert_eq!(visited.load(Ordering::Relaxed), 1);
+    });
+}
+
 #[cfg(feature = "parallel")]
 #[cfg_attr(miri, ignore)]
 #[test]
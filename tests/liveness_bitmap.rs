@@ -0,0 +1,44 @@
+use shipyard::*;
+
+#[test]
+fn liveness_bitmap() {
+    let mut world = World::new();
+
+    let entities: Vec<_> = (0..100).map(|_| world.add_entity(())).collect();
+
+    for entity in entities.iter().step_by(3) {
+        world.delete_entity(*entity);
+    }
+
+    let new_entity = world.add_entity(());
+
+    let bitmap = world.borrow::<EntitiesView>().unwrap().liveness_bitmap();
+
+    assert_eq!(bitmap.len(), 100);
+    assert_eq!(bitmap.as_words().len(), 2);
+    assert_eq!(bitmap.generations().len(), 100);
+
+    for (index, &entity) in entities.iter().enumerate() {
+        assert_eq!(
+            bitmap.is_alive(entity),
+            world.is_entity_alive(entity),
+            "{index}"
+        );
+    }
+
+    assert!(bitmap.is_alive(new_entity));
+    assert!(bitmap.is_index_alive(new_entity.index()));
+    assert!(!bitmap.is_index_alive(100));
+    assert!(!bitmap.is_alive(EntityId::dead()));
+
+    // the snapshot doesn't follow the World
+    world.delete_entity(new_entity);
+    assert!(bitmap.is_alive(new_entity));
+
+    let empty = World::new()
+        .borrow::<EntitiesView>()
+        .unwrap()
+        .liveness_bitmap();
+    assert!(empty.is_empty());
+    assert!(empty.as_words().is_empty());
+}
@@ -0,0 +1,64 @@
+use shipyard::*;
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct Position(u32);
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct Velocity(u32);
+
+fn apply_velocity(mut positions: ViewMut<Position>, velocities: MaybeView<Velocity>) {
+    for (position, velocity) in (&mut positions, &velocities).iter() {
+        position.0 += velocity.0;
+    }
+}
+
+#[test]
+fn missing_storage() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(Position(0));
+
+    Workload::new("Move")
+        .with_system(apply_velocity)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Move").unwrap();
+
+    world.run(|entities: EntitiesView, velocities: MaybeView<Velocity>| {
+        assert!(!velocities.is_present());
+        assert!(velocities.is_empty());
+        assert!(velocities.as_view().is_none());
+        assert!(!(&velocities).contains(entity));
+        assert_eq!(
+            (&velocities).get(entity).unwrap_err().cause(&entities),
+            error::MissingComponentCause::Missing
+        );
+    });
+
+    assert!(!world
+        .storages_info()
+        .iter()
+        .any(|info| info.id == StorageId::of::<SparseSet<Velocity>>()));
+
+    world.add_component(entity, Velocity(2));
+    world.run_workload("Move").unwrap();
+
+    assert_eq!(world.get::<&Position>(entity).as_deref(), Ok(&&Position(2)));
+
+    world.run(|velocities: MaybeView<Velocity>| {
+        assert!(velocities.is_present());
+        assert_eq!(velocities.len(), 1);
+        assert_eq!((&velocities).get(entity), Ok(&Velocity(2)));
+    });
+}
+
+#[test]
+fn exclusive_borrow() {
+    let mut world = World::new();
+    world.add_entity(Velocity(0));
+
+    let _velocities = world.borrow::<ViewMut<Velocity>>().unwrap();
+
+    assert!(world.borrow::<MaybeView<Velocity>>().is_err());
+}
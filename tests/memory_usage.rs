@@ -0,0 +1,69 @@
+use shipyard::{track, Component, StorageCategory, Unique, World};
+
+#[allow(unused)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[allow(unused)]
+struct Usize(usize);
+impl Component for Usize {
+    type Tracking = track::All;
+}
+
+#[allow(unused)]
+struct Counter(u64);
+impl Unique for Counter {}
+
+#[test]
+fn report() {
+    let mut world = World::new();
+
+    world.add_unique(Counter(0));
+    let entity = world.add_entity((U32(0), Usize(0)));
+    world.add_entity(U32(1));
+    world.delete_entity(entity);
+    world.add_workload(|| (|| {}, || {}));
+
+    let report = world.memory_usage().report().unwrap();
+
+    assert!(report.entities.used > 0);
+    assert!(report.components.used > 0);
+    assert!(report.tracking.used > 0);
+    assert!(report.uniques.used > 0);
+    assert_eq!(report.custom_storages.used, 0);
+    assert!(report.scheduler.used > 0);
+    assert_eq!(report.borrowed_storages, 0);
+
+    for bytes in [
+        report.entities,
+        report.components,
+        report.tracking,
+        report.uniques,
+        report.scheduler,
+    ] {
+        assert!(bytes.used <= bytes.allocated);
+    }
+
+    assert_eq!(
+        report
+            .storages
+            .iter()
+            .filter(|storage| storage.category == StorageCategory::Components)
+            .count(),
+        2
+    );
+    assert_eq!(
+        report.total_bytes(),
+        report.entities.allocated
+            + report.components.allocated
+            + report.tracking.allocated
+            + report.uniques.allocated
+            + report.scheduler.allocated
+    );
+    assert_eq!(
+        world.memory_usage().total_bytes().unwrap(),
+        report.total_bytes()
+    );
+}
@@ -0,0 +1,103 @@
+use shipyard::*;
+
+#[derive(Component)]
+struct Health;
+
+/// Returns the message of the panic caused by `f`.
+fn panic_message(f: impl FnOnce()) -> String {
+    let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_err();
+
+    payload.downcast_ref::<String>().unwrap().clone()
+}
+
+#[test]
+fn name_in_view_panics() {
+    let mut world = World::new();
+
+    let door = world.add_entity(Name::new("Boss_Door"));
+    let unnamed = world.add_entity(());
+
+    let message = panic_message(|| {
+        world.run(|healths: View<Health>| {
+            let _ = &healths[door];
+        })
+    });
+    assert!(!message.contains("Boss_Door"));
+
+    world.set_entity_names_in_errors(true);
+    assert!(world.entity_names_in_errors());
+
+    let message = panic_message(|| {
+        world.run(|healths: View<Health>| {
+            let _ = &healths[door];
+        })
+    });
+    assert_eq!(
+        message,
+        format!(
+            "{:?} (\"Boss_Door\") does not have a {} component.",
+            door,
+            core::any::type_name::<Health>()
+        )
+    );
+
+    let message = panic_message(|| {
+        world.run(|mut healths: ViewMut<Health>| {
+            let _ = &mut healths[door];
+        })
+    });
+    assert!(message.contains("(\"Boss_Door\")"));
+
+    let message = panic_message(|| {
+        world.run(|healths: View<Health>| {
+            let _ = &healths[unnamed];
+        })
+    });
+    assert_eq!(
+        message,
+        format!(
+            "{:?} does not have a {} component.",
+            unnamed,
+            core::any::type_name::<Health>()
+        )
+    );
+
+    // the `Name` storage is borrowed exclusively
+    let message = panic_message(|| {
+        world.run(|healths: View<Health>, _names: ViewMut<Name>| {
+            let _ = &healths[door];
+        })
+    });
+    assert!(!message.contains("Boss_Door"));
+}
+
+#[test]
+fn name_not_in_errors() {
+    let mut world = World::new();
+    world.set_entity_names_in_errors(true);
+
+    let door = world.add_entity(Name::new("Boss_Door"));
+
+    // returned errors are the same with or without names
+    assert_eq!(
+        world.get::<&Health>(door).err(),
+        Some(error::GetComponent::MissingComponent(
+            error::MissingComponent {
+                id: door,
+                name: core::any::type_name::<Health>(),
+            }
+        ))
+    );
+}
+
+#[test]
+fn dead_entity_in_add_component_panic() {
+    let mut world = World::new();
+    world.set_entity_names_in_errors(true);
+
+    let door = world.add_entity(Name::from("Boss_Door"));
+    world.delete_entity(door);
+
+    let message = panic_message(|| world.add_component(door, Health));
+    assert!(message.starts_with(&format!("{:?}: ", door)));
+}
@@ -0,0 +1,54 @@
+use shipyard::*;
+
+#[derive(Component)]
+struct Pos;
+
+#[derive(Unique)]
+struct Time;
+
+#[test]
+fn entity_exists() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((Pos,));
+
+    world.run(|_pos: ViewMut<Pos>| {
+        assert!(world.entity_exists(entity));
+    });
+
+    world.delete_entity(entity);
+
+    assert!(!world.entity_exists(entity));
+}
+
+#[test]
+fn orphans() {
+    let mut world = World::new();
+    world.add_unique(Time);
+
+    let orphan = world.add_entity(());
+    let entity = world.add_entity((Pos,));
+    let stripped = world.add_entity((Pos,));
+
+    world.strip(stripped);
+
+    assert_eq!(world.orphans().unwrap(), [orphan, stripped]);
+
+    world.delete_component::<(Pos,)>(entity);
+
+    assert_eq!(world.orphans().unwrap(), [orphan, entity, stripped]);
+}
+
+#[test]
+fn orphans_skip_busy_storages() {
+    let mut world = World::new();
+
+    let orphan = world.add_entity(());
+    let entity = world.add_entity((Pos,));
+
+    world.run(|_pos: ViewMut<Pos>| {
+        assert_eq!(world.orphans().unwrap(), [orphan, entity]);
+    });
+
+    assert_eq!(world.orphans().unwrap(), [orphan]);
+}
@@ -0,0 +1,72 @@
+use shipyard::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Modification;
+}
+
+#[test]
+fn split() {
+    let mut world = World::new();
+
+    let entities = world.bulk_add_entity((0..5).map(U32)).collect::<Vec<_>>();
+
+    world.run(|u32s: View<U32>| {
+        let partition = u32s.partition(1..4);
+        assert_eq!(partition.ids(), &entities[1..4]);
+
+        let (left, right) = partition.split_at(1);
+        assert_eq!(left.as_slice(), &[U32(1)]);
+        assert_eq!(right.as_slice(), &[U32(2), U32(3)]);
+
+        let chunks = u32s
+            .partition(..)
+            .chunks(2)
+            .map(|chunk| chunk.len())
+            .collect::<Vec<_>>();
+        assert_eq!(chunks, vec![2, 2, 1]);
+    });
+}
+
+#[test]
+fn modification_tracking() {
+    let mut world = World::new();
+
+    let entities = world.bulk_add_entity((0..4).map(U32)).collect::<Vec<_>>();
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        let (mut left, mut right) = u32s.partition_mut(..).split_at_mut(2);
+
+        // only reading doesn't flag the component
+        assert_eq!(left.get_mut(0).unwrap().0, 0);
+        left.get_mut(1).unwrap().0 += 10;
+
+        for (_, mut u32) in right.iter_mut() {
+            u32.0 += 10;
+        }
+
+        let (empty, _) = left.split_at_mut(0);
+        assert!(empty.is_empty());
+    });
+
+    world.run(|u32s: View<U32>| {
+        assert!(!u32s.is_modified(entities[0]));
+        assert!(u32s.is_modified(entities[1]));
+        assert!(u32s.is_modified(entities[2]));
+        assert!(u32s.is_modified(entities[3]));
+        assert_eq!(u32s[entities[3]], U32(13));
+    });
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let mut world = World::new();
+
+    world.add_entity(U32(0));
+
+    world.run(|u32s: View<U32>| {
+        u32s.partition(0..2);
+    });
+}
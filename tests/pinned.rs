@@ -0,0 +1,47 @@
+use shipyard::*;
+
+#[derive(Component, Debug, PartialEq)]
+struct RigidBody(u32);
+
+#[test]
+fn stable_address() {
+    let mut world = World::new();
+
+    let entities: Vec<_> = (0..10)
+        .map(|i| world.add_entity((Pinned::new(RigidBody(i)),)))
+        .collect();
+
+    let addresses: Vec<_> = world.run(|bodies: View<Pinned<RigidBody>>| {
+        entities
+            .iter()
+            .map(|&entity| bodies[entity].as_ptr())
+            .collect()
+    });
+
+    world.delete_entity(entities[0]);
+    world.delete_entity(entities[4]);
+    world.run(|mut bodies: ViewMut<Pinned<RigidBody>>| {
+        bodies.sort_unstable_by(|a, b| (**b).0.cmp(&(**a).0));
+
+        for body in (&mut bodies).iter() {
+            (**body).0 += 1;
+        }
+    });
+
+    world.run(|bodies: View<Pinned<RigidBody>>| {
+        assert_eq!(bodies.len(), 8);
+
+        for (i, &entity) in entities.iter().enumerate() {
+            if i == 0 || i == 4 {
+                continue;
+            }
+
+            assert_eq!(bodies[entity].as_ptr(), addresses[i]);
+            assert_eq!(*bodies[entity], RigidBody(i as u32 + 1));
+        }
+    });
+
+    let body = world.remove::<(Pinned<RigidBody>,)>(entities[1]).0.unwrap();
+    assert_eq!(body.as_ptr(), addresses[1]);
+    assert_eq!(body.into_inner(), RigidBody(2));
+}
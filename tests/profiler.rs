@@ -0,0 +1,80 @@
+use shipyard::*;
+
+struct Position;
+impl Component for Position {
+    type Tracking = track::Untracked;
+}
+
+struct Velocity;
+impl Component for Velocity {
+    type Tracking = track::Untracked;
+}
+
+fn sys1(_: View<Position>) {}
+fn sys2(_: ViewMut<Velocity>) {}
+
+#[test]
+fn profile_workload() {
+    let mut world = World::new();
+    world.add_entity((Position, Velocity));
+    world.add_workload(|| (sys1, sys2));
+
+    world.start_profiling();
+    world.run_default_workload().unwrap();
+    world.run_default_workload().unwrap();
+    let profile = world.stop_profiling();
+
+    let count = |kind: ProfileEventKind| {
+        profile
+            .events()
+            .iter()
+            .filter(|event| event.kind == kind)
+            .count()
+    };
+
+    assert_eq!(count(ProfileEventKind::Workload), 2);
+    assert_eq!(count(ProfileEventKind::System), 4);
+    assert_eq!(count(ProfileEventKind::Borrow), 4);
+    #[cfg(feature = "parallel")]
+    assert_eq!(count(ProfileEventKind::Batch), 2);
+
+    assert!(profile
+        .events()
+        .iter()
+        .any(|event| event.kind == ProfileEventKind::System && event.name.ends_with("sys1")));
+
+    let trace = profile.chrome_trace();
+    assert!(trace.starts_with("{\"traceEvents\":["));
+    assert!(trace.contains("\"cat\":\"system\""));
+    assert!(trace.contains("\"cat\":\"borrow\""));
+}
+
+#[test]
+fn not_profiling() {
+    let mut world = World::new();
+    world.add_workload(|| sys1);
+
+    world.run_default_workload().unwrap();
+    assert!(world.stop_profiling().events().is_empty());
+
+    world.start_profiling();
+    let _ = world.stop_profiling();
+
+    world.run_default_workload().unwrap();
+    assert!(world.stop_profiling().events().is_empty());
+}
+
+#[test]
+fn chrome_trace_escapes_names() {
+    let mut world = World::new();
+    Workload::new("\"quoted\"\n")
+        .with_system(sys1)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.start_profiling();
+    world.run_workload("\"quoted\"\n").unwrap();
+    let trace = world.stop_profiling().chrome_trace();
+
+    assert!(trace.contains("\"name\":\"\\\"quoted\\\"\\n\""));
+}
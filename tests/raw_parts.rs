@@ -0,0 +1,156 @@
+use shipyard::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Modification;
+}
+
+#[test]
+fn view_from_raw_parts() {
+    let mut world = World::new();
+
+    let entities = world.bulk_add_entity((0..3).map(U32)).collect::<Vec<_>>();
+
+    world.run(|u32s: ViewMut<U32>| u32s.clear_all_modified());
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        u32s[entities[1]].0 += 10;
+    });
+
+    world.run(|u32s: View<U32>| {
+        let ffi_u32s = unsafe {
+            View::<U32>::from_raw_parts(
+                u32s.as_ptr(),
+                u32s.last_insertion(),
+                u32s.last_modification(),
+                u32s.last_removal_or_deletion(),
+                u32s.current(),
+            )
+        };
+
+        assert_eq!(ffi_u32s.len(), 3);
+        assert_eq!(ffi_u32s[entities[0]], U32(0));
+        assert_eq!(ffi_u32s.get(entities[1]), Ok(&U32(11)));
+        assert_eq!(
+            ffi_u32s.iter().collect::<Vec<_>>(),
+            vec![&U32(0), &U32(11), &U32(2)]
+        );
+        assert_eq!(
+            ffi_u32s.modified().iter().ids().collect::<Vec<_>>(),
+            vec![entities[1]]
+        );
+        assert!(ffi_u32s.get(EntityId::dead()).is_err());
+    });
+}
+
+#[test]
+fn view_from_raw_parts_timestamps() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+
+    world.run(|u32s: ViewMut<U32>| u32s.clear_all_modified());
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        u32s[entity].0 += 1;
+    });
+
+    world.run(|u32s: View<U32>| {
+        assert!(u32s.is_modified(entity));
+
+        // the time window excludes its start, the modification is not part of it
+        let modification = u32s.modification_timestamp(entity).unwrap();
+        let ffi_u32s = unsafe {
+            View::<U32>::from_raw_parts(
+                u32s.as_ptr(),
+                u32s.last_insertion(),
+                modification,
+                u32s.last_removal_or_deletion(),
+                u32s.current(),
+            )
+        };
+
+        assert!(!ffi_u32s.is_modified(entity));
+    });
+}
+
+#[test]
+fn view_from_raw_parts_doesnt_borrow() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+
+    let u32s = world.borrow::<View<U32>>().unwrap();
+    let ffi_u32s = unsafe {
+        View::<U32>::from_raw_parts(
+            u32s.as_ptr(),
+            u32s.last_insertion(),
+            u32s.last_modification(),
+            u32s.last_removal_or_deletion(),
+            u32s.current(),
+        )
+    };
+    let clone = ffi_u32s.clone();
+
+    drop(ffi_u32s);
+    drop(clone);
+
+    // the original view still holds its borrow
+    assert!(world.borrow::<ViewMut<U32>>().is_err());
+
+    drop(u32s);
+
+    let mut u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    u32s[entity].0 += 1;
+}
+
+#[test]
+fn raw_window_from_raw_parts() {
+    let mut world = World::new();
+
+    let entities = world.bulk_add_entity((0..3).map(U32)).collect::<Vec<_>>();
+
+    world.run(|u32s: View<U32>| {
+        let window = u32s.raw_window();
+
+        let ffi_window =
+            unsafe { RawWindow::from_raw_parts(window.ids_ptr(), window.data_ptr(), window.len()) };
+
+        assert_eq!(ffi_window.ids(), &entities[..]);
+        assert_eq!(ffi_window.data(), &[U32(0), U32(1), U32(2)]);
+        assert_eq!(ffi_window.get(entities[2]), Some(&U32(2)));
+        assert_eq!(ffi_window.get(EntityId::dead()), None);
+
+        let empty = unsafe { RawWindow::from_raw_parts(window.ids_ptr(), window.data_ptr(), 0) };
+
+        assert!(empty.is_empty());
+        assert_eq!(empty.get(entities[0]), None);
+    });
+}
+
+#[test]
+fn raw_window_mut_from_raw_parts() {
+    let mut world = World::new();
+
+    let entities = world.bulk_add_entity((0..3).map(U32)).collect::<Vec<_>>();
+
+    world.run(|u32s: ViewMut<U32>| u32s.clear_all_modified());
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        let mut window = u32s.raw_window_mut();
+
+        let mut ffi_window = unsafe {
+            RawWindowMut::from_raw_parts(window.ids_ptr(), window.data_mut_ptr(), window.len())
+        };
+
+        ffi_window.get_mut(entities[1]).unwrap().0 += 10;
+
+        assert_eq!(ffi_window.data(), &[U32(0), U32(11), U32(2)]);
+        assert_eq!(ffi_window.get_mut(EntityId::dead()), None);
+
+        // modifications through raw windows are not tracked
+        assert!(!u32s.is_modified(entities[1]));
+        assert_eq!(u32s[entities[1]], U32(11));
+    });
+}
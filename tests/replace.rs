@@ -0,0 +1,50 @@
+use shipyard::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[derive(Component, Debug, PartialEq, Eq)]
+#[track(Insertion, Modification)]
+struct Name(String);
+
+#[test]
+fn replace() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((Name("Alice".to_string()),));
+    let other = world.add_entity(());
+
+    let insertions = Arc::new(AtomicUsize::new(0));
+    let counter = insertions.clone();
+    world.run(|mut names: ViewMut<Name>| {
+        names.on_insertion(move |_, _| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+    });
+
+    world.run(|names: ViewMut<Name>| {
+        names.clear_all_inserted_and_modified();
+    });
+
+    world.run(|mut names: ViewMut<Name>| {
+        assert_eq!(
+            names.replace(entity, Name("Bob".to_string())),
+            Some(Name("Alice".to_string()))
+        );
+        assert_eq!(names.replace(other, Name("Carol".to_string())), None);
+
+        assert_eq!(names[entity], Name("Bob".to_string()));
+        assert!(!names.contains(other));
+        assert!(names.is_modified(entity));
+        assert!(!names.is_inserted(entity));
+    });
+
+    assert_eq!(
+        world.replace_component(entity, Name("Dave".to_string())),
+        Some(Name("Bob".to_string()))
+    );
+    assert_eq!(
+        world.get::<&Name>(entity).as_deref(),
+        Ok(&&Name("Dave".to_string()))
+    );
+    assert_eq!(insertions.load(Ordering::Relaxed), 0);
+}
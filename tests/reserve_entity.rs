@@ -0,0 +1,143 @@
+use shipyard::*;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn redeem() {
+    let mut world = World::new();
+
+    let ticket = world.reserve_entity().unwrap();
+    let entity = ticket.id();
+
+    assert!(!world.is_entity_alive(entity));
+
+    assert_eq!(world.redeem(ticket, (U32(0),)), Ok(entity));
+    assert!(world.is_entity_alive(entity));
+    assert_eq!(world.get::<&U32>(entity).unwrap().0, 0);
+}
+
+#[test]
+fn add_entity_after_reservation() {
+    let mut world = World::new();
+
+    let ticket0 = world.reserve_entity().unwrap();
+    let ticket1 = world.reserve_entity().unwrap();
+    let entity = world.add_entity((U32(2),));
+
+    assert_eq!(ticket0.id().index(), 0);
+    assert_eq!(ticket1.id().index(), 1);
+    assert_eq!(entity.index(), 2);
+
+    // adding an entity makes the reserved ones alive
+    assert!(world.is_entity_alive(ticket0.id()));
+    assert!(world.is_entity_alive(ticket1.id()));
+
+    let entity1 = ticket1.id();
+    assert_eq!(world.redeem(ticket1, (U32(1),)), Ok(entity1));
+    let entity0 = ticket0.id();
+    assert_eq!(world.redeem(ticket0, ()), Ok(entity0));
+
+    let new_entities: Vec<_> = world.bulk_add_entity((0..2).map(|i| (U32(i),))).collect();
+    let ticket = world.reserve_entity().unwrap();
+
+    assert_eq!(new_entities[0].index(), 3);
+    assert_eq!(new_entities[1].index(), 4);
+    assert_eq!(ticket.id().index(), 5);
+}
+
+#[test]
+fn deleted_before_redeem() {
+    let mut world = World::new();
+
+    let ticket = world.reserve_entity().unwrap();
+    world.clear();
+
+    assert_eq!(
+        world.redeem(ticket, (U32(0),)),
+        Err(error::AddComponent::EntityIsNotAlive)
+    );
+}
+
+#[test]
+fn spawn_after_reservation() {
+    let mut world = World::new();
+
+    let ticket = world.reserve_entity().unwrap();
+
+    assert!(world.spawn(EntityId::new_from_index_and_gen(3, 0)));
+
+    let next = world.reserve_entity().unwrap();
+    assert_eq!(next.id().index(), 4);
+
+    let entity = ticket.id();
+    assert_eq!(world.redeem(ticket, ()), Ok(entity));
+}
+
+// `World` is not `Send` with `thread_local`
+#[cfg(all(feature = "std", not(feature = "thread_local")))]
+#[test]
+fn from_other_threads() {
+    use std::sync::Arc;
+
+    let mut world = Arc::new(World::new());
+
+    let tickets: Vec<_> = (0..4)
+        .map(|_| {
+            let world = world.clone();
+            std::thread::spawn(move || world.reserve_entity().unwrap())
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+
+    let world = Arc::get_mut(&mut world).unwrap();
+
+    let mut indices: Vec<_> = tickets.iter().map(|ticket| ticket.id().index()).collect();
+    indices.sort_unstable();
+    assert_eq!(indices, [0, 1, 2, 3]);
+
+    for (i, ticket) in tickets.into_iter().enumerate() {
+        let entity = ticket.id();
+        assert_eq!(world.redeem(ticket, (U32(i as u32),)), Ok(entity));
+    }
+}
+
+#[test]
+fn deleted_reserved_entity() {
+    let mut world = World::new();
+
+    let ticket = world.reserve_entity().unwrap();
+    assert!(world.delete_entity(ticket.id()));
+
+    assert_eq!(
+        world.redeem(ticket, (U32(0),)),
+        Err(error::AddComponent::EntityIsNotAlive)
+    );
+}
+
+#[test]
+fn entities_borrowed() {
+    let world = World::new();
+
+    let _entities = world.borrow::<EntitiesViewMut>().unwrap();
+
+    assert!(world.reserve_entity().is_err());
+}
+
+#[test]
+fn reserve_after_restore() {
+    let mut world = World::new();
+
+    world.add_entity((U32(0),));
+    let snapshot = world.snapshot::<U32>().unwrap();
+    world.add_entity((U32(1),));
+
+    world.restore(&snapshot);
+
+    assert_eq!(world.reserve_entity().unwrap().id().index(), 1);
+}
@@ -0,0 +1,94 @@
+use shipyard::*;
+
+struct Rolls(Vec<u64>, Vec<u64>);
+impl Unique for Rolls {}
+
+fn roll_a(mut rng: Rng, mut rolls: UniqueViewMut<Rolls>) {
+    rolls.0.push(rng.next_u64());
+}
+
+fn roll_b(mut rng: Rng, mut rolls: UniqueViewMut<Rolls>) {
+    rolls.1.push(rng.next_u64());
+}
+
+fn run(seed: u64, workload: Workload) -> Rolls {
+    let world = World::new();
+    world.add_unique(RngUnique::new(seed));
+    world.add_unique(Rolls(Vec::new(), Vec::new()));
+
+    workload.add_to_world(&world).unwrap();
+
+    world.run_default_workload().unwrap();
+    world.run_default_workload().unwrap();
+
+    world.remove_unique::<Rolls>().unwrap()
+}
+
+#[test]
+fn per_system_streams() {
+    let rolls = run(7, Workload::new("").with_system(roll_a).with_system(roll_b));
+
+    assert_eq!(rolls.0.len(), 2);
+    assert_ne!(rolls.0[0], rolls.0[1]);
+    assert_ne!(rolls.0, rolls.1);
+
+    // the same workload in another world gets the same numbers
+    let again = run(7, Workload::new("").with_system(roll_a).with_system(roll_b));
+    assert_eq!(rolls.0, again.0);
+    assert_eq!(rolls.1, again.1);
+
+    let other_seed = run(8, Workload::new("").with_system(roll_a).with_system(roll_b));
+    assert_ne!(rolls.0, other_seed.0);
+}
+
+#[test]
+fn duplicate_systems() {
+    let rolls = run(7, Workload::new("").with_system(roll_a).with_system(roll_a));
+
+    assert_eq!(rolls.0.len(), 4);
+    assert_ne!(rolls.0[0], rolls.0[1]);
+    assert_ne!(rolls.0[2], rolls.0[3]);
+}
+
+#[test]
+fn multiple_borrows() {
+    fn roll_twice(mut rolls: UniqueViewMut<Rolls>, all_storages: AllStoragesView) {
+        let first = all_storages.run(|mut rng: Rng| rng.next_u64());
+        let second = all_storages.run(|mut rng: Rng| rng.next_u64());
+
+        rolls.0.push(first);
+        rolls.1.push(second);
+    }
+
+    let rolls = run(7, Workload::new("").with_system(roll_twice));
+
+    assert_ne!(rolls.0, rolls.1);
+}
+
+#[test]
+fn range() {
+    let world = World::new();
+    world.add_unique(RngUnique::new(0));
+
+    world.run(|mut rng: Rng| {
+        for _ in 0..100 {
+            assert!((3..5).contains(&rng.range(3..5)));
+            assert!((0.0..1.0).contains(&rng.next_f64()));
+            assert!((0.0..1.0).contains(&rng.next_f32()));
+        }
+    });
+}
+
+#[test]
+fn reseed() {
+    let world = World::new();
+    world.add_unique(RngUnique::new(0));
+
+    let first = world.run(|mut rng: Rng| rng.next_u64());
+    let second = world.run(|mut rng: Rng| rng.next_u64());
+    assert_ne!(first, second);
+
+    world.run(|mut rng_unique: UniqueViewMut<RngUnique>| rng_unique.reseed(0));
+
+    assert_eq!(world.run(|mut rng: Rng| rng.next_u64()), first);
+}
@@ -0,0 +1,70 @@
+use shipyard::*;
+
+#[test]
+fn insert_get_remove() {
+    let mut world = World::new();
+    let mut map = SecondaryMap::new();
+
+    let entity0 = world.add_entity(());
+    let entity1 = world.add_entity(());
+    let entity2 = world.add_entity(());
+
+    assert_eq!(map.insert(entity0, 0), Ok(None));
+    assert_eq!(map.insert(entity1, 1), Ok(None));
+    assert_eq!(map.insert(entity2, 2), Ok(None));
+    assert_eq!(map.insert(entity1, 10), Ok(Some(1)));
+    assert_eq!(map.len(), 3);
+
+    map[entity2] += 10;
+
+    assert_eq!(map.remove(entity0), Some(0));
+    assert_eq!(map.remove(entity0), None);
+    assert_eq!(map.get(entity1), Some(&10));
+    assert_eq!(map.get(entity2), Some(&12));
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        vec![(entity2, &12), (entity1, &10)]
+    );
+}
+
+#[test]
+fn generation() {
+    let mut world = World::new();
+    let mut map = SecondaryMap::new();
+
+    let entity = world.add_entity(());
+    map.insert(entity, 0).unwrap();
+
+    world.delete_entity(entity);
+    let new_entity = world.add_entity(());
+    assert_eq!(entity.index(), new_entity.index());
+
+    assert!(!map.contains(new_entity));
+    assert_eq!(map.insert(new_entity, 1), Ok(None));
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get(entity), None);
+
+    // the entry belongs to a more recent entity
+    assert_eq!(map.insert(entity, 2), Err(2));
+    assert_eq!(map.get(new_entity), Some(&1));
+}
+
+#[test]
+fn retain_alive() {
+    let mut world = World::new();
+    let mut map = SecondaryMap::new();
+
+    let entity0 = world.add_entity(());
+    let entity1 = world.add_entity(());
+    let entity2 = world.add_entity(());
+    map.insert(entity0, 0).unwrap();
+    map.insert(entity1, 1).unwrap();
+    map.insert(entity2, 2).unwrap();
+
+    world.delete_entity(entity0);
+    world.delete_entity(entity2);
+
+    world.run(|entities: EntitiesView| map.retain_alive(&entities));
+
+    assert_eq!(map.iter().collect::<Vec<_>>(), vec![(entity1, &1)]);
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use shipyard::*;
+
+#[derive(Component, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Pos(u32);
+
+#[derive(Component, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Health(u32);
+
+#[derive(Component)]
+struct Particle;
+
+#[test]
+fn archive_roundtrip() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((Pos(0), Health(10)));
+    let archived = world.archive(entity).unwrap();
+
+    let mut save = Vec::new();
+    archived
+        .serialize::<(Pos, Health), _>(&mut serde_json::Serializer::new(&mut save))
+        .unwrap();
+    assert_eq!(save, br#"[0,10]"#);
+
+    let archived = ArchivedEntity::deserialize::<(Pos, Health), _>(
+        &mut serde_json::Deserializer::from_slice(&save),
+    )
+    .unwrap();
+    let restored = world.unarchive(archived);
+
+    assert_eq!(world.get::<&Pos>(restored).as_deref(), Ok(&&Pos(0)));
+    assert_eq!(world.get::<&Health>(restored).as_deref(), Ok(&&Health(10)));
+}
+
+#[test]
+fn archive_missing_component() {
+    let mut world = World::new();
+
+    let entity = world.add_entity((Pos(0), Particle));
+    let archived = world.archive(entity).unwrap();
+
+    let mut save = Vec::new();
+    assert!(archived
+        .serialize::<(Pos,), _>(&mut serde_json::Serializer::new(&mut save))
+        .is_err());
+}
@@ -54,3 +54,18 @@ fn check_roundtrip(entity_id: EntityId, expected: &str) {
     let new_entity_id: EntityId = serde_json::from_str(expected).unwrap();
     assert_eq!(entity_id, new_entity_id);
 }
+
+#[test]
+fn entity_id_set() {
+    let set: EntityIdSet = [
+        EntityId::new_from_index_and_gen(0, 0),
+        EntityId::new_from_index_and_gen(2, 1),
+    ]
+    .into_iter()
+    .collect();
+
+    let expected = "[{\"index\":0,\"gen\":0},{\"index\":2,\"gen\":1}]";
+    assert_eq!(expected, serde_json::to_string(&set).unwrap());
+    let new_set: EntityIdSet = serde_json::from_str(expected).unwrap();
+    assert_eq!(set, new_set);
+}
@@ -0,0 +1,28 @@
+use shipyard::*;
+
+#[allow(unused)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn memory_usage_report_serde() {
+    let mut world = World::new();
+
+    world.add_entity(U32(0));
+
+    let report = world.memory_usage().report().unwrap();
+
+    let json = serde_json::to_string(&report).unwrap();
+    let deserialized: MemoryUsageReport = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.entities, report.entities);
+    assert_eq!(deserialized.components, report.components);
+    assert_eq!(deserialized.total_bytes(), report.total_bytes());
+    assert_eq!(deserialized.storages.len(), report.storages.len());
+    assert_eq!(
+        deserialized.storages[0].storage_name,
+        report.storages[0].storage_name
+    );
+}
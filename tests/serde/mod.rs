@@ -1 +1,4 @@
+mod archive;
 mod entity_id;
+mod memory_usage;
+mod serialize_query;
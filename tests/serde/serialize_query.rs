@@ -0,0 +1,50 @@
+use serde::Serialize;
+use shipyard::*;
+
+#[derive(Component, Serialize)]
+struct Pos(u32);
+
+#[derive(Component, Serialize)]
+struct Health(u32);
+
+#[derive(Component)]
+struct Persistent;
+
+#[derive(Component)]
+struct Particle;
+
+#[test]
+fn serialize_query() {
+    let mut world = World::new();
+
+    world.add_entity((Pos(0), Persistent));
+    world.add_entity((Health(10), Persistent));
+    world.add_entity((Pos(1), Particle));
+    world.add_entity((Persistent,));
+
+    let mut save = Vec::new();
+    world
+        .serialize_query::<(Pos,), (), _>(&mut serde_json::Serializer::new(&mut save))
+        .unwrap();
+    assert_eq!(
+        save,
+        br#"[[{"index":0,"gen":0},[0]],[{"index":2,"gen":0},[1]]]"#
+    );
+
+    let mut save = Vec::new();
+    world
+        .serialize_query::<(Pos, Health), (Persistent,), _>(&mut serde_json::Serializer::new(
+            &mut save,
+        ))
+        .unwrap();
+    assert_eq!(
+        save,
+        br#"[[{"index":0,"gen":0},[0,null]],[{"index":1,"gen":0},[null,10]]]"#
+    );
+
+    world.run(|_: ViewMut<Pos>| {
+        assert!(world
+            .serialize_query::<(Pos,), (), _>(&mut serde_json::Serializer::new(Vec::new()))
+            .is_err());
+    });
+}
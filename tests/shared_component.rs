@@ -0,0 +1,141 @@
+use shipyard::*;
+
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+enum Terrain {
+    Grass,
+    Water,
+    Sand,
+}
+
+#[test]
+fn deduplication() {
+    let mut world = World::new();
+
+    let tiles: Vec<_> = (0..10).map(|_| world.add_entity(())).collect();
+
+    world.run(|mut terrains: SharedComponentViewMut<Terrain>| {
+        for (i, &tile) in tiles.iter().enumerate() {
+            terrains.insert(
+                tile,
+                if i < 7 {
+                    Terrain::Grass
+                } else {
+                    Terrain::Water
+                },
+            );
+        }
+
+        assert_eq!(terrains.len(), 10);
+        assert_eq!(terrains.distinct_len(), 2);
+        assert_eq!(terrains.share_count(tiles[0]), Some(7));
+
+        // overwriting with an equal value doesn't change anything
+        terrains.insert(tiles[0], Terrain::Grass);
+        assert_eq!(terrains.share_count(tiles[0]), Some(7));
+
+        terrains.insert(tiles[7], Terrain::Grass);
+        assert_eq!(terrains.share_count(tiles[0]), Some(8));
+        assert_eq!(terrains.share_count(tiles[8]), Some(2));
+    });
+
+    world.run(|terrains: SharedComponentView<Terrain>| {
+        let mut distinct: Vec<_> = terrains.distinct().collect();
+        distinct.sort_by_key(|&(_, count)| count);
+
+        assert_eq!(distinct, [(&Terrain::Water, 2), (&Terrain::Grass, 8)]);
+        assert_eq!(terrains.iter().count(), 10);
+    });
+}
+
+#[test]
+fn copy_on_write() {
+    let mut world = World::new();
+
+    let first = world.add_entity(());
+    let second = world.add_entity(());
+
+    world.run(|mut terrains: SharedComponentViewMut<Terrain>| {
+        terrains.insert(first, Terrain::Grass);
+        terrains.insert(second, Terrain::Grass);
+
+        assert!(terrains.update(first, |terrain| *terrain = Terrain::Sand));
+
+        assert_eq!(terrains.get(first), Some(&Terrain::Sand));
+        assert_eq!(terrains.get(second), Some(&Terrain::Grass));
+        assert_eq!(terrains.distinct_len(), 2);
+
+        // the last owner is modified in place and merged with the equal value
+        assert!(terrains.update(second, |terrain| *terrain = Terrain::Sand));
+
+        assert_eq!(terrains.distinct_len(), 1);
+        assert_eq!(terrains.share_count(first), Some(2));
+
+        assert!(!terrains.update(EntityId::dead(), |_| {}));
+    });
+}
+
+#[test]
+fn delete_and_clear() {
+    let mut world = World::new();
+
+    let first = world.add_entity(());
+    let second = world.add_entity(());
+
+    world.run(|mut terrains: SharedComponentViewMut<Terrain>| {
+        terrains.insert(first, Terrain::Water);
+        terrains.insert(second, Terrain::Sand);
+    });
+
+    world.delete_entity(first);
+
+    world.run(|terrains: SharedComponentView<Terrain>| {
+        assert!(!terrains.contains(first));
+        assert_eq!(terrains.get(second), Some(&Terrain::Sand));
+        assert_eq!(terrains.distinct_len(), 1);
+    });
+
+    world.clear();
+
+    world.run(|terrains: SharedComponentView<Terrain>| {
+        assert!(terrains.is_empty());
+        assert_eq!(terrains.distinct_len(), 0);
+    });
+}
+
+#[test]
+fn overwrite_previous_generation() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(());
+    world.run(|mut terrains: SharedComponentViewMut<Terrain>| {
+        terrains.insert(entity, Terrain::Water);
+    });
+
+    // the entity is deleted without the storage knowing about it
+    world
+        .borrow::<EntitiesViewMut>()
+        .unwrap()
+        .delete_unchecked(entity);
+    let new_entity = world.add_entity(());
+    assert_eq!(new_entity.index(), entity.index());
+
+    world.run(|mut terrains: SharedComponentViewMut<Terrain>| {
+        terrains.insert(new_entity, Terrain::Sand);
+
+        assert_eq!(terrains.len(), 1);
+        assert_eq!(terrains.distinct_len(), 1);
+        assert_eq!(
+            terrains.distinct().collect::<Vec<_>>(),
+            [(&Terrain::Sand, 1)]
+        );
+    });
+}
+
+#[test]
+fn borrow_conflict() {
+    let world = World::new();
+
+    let _terrains = world.borrow::<SharedComponentViewMut<Terrain>>().unwrap();
+
+    assert!(world.borrow::<SharedComponentView<Terrain>>().is_err());
+}
@@ -0,0 +1,87 @@
+use shipyard::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Mesh(u32);
+impl Component for Mesh {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn share_storage() {
+    let mut assets = World::new();
+    let mesh = assets.add_entity(Mesh(0));
+
+    let mut room1 = World::builder().share_storage::<Mesh>(&assets).build();
+    let room2 = World::builder().share_storage::<Mesh>(&assets).build();
+
+    room1.run(|mut meshes: ViewMut<Mesh>| {
+        meshes[mesh].0 = 1;
+    });
+
+    assets.run(|meshes: View<Mesh>| assert_eq!(meshes.get(mesh), Ok(&Mesh(1))));
+    room2.run(|meshes: View<Mesh>| assert_eq!(meshes.get(mesh), Ok(&Mesh(1))));
+
+    let meshes = room2.borrow::<View<Mesh>>().unwrap();
+    assert!(room1.borrow::<ViewMut<Mesh>>().is_err());
+    drop(meshes);
+
+    // Operations on all storages leave shared storages untouched
+    let entity = room1.add_entity(U32(0));
+    assert!(room1.delete_entity(entity));
+    room1.clear();
+    assets.clear();
+    room2.run(|meshes: View<Mesh>| assert_eq!(meshes.len(), 1));
+
+    drop(assets);
+    drop(room1);
+
+    room2.run(|meshes: View<Mesh>| assert_eq!(meshes.get(mesh), Ok(&Mesh(1))));
+}
+
+#[test]
+fn share_missing_storage() {
+    let assets = World::new();
+
+    let mut room = World::builder().share_storage::<Mesh>(&assets).build();
+    let entity = room.add_entity(());
+
+    room.run(|mut meshes: ViewMut<Mesh>| meshes.add_component_unchecked(entity, Mesh(2)));
+
+    assets.run(|meshes: View<Mesh>| assert_eq!(meshes.get(entity), Ok(&Mesh(2))));
+
+    drop(assets);
+
+    // `room` is the only owner left, it can access the storage directly again
+    room.delete_entity(entity);
+    room.run(|meshes: View<Mesh>| assert!(meshes.is_empty()));
+}
+
+#[test]
+fn add_and_remove_shared_component() {
+    let mut assets = World::new();
+
+    let mut room = World::builder().share_storage::<Mesh>(&assets).build();
+    let entity = room.add_entity(Mesh(0));
+    room.add_component(entity, Mesh(1));
+
+    assets.run(|meshes: View<Mesh>| assert_eq!(meshes.get(entity), Ok(&Mesh(1))));
+
+    assert_eq!(room.remove::<Mesh>(entity), Some(Mesh(1)));
+    assets.run(|meshes: View<Mesh>| assert!(meshes.is_empty()));
+
+    let meshes = assets.borrow::<View<Mesh>>().unwrap();
+    let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        room.add_component(entity, Mesh(2));
+    }));
+    assert!(panic.is_err());
+    drop(meshes);
+
+    let mesh = assets.add_entity(Mesh(3));
+    room.run(|meshes: View<Mesh>| assert_eq!(meshes.get(mesh), Ok(&Mesh(3))));
+}
@@ -0,0 +1,79 @@
+use shipyard::*;
+
+#[derive(Clone, PartialEq, Debug)]
+struct Pos(f32);
+impl Component for Pos {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Health(u32);
+impl Component for Health {
+    type Tracking = track::Modification;
+}
+
+#[derive(PartialEq, Debug)]
+struct Sprite(u32);
+impl Component for Sprite {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn restore() {
+    let mut world = World::new();
+
+    let e0 = world.add_entity((Pos(0.0), Health(10), Sprite(0)));
+    let e1 = world.add_entity((Pos(1.0), Sprite(1)));
+
+    let snapshot = world.snapshot::<(Pos, Health)>().unwrap();
+    assert_eq!(snapshot.storage_ids().count(), 2);
+
+    world.get::<&mut Pos>(e0).unwrap().0 = 5.0;
+    world.delete_component::<Health>(e0);
+    world.delete_entity(e1);
+    let e2 = world.add_entity((Pos(2.0), Sprite(2)));
+    world.get::<&mut Sprite>(e0).unwrap().0 = 10;
+
+    world.restore(&snapshot);
+
+    assert!(world.is_entity_alive(e0));
+    assert!(world.is_entity_alive(e1));
+    assert!(!world.is_entity_alive(e2));
+
+    assert_eq!(world.get::<&Pos>(e0).unwrap().0, 0.0);
+    assert_eq!(world.get::<&Health>(e0).unwrap().0, 10);
+    assert_eq!(world.get::<&Pos>(e1).unwrap().0, 1.0);
+    // storages outside of the snapshot are untouched
+    assert_eq!(world.get::<&Sprite>(e0).unwrap().0, 10);
+    assert!(world.get::<&Sprite>(e1).is_err());
+    assert_eq!(world.storage_len::<Sprite>(), 1);
+
+    // the snapshot can be restored multiple times
+    world.get::<&mut Pos>(e0).unwrap().0 = 5.0;
+    world.restore(&snapshot);
+    assert_eq!(world.get::<&Pos>(e0).unwrap().0, 0.0);
+
+    let e3 = world.add_entity(Pos(3.0));
+    assert_eq!(world.get::<&Pos>(e3).unwrap().0, 3.0);
+    assert_eq!(world.entity_count(), 3);
+}
+
+#[test]
+fn tracking() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(Health(10));
+
+    let snapshot = world.snapshot::<Health>().unwrap();
+
+    world.run(|healths: ViewMut<Health>| {
+        healths.clear_all_modified();
+    });
+
+    world.restore(&snapshot);
+
+    world.run(|healths: View<Health>| {
+        assert!(!healths.is_modified(entity));
+        assert_eq!(healths.get(entity), Ok(&Health(10)));
+    });
+}
@@ -0,0 +1,133 @@
+use shipyard::*;
+
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn stats() {
+    let mut world = World::new();
+
+    let stats = world.borrow::<View<U32>>().unwrap().sparse_stats();
+    assert_eq!(stats.pages, 0);
+    assert_eq!(stats.allocated_pages, 0);
+    assert_eq!(stats.load_factor(), 1.0);
+
+    let page_size = stats.page_size;
+
+    let entities: Vec<_> = (0..page_size as u32 * 3)
+        .map(|i| world.add_entity(U32(i)))
+        .collect();
+
+    // empties the middle page and half of the last one
+    for &entity in &entities[page_size..page_size * 2 + page_size / 2] {
+        world.delete_entity(entity);
+    }
+
+    let stats = world.borrow::<View<U32>>().unwrap().sparse_stats();
+    assert_eq!(stats.pages, 3);
+    assert_eq!(stats.allocated_pages, 3);
+    assert_eq!(stats.holes, 1);
+    assert_eq!(stats.occupied, page_size + page_size / 2);
+    assert_eq!(stats.densest_page_occupancy, page_size);
+    assert_eq!(stats.load_factor(), 0.5);
+}
+
+#[test]
+fn compact_sparse() {
+    let mut world = World::new();
+
+    let page_size = world
+        .borrow::<View<U32>>()
+        .unwrap()
+        .sparse_stats()
+        .page_size;
+
+    let entities: Vec<_> = (0..page_size as u32 * 3)
+        .map(|i| world.add_entity(U32(i)))
+        .collect();
+
+    for &entity in &entities[page_size..] {
+        world.delete_entity(entity);
+    }
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        u32s.compact_sparse();
+
+        let stats = u32s.sparse_stats();
+        assert_eq!(stats.pages, 1);
+        assert_eq!(stats.allocated_pages, 1);
+        assert_eq!(stats.holes, 0);
+        assert_eq!(stats.occupied, page_size);
+    });
+
+    assert_eq!(world.get::<&U32>(entities[0]).unwrap().0, 0);
+    assert!(world.get::<&U32>(entities[page_size]).is_err());
+
+    // reallocates the freed pages
+    let entity = world.add_entity(U32(10));
+    assert_eq!(world.get::<&U32>(entity).unwrap().0, 10);
+}
+
+#[test]
+fn page_size() {
+    let mut world = World::new();
+
+    let entities: Vec<_> = (0..100).map(|_| world.add_entity(())).collect();
+
+    for (i, &entity) in entities.iter().enumerate().skip(90) {
+        world.add_component(entity, U32(i as u32));
+    }
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        let default_page_size = u32s.sparse_page_size();
+
+        // pages before the first used one are not stored
+        let stats = u32s.sparse_stats();
+        assert_eq!(
+            stats.pages,
+            99 / default_page_size - 90 / default_page_size + 1
+        );
+
+        u32s.set_sparse_page_size(Some(4));
+        assert_eq!(u32s.sparse_page_size(), 4);
+
+        let stats = u32s.sparse_stats();
+        assert_eq!(stats.pages, 99 / 4 - 90 / 4 + 1);
+        assert_eq!(stats.allocated_pages, stats.pages);
+        assert_eq!(stats.occupied, 10);
+
+        for (i, &entity) in entities.iter().enumerate() {
+            if i < 90 {
+                assert!(!u32s.contains(entity));
+            } else {
+                assert_eq!(u32s[entity].0, i as u32);
+            }
+        }
+
+        u32s.set_sparse_page_size(None);
+        assert_eq!(u32s.sparse_page_size(), default_page_size);
+    });
+
+    // entities before the first page
+    world.add_component(entities[0], U32(0));
+    world.delete_component::<U32>(entities[95]);
+
+    world.run(|u32s: View<U32>| {
+        assert_eq!(u32s[entities[0]].0, 0);
+        assert!(!u32s.contains(entities[95]));
+        assert_eq!(
+            (&u32s).iter().map(|u| u.0).sum::<u32>(),
+            (90..100).sum::<u32>() - 95
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "Sparse page size has to be a power of two, got 3.")]
+fn page_size_power_of_two() {
+    let world = World::new();
+
+    world.run(|mut u32s: ViewMut<U32>| u32s.set_sparse_page_size(Some(3)));
+}
@@ -0,0 +1,91 @@
+use shipyard::info::TypeId;
+use shipyard::{track, Component, IntoWorkload, SparseSet, StorageId, View, ViewMut, World};
+
+struct U32(#[allow(unused)] u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+struct Usize(#[allow(unused)] usize);
+impl Component for Usize {
+    type Tracking = track::Untracked;
+}
+
+fn sys1(_: View<U32>, _: ViewMut<Usize>) {}
+fn sys2(_: ViewMut<Usize>) {}
+
+#[test]
+fn of_name() {
+    assert_eq!(TypeId::of_name("U32"), TypeId::of_name("U32"));
+    assert_ne!(TypeId::of_name("U32"), TypeId::of_name("Usize"));
+    assert_eq!(
+        StorageId::of_name("U32"),
+        StorageId::TypeId(TypeId::of_name("U32"))
+    );
+}
+
+#[test]
+fn workloads_info() {
+    let mut world = World::new();
+
+    world
+        .stable_names_mut()
+        .register_component::<U32>("U32")
+        .register_system(sys1, "sys1");
+
+    world.add_workload(|| (sys1, sys2).into_workload());
+
+    let workloads_info = world.workloads_info();
+    let workload_info = workloads_info.0.values().next().unwrap();
+
+    let sys1_info = workload_info.batch_info[0].systems().next().unwrap();
+    assert_eq!(sys1_info.name, "sys1");
+    assert_eq!(sys1_info.type_id, TypeId::of_name("sys1"));
+
+    let u32_info = sys1_info
+        .borrow
+        .iter()
+        .find(|type_info| type_info.name == "U32")
+        .unwrap();
+    assert_eq!(u32_info.storage_id, StorageId::of_name("U32"));
+
+    let usize_info = sys1_info
+        .borrow
+        .iter()
+        .find(|type_info| type_info.name != "U32")
+        .unwrap();
+    assert_eq!(usize_info.storage_id, StorageId::of::<SparseSet<Usize>>());
+
+    let sys2_info = workload_info.batch_info[1].systems().next().unwrap();
+    assert_ne!(sys2_info.name, "sys1");
+}
+
+#[test]
+fn lookup() {
+    let mut world = World::new();
+
+    world.stable_names_mut().register_component::<U32>("U32");
+
+    assert_eq!(
+        world.stable_names().storage_id("U32"),
+        Some(StorageId::of::<SparseSet<U32>>())
+    );
+    assert_eq!(world.stable_names().storage_id("Usize"), None);
+    assert_eq!(
+        world
+            .stable_names()
+            .stable_storage_id(StorageId::of::<SparseSet<Usize>>()),
+        StorageId::of::<SparseSet<Usize>>()
+    );
+}
+
+#[test]
+#[should_panic(expected = "\"U32\" is already the stable name of another type.")]
+fn duplicate_name() {
+    let mut world = World::new();
+
+    world
+        .stable_names_mut()
+        .register_component::<U32>("U32")
+        .register_component::<Usize>("U32");
+}
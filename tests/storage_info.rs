@@ -0,0 +1,57 @@
+use shipyard::*;
+
+#[derive(Component)]
+struct U32;
+
+#[derive(Component)]
+struct F32;
+
+fn info_of<T: Component + Send + Sync>(world: &World) -> StorageInfo {
+    world
+        .storages_info()
+        .into_iter()
+        .find(|info| info.id == StorageId::of::<SparseSet<T>>())
+        .unwrap()
+}
+
+#[test]
+fn storages_info() {
+    let mut world = World::new();
+
+    world.add_entity((U32,));
+
+    assert!(info_of::<U32>(&world).name.unwrap().contains("U32"));
+    assert!(world
+        .storages_info()
+        .iter()
+        .all(|info| info.id != StorageId::of::<SparseSet<F32>>()));
+
+    let _u32s = world.borrow::<ViewMut<U32>>().unwrap();
+    assert_eq!(info_of::<U32>(&world).name, None);
+}
+
+#[cfg(feature = "storage_location")]
+#[test]
+fn created_at() {
+    let mut world = World::new();
+
+    let add_line = line!() + 1;
+    world.add_entity((U32,));
+    let run_line = line!() + 1;
+    world.run(|_: View<F32>| {});
+
+    let created_at = info_of::<U32>(&world).created_at.unwrap();
+    assert_eq!(created_at.file(), file!());
+    assert_eq!(created_at.line(), add_line);
+
+    let created_at = info_of::<F32>(&world).created_at.unwrap();
+    assert_eq!(created_at.file(), file!());
+    assert_eq!(created_at.line(), run_line);
+
+    let entities_info = world
+        .storages_info()
+        .into_iter()
+        .find(|info| info.id == StorageId::of::<Entities>())
+        .unwrap();
+    assert_eq!(entities_info.created_at, None);
+}
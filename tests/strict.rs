@@ -0,0 +1,77 @@
+use shipyard::*;
+
+struct Pos(f32);
+impl Component for Pos {
+    type Tracking = track::Untracked;
+}
+
+struct Vel;
+impl Component for Vel {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Clone)]
+struct Health;
+impl Component for Health {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn missing_storage() {
+    let mut world = World::new();
+
+    assert!(!world.is_strict());
+    world.set_strict(true);
+    assert!(world.is_strict());
+
+    assert!(matches!(
+        world.borrow::<View<Pos>>(),
+        Err(error::GetStorage::MissingStorage { .. })
+    ));
+    assert!(matches!(
+        world.borrow::<ViewMut<Pos>>(),
+        Err(error::GetStorage::MissingStorage { .. })
+    ));
+
+    world.add_storage::<Pos>();
+
+    assert!(world.borrow::<View<Pos>>().unwrap().is_empty());
+
+    world.set_strict(false);
+
+    assert!(world.borrow::<View<Vel>>().is_ok());
+}
+
+#[test]
+fn add_entity_creates_storages() {
+    let mut world = World::new();
+    world.set_strict(true);
+
+    let entity = world.add_entity((Pos(1.0), Vel));
+
+    world.run(|positions: View<Pos>, velocities: View<Vel>| {
+        assert_eq!(positions[entity].0, 1.0);
+        assert!(velocities.contains(entity));
+    });
+}
+
+#[test]
+fn internal_paths_create_storages() {
+    let mut world = World::new();
+    world.set_strict(true);
+
+    let entities = world
+        .bulk_add_entity([(Pos(0.0), Vel), (Pos(1.0), Vel)])
+        .collect::<Vec<_>>();
+    world.bulk_add_entity([Pos(2.0)]).for_each(drop);
+
+    let snapshot = world.snapshot::<Health>().unwrap();
+    world.restore(&snapshot);
+
+    assert!(world.view_labeled::<Pos>("label").unwrap().is_empty());
+
+    world.run(|positions: View<Pos>, velocities: View<Vel>| {
+        assert_eq!(positions.len(), 3);
+        assert!(velocities.contains(entities[1]));
+    });
+}
@@ -0,0 +1,61 @@
+use shipyard::*;
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct Position(u32);
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct Velocity(u32);
+
+#[test]
+fn swap_storage() {
+    let mut front = World::new();
+    let mut back = World::new();
+
+    let entity0 = front.add_entity((Position(0), Velocity(1)));
+    let entity1 = front.add_entity((Position(1),));
+    back.spawn(entity0);
+    back.spawn(entity1);
+    back.add_component(entity1, (Position(11),));
+
+    front.swap_storage::<Position>(&mut back);
+
+    assert!(front.get::<&Position>(entity0).is_err());
+    assert_eq!(
+        front.get::<&Position>(entity1).as_deref(),
+        Ok(&&Position(11))
+    );
+    assert_eq!(back.get::<&Position>(entity0).as_deref(), Ok(&&Position(0)));
+    assert_eq!(back.get::<&Position>(entity1).as_deref(), Ok(&&Position(1)));
+
+    // Other storages are left untouched
+    assert_eq!(
+        front.get::<&Velocity>(entity0).as_deref(),
+        Ok(&&Velocity(1))
+    );
+    assert!(back.get::<&Velocity>(entity0).is_err());
+
+    // Missing storages are created empty
+    front.swap_storage::<Velocity>(&mut back);
+
+    assert_eq!(front.storage_len::<Velocity>(), 0);
+    assert_eq!(back.get::<&Velocity>(entity0).as_deref(), Ok(&&Velocity(1)));
+}
+
+#[test]
+fn swap_shared_storage() {
+    let mut assets = World::new();
+    let entity = assets.add_entity((Position(0),));
+
+    let mut room = World::builder().share_storage::<Position>(&assets).build();
+
+    room.swap_storage::<Position>(&mut World::new());
+
+    assert_eq!(assets.storage_len::<Position>(), 0);
+
+    let _positions = assets.borrow::<View<Position>>().unwrap();
+    let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        room.swap_storage::<Position>(&mut World::new());
+    }));
+    assert!(panic.is_err());
+    assert!(!assets.borrow::<View<Position>>().unwrap().contains(entity));
+}
@@ -30,3 +30,36 @@ fn bad_systems() {
         Some(error::InvalidSystem::AllStorages)
     );
 }
+
+#[test]
+fn for_each_system() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Counter(u32);
+    impl Component for Counter {
+        type Tracking = track::Untracked;
+    }
+    impl Unique for Counter {}
+
+    fn add(
+        (entity, value): (EntityId, u32),
+        u32s: &mut ViewMut<U32>,
+        counter: &mut UniqueViewMut<Counter>,
+    ) {
+        u32s[entity].0 += value;
+        counter.0 += 1;
+    }
+
+    let mut world = World::new();
+    world.add_unique(Counter(0));
+
+    let entity1 = world.add_entity(U32(0));
+    let entity2 = world.add_entity(U32(10));
+
+    world.for_each_system([(entity1, 1), (entity2, 2), (entity1, 3)], add);
+
+    world.run(|u32s: View<U32>, counter: UniqueView<Counter>| {
+        assert_eq!(u32s[entity1].0, 4);
+        assert_eq!(u32s[entity2].0, 12);
+        assert_eq!(*counter, Counter(3));
+    });
+}
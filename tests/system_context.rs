@@ -0,0 +1,103 @@
+use shipyard::*;
+
+struct U32;
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Default)]
+struct Contexts(Vec<(String, String, Option<usize>)>);
+impl Unique for Contexts {}
+
+fn record(context: SystemContext, mut contexts: UniqueViewMut<Contexts>) {
+    contexts.0.push((
+        format!("{:?}", context.system().unwrap()),
+        format!("{:?}", context.workload().unwrap()),
+        context.batch(),
+    ));
+}
+
+fn write_u32(_: ViewMut<U32>) {}
+
+#[test]
+fn workload() {
+    let world = World::new();
+    world.add_unique(Contexts::default());
+
+    Workload::new("Update")
+        .with_system(write_u32)
+        .with_system(record)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("Update").unwrap();
+
+    let contexts = world.borrow::<UniqueView<Contexts>>().unwrap();
+    assert_eq!(contexts.0.len(), 1);
+
+    let (system, workload, batch) = &contexts.0[0];
+    assert!(system.ends_with("record"));
+    assert_eq!(workload, "Update");
+    // `write_u32` and `record` can run in parallel
+    #[cfg(feature = "parallel")]
+    assert_eq!(*batch, Some(0));
+    #[cfg(not(feature = "parallel"))]
+    assert_eq!(*batch, Some(1));
+}
+
+#[test]
+fn tick() {
+    let world = World::new();
+
+    let first = world.run(|context: SystemContext| context.tick());
+    let second = world.run(|context: SystemContext| context.tick());
+
+    assert!(first.is_older_than(second));
+}
+
+#[test]
+fn outside_workload() {
+    let world = World::new();
+
+    world.run(|context: SystemContext| {
+        assert!(context.system().is_none());
+        assert!(context.workload().is_none());
+        assert_eq!(context.batch(), None);
+    });
+}
+
+#[test]
+fn nested_run() {
+    let world: &'static World = Box::leak(Box::new(World::new()));
+
+    Workload::new("Update")
+        .with_system(move |outer: SystemContext| {
+            assert!(outer.system().is_some());
+
+            world.run(|context: SystemContext| {
+                assert!(context.system().is_none());
+                assert!(context.workload().is_none());
+                assert_eq!(context.batch(), None);
+            });
+        })
+        .add_to_world(world)
+        .unwrap();
+
+    world.run_workload("Update").unwrap();
+}
+
+#[test]
+fn scheduled_workload() {
+    let world = World::new();
+
+    let (scheduled, _) = Workload::new("Update")
+        .with_system(|context: SystemContext| {
+            assert!(context.system().is_none());
+            assert!(context.workload().is_none());
+            assert_eq!(context.batch(), Some(0));
+        })
+        .build()
+        .unwrap();
+
+    scheduled.run_with_world(&world).unwrap();
+}
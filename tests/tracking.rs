@@ -1,4 +1,7 @@
-use shipyard::{error::GetStorage, track, Component, View, ViewMut, World};
+use shipyard::{
+    error::GetStorage, iter, track, Component, Get, InsertedOrModified, IntoIter, View, ViewMut,
+    World,
+};
 
 struct Unit;
 impl Component for Unit {
@@ -10,6 +13,11 @@ impl Component for UnitInsert {
     type Tracking = track::Insertion;
 }
 
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::InsertionAndModification;
+}
+
 #[test]
 fn runtime_insertion_tracking() {
     let mut world = World::new();
@@ -148,3 +156,108 @@ fn workload_enable_runtime_removal_tracking() {
     assert!(world.borrow::<View<Unit, track::Removal>>().is_ok());
     assert!(world.borrow::<ViewMut<Unit, track::Removal>>().is_ok());
 }
+
+#[test]
+fn tracking_timestamps() {
+    let mut world = World::new();
+
+    let before_insertion = world.get_tracking_timestamp();
+    let entity = world.add_entity(U32(0));
+    let other = world.add_entity(());
+
+    world.run(|mut u32s: ViewMut<U32>| {
+        let inserted_at = u32s.insertion_timestamp(entity).unwrap();
+        assert!(inserted_at.get() >= before_insertion.get());
+        assert!(u32s.insertion_timestamp(other).is_none());
+
+        assert!(u32s.modification_timestamp(entity).is_none());
+
+        (&mut u32s).get(entity).unwrap().0 += 1;
+
+        let modified_at = u32s.modification_timestamp(entity).unwrap();
+        assert!(modified_at.get() > inserted_at.get());
+    });
+
+    let now = world.get_tracking_timestamp();
+    world.run(|u32s: View<U32>| {
+        let modified_at = u32s.modification_timestamp(entity).unwrap();
+        assert!(now.get() > modified_at.get());
+    });
+}
+
+#[test]
+fn tick() {
+    let mut world = World::new();
+
+    let last = world.tick();
+    let entity = world.add_entity(U32(0));
+    let current = world.tick();
+    assert!(current.elapsed_since(last) > 0);
+
+    world.run(|u32s: ViewMut<U32>| {
+        let inserted_at = u32s.insertion_timestamp(entity).unwrap();
+        assert!(inserted_at.is_within(last, current));
+
+        u32s.clear_all_inserted_and_modified();
+    });
+
+    // clearing tracking never rewinds the counter
+    let after_clear = world.tick();
+    assert!(current.is_older_than(after_clear));
+}
+
+#[test]
+fn or_tracking_filters() {
+    fn changed<'a, 'v>(
+        u32s: &'a View<'v, U32>,
+    ) -> iter::IterOf<InsertedOrModified<&'a View<'v, U32>>> {
+        u32s.inserted_or_modified().iter()
+    }
+
+    let mut world = World::new();
+
+    world.add_entity((U32(0),));
+    world.add_entity((UnitInsert,));
+    world.add_entity((Unit,));
+
+    world.run(|u32s: ViewMut<U32>, units: View<UnitInsert>| {
+        assert_eq!(
+            (u32s.inserted_or_modified() | units.inserted())
+                .iter()
+                .count(),
+            2
+        );
+        assert_eq!((u32s.modified() | units.inserted()).iter().count(), 1);
+    });
+
+    world.run(|u32s: View<U32>| {
+        assert_eq!(changed(&u32s).count(), 1);
+    });
+}
+
+#[test]
+fn mut_iteration_flags_on_write() {
+    let mut world = World::new();
+
+    let first = world.add_entity((U32(0),));
+    let second = world.add_entity((U32(1), UnitInsert));
+
+    world.run(|mut u32s: ViewMut<U32>, units: View<UnitInsert>| {
+        for mut u32 in u32s.inserted_mut().iter() {
+            if u32.0 == 0 {
+                u32.0 += 10;
+            }
+        }
+
+        for (u32, _) in (u32s.inserted_mut(), &units).iter() {
+            assert_eq!(u32.0, 1);
+        }
+
+        for u32 in (&mut u32s).iter() {
+            assert!(u32.0 < 20);
+        }
+
+        assert!(u32s.is_modified(first));
+        assert!(!u32s.is_modified(second));
+    });
+}
@@ -159,10 +159,10 @@ fn non_send_remove() {
         if let Some(shipyard::error::UniqueRemove::StorageBorrow(infos)) =
             world.remove_unique::<USIZE>().err()
         {
-            assert_eq!(
+            assert!(matches!(
                 infos,
-                (type_name::<USIZE>(), shipyard::error::Borrow::WrongThread)
-            );
+                (name, shipyard::error::Borrow::WrongThread { .. }) if name == type_name::<USIZE>()
+            ));
         } else {
             panic!()
         }
@@ -171,6 +171,49 @@ fn non_send_remove() {
     .unwrap();
 }
 
+#[test]
+#[cfg(feature = "thread_local")]
+fn duplicate_unique_thread_safety() {
+    let world = World::new();
+
+    world.add_unique(USIZE(0));
+    let location = std::panic::Location::caller();
+    // same thread safety, the unique is replaced
+    world.add_unique(USIZE(1));
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 1);
+
+    let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        world.add_unique_non_send(USIZE(2));
+    }))
+    .unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+
+    assert!(message.starts_with(&format!(
+        "{} unique was added as Send + Sync at {}:{}:",
+        type_name::<USIZE>(),
+        location.file(),
+        location.line() + 2
+    )));
+    assert!(message.ends_with("it can't be added again as NonSend."));
+    assert_eq!(world.borrow::<UniqueView<USIZE>>().unwrap().0, 1);
+}
+
+#[test]
+#[cfg(feature = "thread_local")]
+fn try_add_unique_thread_safety() {
+    let world = World::new();
+
+    world.add_unique_non_sync(USIZE(0));
+
+    let err = world.try_add_unique_non_send_sync(USIZE(1)).unwrap_err();
+    assert_eq!(err.existing, shipyard::error::ThreadSafety::NonSync);
+    assert_eq!(err.added, shipyard::error::ThreadSafety::NonSendSync);
+
+    // same thread safety, the unique is kept
+    assert!(world.try_add_unique_non_sync(USIZE(2)).is_ok());
+    assert_eq!(world.borrow::<NonSync<UniqueView<USIZE>>>().unwrap().0, 0);
+}
+
 #[test]
 fn unique_or_default() {
     let world = World::new();
@@ -211,3 +254,77 @@ fn unique_or_init_mut() {
 
     world.run(|u: UniqueOrInitViewMut<USIZE>| assert_eq!(**u.get().unwrap(), USIZE(11)));
 }
+
+#[test]
+fn erased() {
+    let mut world = World::new();
+    world.add_unique(USIZE(0));
+    world.add_entity((USIZE(1),));
+
+    let all_storages = world.all_storages().unwrap();
+
+    let unique_id = StorageId::of::<UniqueStorage<USIZE>>();
+    assert_eq!(all_storages.unique_ids(), vec![unique_id]);
+
+    assert_eq!(
+        all_storages
+            .get_unique_erased(unique_id)
+            .unwrap()
+            .downcast_ref::<USIZE>(),
+        Some(&USIZE(0))
+    );
+
+    all_storages
+        .get_unique_erased_mut(unique_id)
+        .unwrap()
+        .downcast_mut::<USIZE>()
+        .unwrap()
+        .0 += 1;
+    assert_eq!(*all_storages.get_unique::<&USIZE>().unwrap(), USIZE(1));
+
+    let _borrow = all_storages.get_unique_erased(unique_id).unwrap();
+    assert!(matches!(
+        all_storages.get_unique_erased_mut(unique_id).err(),
+        Some(error::GetStorage::StorageBorrow { .. })
+    ));
+
+    assert!(matches!(
+        all_storages
+            .get_unique_erased(StorageId::of::<SparseSet<USIZE>>())
+            .err(),
+        Some(error::GetStorage::MissingStorage { .. })
+    ));
+}
+
+#[test]
+fn uniques_info() {
+    #[derive(Unique)]
+    struct Map([u64; 8]);
+
+    let mut world = World::new();
+    world.add_unique(USIZE(0));
+    world.add_unique(Map([0; 8]));
+    world.add_entity((USIZE(1),));
+
+    let uniques_info = world.uniques_info();
+    assert_eq!(uniques_info.len(), 2);
+    assert!(uniques_info
+        .iter()
+        .all(|info| info.is_send && info.is_sync && info.last_modified.is_none()));
+
+    let map_info = |world: &World| {
+        world
+            .uniques_info()
+            .into_iter()
+            .find(|info| info.name == type_name::<Map>())
+            .unwrap()
+    };
+    assert_eq!(map_info(&world).size_bytes, 64);
+
+    world.run(|mut map: UniqueViewMut<Map>| map.0[0] = 1);
+    let modified_at = map_info(&world).last_modified.unwrap();
+    assert!(modified_at.is_older_than(world.get_tracking_timestamp()));
+
+    let _map = world.borrow::<UniqueViewMut<Map>>().unwrap();
+    assert_eq!(world.uniques_info().len(), 1);
+}
@@ -249,3 +249,1229 @@ fn skip_first() {
 
     world.run_default_workload().unwrap();
 }
+
+#[test]
+fn on_error() {
+    fn fail() -> Result<(), &'static str> {
+        Err("fail")
+    }
+
+    fn increment(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("skip")
+        .with_try_system(fail)
+        .with_system(increment)
+        .on_error(|_, _, _| ErrorPolicy::Skip)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("retry")
+        .with_try_system(fail)
+        .on_error(|_, _, retries| {
+            if retries < 3 {
+                ErrorPolicy::Retry
+            } else {
+                ErrorPolicy::Abort
+            }
+        })
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_workload("skip").unwrap();
+    assert_eq!(world.get_unique::<&U32>().unwrap().0, 1);
+
+    assert!(world.run_workload("retry").is_err());
+}
+
+#[test]
+fn error_context() {
+    use shipyard::error::ErrorContext;
+    use std::error::Error;
+
+    fn fail() -> Result<(), &'static str> {
+        Err("missing file")
+    }
+
+    fn load() -> Result<(), error::Context> {
+        fail().context("loading level 3")
+    }
+
+    let world = World::new();
+
+    Workload::new("inner")
+        .with_try_system(load)
+        .add_to_world(&world)
+        .unwrap();
+
+    let err = world.run_workload("inner").unwrap_err();
+    let run = err.source().unwrap();
+    let context = run.source().unwrap();
+    assert_eq!(context.to_string(), "loading level 3");
+    assert_eq!(context.source().unwrap().to_string(), "missing file");
+
+    // Errors from nested workloads keep the whole chain
+    let err = world
+        .run_workload("inner")
+        .with_context(|| format!("running {}", "inner"))
+        .unwrap_err();
+
+    let mut chain = Vec::new();
+    let mut source: Option<&dyn Error> = Some(&err);
+    while let Some(err) = source {
+        chain.push(err.to_string());
+        source = err.source();
+    }
+
+    assert_eq!(chain[0], "running inner");
+    assert_eq!(chain[chain.len() - 2], "loading level 3");
+    assert_eq!(chain[chain.len() - 1], "missing file");
+}
+
+#[test]
+fn run_if_info() {
+    fn is_odd(u32: UniqueView<U32>) -> bool {
+        u32.0 % 2 == 1
+    }
+
+    fn is_paused() -> bool {
+        false
+    }
+
+    fn increment(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+
+    fn render() {}
+
+    fn noop() {}
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("Frame")
+        .with_system(render)
+        .with_workload(
+            Workload::new("Odd")
+                .with_system(noop.skip_if(is_paused))
+                .run_if(is_odd),
+        )
+        .skip_if(is_paused)
+        .add_to_world(&world)
+        .unwrap();
+
+    for _ in 0..3 {
+        world.run_workload("Frame").unwrap();
+        world.run(increment);
+    }
+
+    let workloads_info = world.workloads_info();
+    let workload_info = workloads_info.0.values().next().unwrap();
+
+    assert_eq!(workload_info.run_if.len(), 1);
+    assert!(workload_info.run_if[0].name.ends_with("is_paused"));
+    assert!(workload_info.run_if[0].skip);
+    assert_eq!(workload_info.run_if[0].evaluated_true(), 0);
+    assert_eq!(workload_info.run_if[0].evaluated_false(), 3);
+
+    let render_info = workload_info
+        .batch_info
+        .iter()
+        .flat_map(|batch| batch.systems())
+        .find(|system| system.name.contains("render"))
+        .unwrap();
+    assert!(render_info.run_if.is_empty());
+
+    let noop_info = workload_info
+        .batch_info
+        .iter()
+        .flat_map(|batch| batch.systems())
+        .find(|system| system.name.contains("noop"))
+        .unwrap();
+
+    // Conditions of the merged workload are evaluated first
+    assert_eq!(noop_info.run_if.len(), 2);
+    assert!(noop_info.run_if[0].name.ends_with("is_odd"));
+    assert!(!noop_info.run_if[0].skip);
+    assert_eq!(noop_info.run_if[0].evaluated_true(), 1);
+    assert_eq!(noop_info.run_if[0].evaluated_false(), 2);
+    assert!(noop_info.run_if[1].name.ends_with("is_paused"));
+    assert!(noop_info.run_if[1].skip);
+    assert_eq!(noop_info.run_if[1].evaluated_true(), 0);
+    assert_eq!(noop_info.run_if[1].evaluated_false(), 1);
+}
+
+#[test]
+fn replace_workload() {
+    fn set_1(mut u32: UniqueViewMut<U32>) {
+        u32.0 = 1;
+    }
+
+    fn set_2(mut u32: UniqueViewMut<U32>) {
+        u32.0 = 2;
+    }
+
+    fn set_3(mut u32: UniqueViewMut<U32>) {
+        u32.0 = 3;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("Default")
+        .with_system(set_1)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("Other")
+        .with_system(set_3)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(
+        world.replace_workload("Missing", Workload::new("").with_system(set_2)),
+        Err(error::AddWorkload::MissingWorkload)
+    );
+    assert!(!world.contains_workload("Missing"));
+
+    world
+        .replace_workload("Default", Workload::new("").with_system(set_2))
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&U32>().unwrap().0, 2);
+
+    world.run_workload("Other").unwrap();
+    assert_eq!(world.get_unique::<&U32>().unwrap().0, 3);
+
+    let workloads_info = world.workloads_info();
+    assert_eq!(workloads_info.0.len(), 2);
+    assert!(workloads_info.0.values().any(|workload_info| {
+        workload_info
+            .batch_info
+            .iter()
+            .flat_map(|batch| batch.systems())
+            .any(|system| system.name.contains("set_2"))
+    }));
+    assert!(!workloads_info.0.values().any(|workload_info| {
+        workload_info
+            .batch_info
+            .iter()
+            .flat_map(|batch| batch.systems())
+            .any(|system| system.name.contains("set_1"))
+    }));
+}
+
+#[test]
+fn replace_workload_keeps_statistics() {
+    fn is_set(u32: UniqueView<U32>) -> bool {
+        u32.0 != 0
+    }
+
+    fn set_1(mut u32: UniqueViewMut<U32>) {
+        u32.0 = 1;
+    }
+
+    fn set_2(mut u32: UniqueViewMut<U32>) {
+        u32.0 = 2;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("Default")
+        .with_system(set_1.skip_if(is_set))
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+    world.run_default_workload().unwrap();
+
+    world
+        .replace_workload(
+            "Default",
+            Workload::new("")
+                .with_system(set_1.skip_if(is_set))
+                .with_system(set_2.run_if(is_set)),
+        )
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    let workloads_info = world.workloads_info();
+    let systems = workloads_info.0["Default"]
+        .batch_info
+        .iter()
+        .flat_map(|batch| batch.systems())
+        .collect::<Vec<_>>();
+
+    assert!(systems[0].name.ends_with("set_1"));
+    assert_eq!(systems[0].run_if[0].evaluated_true(), 2);
+    assert_eq!(systems[0].run_if[0].evaluated_false(), 1);
+    assert!(systems[1].name.ends_with("set_2"));
+    assert_eq!(systems[1].run_if[0].evaluated_true(), 1);
+    assert_eq!(systems[1].run_if[0].evaluated_false(), 0);
+}
+
+#[test]
+fn validate() {
+    fn sys(_: View<USIZE>, _: UniqueView<U32>) {}
+
+    let world = World::new();
+
+    let workload = Workload::new("")
+        .with_system(sys)
+        .with_system(|_: Option<UniqueView<USIZE>>| {})
+        .with_system((|| {}).run_if(|_: UniqueView<USIZE>| panic!()));
+
+    let errors = workload.validate(&world).unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        &errors[0],
+        error::Validation::MissingUnique { type_info, .. } if type_info.name.contains("U32")
+    ));
+
+    world.add_unique(U32(0));
+
+    workload.validate(&world).unwrap();
+}
+
+#[cfg(feature = "thread_local")]
+#[test]
+fn validate_non_send() {
+    let world = World::new();
+
+    let workload = Workload::new("").with_system(|_: NonSendSync<UniqueViewMut<U32>>| {});
+
+    let errors = workload.validate(&world).unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        &errors[0],
+        error::Validation::MissingUnique { type_info, .. } if type_info.name.contains("U32")
+    ));
+
+    world.add_unique_non_send_sync(U32(0));
+
+    workload.validate(&world).unwrap();
+}
+
+#[test]
+fn insert_remove_system() {
+    fn a(_: View<USIZE>) {}
+    fn b(_: View<USIZE>) {}
+    fn c(_: View<USIZE>) {}
+    fn d(_: View<USIZE>) {}
+
+    let (_, info) = Workload::new("")
+        .with_system(a)
+        .with_system(b.tag("debug"))
+        .with_barrier()
+        .with_system(c)
+        .insert_system_at(1, d)
+        .remove_system("debug")
+        .build()
+        .unwrap();
+
+    let batches = info
+        .batch_info
+        .iter()
+        .map(|batch| {
+            batch
+                .systems()
+                .map(|system| system.name.rsplit("::").next().unwrap().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(batches, vec![vec!["a", "d"], vec!["c"]]);
+}
+
+#[test]
+fn skip_if_no_inserted_modified() {
+    struct Tracked(u32);
+    impl Component for Tracked {
+        type Tracking = track::InsertionAndModification;
+    }
+
+    struct Runs(u32, u32);
+    impl Unique for Runs {}
+
+    fn on_insert(mut runs: UniqueViewMut<Runs>) {
+        runs.0 += 1;
+    }
+
+    fn on_modify(mut runs: UniqueViewMut<Runs>) {
+        runs.1 += 1;
+    }
+
+    let mut world = World::new();
+    world.add_unique(Runs(0, 0));
+
+    Workload::new("")
+        .with_system(on_insert.skip_if_no_inserted::<Tracked>())
+        .with_system(on_modify.skip_if_no_modified::<Tracked>())
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 0);
+    assert_eq!(world.get_unique::<&Runs>().unwrap().1, 0);
+
+    let entity = world.add_entity(Tracked(0));
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 1);
+    assert_eq!(world.get_unique::<&Runs>().unwrap().1, 0);
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 1);
+
+    world.get::<&mut Tracked>(entity).unwrap().0 += 1;
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 1);
+    assert_eq!(world.get_unique::<&Runs>().unwrap().1, 1);
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().1, 1);
+}
+
+#[test]
+fn reactive_to() {
+    struct Runs(u32);
+    impl Unique for Runs {}
+
+    fn reactive(mut runs: UniqueViewMut<Runs>, mut u32s: ViewMut<U32>) {
+        runs.0 += 1;
+
+        for u32 in (&mut u32s).iter() {
+            u32.0 += 1;
+        }
+    }
+
+    let mut world = World::new();
+    world.add_unique(Runs(0));
+
+    Workload::new("")
+        .with_system(reactive.reactive_to::<(U32, USIZE)>())
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 0);
+
+    let entity = world.add_entity(U32(0));
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 1);
+
+    // the system's own modifications don't trigger it
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 1);
+
+    world.add_component(entity, USIZE(0));
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 2);
+
+    world.get::<&mut USIZE>(entity).unwrap().0 += 1;
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 3);
+
+    world.delete_component::<(USIZE,)>(entity);
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 4);
+
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 4);
+
+    // an exclusive borrow might modify components
+    world.run(|_: ViewMut<USIZE>| {});
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 5);
+
+    world.run(|_: View<USIZE>| {});
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Runs>().unwrap().0, 5);
+
+    // tracking is left to the user
+    let usizes = world.borrow::<View<USIZE>>().unwrap();
+    assert!(!usizes.is_tracking_insertion());
+    assert!(!usizes.is_tracking_modification());
+    assert!(!usizes.is_tracking_deletion());
+}
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn max_threads() {
+    use std::sync::Mutex;
+    use std::thread::ThreadId;
+
+    struct ThreadIds(Mutex<Vec<ThreadId>>);
+    impl Unique for ThreadIds {}
+
+    fn push_thread_id(thread_ids: &ThreadIds) {
+        thread_ids
+            .0
+            .lock()
+            .unwrap()
+            .push(std::thread::current().id());
+    }
+
+    fn sys1(thread_ids: UniqueView<ThreadIds>) {
+        push_thread_id(&thread_ids);
+    }
+    fn sys2(thread_ids: UniqueView<ThreadIds>) {
+        push_thread_id(&thread_ids);
+    }
+    fn sys3(thread_ids: UniqueView<ThreadIds>) {
+        push_thread_id(&thread_ids);
+    }
+    fn sys4(thread_ids: UniqueView<ThreadIds>) {
+        push_thread_id(&thread_ids);
+    }
+
+    let world = World::new();
+    world.add_unique(ThreadIds(Mutex::new(Vec::new())));
+
+    Workload::new("")
+        .with_system(sys1)
+        .with_system(sys2)
+        .with_system(sys3)
+        .with_system(sys4)
+        .with_max_threads(1)
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+
+    let thread_ids = world
+        .remove_unique::<ThreadIds>()
+        .unwrap()
+        .0
+        .into_inner()
+        .unwrap();
+    assert_eq!(thread_ids.len(), 4);
+    assert!(thread_ids
+        .iter()
+        .all(|thread_id| *thread_id == thread_ids[0]));
+}
+
+#[test]
+fn clear_tracking_after() {
+    struct Tracked(u32);
+    impl Component for Tracked {
+        type Tracking = track::InsertionAndModification;
+    }
+
+    struct Other;
+    impl Component for Other {
+        type Tracking = track::InsertionAndModification;
+    }
+
+    struct Seen(u32);
+    impl Unique for Seen {}
+
+    fn count(tracked: View<Tracked>, mut seen: UniqueViewMut<Seen>) {
+        seen.0 += tracked.inserted_or_modified().iter().count() as u32;
+    }
+
+    let mut world = World::new();
+    world.add_unique(Seen(0));
+
+    Workload::new("")
+        .clear_tracking_after::<(Tracked, Other)>()
+        .with_system(count)
+        .add_to_world(&world)
+        .unwrap();
+
+    let entity = world.add_entity((Tracked(0), Other));
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&Seen>().unwrap().0, 1);
+
+    assert!(!world.borrow::<View<Tracked>>().unwrap().is_inserted(entity));
+    assert!(!world.borrow::<View<Other>>().unwrap().is_inserted(entity));
+
+    world.get::<&mut Tracked>(entity).unwrap().0 += 1;
+    assert!(world.borrow::<View<Tracked>>().unwrap().is_modified(entity));
+
+    world.run_default_workload().unwrap();
+    assert!(!world.borrow::<View<Tracked>>().unwrap().is_modified(entity));
+}
+
+#[test]
+fn clear_tracking_after_sub_workload() {
+    struct Tracked;
+    impl Component for Tracked {
+        type Tracking = track::Insertion;
+    }
+
+    struct Other;
+    impl Component for Other {
+        type Tracking = track::Insertion;
+    }
+
+    let mut world = World::new();
+
+    let skipped = Workload::new("Skipped")
+        .clear_tracking_after::<Other>()
+        .skip_if(|| true);
+    let mut appended = Workload::new("Appended").clear_tracking_after::<Tracked>();
+
+    let (_, info) = Workload::new("")
+        .with_workload(skipped)
+        .append(&mut appended)
+        .build()
+        .unwrap();
+
+    // the clearing systems don't borrow the same storages
+    assert_eq!(info.batch_info.len(), 1);
+    assert_eq!(info.batch_info[0].systems().count(), 2);
+
+    Workload::new("")
+        .with_workload(
+            Workload::new("Skipped")
+                .clear_tracking_after::<Other>()
+                .skip_if(|| true),
+        )
+        .append(&mut Workload::new("Appended").clear_tracking_after::<Tracked>())
+        .add_to_world(&world)
+        .unwrap();
+
+    let entity = world.add_entity((Tracked, Other));
+    world.run_default_workload().unwrap();
+
+    assert!(!world.borrow::<View<Tracked>>().unwrap().is_inserted(entity));
+    assert!(world.borrow::<View<Other>>().unwrap().is_inserted(entity));
+}
+
+#[test]
+fn option_system() {
+    fn first(u32: UniqueView<U32>) -> Option<u32> {
+        u32.0.checked_sub(1)
+    }
+
+    fn increment(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("")
+        .with_option_system(first)
+        .with_system(increment)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert!(world.run_default_workload().is_err());
+    assert_eq!(world.get_unique::<&U32>().unwrap().0, 0);
+
+    world.get_unique::<&mut U32>().unwrap().0 = 1;
+    world.run_default_workload().unwrap();
+    assert_eq!(world.get_unique::<&U32>().unwrap().0, 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn timeout() {
+    fn slow(mut u32: UniqueViewMut<U32>) {
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        u32.0 += 1;
+    }
+
+    fn increment(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("")
+        .with_system(slow)
+        .with_system(increment)
+        .add_to_world(&world)
+        .unwrap();
+
+    match world.try_run_workload_with_timeout("", std::time::Duration::from_millis(1)) {
+        Err(error::RunWorkload::Timeout(skipped)) => {
+            assert_eq!(skipped.len(), 1);
+            assert!(format!("{:?}", skipped[0]).contains("increment"));
+        }
+        result => panic!("{:?}", result),
+    }
+    assert_eq!(world.get_unique::<&U32>().unwrap().0, 1);
+
+    world
+        .try_run_workload_with_timeout("", std::time::Duration::from_secs(60))
+        .unwrap();
+    assert_eq!(world.get_unique::<&U32>().unwrap().0, 3);
+}
+
+#[cfg(all(feature = "std", feature = "parallel"))]
+#[test]
+fn timeout_inside_batch() {
+    fn slow(_: UniqueView<U32>) {
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+
+    fn fast(_: UniqueView<USIZE>) {}
+
+    let world = World::new();
+    world.add_unique(U32(0));
+    world.add_unique(USIZE(0));
+
+    // both systems are in the same batch but run one after the other
+    Workload::new("")
+        .with_system(slow)
+        .with_system(fast)
+        .with_max_threads(1)
+        .add_to_world(&world)
+        .unwrap();
+
+    match world.try_run_workload_with_timeout("", std::time::Duration::from_millis(1)) {
+        Err(error::RunWorkload::Timeout(skipped)) => {
+            assert_eq!(skipped.len(), 1);
+            assert!(format!("{:?}", skipped[0]).contains("fast"));
+        }
+        result => panic!("{:?}", result),
+    }
+}
+
+#[test]
+fn add_and_when() {
+    fn a(_: View<USIZE>) {}
+    fn b(_: ViewMut<USIZE>) {}
+    fn c(_: View<USIZE>) {}
+
+    let build = |is_server: bool| {
+        let (_, info) = (Workload::new("Shared").with_system(a)
+            + Workload::new("Variant").with_system(b))
+        .when(!is_server, |workload| workload.with_system(c))
+        .build()
+        .unwrap();
+
+        assert!(info.name.contains("Shared"));
+
+        info.batch_info
+            .iter()
+            .map(|batch| {
+                batch
+                    .systems()
+                    .map(|system| system.name.rsplit("::").next().unwrap().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(build(true), vec![vec!["a"], vec!["b"]]);
+    assert_eq!(build(false), vec![vec!["a"], vec!["b"], vec!["c"]]);
+}
+
+#[test]
+fn label_namespaces() {
+    fn add_1(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 1;
+    }
+
+    fn add_10(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 10;
+    }
+
+    fn add_100(mut u32: UniqueViewMut<U32>) {
+        u32.0 += 100;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    Workload::new("render/shadows")
+        .with_system(add_1)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new(String::from("render/ui"))
+        .with_system(add_10)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("rendering")
+        .with_system(add_100)
+        .add_to_world(&world)
+        .unwrap();
+
+    assert_eq!(
+        Workload::new(String::from("render/shadows"))
+            .with_system(add_100)
+            .add_to_world(&world),
+        Err(error::AddWorkload::AlreadyExists)
+    );
+
+    world.run_workloads_under("render").unwrap();
+    assert_eq!(world.get_unique::<&U32>().unwrap().0, 11);
+
+    world.run_workloads_under("render/ui/").unwrap();
+    assert_eq!(world.get_unique::<&U32>().unwrap().0, 21);
+
+    assert!(matches!(
+        world.run_workloads_under("physics"),
+        Err(error::RunWorkload::MissingWorkload)
+    ));
+}
+
+#[test]
+fn workload_data() {
+    fn read_dt(dt: WorkloadData<f32>, u32: UniqueView<U32>) {
+        assert_eq!(*dt, u32.0 as f32 / 2.0);
+    }
+
+    fn read_dt_again(dt: WorkloadData<f32>) {
+        assert!(*dt > 0.0);
+    }
+
+    fn fail_on_large_dt(dt: WorkloadData<f32>) -> Result<(), String> {
+        if *dt > 10.0 {
+            Err(format!("dt too large: {}", *dt))
+        } else {
+            Ok(())
+        }
+    }
+
+    let world = World::new();
+    world.add_unique(U32(1));
+
+    Workload::new("Read")
+        .with_system(read_dt)
+        .with_system(read_dt_again)
+        .add_to_world(&world)
+        .unwrap();
+    Workload::new("Fail")
+        .with_try_system(fail_on_large_dt)
+        .add_to_world(&world)
+        .unwrap();
+
+    let workloads_info = world.workloads_info();
+    assert_eq!(workloads_info.0["Read"].batch_info.len(), 1);
+
+    world.run_workload_with_data("Read", 0.5f32).unwrap();
+
+    assert!(world.run_workload_with_data("Fail", 20.0f32).is_err());
+    world.run_workload_with_data("Fail", 1.0f32).unwrap();
+
+    assert!(world.borrow::<WorkloadData<f32>>().is_err());
+    assert!(matches!(
+        world.run_workload("Read"),
+        Err(error::RunWorkload::Run(_))
+    ));
+}
+
+#[test]
+fn workload_data_removed_on_panic() {
+    fn panic_on_large_dt(dt: WorkloadData<f32>) {
+        assert!(*dt < 10.0);
+    }
+
+    let world = World::new();
+
+    Workload::new("Update")
+        .with_system(panic_on_large_dt)
+        .add_to_world(&world)
+        .unwrap();
+
+    let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _ = world.run_workload_with_data("Update", 20.0f32);
+    }));
+    assert!(panic.is_err());
+
+    assert!(world.borrow::<WorkloadData<f32>>().is_err());
+    world.run_workload_with_data("Update", 1.0f32).unwrap();
+}
+
+#[test]
+fn from_fn_with_borrows() {
+    fn type_info(mutability: Mutability) -> info::TypeInfo {
+        info::TypeInfo {
+            name: type_name::<U32>().into(),
+            mutability,
+            storage_id: StorageId::of::<UniqueStorage<U32>>(),
+            thread_safe: true,
+            optional: false,
+        }
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+
+    let increment = WorkloadSystem::from_fn_with_borrows(
+        "increment",
+        |world: &World| {
+            world.borrow::<UniqueViewMut<U32>>()?.0 += 1;
+
+            Ok(())
+        },
+        vec![type_info(Mutability::Exclusive)],
+    )
+    .unwrap();
+    let check = WorkloadSystem::from_fn_with_borrows(
+        String::from("check"),
+        |world: &World| {
+            assert_eq!(world.borrow::<UniqueView<U32>>()?.0, 1);
+
+            Ok(())
+        },
+        vec![type_info(Mutability::Shared)],
+    )
+    .unwrap();
+
+    Workload::new("Dynamic")
+        .with_system(increment)
+        .with_system(check)
+        .add_to_world(&world)
+        .unwrap();
+
+    let workloads_info = world.workloads_info();
+    let batch_info = &workloads_info.0["Dynamic"].batch_info;
+    assert_eq!(batch_info.len(), 2);
+    assert_eq!(
+        batch_info[1].systems().next().unwrap().borrow,
+        vec![type_info(Mutability::Shared)]
+    );
+
+    world.run_workload("Dynamic").unwrap();
+
+    assert_eq!(
+        WorkloadSystem::from_fn_with_borrows(
+            "invalid",
+            |_: &World| Ok(()),
+            vec![
+                type_info(Mutability::Exclusive),
+                type_info(Mutability::Exclusive)
+            ],
+        )
+        .err(),
+        Some(error::InvalidSystem::MultipleViewsMut)
+    );
+}
+
+fn read_u32(_: View<U32>) {}
+fn write_u32(_: ViewMut<U32>) {}
+fn read_usize(_: View<USIZE>) {}
+
+fn ordering_world() -> World {
+    let world = World::new();
+
+    Workload::new("Ordering")
+        .with_system(read_u32)
+        .with_system(read_usize)
+        .with_system(write_u32)
+        .add_to_world(&world)
+        .unwrap();
+
+    world
+}
+
+#[test]
+fn ordering_assertions() {
+    let world = ordering_world();
+    let workloads_info = world.workloads_info();
+    let info = &workloads_info.0["Ordering"];
+
+    assert_eq!(info.batches_of("read_u32"), [0]);
+    assert!(info.batches_of("missing").is_empty());
+
+    info.assert_runs_before("read_u32", "write_u32");
+    info.assert_in_same_batch("read_u32", "read_usize");
+    info.assert_not_in_same_batch("read_usize", "write_u32");
+}
+
+#[test]
+#[should_panic(expected = "Expected write_u32 to run before read_u32")]
+fn ordering_assertion_failure() {
+    let world = ordering_world();
+
+    world.workloads_info().0["Ordering"].assert_runs_before("write_u32", "read_u32");
+}
+
+#[test]
+#[should_panic(expected = "System missing is not part of workload")]
+fn ordering_assertion_missing_system() {
+    let world = ordering_world();
+
+    world.workloads_info().0["Ordering"].assert_in_same_batch("read_u32", "missing");
+}
+
+#[test]
+fn produced_uniques() {
+    fn create_u32(all_storages: AllStoragesViewMut) {
+        let usize = all_storages.borrow::<UniqueView<USIZE>>().unwrap().0;
+
+        all_storages.add_unique(U32(usize as u32 + 1));
+    }
+
+    fn create_usize(all_storages: AllStoragesViewMut) {
+        all_storages.add_unique(USIZE(1));
+    }
+
+    fn check(u32: UniqueView<U32>) {
+        assert_eq!(u32.0, 2);
+    }
+
+    let world = World::new();
+
+    Workload::new("Setup")
+        .with_system(check)
+        .with_system(create_u32.produces::<U32>().consumes::<USIZE>())
+        .with_system(create_usize.produces::<USIZE>())
+        .add_to_world(&world)
+        .unwrap();
+
+    let workloads_info = world.workloads_info();
+    let info = &workloads_info.0["Setup"];
+    info.assert_runs_before("create_usize", "create_u32");
+    info.assert_runs_before("create_u32", "check");
+
+    world.run_workload("Setup").unwrap();
+}
+
+#[test]
+fn produced_uniques_cycle() {
+    fn create_u32() {}
+    fn create_usize() {}
+
+    let result = Workload::new("Setup")
+        .with_system(create_u32.produces::<U32>().consumes::<USIZE>())
+        .with_system(create_usize.produces::<USIZE>().consumes::<U32>())
+        .add_to_world(&World::new());
+
+    assert!(matches!(
+        result,
+        Err(error::AddWorkload::ImpossibleRequirements(_))
+    ));
+}
+
+#[test]
+fn prefetch() {
+    struct Missing;
+    impl Component for Missing {
+        type Tracking = track::Untracked;
+    }
+
+    fn add(mut usizes: ViewMut<USIZE>, u32s: View<U32>) {
+        for (usize, u32) in (&mut usizes, &u32s).iter() {
+            usize.0 += u32.0 as usize;
+        }
+    }
+
+    fn check(usizes: View<USIZE>) {
+        assert_eq!(usizes.iter().map(|usize| usize.0).sum::<usize>(), 6);
+    }
+
+    let mut world = World::new();
+
+    world.add_entity((USIZE(0), U32(1)));
+    world.add_entity((USIZE(2), U32(3)));
+
+    Workload::new("")
+        .with_system(add.prefetch::<(USIZE, U32)>())
+        // missing storages are skipped
+        .with_system(check.prefetch::<(USIZE, Missing)>())
+        .add_to_world(&world)
+        .unwrap();
+
+    world.start_profiling();
+    world.run_default_workload().unwrap();
+    let profile = world.stop_profiling();
+
+    let events = profile.events();
+    let prefetches = events
+        .iter()
+        .filter(|event| event.kind == ProfileEventKind::Prefetch)
+        .collect::<Vec<_>>();
+
+    assert_eq!(prefetches.len(), 2);
+    for prefetch in prefetches {
+        let system = events
+            .iter()
+            .find(|event| event.kind == ProfileEventKind::System && event.name == prefetch.name)
+            .unwrap();
+
+        // storages are read by the thread running the system, before it runs
+        assert_eq!(prefetch.thread, system.thread);
+        assert!(prefetch.start + prefetch.duration <= system.start);
+    }
+}
+
+#[test]
+fn conditions() {
+    fn is_odd(u32: UniqueView<U32>) -> bool {
+        u32.0 % 2 == 1
+    }
+
+    fn is_big(u32: UniqueView<U32>) -> bool {
+        u32.0 > 2
+    }
+
+    fn both(mut usize: UniqueViewMut<USIZE>) {
+        usize.0 += 1;
+    }
+
+    fn either(mut usize: UniqueViewMut<USIZE>) {
+        usize.0 += 10;
+    }
+
+    fn even(mut usize: UniqueViewMut<USIZE>) {
+        usize.0 += 100;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+    world.add_unique(USIZE(0));
+
+    let condition = is_odd.and(is_big);
+    assert!(condition.name().starts_with('('));
+    assert!(condition.name().contains("is_odd && "));
+    assert!(condition.name().ends_with("is_big)"));
+    assert!(not(is_odd).name().starts_with('!'));
+
+    world.add_workload(move || {
+        (
+            both.run_if(condition.clone()),
+            either.run_if(is_odd.or(is_big)),
+            even.run_if(not(is_odd)),
+        )
+    });
+
+    // 0, 1, 2, 3
+    for _ in 0..4 {
+        world.run_default_workload().unwrap();
+        world.run(|mut u32: UniqueViewMut<U32>| u32.0 += 1);
+    }
+
+    assert_eq!(world.get_unique::<&USIZE>().unwrap().0, 1 + 20 + 200);
+}
+
+#[test]
+fn condition_evaluated_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn sys1() {}
+    fn sys2() {}
+    fn sys3() {}
+
+    let evaluations = Arc::new(AtomicUsize::new(0));
+    let count = evaluations.clone();
+    let condition = Condition::new(move || {
+        count.fetch_add(1, Ordering::Relaxed);
+
+        true
+    });
+
+    let world = World::new();
+    Workload::new("")
+        .with_system(sys1.run_if(condition.clone()))
+        .with_system(sys2.skip_if(not(condition.clone())))
+        .with_workload(Workload::new("Nested").with_system(sys3).run_if(condition))
+        .add_to_world(&world)
+        .unwrap();
+
+    world.run_default_workload().unwrap();
+    world.run_default_workload().unwrap();
+
+    assert_eq!(evaluations.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn run_if_tagged() {
+    fn is_odd(u32: UniqueView<U32>) -> bool {
+        u32.0 % 2 == 1
+    }
+
+    fn physics(mut usize: UniqueViewMut<USIZE>) {
+        usize.0 += 1;
+    }
+
+    fn ai(mut usize: UniqueViewMut<USIZE>) {
+        usize.0 += 10;
+    }
+
+    fn render(mut usize: UniqueViewMut<USIZE>) {
+        usize.0 += 100;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+    world.add_unique(USIZE(0));
+
+    Workload::new("Frame")
+        .with_workload(Workload::new("Simulation").with_system(physics))
+        .with_system(ai.tag("Simulation"))
+        .with_system(render)
+        .run_if_tagged("Simulation", is_odd)
+        .skip_if_tagged(render, is_odd)
+        .add_to_world(&world)
+        .unwrap();
+
+    // 0, 1, 2
+    for _ in 0..3 {
+        world.run_default_workload().unwrap();
+        world.run(|mut u32: UniqueViewMut<U32>| u32.0 += 1);
+    }
+
+    assert_eq!(world.get_unique::<&USIZE>().unwrap().0, 11 + 200);
+
+    let workloads_info = world.workloads_info();
+    let physics_info = workloads_info
+        .0
+        .values()
+        .next()
+        .unwrap()
+        .batch_info
+        .iter()
+        .flat_map(|batch| batch.systems())
+        .find(|system| system.name.contains("physics"))
+        .unwrap();
+
+    assert_eq!(physics_info.run_if.len(), 1);
+    assert!(physics_info.run_if[0].name.ends_with("is_odd"));
+    assert_eq!(physics_info.run_if[0].evaluated_true(), 1);
+    assert_eq!(physics_info.run_if[0].evaluated_false(), 2);
+}
+
+#[test]
+fn run_if_tagged_before_systems() {
+    fn is_odd(u32: UniqueView<U32>) -> bool {
+        u32.0 % 2 == 1
+    }
+
+    fn physics(mut usize: UniqueViewMut<USIZE>) {
+        usize.0 += 1;
+    }
+
+    fn render(mut usize: UniqueViewMut<USIZE>) {
+        usize.0 += 100;
+    }
+
+    let world = World::new();
+    world.add_unique(U32(0));
+    world.add_unique(USIZE(0));
+
+    // the conditions are applied when the workload is built
+    Workload::new("Frame")
+        .skip_if_tagged("Simulation", is_odd)
+        .with_system(physics.tag("Simulation"))
+        .with_workload(
+            Workload::new("Inner")
+                .run_if_tagged("Render", is_odd)
+                .with_system(render.tag("Render")),
+        )
+        .add_to_world(&world)
+        .unwrap();
+
+    // 0, 1, 2
+    for _ in 0..3 {
+        world.run_default_workload().unwrap();
+        world.run(|mut u32: UniqueViewMut<U32>| u32.0 += 1);
+    }
+
+    assert_eq!(world.get_unique::<&USIZE>().unwrap().0, 2 + 100);
+}
@@ -108,3 +108,35 @@ fn dead_entity() {
     let u32s = world.borrow::<View<U32>>().unwrap();
     assert!(u32s.get(entity).is_err());
 }
+
+#[test]
+fn bulk_add_component() {
+    #[derive(Component, PartialEq, Eq, Debug)]
+    #[track(Insertion)]
+    struct USIZE(usize);
+
+    let mut world = World::new();
+
+    let entities: Vec<_> = (0..4).map(|_| world.add_entity(())).collect();
+    world.add_component(entities[2], (USIZE(100),));
+
+    world.bulk_add_component(
+        entities
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, &entity)| (entity, (USIZE(i),))),
+    );
+
+    world.run(|usizes: View<USIZE>| {
+        assert_eq!(
+            usizes.iter().collect::<Vec<_>>(),
+            [&USIZE(1), &USIZE(0), &USIZE(2), &USIZE(3)]
+        );
+
+        let inserted_at = usizes.insertion_timestamp(entities[3]).unwrap();
+        for &entity in &entities[..2] {
+            assert_eq!(usizes.insertion_timestamp(entity), Some(inserted_at));
+        }
+    });
+}
@@ -172,3 +172,33 @@ fn bulk() {
     assert_eq!(u32s.len(), 10);
     assert_eq!(usizes.len(), 10);
 }
+
+#[test]
+fn add_entity_mut() {
+    #[derive(PartialEq, Eq, Debug)]
+    struct USIZE(usize);
+    impl Component for USIZE {
+        type Tracking = track::Untracked;
+    }
+
+    let mut world = World::new();
+
+    let mut entity = world.add_entity_mut((U32(0),));
+    let id = entity.id();
+
+    entity.add_component((USIZE(1),));
+    **entity.get::<&mut U32>().unwrap() = U32(2);
+
+    {
+        let (u32s, usizes) = entity.get::<(&U32, &USIZE)>().unwrap();
+        assert_eq!(*u32s, &U32(2));
+        assert_eq!(*usizes, &USIZE(1));
+    }
+
+    assert_eq!(entity.remove::<(USIZE,)>(), (Some(USIZE(1)),));
+    entity.delete_component::<(U32,)>();
+
+    assert!(entity.get::<&U32>().is_err());
+    assert!(world.is_entity_alive(id));
+    assert!(world.get::<&U32>(id).is_err());
+}
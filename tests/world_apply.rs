@@ -0,0 +1,51 @@
+use shipyard::*;
+
+#[derive(Component, Debug, PartialEq, Eq)]
+struct U32(u32);
+
+#[test]
+fn apply() {
+    let mut world = World::new();
+
+    let entity = world.add_entity(U32(0));
+
+    let (deleted, added) = world.apply(|all_storages| {
+        let added = all_storages.add_entity(U32(1));
+
+        (all_storages.delete_entity(entity), added)
+    });
+
+    assert!(deleted);
+    assert!(!world.is_entity_alive(entity));
+    assert_eq!(world.get::<&U32>(added).unwrap().0, 1);
+}
+
+#[test]
+fn reentrant() {
+    let world = World::new();
+
+    world.apply(|_| {
+        let err = world.try_apply(|_| {}).unwrap_err();
+
+        assert_eq!(err.borrow, error::Borrow::Unique);
+        assert_eq!(err.held_at.unwrap().file(), file!());
+        assert_eq!(err.held_at.unwrap().line(), line!() - 5);
+    });
+
+    // the location is cleared with the borrow
+    world.run(|_: AllStoragesViewMut| {
+        let err = world.try_apply(|_| {}).unwrap_err();
+
+        assert_eq!(err.held_at, None);
+    });
+
+    assert!(world.try_apply(|_| {}).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "AllStorages is already borrowed by World::apply at")]
+fn reentrant_panic() {
+    let world = World::new();
+
+    world.apply(|_| world.apply(|_| {}));
+}
@@ -0,0 +1,78 @@
+use shipyard::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Mesh(u32);
+impl Component for Mesh {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct U32(u32);
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[derive(Clone)]
+struct Count(u32);
+impl Unique for Count {}
+
+fn increment(mut count: UniqueViewMut<Count>) {
+    count.0 += 1;
+}
+
+#[test]
+fn template() {
+    let assets = World::new();
+
+    let template = World::builder()
+        .share_storage::<Mesh>(&assets)
+        .with_tracking::<U32>(track::InsertionAndModification)
+        .with_unique(Count(0))
+        .with_workload(|| increment);
+
+    let room1 = template.clone().build();
+    let room2 = template.build();
+
+    room1.run_default_workload().unwrap();
+    room1.run_default_workload().unwrap();
+    room2.run_default_workload().unwrap();
+
+    assert_eq!(room1.get_unique::<&Count>().unwrap().0, 2);
+    assert_eq!(room2.get_unique::<&Count>().unwrap().0, 1);
+
+    room1.run(|u32s: View<U32>| {
+        assert!(u32s.is_tracking_insertion());
+        assert!(u32s.is_tracking_modification());
+        assert!(!u32s.is_tracking_deletion());
+    });
+
+    let mesh = assets.run(|mut entities: EntitiesViewMut, mut meshes: ViewMut<Mesh>| {
+        entities.add_entity(&mut meshes, Mesh(0))
+    });
+    room1.run(|meshes: View<Mesh>| assert_eq!(meshes.get(mesh), Ok(&Mesh(0))));
+    room2.run(|meshes: View<Mesh>| assert_eq!(meshes.get(mesh), Ok(&Mesh(0))));
+}
+
+#[cfg(feature = "parallel")]
+#[cfg_attr(miri, ignore)]
+#[test]
+fn thread_pool() {
+    let template = World::builder().with_thread_pool(|| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap()
+    });
+
+    let mut room1 = template.clone().build();
+    let mut room2 = template.build();
+
+    assert_eq!(
+        room1
+            .remove_local_thread_pool()
+            .unwrap()
+            .current_num_threads(),
+        1
+    );
+    assert!(room2.remove_local_thread_pool().is_some());
+}
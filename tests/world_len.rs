@@ -0,0 +1,48 @@
+use shipyard::*;
+
+struct U32;
+impl Component for U32 {
+    type Tracking = track::Untracked;
+}
+
+#[test]
+fn entity_count() {
+    let mut world = World::new();
+
+    assert_eq!(world.entity_count(), 0);
+
+    let entity0 = world.add_entity(());
+    let entity1 = world.add_entity((U32,));
+    world.add_entity((U32,));
+    assert_eq!(world.entity_count(), 3);
+
+    world.delete_entity(entity0);
+    world.delete_entity(entity1);
+    assert_eq!(world.entity_count(), 1);
+
+    world.add_entity(());
+    assert_eq!(world.entity_count(), 2);
+
+    world.clear();
+    assert_eq!(world.entity_count(), 0);
+}
+
+#[test]
+fn storage_len() {
+    let mut world = World::new();
+
+    assert_eq!(world.storage_len::<U32>(), 0);
+    assert!(world.is_storage_empty::<U32>());
+
+    let entity = world.add_entity((U32,));
+    world.add_entity((U32,));
+    assert_eq!(world.storage_len::<U32>(), 2);
+
+    world.delete_component::<U32>(entity);
+    assert_eq!(world.storage_len::<U32>(), 1);
+    assert!(!world.is_storage_empty::<U32>());
+
+    // a view can be held at the same time
+    let u32s = world.borrow::<View<U32>>().unwrap();
+    assert_eq!(world.storage_len::<U32>(), u32s.len());
+}